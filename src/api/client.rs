@@ -1,26 +1,78 @@
 //! Bilibili API Client with cookie management and WBI signing
 
+use super::error::{ApiError, ApiErrorKind};
+use super::rate_limit::RateLimiter;
+use super::stats::{self, HttpStats, HttpStatsSnapshot};
+use super::transport::{self, HttpRequest, HttpResponse, Transport};
 use super::wbi;
-use crate::storage::Credentials;
+use crate::storage::{ApiHosts, Credentials};
 use anyhow::{anyhow, Result};
-use reqwest::header::{HeaderMap, HeaderValue, COOKIE, REFERER, USER_AGENT};
-use reqwest::Client;
 use serde::Deserialize;
-use std::sync::RwLock;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Instant;
 
-const UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+/// Host overrides from config, set once at startup
+static API_HOSTS: OnceLock<ApiHosts> = OnceLock::new();
+
+/// Install host overrides (reverse proxy / mirror) before any request is made
+pub fn set_api_hosts(hosts: ApiHosts) {
+    let _ = API_HOSTS.set(hosts);
+}
+
+fn api_hosts() -> &'static ApiHosts {
+    API_HOSTS.get_or_init(ApiHosts::default)
+}
 
 pub enum BilibiliApiDomain {
     Main,
     Passport,
+    Live,
+    Search,
+    LiveTrace,
 }
 
 impl BilibiliApiDomain {
     pub fn as_str(&self) -> &'static str {
-        match self {
-            BilibiliApiDomain::Main => "https://api.bilibili.com",
-            BilibiliApiDomain::Passport => "https://passport.bilibili.com",
+        let hosts = api_hosts();
+        let (custom, default) = match self {
+            BilibiliApiDomain::Main => (&hosts.main, "https://api.bilibili.com"),
+            BilibiliApiDomain::Passport => (&hosts.passport, "https://passport.bilibili.com"),
+            BilibiliApiDomain::Live => (&hosts.live, "https://api.live.bilibili.com"),
+            BilibiliApiDomain::Search => (&hosts.search, "https://s.search.bilibili.com"),
+            BilibiliApiDomain::LiveTrace => (&hosts.live_trace, "https://live-trace.bilibili.com"),
+        };
+        custom
+            .as_deref()
+            .map(|host| host.trim_end_matches('/'))
+            .unwrap_or(default)
+    }
+}
+
+/// Keep only videos with a bvid that weren't already returned by an earlier page
+fn unseen_recommendations(
+    data: Option<super::recommend::RecommendData>,
+    seen: &mut super::recommend::SeenBvids,
+) -> Vec<super::recommend::VideoItem> {
+    let mut items = data.map(|d| d.item).unwrap_or_default();
+    seen.retain_unseen(&mut items);
+    items
+}
+
+/// Rewrite an image URL on the hdslb.com CDN to the configured image host
+pub fn image_url(url: &str) -> String {
+    let Some(cdn) = api_hosts().image_cdn.as_deref() else {
+        return url.to_string();
+    };
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .or_else(|| url.strip_prefix("//"))
+        .unwrap_or(url);
+    match rest.split_once('/') {
+        Some((host, path)) if host.ends_with("hdslb.com") => {
+            format!("{}/{}", cdn.trim_end_matches('/'), path)
         }
+        _ => url.to_string(),
     }
 }
 
@@ -33,6 +85,14 @@ pub struct ApiResponse<T> {
     pub data: Option<T>,
 }
 
+/// API code of a request rejected by risk control (风控校验失败)
+pub const RISK_CONTROL_CODE: i32 = -352;
+
+/// Whether an error came from risk control, so the UI can offer re-login / browser verification
+pub fn is_risk_control(err: &anyhow::Error) -> bool {
+    ApiErrorKind::of(err) == ApiErrorKind::RiskControl
+}
+
 /// WBI keys for signing requests
 #[derive(Debug, Clone)]
 pub struct WbiKeys {
@@ -41,20 +101,27 @@ pub struct WbiKeys {
 }
 
 pub struct ApiClient {
-    client: Client,
+    transport: Arc<dyn Transport>,
     cookies: RwLock<Option<String>>,
     wbi_keys: RwLock<Option<WbiKeys>>,
+    stats: HttpStats,
+    limiter: RateLimiter,
 }
 
 impl ApiClient {
+    /// Client using the transport selected by the environment (network by default)
     pub fn new() -> Self {
+        Self::with_transport(transport::from_env())
+    }
+
+    /// Client sending requests through a custom transport, e.g. a mock in tests
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Self {
         Self {
-            client: Client::builder()
-                .default_headers(Self::default_headers())
-                .build()
-                .expect("Failed to create HTTP client"),
+            transport,
             cookies: RwLock::new(None),
             wbi_keys: RwLock::new(None),
+            stats: HttpStats::default(),
+            limiter: RateLimiter::default(),
         }
     }
 
@@ -64,22 +131,36 @@ impl ApiClient {
         client
     }
 
-    fn default_headers() -> HeaderMap {
-        let mut headers = HeaderMap::new();
-        headers.insert(USER_AGENT, HeaderValue::from_static(UA));
-        headers.insert(
-            REFERER,
-            HeaderValue::from_static("https://www.bilibili.com/"),
-        );
-        headers
+    pub fn set_credentials(&self, credentials: &Credentials) {
+        *self.cookies.write().expect("cookies lock poisoned") = Some(credentials.cookie_string());
     }
 
-    pub fn set_credentials(&self, credentials: &Credentials) {
-        let cookie_str = format!(
-            "SESSDATA={}; bili_jct={}; DedeUserID={}",
-            credentials.sessdata, credentials.bili_jct, credentials.dede_user_id
-        );
-        *self.cookies.write().expect("cookies lock poisoned") = Some(cookie_str);
+    /// Current network statistics
+    pub fn stats(&self) -> HttpStatsSnapshot {
+        self.stats.snapshot()
+    }
+
+    fn cookie_header(&self) -> Option<String> {
+        self.cookies.read().expect("cookies lock poisoned").clone()
+    }
+
+    /// Limit requests to `qps` per second, 0 to disable
+    pub fn set_rate_limit(&self, qps: f64) {
+        self.limiter.set_qps(qps);
+    }
+
+    /// Send a request with the current cookies, recording it in the network statistics.
+    /// Waits in line when requests exceed the rate limit
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.limiter.acquire().await;
+        let request = request.cookie(self.cookie_header());
+        let path = stats::url_path(&request.url).to_string();
+        let _active = self.stats.begin();
+        let start = Instant::now();
+        let result = self.transport.execute(request).await;
+        let status = result.as_ref().ok().map(|resp| resp.status);
+        self.stats.finish(start.elapsed(), status, &path);
+        result.map_err(|e| ApiError::Network(format!("{:#}", e)).into())
     }
 
     fn build_url(&self, domain: BilibiliApiDomain, endpoint: &str) -> String {
@@ -88,12 +169,10 @@ impl ApiClient {
 
     /// Make a GET request
     pub async fn get<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<ApiResponse<T>> {
-        let mut req = self.client.get(url);
-        if let Some(ref cookies) = *self.cookies.read().expect("cookies lock poisoned") {
-            req = req.header(COOKIE, cookies.as_str());
-        }
-        let resp = req.send().await?;
-        let api_resp: ApiResponse<T> = resp.json().await?;
+        let resp = self.send(HttpRequest::get(url)).await?;
+        let api_resp: ApiResponse<T> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(url));
         Ok(api_resp)
     }
 
@@ -103,51 +182,97 @@ impl ApiClient {
         url: &str,
         form_data: Vec<(&str, String)>,
     ) -> Result<ApiResponse<T>> {
-        let mut req = self.client.post(url);
-
-        // 使用块作用域确保锁在 await 之前释放
-        let params = {
-            let cookies = self.cookies.read().expect("cookies lock poisoned");
-            if let Some(ref cookie_str) = *cookies {
-                req = req.header(COOKIE, cookie_str.as_str());
+        let mut params: Vec<(String, String)> = form_data
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        if !params.iter().any(|(k, _)| k == "csrf") {
+            if let Some(csrf) = self.cookie_value("bili_jct") {
+                params.push(("csrf".to_string(), csrf));
             }
+        }
 
-            let has_csrf = cookies
-                .as_ref()
-                .map(|c| c.contains("bili_jct"))
-                .unwrap_or(false);
-
-            let mut params: Vec<(String, String)> = form_data
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect();
-
-            if has_csrf && !params.iter().any(|(k, _)| k == "csrf") {
-                if let Some(cookie_str) = cookies.as_ref() {
-                    if let Some(csrf) = cookie_str.split(';').find_map(|part| {
-                        let part = part.trim();
-                        part.split_once('=')
-                            .filter(|(name, _)| *name == "bili_jct")
-                            .map(|(_, value)| value.to_string())
-                    }) {
-                        params.push(("csrf".to_string(), csrf));
-                    }
-                }
-            }
-            params
-        }; // 锁在此处释放
+        let resp = self.send(HttpRequest::post(url, params)).await?;
+        let api_resp: ApiResponse<T> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(url));
+        Ok(api_resp)
+    }
 
-        req = req.form(&params);
-        let resp = req.send().await?;
-        let api_resp: ApiResponse<T> = resp.json().await?;
+    /// Make a POST request with a raw body, passing the CSRF token in the query
+    pub async fn post_body<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<ApiResponse<T>> {
+        let csrf = self.require_csrf()?;
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let url = format!("{url}{separator}csrf={csrf}");
+        let resp = self
+            .send(HttpRequest::post_body(&url, content_type, body))
+            .await?;
+        let api_resp: ApiResponse<T> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(&url));
         Ok(api_resp)
     }
 
-    /// Make a WBI-signed GET request
+    /// CSRF token (`bili_jct`) of the logged-in user
+    pub fn csrf_token(&self) -> Option<String> {
+        self.cookie_value("bili_jct")
+    }
+
+    /// CSRF token for requests that can't go through without it
+    fn require_csrf(&self) -> Result<String> {
+        self.csrf_token().ok_or_else(|| {
+            ApiError::NotLoggedIn {
+                code: -101,
+                message: "Missing bili_jct cookie, please log in again".to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Value of a login cookie such as `bili_jct` (CSRF token) or `DedeUserID`
+    fn cookie_value(&self, name: &str) -> Option<String> {
+        let cookies = self.cookies.read().expect("cookies lock poisoned");
+        cookies.as_ref()?.split(';').find_map(|part| {
+            part.trim()
+                .split_once('=')
+                .filter(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    /// Make a WBI-signed GET request, refreshing the keys and retrying once on -352
     pub async fn get_with_wbi<T: for<'de> Deserialize<'de>>(
         &self,
         base_url: &str,
         params: Vec<(&str, String)>,
+    ) -> Result<ApiResponse<T>> {
+        let resp: ApiResponse<T> = self.get_signed(base_url, params.clone()).await?;
+        if resp.code != RISK_CONTROL_CODE {
+            return Ok(resp);
+        }
+
+        // -352 is often caused by stale WBI keys: fetch new ones and retry once
+        self.wbi_keys
+            .write()
+            .expect("wbi_keys lock poisoned")
+            .take();
+        let resp: ApiResponse<T> = self.get_signed(base_url, params).await?;
+        if resp.code == RISK_CONTROL_CODE {
+            return Err(ApiError::from_code(resp.code, resp.message).into());
+        }
+        Ok(resp)
+    }
+
+    /// Sign the query with the WBI keys and send the request
+    async fn get_signed<T: for<'de> Deserialize<'de>>(
+        &self,
+        base_url: &str,
+        params: Vec<(&str, String)>,
     ) -> Result<ApiResponse<T>> {
         // Ensure we have WBI keys
         self.ensure_wbi_keys().await?;
@@ -220,29 +345,20 @@ impl ApiClient {
             qrcode_key
         );
 
-        let mut req = self.client.get(&url);
-        if let Some(ref cookies) = *self.cookies.read().unwrap() {
-            req = req.header(COOKIE, cookies.as_str());
-        }
-
-        let resp = req.send().await?;
-
-        // Extract cookies from response headers
-        let mut new_cookies = Vec::new();
-        for cookie in resp.cookies() {
-            new_cookies.push((cookie.name().to_string(), cookie.value().to_string()));
-        }
-
-        let api_resp: ApiResponse<super::auth::QrcodePollData> = resp.json().await?;
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        let api_resp: ApiResponse<super::auth::QrcodePollData> = resp.json()?;
 
         Ok(super::auth::QrcodePollResult {
             data: api_resp.data,
-            cookies: new_cookies,
+            cookies: resp.cookies,
         })
     }
 
     // Recommendation API
-    pub async fn get_recommendations(&self) -> Result<Vec<super::recommend::VideoItem>> {
+    pub async fn get_recommendations(
+        &self,
+        seen: &mut super::recommend::SeenBvids,
+    ) -> Result<Vec<super::recommend::VideoItem>> {
         let url = self.build_url(
             BilibiliApiDomain::Main,
             "/x/web-interface/wbi/index/top/feed/rcmd",
@@ -258,10 +374,35 @@ impl ApiClient {
         let resp: ApiResponse<super::recommend::RecommendData> =
             self.get_with_wbi(&url, params).await?;
 
-        Ok(resp
-            .data
-            .map(|d| d.item.into_iter().filter(|v| v.bvid.is_some()).collect())
-            .unwrap_or_default())
+        // A fresh first page starts a new feed, forget what was seen before
+        seen.clear();
+        Ok(unseen_recommendations(resp.data, seen))
+    }
+
+    /// Newest videos of a region (分区), e.g. rid 3 = 音乐
+    pub async fn get_region_videos(
+        &self,
+        rid: i32,
+        page: i32,
+        page_size: i32,
+    ) -> Result<Vec<super::recommend::RegionArchive>> {
+        let url = format!(
+            "{}/x/web-interface/dynamic/region?rid={}&pn={}&ps={}",
+            BilibiliApiDomain::Main.as_str(),
+            rid,
+            page,
+            page_size
+        );
+
+        let resp: ApiResponse<super::recommend::RegionData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get region videos: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.map(|data| data.archives).unwrap_or_default())
     }
 
     // Video API
@@ -302,21 +443,17 @@ impl ApiClient {
 
     /// Fetch hot search keywords (web)
     pub async fn get_hot_search(&self) -> Result<Vec<super::search::HotwordItem>> {
-        const HOTWORD_URL: &str = "https://s.search.bilibili.com/main/hotword";
-
-        let mut req = self.client.get(HOTWORD_URL);
-
-        if let Some(ref cookies) = *self.cookies.read().expect("cookies lock poisoned") {
-            req = req.header(COOKIE, cookies.as_str());
-        }
+        let url = self.build_url(BilibiliApiDomain::Search, "/main/hotword");
 
-        let resp = req.send().await?;
-        let data: super::search::HotwordResponse = resp.json().await?;
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        let data: super::search::HotwordResponse = resp.json()?;
 
         if let Some(code) = data.code {
             if code != 0 {
                 let msg = data.message.unwrap_or_else(|| "unknown error".to_string());
-                return Err(anyhow!("Hot search API error: {}", msg));
+                return Err(
+                    ApiError::from_code(code, format!("Hot search API error: {}", msg)).into(),
+                );
             }
         }
 
@@ -360,9 +497,32 @@ impl ApiClient {
             offset: None,
             has_more: Some(false),
             update_num: Some(0),
+            update_baseline: None,
         }))
     }
 
+    /// Number of dynamics published since `update_baseline`
+    pub async fn get_dynamic_update_num(
+        &self,
+        feed_type: Option<&str>,
+        update_baseline: &str,
+    ) -> Result<i32> {
+        let url = format!(
+            "{}/x/polymer/web-dynamic/v1/feed/all/update?type={}&update_baseline={}",
+            BilibiliApiDomain::Main.as_str(),
+            feed_type.unwrap_or("all"),
+            update_baseline
+        );
+
+        #[derive(Deserialize)]
+        struct UpdateData {
+            update_num: Option<i32>,
+        }
+
+        let resp: ApiResponse<UpdateData> = self.get(&url).await?;
+        Ok(resp.data.and_then(|d| d.update_num).unwrap_or(0))
+    }
+
     // Dynamic Detail API
     pub async fn get_dynamic_detail(
         &self,
@@ -407,6 +567,24 @@ impl ApiClient {
         }))
     }
 
+    /// Get the UPs in the special follow group (特别关注)
+    pub async fn get_special_followings(&self) -> Result<Vec<super::dynamic::FollowingUser>> {
+        let url = format!(
+            "{}/x/relation/tag?tagid=-10&pn=1&ps=100",
+            BilibiliApiDomain::Main.as_str()
+        );
+
+        let resp: ApiResponse<Vec<super::dynamic::FollowingUser>> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get special followings: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
     /// Get dynamic portal with frequently watched UP masters (常看UP主)
     pub async fn get_dynamic_portal(&self) -> Result<super::dynamic::PortalData> {
         let url = format!(
@@ -415,9 +593,10 @@ impl ApiClient {
         );
 
         let resp: ApiResponse<super::dynamic::PortalData> = self.get(&url).await?;
-        Ok(resp
-            .data
-            .unwrap_or(super::dynamic::PortalData { up_list: None }))
+        Ok(resp.data.unwrap_or(super::dynamic::PortalData {
+            up_list: None,
+            live_users: None,
+        }))
     }
 
     // Comments API
@@ -437,6 +616,34 @@ impl ApiClient {
         }))
     }
 
+    /// A comment thread by its root rpid (评论详情), for jumping to a comment
+    pub async fn get_comment_detail(
+        &self,
+        oid: i64,
+        comment_type: i32,
+        root: i64,
+    ) -> Result<super::comment::CommentItem> {
+        let url = format!(
+            "{}/x/v2/reply/detail?type={}&oid={}&root={}&ps=20&pn=1",
+            BilibiliApiDomain::Main.as_str(),
+            comment_type,
+            oid,
+            root
+        );
+
+        let resp: ApiResponse<super::comment::CommentDetailData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get comment: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .and_then(|data| data.root)
+            .ok_or_else(|| anyhow::anyhow!("No comment in detail response"))
+    }
+
     // Dynamic Comments API
     // type=11: 相簿（图片动态） - image/photo albums
     // type=17: 动态（纯文字动态&分享） - text dynamics and shares
@@ -504,6 +711,7 @@ impl ApiClient {
     pub async fn get_recommendations_paged(
         &self,
         fresh_idx: i32,
+        seen: &mut super::recommend::SeenBvids,
     ) -> Result<Vec<super::recommend::VideoItem>> {
         let url = self.build_url(
             BilibiliApiDomain::Main,
@@ -520,10 +728,7 @@ impl ApiClient {
         let resp: ApiResponse<super::recommend::RecommendData> =
             self.get_with_wbi(&url, params).await?;
 
-        Ok(resp
-            .data
-            .map(|d| d.item.into_iter().filter(|v| v.bvid.is_some()).collect())
-            .unwrap_or_default())
+        Ok(unseen_recommendations(resp.data, seen))
     }
 
     pub async fn get_history(
@@ -560,6 +765,25 @@ impl ApiClient {
             .ok_or_else(|| anyhow::anyhow!("No data in history response"))
     }
 
+    /// Delete one history record by its kid (see [`super::history::HistoryItem::delete_kid`])
+    pub async fn delete_history(&self, kid: &str) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v2/history/delete");
+
+        let form_data = vec![("kid", kid.to_string())];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to delete history: {}", resp.message),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
     // ========== Comment Action APIs ==========
 
     /// Add a comment (发表评论)
@@ -596,7 +820,11 @@ impl ApiClient {
             self.post(&url, form_data).await?;
 
         if resp.code != 0 {
-            return Err(anyhow::anyhow!("Failed to add comment: {}", resp.message));
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to add comment: {}", resp.message),
+            )
+            .into());
         }
 
         resp.data
@@ -624,11 +852,15 @@ impl ApiClient {
         let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
 
         if resp.code != 0 {
-            return Err(anyhow::anyhow!(
-                "Failed to {} comment: {}",
-                if action { "like" } else { "unlike" },
-                resp.message
-            ));
+            return Err(ApiError::from_code(
+                resp.code,
+                format!(
+                    "Failed to {} comment: {}",
+                    if action { "like" } else { "unlike" },
+                    resp.message
+                ),
+            )
+            .into());
         }
 
         Ok(())
@@ -655,11 +887,15 @@ impl ApiClient {
         let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
 
         if resp.code != 0 {
-            return Err(anyhow::anyhow!(
-                "Failed to {} comment: {}",
-                if action { "dislike" } else { "un-dislike" },
-                resp.message
-            ));
+            return Err(ApiError::from_code(
+                resp.code,
+                format!(
+                    "Failed to {} comment: {}",
+                    if action { "dislike" } else { "un-dislike" },
+                    resp.message
+                ),
+            )
+            .into());
         }
 
         Ok(())
@@ -679,31 +915,719 @@ impl ApiClient {
         let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
 
         if resp.code != 0 {
-            return Err(anyhow::anyhow!(
-                "Failed to delete comment: {}",
-                resp.message
-            ));
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to delete comment: {}", resp.message),
+            )
+            .into());
         }
 
         Ok(())
     }
 
-    // ========== Live Streaming APIs ==========
+    // ========== Link APIs ==========
 
-    /// Get live streaming recommendations
-    pub async fn get_live_recommendations(&self) -> Result<Vec<super::live::LiveRoom>> {
-        const LIVE_REC_URL: &str =
-            "https://api.live.bilibili.com/xlive/web-interface/v1/webMain/getMoreRecList";
+    /// Expand a b23.tv short link by following its redirect
+    pub async fn resolve_short_link(&self, url: &str) -> Result<String> {
+        let url = if url.starts_with("http") {
+            url.to_string()
+        } else {
+            format!("https://{}", url)
+        };
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        Ok(resp.url)
+    }
+
+    // ========== Message APIs ==========
+
+    /// Get unread counts of the message center (回复/@/赞/系统通知)
+    pub async fn get_unread_count(&self) -> Result<super::message::UnreadData> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/msgfeed/unread");
+        let resp: ApiResponse<super::message::UnreadData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get unread count: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in unread count response"))
+    }
+
+    // ========== User Space APIs ==========
+
+    /// Get a user's profile card (name, avatar, sign, follower count)
+    pub async fn get_user_card(&self, mid: i64) -> Result<super::space::UserCardData> {
+        let url = format!(
+            "{}/x/web-interface/card?mid={}",
+            BilibiliApiDomain::Main.as_str(),
+            mid
+        );
+
+        let resp: ApiResponse<super::space::UserCardData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get user card: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in user card response"))
+    }
+
+    /// Get videos uploaded by a user, newest first, optionally only those matching `keyword`
+    pub async fn get_user_videos(
+        &self,
+        mid: i64,
+        page: i32,
+        page_size: i32,
+        keyword: &str,
+    ) -> Result<super::space::SpaceArcData> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/space/wbi/arc/search");
+
+        let mut params = vec![
+            ("mid", mid.to_string()),
+            ("pn", page.to_string()),
+            ("ps", page_size.to_string()),
+            ("order", "pubdate".to_string()),
+        ];
+        if !keyword.is_empty() {
+            params.push(("keyword", keyword.to_string()));
+        }
+
+        let resp: ApiResponse<super::space::SpaceArcData> = self.get_with_wbi(&url, params).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get user videos: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in user videos response"))
+    }
+
+    // ========== Interactive Video APIs ==========
+
+    /// Story graph version of an interactive video (互动视频)
+    pub async fn get_interactive_graph_version(&self, aid: i64, cid: i64) -> Result<i64> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/player/wbi/v2");
+        let params = vec![("aid", aid.to_string()), ("cid", cid.to_string())];
+
+        let resp: ApiResponse<super::interactive::PlayerInfo> =
+            self.get_with_wbi(&url, params).await?;
+        resp.data
+            .and_then(|info| info.interaction)
+            .map(|i| i.graph_version)
+            .ok_or_else(|| anyhow::anyhow!("Not an interactive video"))
+    }
 
-        let url = format!("{}?platform=web", LIVE_REC_URL);
+    /// A story node; `edge_id` None returns the first node
+    pub async fn get_interactive_edge(
+        &self,
+        aid: i64,
+        graph_version: i64,
+        edge_id: Option<i64>,
+    ) -> Result<super::interactive::EdgeInfo> {
+        let mut url = format!(
+            "{}/x/stein/edgeinfo_v2?aid={}&graph_version={}",
+            BilibiliApiDomain::Main.as_str(),
+            aid,
+            graph_version
+        );
+        if let Some(edge_id) = edge_id {
+            url.push_str(&format!("&edge_id={}", edge_id));
+        }
+
+        let resp: ApiResponse<super::interactive::EdgeInfo> = self.get(&url).await?;
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in edge info response"))
+    }
+
+    /// Direct stream URL for a cid, for nodes yt-dlp can't address by page
+    pub async fn get_play_url(&self, bvid: &str, cid: i64) -> Result<String> {
+        let url = format!(
+            "{}/x/player/playurl?bvid={}&cid={}&qn=64&fnval=1",
+            BilibiliApiDomain::Main.as_str(),
+            bvid,
+            cid
+        );
+
+        let resp: ApiResponse<super::interactive::PlayUrlData> = self.get(&url).await?;
+        resp.data
+            .and_then(|data| data.durl.into_iter().next())
+            .map(|segment| segment.url)
+            .ok_or_else(|| anyhow::anyhow!("No stream URL in play url response"))
+    }
+
+    /// DASH streams of a cid up to quality `qn` (画质), plus the qualities on offer
+    pub async fn get_playurl(
+        &self,
+        bvid: &str,
+        cid: i64,
+        qn: i32,
+    ) -> Result<super::playurl::PlayUrlInfo> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/player/wbi/playurl");
+        let params = vec![
+            ("bvid", bvid.to_string()),
+            ("cid", cid.to_string()),
+            ("qn", qn.to_string()),
+            ("fnval", "4048".to_string()),
+            ("fnver", "0".to_string()),
+            ("fourk", "1".to_string()),
+        ];
+
+        let resp: ApiResponse<super::playurl::PlayUrlInfo> =
+            self.get_with_wbi(&url, params).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get play url: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in play url response"))
+    }
+
+    // ========== Video Interaction APIs ==========
 
-        let mut req = self.client.get(&url);
-        if let Some(ref cookies) = *self.cookies.read().expect("cookies lock poisoned") {
-            req = req.header(COOKIE, cookies.as_str());
+    /// Like or unlike a video (点赞)
+    pub async fn like_video(&self, aid: i64, like: bool) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/web-interface/archive/like");
+        let form_data = vec![
+            ("aid", aid.to_string()),
+            ("like", if like { "1" } else { "2" }.to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to like video: {}", resp.message),
+            )
+            .into());
         }
+        Ok(())
+    }
 
-        let resp = req.send().await?;
-        let api_resp: ApiResponse<super::live::LiveRecommendData> = resp.json().await?;
+    /// Give a video 1 or 2 coins (投币), optionally liking it as well
+    pub async fn coin_video(&self, aid: i64, multiply: u8, also_like: bool) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/web-interface/coin/add");
+        let form_data = vec![
+            ("aid", aid.to_string()),
+            ("multiply", multiply.clamp(1, 2).to_string()),
+            ("select_like", u8::from(also_like).to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to give coins: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Whether the logged-in user liked, coined and favorited a video
+    pub async fn get_video_relation(&self, aid: i64) -> Result<super::video::VideoRelation> {
+        let url = format!(
+            "{}/x/web-interface/archive/relation?aid={}",
+            BilibiliApiDomain::Main.as_str(),
+            aid
+        );
+
+        let resp: ApiResponse<super::video::VideoRelation> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get video relation: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
+    /// Like, coin and favorite a video in one go (一键三连)
+    pub async fn triple_video(&self, aid: i64) -> Result<super::video::TripleResult> {
+        let url = self.build_url(
+            BilibiliApiDomain::Main,
+            "/x/web-interface/archive/like/triple",
+        );
+        let form_data = vec![("aid", aid.to_string())];
+
+        let resp: ApiResponse<super::video::TripleResult> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to triple video: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
+    // ========== Favorites APIs ==========
+
+    /// Favorites folders created by a user (收藏夹列表)
+    pub async fn get_fav_folders(&self, up_mid: i64) -> Result<Vec<super::favorite::FavFolder>> {
+        let url = format!(
+            "{}/x/v3/fav/folder/created/list-all?up_mid={}",
+            BilibiliApiDomain::Main.as_str(),
+            up_mid
+        );
+
+        let resp: ApiResponse<super::favorite::FavFolderList> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get favorite folders: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.and_then(|data| data.list).unwrap_or_default())
+    }
+
+    /// Favorites folders of a user, each telling whether the video `aid` is in it
+    pub async fn get_fav_folders_with(
+        &self,
+        up_mid: i64,
+        aid: i64,
+    ) -> Result<Vec<super::favorite::FavFolder>> {
+        let url = format!(
+            "{}/x/v3/fav/folder/created/list-all?up_mid={}&rid={}&type=2",
+            BilibiliApiDomain::Main.as_str(),
+            up_mid,
+            aid
+        );
+
+        let resp: ApiResponse<super::favorite::FavFolderList> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get favorite folders: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.and_then(|data| data.list).unwrap_or_default())
+    }
+
+    /// One page of videos in a favorites folder, newest favorited first
+    pub async fn get_fav_resources(
+        &self,
+        media_id: i64,
+        page: i32,
+        page_size: i32,
+    ) -> Result<super::favorite::FavResourceData> {
+        let url = format!(
+            "{}/x/v3/fav/resource/list?media_id={}&pn={}&ps={}&order=mtime&platform=web",
+            BilibiliApiDomain::Main.as_str(),
+            media_id,
+            page,
+            page_size
+        );
+
+        let resp: ApiResponse<super::favorite::FavResourceData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get favorite videos: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in favorite videos response"))
+    }
+
+    /// Add a video to and remove it from favorites folders (收藏)
+    pub async fn favorite_video(
+        &self,
+        aid: i64,
+        add_to: &[i64],
+        remove_from: &[i64],
+    ) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v3/fav/resource/deal");
+        let join = |ids: &[i64]| ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",");
+        let form_data = vec![
+            ("rid", aid.to_string()),
+            ("type", "2".to_string()),
+            ("add_media_ids", join(add_to)),
+            ("del_media_ids", join(remove_from)),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to favorite video: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Create a favorites folder, returning it
+    pub async fn create_fav_folder(
+        &self,
+        title: &str,
+        private: bool,
+    ) -> Result<super::favorite::FavFolder> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v3/fav/folder/add");
+        let form_data = vec![
+            ("title", title.to_string()),
+            ("privacy", (private as i32).to_string()),
+        ];
+
+        let resp: ApiResponse<super::favorite::FavFolder> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to create favorite folder: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("No data in create favorite folder response"))
+    }
+
+    /// Rename a favorites folder and set whether it is private
+    pub async fn edit_fav_folder(&self, media_id: i64, title: &str, private: bool) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v3/fav/folder/edit");
+        let form_data = vec![
+            ("media_id", media_id.to_string()),
+            ("title", title.to_string()),
+            ("privacy", (private as i32).to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to edit favorite folder: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Delete favorites folders with the videos in them
+    pub async fn delete_fav_folders(&self, media_ids: &[i64]) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v3/fav/folder/del");
+        let media_ids = media_ids
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let form_data = vec![("media_ids", media_ids)];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to delete favorite folder: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Remove videos from one of the user's favorites folders (批量取消收藏)
+    pub async fn remove_fav_resources(&self, media_id: i64, aids: &[i64]) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v3/fav/resource/batch-del");
+        let resources = aids
+            .iter()
+            .map(|aid| format!("{}:2", aid))
+            .collect::<Vec<_>>()
+            .join(",");
+        let form_data = vec![
+            ("resources", resources),
+            ("media_id", media_id.to_string()),
+            ("platform", "web".to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to remove favorite videos: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Move videos from one of the user's favorites folders to another
+    pub async fn move_fav_resources(
+        &self,
+        from: i64,
+        to: i64,
+        mid: i64,
+        aids: &[i64],
+    ) -> Result<()> {
+        self.transfer_fav_resources("/x/v3/fav/resource/move", "move", from, to, mid, aids)
+            .await
+    }
+
+    /// Copy videos from one favorites folder into one of the user's folders
+    pub async fn copy_fav_resources(
+        &self,
+        from: i64,
+        to: i64,
+        mid: i64,
+        aids: &[i64],
+    ) -> Result<()> {
+        self.transfer_fav_resources("/x/v3/fav/resource/copy", "copy", from, to, mid, aids)
+            .await
+    }
+
+    async fn transfer_fav_resources(
+        &self,
+        path: &str,
+        verb: &str,
+        from: i64,
+        to: i64,
+        mid: i64,
+        aids: &[i64],
+    ) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, path);
+        let resources = aids
+            .iter()
+            .map(|aid| format!("{}:2", aid))
+            .collect::<Vec<_>>()
+            .join(",");
+        let form_data = vec![
+            ("src_media_id", from.to_string()),
+            ("tar_media_id", to.to_string()),
+            ("mid", mid.to_string()),
+            ("resources", resources),
+            ("platform", "web".to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to {} favorite videos: {}", verb, resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    // ========== Relation APIs ==========
+
+    /// Whether the logged-in user follows `mid` (关注状态)
+    pub async fn get_user_relation(&self, mid: i64) -> Result<super::space::UserRelation> {
+        let url = format!(
+            "{}/x/relation?fid={}",
+            BilibiliApiDomain::Main.as_str(),
+            mid
+        );
+
+        let resp: ApiResponse<super::space::UserRelation> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get user relation: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
+    /// Follow or unfollow a user (关注/取关)
+    pub async fn follow_user(&self, mid: i64, follow: bool) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/relation/modify");
+        let form_data = vec![
+            ("fid", mid.to_string()),
+            ("act", if follow { "1" } else { "2" }.to_string()),
+            ("re_src", "11".to_string()),
+        ];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!(
+                    "Failed to {} user: {}",
+                    if follow { "follow" } else { "unfollow" },
+                    resp.message
+                ),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    // ========== Watch Later APIs ==========
+
+    /// Add a video to watch later (稍后再看)
+    pub async fn add_to_watch_later(&self, aid: i64) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v2/history/toview/add");
+
+        let form_data = vec![("aid", aid.to_string())];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to add to watch later: {}", resp.message),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Get the watch later list, all of it in one response
+    pub async fn get_watch_later(&self) -> Result<Vec<super::watch_later::WatchLaterItem>> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v2/history/toview");
+        let resp: ApiResponse<super::watch_later::WatchLaterData> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get watch later list: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.and_then(|d| d.list).unwrap_or_default())
+    }
+
+    /// Remove a video from watch later
+    pub async fn remove_from_watch_later(&self, aid: i64) -> Result<()> {
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/v2/history/toview/del");
+
+        let form_data = vec![("aid", aid.to_string())];
+
+        let resp: ApiResponse<serde_json::Value> = self.post(&url, form_data).await?;
+
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to remove from watch later: {}", resp.message),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    // ========== Dynamic Publishing APIs ==========
+
+    /// Upload a local image for an image dynamic (上传动态图片)
+    pub async fn upload_dynamic_image(
+        &self,
+        path: &std::path::Path,
+    ) -> Result<super::publish::UploadedImage> {
+        use super::publish::{image_mime, multipart_body, MAX_IMAGE_BYTES};
+
+        let mime = image_mime(path)
+            .ok_or_else(|| anyhow!("Unsupported image format, use jpg/png/gif/webp"))?;
+        let csrf = self.require_csrf()?;
+        // Check the size first so a huge file is never read into memory
+        if tokio::fs::metadata(path).await?.len() > MAX_IMAGE_BYTES {
+            return Err(anyhow!("Image is larger than 20MB"));
+        }
+        let bytes = tokio::fs::read(path).await?;
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("image");
+        let boundary = format!(
+            "----BilibiliTui{:x}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default()
+        );
+        let body = multipart_body(
+            &boundary,
+            &[("biz", "new_dyn"), ("category", "daily"), ("csrf", &csrf)],
+            "file_up",
+            file_name,
+            mime,
+            &bytes,
+        );
+
+        let url = self.build_url(BilibiliApiDomain::Main, "/x/dynamic/feed/draw/upload_bfs");
+        let content_type = format!("multipart/form-data; boundary={}", boundary);
+        let resp: ApiResponse<super::publish::UploadedImage> =
+            self.post_body(&url, &content_type, body).await?;
+
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to upload image: {}", resp.message),
+            )
+            .into());
+        }
+        resp.data
+            .ok_or_else(|| anyhow!("No data in upload response"))
+    }
+
+    /// Publish a dynamic with text and already uploaded images, returns the new dynamic id
+    pub async fn create_dynamic(
+        &self,
+        text: &str,
+        images: &[super::publish::UploadedImage],
+    ) -> Result<String> {
+        let body = super::publish::create_dynamic_body(text, images, &self.dynamic_upload_id());
+        self.post_dynamic(body).await
+    }
+
+    /// Share a video to the user's dynamic feed with a comment, returns the new dynamic id
+    pub async fn repost_video(&self, text: &str, aid: i64) -> Result<String> {
+        let body = super::publish::repost_video_body(text, aid, &self.dynamic_upload_id());
+        self.post_dynamic(body).await
+    }
+
+    /// Client-side id of a dynamic being created: `<mid>_<secs>_<millis>`
+    fn dynamic_upload_id(&self) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        format!(
+            "{}_{}_{}",
+            self.cookie_value("DedeUserID").unwrap_or_default(),
+            now.as_secs(),
+            now.subsec_millis()
+        )
+    }
+
+    async fn post_dynamic(&self, body: serde_json::Value) -> Result<String> {
+        let url = self.build_url(
+            BilibiliApiDomain::Main,
+            "/x/dynamic/feed/create/dyn?platform=web",
+        );
+        let resp: ApiResponse<super::publish::CreateDynamicData> = self
+            .post_body(&url, "application/json", body.to_string().into_bytes())
+            .await?;
+
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to create dynamic: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.and_then(|d| d.dyn_id_str).unwrap_or_default())
+    }
+
+    // ========== Live Streaming APIs ==========
+
+    /// Get live streaming recommendations
+    pub async fn get_live_recommendations(&self) -> Result<Vec<super::live::LiveRoom>> {
+        let url = format!(
+            "{}/xlive/web-interface/v1/webMain/getMoreRecList?platform=web",
+            BilibiliApiDomain::Live.as_str()
+        );
+
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        let api_resp: ApiResponse<super::live::LiveRecommendData> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(&url));
 
         Ok(api_resp
             .data
@@ -711,29 +1635,121 @@ impl ApiClient {
             .unwrap_or_default())
     }
 
+    /// Most popular live rooms of a parent area (分区人气榜), highest first
+    pub async fn get_area_rank(
+        &self,
+        parent_area_id: i64,
+        count: usize,
+    ) -> Result<Vec<super::live::AreaRankRoom>> {
+        let url = format!(
+            "{}/room/v1/area/getRoomList?parent_area_id={}&area_id=0&sort_type=online&page=1&page_size={}&platform=web",
+            BilibiliApiDomain::Live.as_str(),
+            parent_area_id,
+            count
+        );
+
+        let resp: ApiResponse<Vec<super::live::AreaRankRoom>> = self.get(&url).await?;
+        if resp.code != 0 {
+            return Err(ApiError::from_code(
+                resp.code,
+                format!("Failed to get area ranking: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
+    /// Get the followed anchors that are live right now
+    pub async fn get_following_lives(&self) -> Result<Vec<super::live::FollowingLive>> {
+        const PAGE_SIZE: usize = 50;
+        const MAX_PAGES: i32 = 5;
+
+        let mut lives = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let url = format!(
+                "{}/xlive/web-ucenter/v1/xfetter/GetWebList?page={}&page_size={}",
+                BilibiliApiDomain::Live.as_str(),
+                page,
+                PAGE_SIZE
+            );
+            let resp: ApiResponse<super::live::FollowingLiveData> = self.get(&url).await?;
+            if resp.code != 0 {
+                return Err(ApiError::from_code(
+                    resp.code,
+                    format!("Failed to get followed live rooms: {}", resp.message),
+                )
+                .into());
+            }
+            let Some(data) = resp.data else {
+                break;
+            };
+            let list = data.list.unwrap_or_default();
+            let last = list.len() < PAGE_SIZE;
+            lives.extend(list);
+            if last || lives.len() >= data.count.max(0) as usize {
+                break;
+            }
+        }
+        Ok(lives)
+    }
+
     /// Get live room info
     pub async fn get_live_room_info(&self, room_id: i64) -> Result<super::live::LiveRoomInfo> {
         let url = format!(
-            "https://api.live.bilibili.com/room/v1/Room/get_info?room_id={}",
+            "{}/room/v1/Room/get_info?room_id={}",
+            BilibiliApiDomain::Live.as_str(),
             room_id
         );
 
-        let mut req = self.client.get(&url);
-        if let Some(ref cookies) = *self.cookies.read().expect("cookies lock poisoned") {
-            req = req.header(COOKIE, cookies.as_str());
-        }
-
-        let resp = req.send().await?;
-        let api_resp: ApiResponse<super::live::LiveRoomInfo> = resp.json().await?;
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        let api_resp: ApiResponse<super::live::LiveRoomInfo> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(&url));
 
         api_resp
             .data
             .ok_or_else(|| anyhow::anyhow!("No data in live room info response"))
     }
 
+    /// Get anchor info (name and avatar) of a live room
+    pub async fn get_live_anchor_info(&self, room_id: i64) -> Result<super::live::LiveAnchorInfo> {
+        let url = format!(
+            "{}/live_user/v1/UserInfo/get_anchor_in_room?roomid={}",
+            BilibiliApiDomain::Live.as_str(),
+            room_id
+        );
+
+        let resp: ApiResponse<super::live::LiveAnchorData> = self.get(&url).await?;
+        resp.data
+            .map(|d| d.info)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get anchor info: {}", resp.message))
+    }
+
+    /// Get high-energy user rank of a live room (高能榜)
+    /// - `ruid`: Anchor uid
+    pub async fn get_online_gold_rank(
+        &self,
+        ruid: i64,
+        room_id: i64,
+        page_size: i32,
+    ) -> Result<super::live::OnlineGoldRankData> {
+        let url = format!(
+            "{}/xlive/general-interface/v1/rank/getOnlineGoldRank?ruid={}&roomId={}&page=1&pageSize={}",
+            BilibiliApiDomain::Live.as_str(),
+            ruid, room_id, page_size
+        );
+
+        let resp: ApiResponse<super::live::OnlineGoldRankData> = self.get(&url).await?;
+        resp.data
+            .ok_or_else(|| anyhow::anyhow!("Failed to get online gold rank: {}", resp.message))
+    }
+
     /// Get danmu info for WebSocket connection
     pub async fn get_danmu_info(&self, room_id: i64) -> Result<super::live_ws::DanmuInfoData> {
-        let base_url = "https://api.live.bilibili.com/xlive/web-room/v1/index/getDanmuInfo";
+        let base_url = self.build_url(
+            BilibiliApiDomain::Live,
+            "/xlive/web-room/v1/index/getDanmuInfo",
+        );
 
         // WBI signature is REQUIRED since 2025-05-26
         self.ensure_wbi_keys().await?;
@@ -766,21 +1782,16 @@ impl ApiClient {
 
             let url = build_signed_url(&keys);
 
-            let mut req = self.client.get(&url);
-            if let Some(ref cookies) = *self.cookies.read().expect("cookies lock") {
-                req = req.header(COOKIE, cookies.as_str());
-            }
-
-            let resp = req.send().await?;
-            let resp_text = resp.text().await?;
+            let resp = self.send(HttpRequest::get(&url)).await?;
+            let resp_text = resp.body;
 
             let api_resp: ApiResponse<super::live_ws::DanmuInfoData> =
                 serde_json::from_str(&resp_text).map_err(|e| {
-                    anyhow::anyhow!(
+                    ApiError::Parse(format!(
                         "解析失败: {} (响应: {})",
                         e,
                         &resp_text[..resp_text.len().min(200)]
-                    )
+                    ))
                 })?;
 
             if api_resp.code == 0 {
@@ -794,11 +1805,11 @@ impl ApiClient {
                 continue;
             }
 
-            return Err(anyhow::anyhow!(
-                "API错误 {}: {}",
+            return Err(ApiError::from_code(
                 api_resp.code,
-                api_resp.message
-            ));
+                format!("API错误 {}: {}", api_resp.code, api_resp.message),
+            )
+            .into());
         }
     }
 
@@ -808,24 +1819,22 @@ impl ApiClient {
         room_id: i64,
     ) -> Result<super::live_ws::HistoryDanmakuData> {
         let url = format!(
-            "https://api.live.bilibili.com/xlive/web-room/v1/dM/gethistory?roomid={}",
+            "{}/xlive/web-room/v1/dM/gethistory?roomid={}",
+            BilibiliApiDomain::Live.as_str(),
             room_id
         );
 
-        let mut req = self.client.get(&url);
-        if let Some(ref cookies) = *self.cookies.read().expect("cookies lock") {
-            req = req.header(COOKIE, cookies.as_str());
-        }
-
-        let resp = req.send().await?;
-        let api_resp: ApiResponse<super::live_ws::HistoryDanmakuData> = resp.json().await?;
+        let resp = self.send(HttpRequest::get(&url)).await?;
+        let api_resp: ApiResponse<super::live_ws::HistoryDanmakuData> = resp.json()?;
+        self.stats
+            .record_api_code(api_resp.code, &api_resp.message, stats::url_path(&url));
 
         if api_resp.code != 0 {
-            return Err(anyhow::anyhow!(
-                "API错误 {}: {}",
+            return Err(ApiError::from_code(
                 api_resp.code,
-                api_resp.message
-            ));
+                format!("API错误 {}: {}", api_resp.code, api_resp.message),
+            )
+            .into());
         }
 
         api_resp.data.ok_or_else(|| anyhow::anyhow!("响应无数据"))
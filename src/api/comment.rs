@@ -1,6 +1,15 @@
 //! Comment API types and functions
 
+use crate::format::format_count_opt;
+use crate::i18n::{t, tf};
+use regex::Regex;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Clock-like numbers such as `03:24`, `1:02:03` or `03：24`
+static TIMESTAMP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d+(?:[:：]\d+)+").expect("valid regex"));
 
 /// Comment list response
 #[derive(Debug, Deserialize)]
@@ -10,6 +19,12 @@ pub struct CommentData {
     pub hots: Option<Vec<CommentItem>>,
 }
 
+/// Response of /x/v2/reply/detail, one comment thread
+#[derive(Debug, Deserialize)]
+pub struct CommentDetailData {
+    pub root: Option<CommentItem>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CommentPage {
     pub num: Option<i32>,
@@ -33,6 +48,50 @@ pub struct CommentItem {
     pub member: Option<CommentMember>,
     pub content: Option<CommentContent>,
     pub replies: Option<Vec<CommentItem>>,
+    /// UP 主对这条评论的互动
+    pub up_action: Option<UpAction>,
+    /// 当前账号的态度: 1 赞, 2 踩
+    #[serde(default)]
+    pub action: i32,
+}
+
+/// The logged-in user's vote on a comment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommentVote {
+    #[default]
+    None,
+    Like,
+    Dislike,
+}
+
+impl CommentVote {
+    /// Vote after pressing like or dislike: the same key again takes the vote back,
+    /// the other one switches sides
+    pub fn toggled(self, pressed: CommentVote) -> CommentVote {
+        if self == pressed {
+            CommentVote::None
+        } else {
+            pressed
+        }
+    }
+
+    /// Marker shown next to the like count
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            CommentVote::None => None,
+            CommentVote::Like => Some(t("👍 已赞")),
+            CommentVote::Dislike => Some(t("👎 已踩")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpAction {
+    #[serde(default)]
+    pub like: bool,
+    /// UP 主在楼中楼里回复过
+    #[serde(default)]
+    pub reply: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -51,6 +110,13 @@ pub struct LevelInfo {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CommentContent {
     pub message: Option<String>,
+    /// 图片评论附带的图片
+    pub pictures: Option<Vec<CommentPicture>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommentPicture {
+    pub img_src: Option<String>,
 }
 
 impl CommentItem {
@@ -58,7 +124,7 @@ impl CommentItem {
         self.member
             .as_ref()
             .and_then(|m| m.uname.as_deref())
-            .unwrap_or("匿名")
+            .unwrap_or(t("匿名"))
     }
 
     pub fn message(&self) -> &str {
@@ -68,12 +134,34 @@ impl CommentItem {
             .unwrap_or("")
     }
 
+    /// URLs of the pictures attached to the comment
+    pub fn pictures(&self) -> Vec<&str> {
+        self.content
+            .as_ref()
+            .and_then(|c| c.pictures.as_ref())
+            .map(|pics| pics.iter().filter_map(|p| p.img_src.as_deref()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Video time points (in seconds) mentioned in the comment, like "03:24 名场面"
+    pub fn timestamps(&self) -> Vec<i64> {
+        parse_timestamps(self.message())
+    }
+
+    /// The user's vote, unless it was changed on the page since loading
+    pub fn vote(&self, changed: &HashMap<i64, CommentVote>) -> CommentVote {
+        changed
+            .get(&self.rpid)
+            .copied()
+            .unwrap_or(match self.action {
+                1 => CommentVote::Like,
+                2 => CommentVote::Dislike,
+                _ => CommentVote::None,
+            })
+    }
+
     pub fn format_like(&self) -> String {
-        match self.like {
-            Some(n) if n >= 10000 => format!("{:.1}万", n as f64 / 10000.0),
-            Some(n) => format!("{}", n),
-            None => "-".to_string(),
-        }
+        format_count_opt(self.like.map(i64::from))
     }
 
     pub fn format_time(&self) -> String {
@@ -86,15 +174,15 @@ impl CommentItem {
             let diff = now - ctime;
 
             if diff < 60 {
-                "刚刚".to_string()
+                t("刚刚").to_string()
             } else if diff < 3600 {
-                format!("{}分钟前", diff / 60)
+                tf("{}分钟前", &[&(diff / 60)])
             } else if diff < 86400 {
-                format!("{}小时前", diff / 3600)
+                tf("{}小时前", &[&(diff / 3600)])
             } else if diff < 2592000 {
-                format!("{}天前", diff / 86400)
+                tf("{}天前", &[&(diff / 86400)])
             } else {
-                format!("{}月前", diff / 2592000)
+                tf("{}月前", &[&(diff / 2592000)])
             }
         } else {
             "".to_string()
@@ -104,6 +192,17 @@ impl CommentItem {
     pub fn reply_count(&self) -> i32 {
         self.rcount.unwrap_or(0)
     }
+
+    /// Whether the UP wrote this comment or replied under it
+    pub fn involves_up(&self, up_mid: i64) -> bool {
+        self.mid == up_mid
+            || self.up_action.as_ref().is_some_and(|a| a.reply)
+            || self
+                .replies
+                .iter()
+                .flatten()
+                .any(|reply| reply.mid == up_mid)
+    }
 }
 
 /// Comment type enum for different content types
@@ -147,3 +246,102 @@ pub struct AddCommentResponse {
     pub parent: Option<i64>,
     pub reply: Option<CommentItem>,
 }
+
+/// Why a comment was rejected, for the reply/add codes with a known cause
+pub fn add_comment_error_reason(code: i32) -> Option<&'static str> {
+    let reason = match code {
+        -509 | 12015 => t("发送太频繁，请稍后再试"),
+        -102 => t("账号已被封禁"),
+        12002 => t("评论区已关闭"),
+        12003 => t("该评论区禁止回复"),
+        12016 => t("评论包含敏感词，请修改后重发"),
+        12025 => t("评论字数超出上限"),
+        12035 => t("你已被 UP 主加入评论黑名单"),
+        12051 => t("重复评论，请勿刷屏"),
+        _ => return None,
+    };
+    Some(reason)
+}
+
+/// Whether the code says the comment was deleted (已经被删除了) or can't be found
+pub fn is_comment_gone(code: i32) -> bool {
+    matches!(code, -404 | 12022)
+}
+
+/// Find `mm:ss` / `h:mm:ss` time points in text, in seconds
+pub fn parse_timestamps(text: &str) -> Vec<i64> {
+    TIMESTAMP_RE
+        .find_iter(text)
+        .filter_map(|m| parse_clock(m.as_str()))
+        .collect()
+}
+
+fn parse_clock(clock: &str) -> Option<i64> {
+    let parts: Vec<&str> = clock.split([':', '：']).collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [m, s] if m.len() <= 3 && s.len() == 2 => ("0", *m, *s),
+        [h, m, s] if h.len() <= 2 && m.len() == 2 && s.len() == 2 => (*h, *m, *s),
+        _ => return None,
+    };
+    let hours: i64 = hours.parse().ok()?;
+    let minutes: i64 = minutes.parse().ok()?;
+    let seconds: i64 = seconds.parse().ok()?;
+    if seconds >= 60 || (parts.len() == 3 && minutes >= 60) {
+        return None;
+    }
+    Some(hours * 3600 + minutes * 60 + seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamps() {
+        assert_eq!(parse_timestamps("03:24 名场面"), vec![204]);
+        assert_eq!(parse_timestamps("1:02:03 和 12：05"), vec![3723, 725]);
+        assert_eq!(parse_timestamps("前方 0:5 高能"), Vec::<i64>::new());
+        // Not a time point: seconds out of range, too many parts
+        assert_eq!(parse_timestamps("比分 3:75，1:2:3:4"), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_involves_up() {
+        let comment = |json: &str| -> CommentItem { serde_json::from_str(json).unwrap() };
+        let by_up = comment(r#"{"rpid":1,"oid":1,"mid":42,"parent":0}"#);
+        let replied = comment(
+            r#"{"rpid":2,"oid":1,"mid":7,"parent":0,"up_action":{"like":false,"reply":true}}"#,
+        );
+        let preview = comment(
+            r#"{"rpid":3,"oid":1,"mid":7,"parent":0,"replies":[{"rpid":4,"oid":1,"mid":42,"parent":3}]}"#,
+        );
+        let other = comment(r#"{"rpid":5,"oid":1,"mid":7,"parent":0,"up_action":{"like":true}}"#);
+        assert!(by_up.involves_up(42));
+        assert!(replied.involves_up(42));
+        assert!(preview.involves_up(42));
+        assert!(!other.involves_up(42));
+    }
+
+    #[test]
+    fn test_comment_vote() {
+        let comment: CommentItem =
+            serde_json::from_str(r#"{"rpid":1,"oid":1,"mid":7,"parent":0,"action":1}"#).unwrap();
+        let mut changed = HashMap::new();
+        assert_eq!(comment.vote(&changed), CommentVote::Like);
+        changed.insert(1, CommentVote::Dislike);
+        assert_eq!(comment.vote(&changed), CommentVote::Dislike);
+
+        assert_eq!(
+            CommentVote::Like.toggled(CommentVote::Like),
+            CommentVote::None
+        );
+        assert_eq!(
+            CommentVote::Like.toggled(CommentVote::Dislike),
+            CommentVote::Dislike
+        );
+        assert_eq!(
+            CommentVote::None.toggled(CommentVote::Like),
+            CommentVote::Like
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Dynamic feed API types and functions
 
+use crate::i18n::t;
 use serde::Deserialize;
 
 /// Dynamic feed response
@@ -9,13 +10,29 @@ pub struct DynamicFeedData {
     pub offset: Option<String>,
     pub has_more: Option<bool>,
     pub update_num: Option<i32>,
+    /// Newest item id of this page, used to ask for new items later
+    #[serde(default)]
+    pub update_baseline: Option<String>,
 }
 
 /// Portal data response (frequently watched UPs)
 #[derive(Debug, Deserialize)]
 pub struct PortalData {
     pub up_list: Option<Vec<UpListItem>>,
-    // my_info and live_users are available but not needed for now
+    /// Followed UPs that are live right now
+    pub live_users: Option<PortalLiveUsers>,
+    // my_info is available but not needed for now
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PortalLiveUsers {
+    #[serde(default)]
+    pub items: Vec<PortalLiveUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PortalLiveUser {
+    pub mid: i64,
 }
 
 /// UP master in portal up_list
@@ -76,6 +93,13 @@ pub struct ArchiveInfo {
     pub desc: Option<String>,
     pub duration_text: Option<String>,
     pub stat: Option<ArchiveStat>,
+    /// 角标，如「投稿视频」「充电专属」
+    pub badge: Option<ArchiveBadge>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchiveBadge {
+    pub text: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -147,7 +171,7 @@ impl DynamicItem {
             .as_ref()
             .and_then(|m| m.module_author.as_ref())
             .and_then(|a| a.name.as_deref())
-            .unwrap_or("未知")
+            .unwrap_or(t("未知"))
     }
 
     pub fn author_face(&self) -> Option<&str> {
@@ -214,6 +238,33 @@ impl DynamicItem {
             .unwrap_or("-")
     }
 
+    /// Whether the video is 充电专属
+    pub fn video_charge_only(&self) -> bool {
+        self.modules
+            .as_ref()
+            .and_then(|m| m.module_dynamic.as_ref())
+            .and_then(|d| d.major.as_ref())
+            .and_then(|m| m.archive.as_ref())
+            .and_then(|a| a.badge.as_ref())
+            .and_then(|b| b.text.as_deref())
+            .is_some_and(|text| text == "充电专属")
+    }
+
+    /// Publish time (Unix timestamp)
+    pub fn pub_ts(&self) -> Option<i64> {
+        self.modules
+            .as_ref()
+            .and_then(|m| m.module_author.as_ref())
+            .and_then(|a| a.pub_ts)
+    }
+
+    pub fn author_mid(&self) -> Option<i64> {
+        self.modules
+            .as_ref()
+            .and_then(|m| m.module_author.as_ref())
+            .and_then(|a| a.mid)
+    }
+
     pub fn video_duration(&self) -> &str {
         self.modules
             .as_ref()
@@ -0,0 +1,168 @@
+//! Structured API errors, so pages can tell "not logged in / risk control / network /
+//! bad response" apart and pick the error UI and retry strategy
+//!
+//! Errors still travel as `anyhow::Error`; [`ApiError::of`] gets the variant back.
+
+/// Codes meaning the login cookie is missing or expired (账号未登录)
+const NOT_LOGGED_IN_CODES: &[i32] = &[-101, -111];
+/// Codes of requests rejected by risk control (风控校验失败 / 请求被拦截)
+const RISK_CONTROL_CODES: &[i32] = &[-352, -412];
+/// Codes of requests refused for coming too often (请求过于频繁)
+const RATE_LIMIT_CODES: &[i32] = &[-509, -799];
+/// HTTP statuses of throttled or blocked requests
+const RATE_LIMIT_STATUSES: &[u16] = &[412, 429];
+/// Codes of temporary server trouble (服务调用超时 / 服务繁忙), worth retrying
+const SERVER_BUSY_CODES: &[i32] = &[-500, -503, -504, -509];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    /// The request needs a login, or the saved cookie expired
+    NotLoggedIn { code: i32, message: String },
+    /// Rejected by risk control, even after refreshing the WBI keys where that applies
+    RiskControl { code: i32, message: String },
+    /// No response: DNS, connection, TLS or timeout
+    Network(String),
+    /// The response is not the expected JSON
+    Parse(String),
+    /// Any other non-zero Bilibili code
+    Code { code: i32, message: String },
+}
+
+/// What kind of error a page is showing, without the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiErrorKind {
+    NotLoggedIn,
+    RiskControl,
+    Network,
+    Parse,
+    /// A Bilibili code without special handling, or not an API error at all
+    #[default]
+    Other,
+}
+
+impl ApiError {
+    /// Error for a non-zero `code`; `message` is shown as is
+    pub fn from_code(code: i32, message: impl Into<String>) -> Self {
+        let message = message.into();
+        match ApiErrorKind::from_code(code) {
+            ApiErrorKind::NotLoggedIn => ApiError::NotLoggedIn { code, message },
+            ApiErrorKind::RiskControl => ApiError::RiskControl { code, message },
+            _ => ApiError::Code { code, message },
+        }
+    }
+
+    /// The API error inside an `anyhow::Error`, if it is one
+    pub fn of(err: &anyhow::Error) -> Option<&ApiError> {
+        err.downcast_ref::<ApiError>()
+    }
+
+    pub fn kind(&self) -> ApiErrorKind {
+        match self {
+            ApiError::NotLoggedIn { .. } => ApiErrorKind::NotLoggedIn,
+            ApiError::RiskControl { .. } => ApiErrorKind::RiskControl,
+            ApiError::Network(_) => ApiErrorKind::Network,
+            ApiError::Parse(_) => ApiErrorKind::Parse,
+            ApiError::Code { .. } => ApiErrorKind::Other,
+        }
+    }
+
+    /// Bilibili error code, None for network and parse errors
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            ApiError::NotLoggedIn { code, .. }
+            | ApiError::RiskControl { code, .. }
+            | ApiError::Code { code, .. } => Some(*code),
+            ApiError::Network(_) | ApiError::Parse(_) => None,
+        }
+    }
+
+    /// Whether the same request is likely to succeed if sent again a bit later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::Network(_) => true,
+            ApiError::Code { code, .. } => SERVER_BUSY_CODES.contains(code),
+            _ => false,
+        }
+    }
+}
+
+/// Whether a non-zero code means the request was rate-limited or blocked by risk control
+/// (限流/风控), the errors listed in the network stats panel
+pub fn is_throttled_code(code: i32) -> bool {
+    RATE_LIMIT_CODES.contains(&code) || ApiErrorKind::from_code(code) == ApiErrorKind::RiskControl
+}
+
+/// Whether an HTTP status means the request was rate-limited or blocked
+pub fn is_throttled_status(status: u16) -> bool {
+    RATE_LIMIT_STATUSES.contains(&status)
+}
+
+impl ApiErrorKind {
+    /// Kind of a non-zero Bilibili code
+    pub fn from_code(code: i32) -> Self {
+        if NOT_LOGGED_IN_CODES.contains(&code) {
+            ApiErrorKind::NotLoggedIn
+        } else if RISK_CONTROL_CODES.contains(&code) {
+            ApiErrorKind::RiskControl
+        } else {
+            ApiErrorKind::Other
+        }
+    }
+
+    /// Kind of any error; errors from outside the API client are [`ApiErrorKind::Other`]
+    pub fn of(err: &anyhow::Error) -> Self {
+        ApiError::of(err).map(ApiError::kind).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::NotLoggedIn { message, .. } | ApiError::Code { message, .. } => {
+                f.write_str(message)
+            }
+            ApiError::RiskControl { code, message } => {
+                write!(f, "Rejected by risk control ({}): {}", code, message)
+            }
+            ApiError::Network(message) => write!(f, "Network error: {}", message),
+            ApiError::Parse(message) => write!(f, "Unexpected response: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_map_to_kinds() {
+        assert_eq!(
+            ApiError::from_code(-101, "账号未登录").kind(),
+            ApiErrorKind::NotLoggedIn
+        );
+        assert_eq!(
+            ApiError::from_code(-352, "风控校验失败").kind(),
+            ApiErrorKind::RiskControl
+        );
+        assert!(is_throttled_code(-509));
+        assert!(is_throttled_code(-412));
+        assert!(!is_throttled_code(-101));
+        assert!(is_throttled_status(429));
+        assert!(!is_throttled_status(404));
+
+        let busy = ApiError::from_code(-503, "服务繁忙");
+        assert_eq!(busy.kind(), ApiErrorKind::Other);
+        assert!(busy.is_retryable());
+        assert!(!ApiError::from_code(-404, "啥都木有").is_retryable());
+
+        // The variant survives the trip through anyhow
+        let err: anyhow::Error = ApiError::Network("timed out".into()).into();
+        assert_eq!(ApiErrorKind::of(&err), ApiErrorKind::Network);
+        assert_eq!(
+            ApiErrorKind::of(&anyhow::anyhow!("other")),
+            ApiErrorKind::Other
+        );
+    }
+}
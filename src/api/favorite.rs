@@ -0,0 +1,139 @@
+//! Favorites (收藏夹) API types
+//!
+//! API endpoints:
+//! - GET https://api.bilibili.com/x/v3/fav/folder/created/list-all
+//! - GET https://api.bilibili.com/x/v3/fav/resource/list
+//! - POST https://api.bilibili.com/x/v3/fav/resource/move, /copy
+//! - POST https://api.bilibili.com/x/v3/fav/folder/add, /edit, /del
+//!
+//! Authentication: Cookie (SESSDATA), private folders are only visible to their owner
+
+use crate::format::format_count;
+use serde::Deserialize;
+
+/// Response data from /x/v3/fav/folder/created/list-all
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavFolderList {
+    #[serde(default)]
+    pub count: i32,
+    #[serde(default)]
+    pub list: Option<Vec<FavFolder>>,
+}
+
+/// A favorites folder created by the user
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavFolder {
+    /// media_id used by the resource APIs
+    pub id: i64,
+    pub title: String,
+    #[serde(default)]
+    pub media_count: i32,
+    /// Bit 0 set: 私密; bit 1 clear: 默认收藏夹
+    #[serde(default)]
+    pub attr: i32,
+    /// 1 when the video asked about with `rid` is in this folder
+    #[serde(default)]
+    pub fav_state: i32,
+}
+
+impl FavFolder {
+    pub fn is_private(&self) -> bool {
+        self.attr & 1 != 0
+    }
+
+    /// The default folder can't be deleted
+    pub fn is_default(&self) -> bool {
+        self.attr & 2 == 0
+    }
+}
+
+/// Response data from /x/v3/fav/resource/list
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavResourceData {
+    #[serde(default)]
+    pub medias: Option<Vec<FavMedia>>,
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// A video in a favorites folder
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavMedia {
+    /// aid for videos
+    pub id: i64,
+    /// 2 = video, 12 = audio, 21 = collection
+    #[serde(rename = "type")]
+    pub media_type: i32,
+    pub title: String,
+    pub cover: Option<String>,
+    #[serde(default)]
+    pub duration: i64,
+    pub upper: FavUpper,
+    pub cnt_info: Option<FavCntInfo>,
+    #[serde(default)]
+    pub bvid: String,
+    /// 收藏时间 (Unix timestamp)
+    #[serde(default)]
+    pub fav_time: i64,
+    /// 分P数
+    #[serde(default)]
+    pub page: i32,
+    /// Bit 0 set: 已失效（被删除或下架）
+    #[serde(default)]
+    pub attr: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavUpper {
+    pub mid: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FavCntInfo {
+    #[serde(default)]
+    pub play: i64,
+}
+
+impl FavMedia {
+    pub fn is_video(&self) -> bool {
+        self.media_type == 2 && !self.bvid.is_empty()
+    }
+
+    /// The video was deleted or taken down, only a placeholder is left
+    pub fn is_invalid(&self) -> bool {
+        self.attr & 1 != 0
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://www.bilibili.com/video/{}", self.bvid)
+    }
+
+    pub fn format_play(&self) -> String {
+        let play = self.cnt_info.as_ref().map(|c| c.play).unwrap_or(0);
+        format_count(play)
+    }
+
+    pub fn format_duration(&self) -> String {
+        let (h, m, s) = (
+            self.duration / 3600,
+            (self.duration % 3600) / 60,
+            self.duration % 60,
+        );
+        if h > 0 {
+            format!("{}:{:02}:{:02}", h, m, s)
+        } else {
+            format!("{:02}:{:02}", m, s)
+        }
+    }
+
+    pub fn cover_url(&self) -> Option<String> {
+        self.cover.as_ref().map(|url| {
+            if url.starts_with("//") {
+                format!("https:{}", url)
+            } else {
+                url.clone()
+            }
+        })
+    }
+}
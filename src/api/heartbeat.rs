@@ -1,5 +1,310 @@
+//! Playback progress reporting
+//!
+//! Each content type reports differently, so players drive a [`MediaSession`]:
+//! `start` once, `heartbeat` every [`HEARTBEAT_INTERVAL`] and `stop` after the player exits
+
+use super::client::{ApiClient, BilibiliApiDomain};
 use anyhow::Result;
+use hmac::{Hmac, Mac};
 use serde::Deserialize;
+use std::future::Future;
+use std::hash::{BuildHasher, RandomState};
+use std::time::{Duration, Instant};
+
+/// How often a running session reports progress
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Progress reporting for one playback; `played` is the position in seconds
+pub trait MediaSession: Send {
+    /// Playback is about to start
+    fn start(&mut self, client: &ApiClient) -> impl Future<Output = Result<()>> + Send;
+
+    /// Periodic progress report while playing
+    fn heartbeat(
+        &mut self,
+        _client: &ApiClient,
+        _played: i64,
+    ) -> impl Future<Output = Result<()>> + Send {
+        async { Ok(()) }
+    }
+
+    /// Final report after the player exited
+    fn stop(
+        &mut self,
+        _client: &ApiClient,
+        _played: i64,
+    ) -> impl Future<Output = Result<()>> + Send {
+        async { Ok(()) }
+    }
+}
+
+/// 普通视频（稿件）的观看上报
+pub struct VideoSession {
+    aid: i64,
+    cid: i64,
+    bvid: String,
+    duration: i64,
+    start_ts: i64,
+    started: Instant,
+}
+
+impl VideoSession {
+    pub fn new(aid: i64, cid: i64, bvid: &str, duration: i64) -> Self {
+        Self {
+            aid,
+            cid,
+            bvid: bvid.to_string(),
+            duration,
+            start_ts: chrono::Utc::now().timestamp(),
+            started: Instant::now(),
+        }
+    }
+
+    async fn report(&self, client: &ApiClient, played: i64, play_type: i32) -> Result<()> {
+        let real_played_time = self.started.elapsed().as_secs() as i64;
+        report_heartbeat(
+            client,
+            self.aid,
+            self.cid,
+            &self.bvid,
+            played,
+            real_played_time,
+            real_played_time,
+            self.start_ts,
+            play_type,
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl MediaSession for VideoSession {
+    async fn start(&mut self, client: &ApiClient) -> Result<()> {
+        self.start_ts = chrono::Utc::now().timestamp();
+        self.started = Instant::now();
+        report_watch_start(client, self.aid, self.cid, &self.bvid, self.duration)
+            .await
+            .map(|_| ())
+    }
+
+    async fn heartbeat(&mut self, client: &ApiClient, played: i64) -> Result<()> {
+        self.report(client, played, 0).await // play_type: 0 = playing
+    }
+
+    async fn stop(&mut self, client: &ApiClient, played: i64) -> Result<()> {
+        self.report(client, played, 4).await // play_type: 4 = end
+    }
+}
+
+/// 直播间观看：进入时上报一次，记录到直播观看历史
+pub struct LiveSession {
+    room_id: i64,
+}
+
+impl LiveSession {
+    pub fn new(room_id: i64) -> Self {
+        Self { room_id }
+    }
+}
+
+impl MediaSession for LiveSession {
+    async fn start(&mut self, client: &ApiClient) -> Result<()> {
+        let url = format!(
+            "{}/xlive/web-room/v1/index/roomEntryAction",
+            super::client::BilibiliApiDomain::Live.as_str()
+        );
+        let form_data = vec![
+            ("room_id", self.room_id.to_string()),
+            ("platform", "pc".to_string()),
+        ];
+        let resp: super::client::ApiResponse<serde_json::Value> =
+            client.post(&url, form_data).await?;
+        if resp.code != 0 {
+            return Err(super::error::ApiError::from_code(
+                resp.code,
+                format!("Failed to enter live room: {}", resp.message),
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// 直播观看时长上报（E/X 心跳）的接口路径，域名见 [`BilibiliApiDomain::LiveTrace`]
+const LIVE_TRACE_PATH: &str = "/xlive/data-interface/v1/x25Kn";
+/// 服务端没给间隔时的心跳间隔（秒）
+const DEFAULT_LIVE_INTERVAL: i64 = 60;
+
+/// E/X 心跳响应
+#[derive(Debug, Deserialize)]
+struct LiveTraceData {
+    timestamp: i64,
+    heartbeat_interval: i64,
+    secret_key: String,
+    /// 依次做哪些 HMAC，见 [`sign_live_heartbeat`]
+    secret_rule: Vec<u8>,
+}
+
+/// 直播观看时长上报：进房发 E，之后按服务端给的间隔发带签名的 X，
+/// 观看时长和粉丝勋章亲密度才会增长
+pub struct LiveHeartbeat {
+    room_id: i64,
+    parent_area_id: i64,
+    area_id: i64,
+    /// 本次进房后的第几次心跳
+    seq: i64,
+    buvid: String,
+    uuid: String,
+    /// 上一次响应的内容，下一次心跳要带上并参与签名
+    last: Option<LiveTraceData>,
+}
+
+impl LiveHeartbeat {
+    pub fn new(room_id: i64, parent_area_id: i64, area_id: i64) -> Self {
+        let uuid = random_uuid();
+        Self {
+            room_id,
+            parent_area_id,
+            area_id,
+            seq: 0,
+            buvid: format!("{}infoc", random_uuid().to_uppercase()),
+            uuid,
+            last: None,
+        }
+    }
+
+    /// Report entering the room (E), returning how long to wait before [`Self::beat`]
+    pub async fn enter(&mut self, client: &ApiClient) -> Result<Duration> {
+        self.seq = 0;
+        let mut form = self.common_form(client);
+        form.push(("is_patch", "0".to_string()));
+        form.push(("heart_beat", "[]".to_string()));
+        self.send(client, "E", form).await
+    }
+
+    /// Report that the room is still being watched (X)
+    pub async fn beat(&mut self, client: &ApiClient) -> Result<Duration> {
+        let Some(last) = &self.last else {
+            return self.enter(client).await;
+        };
+        self.seq += 1;
+        let ts = chrono::Utc::now().timestamp_millis();
+        let data = format!(
+            r#"{{"platform":"web","parent_id":{},"area_id":{},"seq_id":{},"room_id":{},"buvid":"{}","uuid":"{}","ets":{},"time":{},"ts":{}}}"#,
+            self.parent_area_id,
+            self.area_id,
+            self.seq,
+            self.room_id,
+            self.buvid,
+            self.uuid,
+            last.timestamp,
+            last.heartbeat_interval,
+            ts
+        );
+        let signature = sign_live_heartbeat(&data, &last.secret_key, &last.secret_rule);
+
+        let mut form = self.common_form(client);
+        form.push(("s", signature));
+        form.push(("ets", last.timestamp.to_string()));
+        form.push(("benchmark", last.secret_key.clone()));
+        form.push(("time", last.heartbeat_interval.to_string()));
+        form.retain(|(key, _)| *key != "ts");
+        form.push(("ts", ts.to_string()));
+        self.send(client, "X", form).await
+    }
+
+    fn common_form(&self, client: &ApiClient) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "id",
+                format!(
+                    "[{},{},{},{}]",
+                    self.parent_area_id, self.area_id, self.seq, self.room_id
+                ),
+            ),
+            ("device", format!(r#"["{}","{}"]"#, self.buvid, self.uuid)),
+            ("ts", chrono::Utc::now().timestamp_millis().to_string()),
+            ("ua", super::transport::UA.to_string()),
+            ("csrf_token", client.csrf_token().unwrap_or_default()),
+            ("visit_id", String::new()),
+        ]
+    }
+
+    async fn send(
+        &mut self,
+        client: &ApiClient,
+        endpoint: &str,
+        form: Vec<(&str, String)>,
+    ) -> Result<Duration> {
+        let url = format!(
+            "{}{}/{}",
+            BilibiliApiDomain::LiveTrace.as_str(),
+            LIVE_TRACE_PATH,
+            endpoint
+        );
+        let resp: super::client::ApiResponse<LiveTraceData> = client.post(&url, form).await?;
+        if resp.code != 0 {
+            self.last = None;
+            return Err(super::error::ApiError::from_code(
+                resp.code,
+                format!("Failed to report live heartbeat: {}", resp.message),
+            )
+            .into());
+        }
+        let data = resp
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in live heartbeat response"))?;
+        let interval = if data.heartbeat_interval > 0 {
+            data.heartbeat_interval
+        } else {
+            DEFAULT_LIVE_INTERVAL
+        };
+        self.last = Some(data);
+        Ok(Duration::from_secs(interval as u64))
+    }
+}
+
+/// Sign an X heartbeat: HMAC `data` with `key` once per rule, hex-encoding each result as
+/// the input of the next. Rules: 0 MD5, 1 SHA1, 2 SHA256, 3 SHA224, 4 SHA512, 5 SHA384
+pub fn sign_live_heartbeat(data: &str, key: &str, rules: &[u8]) -> String {
+    fn hmac_hex<M: Mac + hmac::digest::KeyInit>(key: &str, data: &str) -> String {
+        let mut mac = <M as hmac::digest::KeyInit>::new_from_slice(key.as_bytes())
+            .expect("HMAC accepts any key length");
+        mac.update(data.as_bytes());
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    rules
+        .iter()
+        .fold(data.to_string(), |text, rule| match rule {
+            0 => hmac_hex::<Hmac<md5::Md5>>(key, &text),
+            1 => hmac_hex::<Hmac<sha1::Sha1>>(key, &text),
+            2 => hmac_hex::<Hmac<sha2::Sha256>>(key, &text),
+            3 => hmac_hex::<Hmac<sha2::Sha224>>(key, &text),
+            4 => hmac_hex::<Hmac<sha2::Sha512>>(key, &text),
+            5 => hmac_hex::<Hmac<sha2::Sha384>>(key, &text),
+            _ => text,
+        })
+}
+
+/// A random version 4 style UUID, enough to tell this client's heartbeats apart
+fn random_uuid() -> String {
+    let hex: String = (0..2)
+        .map(|i| format!("{:016x}", RandomState::new().hash_one((i, Instant::now()))))
+        .collect();
+    format!(
+        "{}-{}-4{}-a{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[13..16],
+        &hex[17..20],
+        &hex[20..32]
+    )
+}
 
 #[derive(Debug, Deserialize)]
 pub struct HeartbeatResponse {
@@ -9,7 +314,7 @@ pub struct HeartbeatResponse {
 
 /// Report video watch start to Bilibili
 pub async fn report_watch_start(
-    client: &super::client::ApiClient,
+    client: &ApiClient,
     aid: i64,
     cid: i64,
     bvid: &str,
@@ -44,7 +349,7 @@ pub async fn report_watch_start(
 /// Report video playback heartbeat
 #[allow(clippy::too_many_arguments)]
 pub async fn report_heartbeat(
-    client: &super::client::ApiClient,
+    client: &ApiClient,
     aid: i64,
     cid: i64,
     bvid: &str,
@@ -83,3 +388,35 @@ pub async fn report_heartbeat(
     resp.data
         .ok_or_else(|| anyhow::anyhow!("No data in heartbeat response"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_live_heartbeat_chains_hmacs() {
+        // The well-known HMAC-MD5 / HMAC-SHA256 examples for a single round
+        let key = "key";
+        let data = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            sign_live_heartbeat(data, key, &[0]),
+            "80070713463e7749b90c2dc24911e275"
+        );
+        assert_eq!(
+            sign_live_heartbeat(data, key, &[2]),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        // Each round hashes the hex output of the previous one
+        let chained = sign_live_heartbeat(data, key, &[0, 2]);
+        let md5 = sign_live_heartbeat(data, key, &[0]);
+        assert_eq!(chained, sign_live_heartbeat(&md5, key, &[2]));
+        assert_eq!(sign_live_heartbeat(data, key, &[]), data);
+    }
+
+    #[test]
+    fn test_random_uuid_format() {
+        let uuid = random_uuid();
+        let lengths: Vec<usize> = uuid.split('-').map(str::len).collect();
+        assert_eq!(lengths, [8, 4, 4, 4, 12]);
+    }
+}
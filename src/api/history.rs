@@ -3,6 +3,7 @@
 //! API endpoint: GET https://api.bilibili.com/x/web-interface/history/cursor
 //! Authentication: Cookie (SESSDATA)
 
+use crate::i18n::{t, tf};
 use serde::Deserialize;
 
 /// Response data for history cursor API
@@ -34,6 +35,9 @@ pub struct HistoryTab {
     pub name: String,
 }
 
+/// Title history keeps for a video that was deleted or taken down
+const INVALID_TITLE: &str = "已失效视频";
+
 /// Individual history item
 #[derive(Debug, Clone, Deserialize)]
 pub struct HistoryItem {
@@ -164,19 +168,19 @@ impl HistoryItem {
         let diff = now - self.view_at;
 
         if diff < 60 {
-            "刚刚".to_string()
+            t("刚刚").to_string()
         } else if diff < 3600 {
-            format!("{}分钟前", diff / 60)
+            tf("{}分钟前", &[&(diff / 60)])
         } else if diff < 86400 {
-            format!("{}小时前", diff / 3600)
+            tf("{}小时前", &[&(diff / 3600)])
         } else if diff < 604800 {
-            format!("{}天前", diff / 86400)
+            tf("{}天前", &[&(diff / 86400)])
         } else {
             // Format as date
             let secs = self.view_at;
             let days_since_epoch = secs / 86400;
             let year = 1970 + (days_since_epoch / 365);
-            format!("{}年", year)
+            tf("{}年", &[&year])
         }
     }
 
@@ -185,8 +189,24 @@ impl HistoryItem {
         matches!(self.history.business.as_str(), "archive" | "pgc")
     }
 
+    /// The video was deleted or taken down; history only keeps a placeholder title for it
+    pub fn is_invalid(&self) -> bool {
+        self.history.business == "archive" && self.title == INVALID_TITLE
+    }
+
     /// Get bvid if available
     pub fn get_bvid(&self) -> Option<&str> {
         self.history.bvid.as_deref().filter(|s| !s.is_empty())
     }
+
+    /// Record id used to delete this entry, e.g. `archive_170001`;
+    /// 番剧按 season id（`kid`）删除
+    pub fn delete_kid(&self) -> String {
+        let id = if self.history.business == "pgc" {
+            self.kid
+        } else {
+            self.history.oid
+        };
+        format!("{}_{}", self.history.business, id)
+    }
 }
@@ -0,0 +1,91 @@
+//! Interactive video (互动视频) story graph API types
+
+use serde::Deserialize;
+
+/// Player info from /x/player/wbi/v2, only the interactive part is used
+#[derive(Debug, Deserialize)]
+pub struct PlayerInfo {
+    #[serde(default)]
+    pub interaction: Option<Interaction>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Interaction {
+    pub graph_version: i64,
+}
+
+/// A story node from /x/stein/edgeinfo_v2
+#[derive(Debug, Deserialize)]
+pub struct EdgeInfo {
+    #[serde(default)]
+    pub title: String,
+    pub edge_id: i64,
+    #[serde(default)]
+    pub edges: Option<Edges>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Edges {
+    #[serde(default)]
+    pub questions: Vec<Question>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Question {
+    #[serde(default)]
+    pub choices: Vec<StoryChoice>,
+}
+
+/// A branch the viewer can pick at the end of a node
+#[derive(Debug, Clone, Deserialize)]
+pub struct StoryChoice {
+    /// Edge id of the node this choice leads to
+    pub id: i64,
+    pub cid: i64,
+    #[serde(default)]
+    pub option: String,
+}
+
+impl EdgeInfo {
+    /// Choices offered at the end of this node; empty for an ending
+    pub fn choices(&self) -> Vec<StoryChoice> {
+        self.edges
+            .as_ref()
+            .and_then(|edges| edges.questions.first())
+            .map(|q| q.choices.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Progressive stream URLs from /x/player/playurl
+#[derive(Debug, Deserialize)]
+pub struct PlayUrlData {
+    #[serde(default)]
+    pub durl: Vec<PlayUrlSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayUrlSegment {
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_choices() {
+        let edge: EdgeInfo = serde_json::from_str(
+            r#"{"title":"开头","edge_id":1,"edges":{"questions":[{"choices":[
+                {"id":2,"cid":200,"option":"向左走"},
+                {"id":3,"cid":300,"option":"向右走"}]}]}}"#,
+        )
+        .unwrap();
+        let choices = edge.choices();
+        let options: Vec<&str> = choices.iter().map(|c| c.option.as_str()).collect();
+        assert_eq!(options, vec!["向左走", "向右走"]);
+
+        let ending: EdgeInfo = serde_json::from_str(r#"{"title":"结局","edge_id":9}"#).unwrap();
+        assert!(ending.choices().is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+//! Bilibili link parsing: BV号 / av号 / 视频链接 / 评论楼层 / 直播间链接 / b23.tv 短链
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+const XOR_CODE: u64 = 23442827791579;
+const MASK_CODE: u64 = 2251799813685247;
+const MAX_AID: u64 = 1 << 51;
+const BASE: u64 = 58;
+const ALPHABET: &[u8] = b"FcwAPNKTMug3GV5Lj7EJnHpWsx4tb8haYeviqBz6rkCy12mUSDQX9RdoZf";
+
+static BV_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bBV(1[0-9A-Za-z]{9})").expect("valid regex"));
+static AV_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bav(\d+)").expect("valid regex"));
+static LIVE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"live\.bilibili\.com/(?:h5/|blanc/)?(\d+)").expect("valid regex"));
+/// `#reply123` anchors and `comment_root_id=123` of comment links, e.g. from reply notifications
+static COMMENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:#reply|[?&]comment_root_id=)(\d+)").expect("valid regex"));
+static SHORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:https?://)?(?:b23\.tv|bili2233\.cn)/[0-9A-Za-z]+").expect("valid regex")
+});
+
+/// A link target recognized from user input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BiliLink {
+    Video { bvid: String, aid: i64 },
+    Live { room_id: i64 },
+}
+
+/// Find a b23.tv short link in the input, which must be expanded before parsing
+pub fn find_short_link(input: &str) -> Option<&str> {
+    SHORT_RE.find(input).map(|m| m.as_str())
+}
+
+/// Whether the text contains anything [`parse_link`] or a short link expansion can open
+pub fn contains_link(text: &str) -> bool {
+    find_short_link(text).is_some() || parse_link(text).is_some()
+}
+
+/// Parse a BV号, av号, video URL or live room URL
+pub fn parse_link(input: &str) -> Option<BiliLink> {
+    let input = input.trim();
+
+    if let Some(caps) = BV_RE.captures(input) {
+        let bvid = format!("BV{}", &caps[1]);
+        let aid = bv_to_av(&bvid)?;
+        return Some(BiliLink::Video { bvid, aid });
+    }
+
+    if let Some(caps) = AV_RE.captures(input) {
+        let aid: i64 = caps[1].parse().ok()?;
+        return Some(BiliLink::Video {
+            bvid: av_to_bv(aid),
+            aid,
+        });
+    }
+
+    if let Some(caps) = LIVE_RE.captures(input) {
+        let room_id: i64 = caps[1].parse().ok()?;
+        return Some(BiliLink::Live { room_id });
+    }
+
+    None
+}
+
+/// The comment (rpid) a video link points at
+pub fn comment_anchor(input: &str) -> Option<i64> {
+    COMMENT_RE.captures(input)?[1].parse().ok()
+}
+
+/// Convert an av号 to BV号
+pub fn av_to_bv(aid: i64) -> String {
+    let mut bytes = *b"BV1000000000";
+    let mut idx = bytes.len() - 1;
+    let mut tmp = (MAX_AID | aid as u64) ^ XOR_CODE;
+    while tmp > 0 {
+        bytes[idx] = ALPHABET[(tmp % BASE) as usize];
+        tmp /= BASE;
+        idx -= 1;
+    }
+    bytes.swap(3, 9);
+    bytes.swap(4, 7);
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Convert a BV号 to av号
+pub fn bv_to_av(bvid: &str) -> Option<i64> {
+    let mut bytes: Vec<u8> = bvid.bytes().collect();
+    if bytes.len() != 12 {
+        return None;
+    }
+    bytes.swap(3, 9);
+    bytes.swap(4, 7);
+
+    let mut tmp: u64 = 0;
+    for b in &bytes[3..] {
+        let pos = ALPHABET.iter().position(|c| c == b)? as u64;
+        tmp = tmp * BASE + pos;
+    }
+    Some(((tmp & MASK_CODE) ^ XOR_CODE) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_av_bv_conversion() {
+        assert_eq!(av_to_bv(170001), "BV17x411w7KC");
+        assert_eq!(av_to_bv(882584971), "BV1mK4y1C7Bz");
+        assert_eq!(bv_to_av("BV17x411w7KC"), Some(170001));
+        assert_eq!(bv_to_av("BV1mK4y1C7Bz"), Some(882584971));
+    }
+
+    #[test]
+    fn test_parse_link() {
+        let video = BiliLink::Video {
+            bvid: "BV17x411w7KC".to_string(),
+            aid: 170001,
+        };
+        assert_eq!(parse_link("BV17x411w7KC"), Some(video.clone()));
+        assert_eq!(parse_link(" av170001 "), Some(video.clone()));
+        assert_eq!(
+            parse_link("https://www.bilibili.com/video/BV17x411w7KC/?p=2"),
+            Some(video.clone())
+        );
+        assert_eq!(
+            parse_link("https://www.bilibili.com/video/av170001"),
+            Some(video)
+        );
+        assert_eq!(
+            parse_link("https://live.bilibili.com/21452505?broadcast_type=0"),
+            Some(BiliLink::Live { room_id: 21452505 })
+        );
+        assert_eq!(parse_link("hello"), None);
+    }
+
+    #[test]
+    fn test_comment_anchor() {
+        assert_eq!(
+            comment_anchor("https://www.bilibili.com/video/BV17x411w7KC#reply123456"),
+            Some(123456)
+        );
+        assert_eq!(
+            comment_anchor("https://www.bilibili.com/video/BV17x411w7KC?comment_root_id=42"),
+            Some(42)
+        );
+        assert_eq!(
+            comment_anchor("https://www.bilibili.com/video/BV17x411w7KC#comment"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_short_link() {
+        assert_eq!(
+            find_short_link("【分享】 https://b23.tv/aBc123 快来看"),
+            Some("https://b23.tv/aBc123")
+        );
+        assert_eq!(find_short_link("BV17x411w7KC"), None);
+    }
+}
@@ -1,5 +1,7 @@
 //! Bilibili Live Streaming API types and utilities
 
+use crate::i18n::{t, tf};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, TimeZone};
 use serde::Deserialize;
 
 /// Live room recommendation item from getMoreRecList API
@@ -19,6 +21,8 @@ pub struct LiveRoom {
     #[serde(default)]
     pub area_v2_parent_name: String,
     #[serde(default)]
+    pub area_v2_parent_id: i64,
+    #[serde(default)]
     pub watched_show: Option<WatchedShow>,
 }
 
@@ -37,6 +41,38 @@ pub struct LiveRecommendData {
     pub recommend_room_list: Vec<LiveRoom>,
 }
 
+/// A room of an area's popularity ranking, from room/v1/area/getRoomList
+#[derive(Debug, Clone, Deserialize)]
+pub struct AreaRankRoom {
+    pub roomid: i64,
+    pub uid: i64,
+    pub uname: String,
+    #[serde(default)]
+    pub title: String,
+    /// 人气值
+    #[serde(default)]
+    pub online: i64,
+}
+
+/// Followed anchors that are live, from xfetter/GetWebList
+#[derive(Debug, Deserialize)]
+pub struct FollowingLiveData {
+    #[serde(default)]
+    pub count: i32,
+    #[serde(default)]
+    pub list: Option<Vec<FollowingLive>>,
+}
+
+/// A followed anchor that is live right now
+#[derive(Debug, Clone, Deserialize)]
+pub struct FollowingLive {
+    pub room_id: i64,
+    pub uid: i64,
+    pub uname: String,
+    #[serde(default)]
+    pub title: String,
+}
+
 /// Live room detailed info from get_info API
 #[derive(Debug, Clone, Deserialize)]
 pub struct LiveRoomInfo {
@@ -55,6 +91,8 @@ pub struct LiveRoomInfo {
     pub area_name: String,
     #[serde(default)]
     pub parent_area_name: String,
+    #[serde(default)]
+    pub parent_area_id: i64,
     /// 关注数
     #[serde(default)]
     pub attention: i64,
@@ -71,6 +109,56 @@ pub struct LiveRoomInfo {
     pub tags: String,
 }
 
+/// Anchor info response from get_anchor_in_room API
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiveAnchorData {
+    pub info: LiveAnchorInfo,
+}
+
+/// Basic anchor (streamer) info
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiveAnchorInfo {
+    pub uid: i64,
+    pub uname: String,
+    #[serde(default)]
+    pub face: String,
+}
+
+/// High-energy rank response from getOnlineGoldRank API
+#[derive(Debug, Clone, Deserialize)]
+pub struct OnlineGoldRankData {
+    #[serde(rename = "onlineNum", default)]
+    pub online_num: i64,
+    #[serde(rename = "OnlineRankItem", default)]
+    pub items: Vec<OnlineRankItem>,
+}
+
+/// Single user in the high-energy rank
+#[derive(Debug, Clone, Deserialize)]
+pub struct OnlineRankItem {
+    #[serde(rename = "userRank")]
+    pub user_rank: i32,
+    pub uid: i64,
+    pub name: String,
+    #[serde(default)]
+    pub face: String,
+    /// 贡献值
+    #[serde(default)]
+    pub score: i64,
+}
+
+impl From<OnlineRankItem> for super::live_ws::RankUser {
+    fn from(item: OnlineRankItem) -> Self {
+        Self {
+            uid: item.uid,
+            uname: item.name,
+            rank: item.user_rank,
+            face: item.face,
+            score: item.score.to_string(),
+        }
+    }
+}
+
 impl LiveRoomInfo {
     /// Get display cover URL
     pub fn cover_url(&self) -> &str {
@@ -83,13 +171,80 @@ impl LiveRoomInfo {
         }
     }
 
+    /// Get preview image URL, prefer the keyframe while live
+    pub fn preview_url(&self) -> &str {
+        if self.live_status == 1 && !self.keyframe.is_empty() {
+            &self.keyframe
+        } else {
+            self.cover_url()
+        }
+    }
+
+    /// Start of the current broadcast in local time, None when not live.
+    /// live_time is given in Beijing time ("0000-00-00 00:00:00" when offline)
+    pub fn live_since(&self) -> Option<DateTime<Local>> {
+        if self.live_status != 1 {
+            return None;
+        }
+        let naive = NaiveDateTime::parse_from_str(&self.live_time, "%Y-%m-%d %H:%M:%S").ok()?;
+        let beijing = FixedOffset::east_opt(8 * 3600)?;
+        let start = beijing.from_local_datetime(&naive).single()?;
+        Some(start.with_timezone(&Local))
+    }
+
     /// Get live status text
     pub fn status_text(&self) -> &'static str {
         match self.live_status {
-            0 => "未开播",
-            1 => "直播中",
-            2 => "轮播中",
-            _ => "未知",
+            0 => t("未开播"),
+            1 => t("直播中"),
+            2 => t("轮播中"),
+            _ => t("未知"),
         }
     }
 }
+
+/// "已开播 2 小时 13 分" for a broadcast running `secs` seconds
+pub fn format_live_duration(secs: i64) -> String {
+    let minutes = secs.max(0) / 60;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    if hours > 0 {
+        tf("已开播 {} 小时 {} 分", &[&hours, &minutes])
+    } else {
+        tf("已开播 {} 分", &[&minutes])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_since_parses_beijing_time() {
+        let room: LiveRoomInfo = serde_json::from_value(serde_json::json!({
+            "uid": 1,
+            "room_id": 2,
+            "title": "t",
+            "live_status": 1,
+            "live_time": "2024-05-01 20:00:00"
+        }))
+        .unwrap();
+        let since = room.live_since().unwrap();
+        assert_eq!(since.timestamp(), 1714564800);
+
+        let offline = LiveRoomInfo {
+            live_status: 0,
+            live_time: "0000-00-00 00:00:00".to_string(),
+            ..room
+        };
+        assert!(offline.live_since().is_none());
+    }
+
+    #[test]
+    fn test_format_live_duration() {
+        assert_eq!(format_live_duration(59), "已开播 0 分");
+        assert_eq!(
+            format_live_duration(2 * 3600 + 13 * 60 + 5),
+            "已开播 2 小时 13 分"
+        );
+    }
+}
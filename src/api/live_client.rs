@@ -11,12 +11,45 @@ use tokio::sync::mpsc;
 use tokio::time::{interval, Duration};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+/// Messages are delivered in batches, so a danmaku burst costs one redraw per window
+const BATCH_INTERVAL: Duration = Duration::from_millis(100);
+/// Danmaku kept per batch (100 per second); the rest are only counted
+const MAX_DANMAKU_PER_BATCH: usize = 10;
+
+/// Messages received during one batch window
+#[derive(Debug, Default)]
+pub struct LiveBatch {
+    pub messages: Vec<LiveMessage>,
+    /// Danmaku dropped because they came in faster than the limit
+    pub omitted: usize,
+    /// Danmaku kept so far
+    danmaku: usize,
+}
+
+impl LiveBatch {
+    /// Add a message, dropping danmaku over the per-batch limit
+    fn push(&mut self, msg: LiveMessage) {
+        if matches!(msg, LiveMessage::Danmaku { .. }) {
+            if self.danmaku >= MAX_DANMAKU_PER_BATCH {
+                self.omitted += 1;
+                return;
+            }
+            self.danmaku += 1;
+        }
+        self.messages.push(msg);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.messages.is_empty() && self.omitted == 0
+    }
+}
+
 /// Live WebSocket client
 pub struct LiveClient {
     /// Sender to signal shutdown
     shutdown_tx: Option<mpsc::Sender<()>>,
-    /// Receiver for live messages
-    message_rx: mpsc::Receiver<LiveMessage>,
+    /// Receiver for batched live messages
+    message_rx: mpsc::Receiver<LiveBatch>,
 }
 
 impl LiveClient {
@@ -32,7 +65,7 @@ impl LiveClient {
         let token = danmu_info.token.clone();
 
         // Create channels
-        let (message_tx, message_rx) = mpsc::channel::<LiveMessage>(256);
+        let (message_tx, message_rx) = mpsc::channel::<LiveBatch>(256);
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
 
         // Spawn connection task
@@ -46,8 +79,8 @@ impl LiveClient {
         })
     }
 
-    /// Try to receive a message (non-blocking)
-    pub fn try_recv(&mut self) -> Option<LiveMessage> {
+    /// Try to receive a batch of messages (non-blocking)
+    pub fn try_recv(&mut self) -> Option<LiveBatch> {
         self.message_rx.try_recv().ok()
     }
 
@@ -74,7 +107,7 @@ async fn run_connection(
     room_id: i64,
     uid: i64,
     token: &str,
-    message_tx: mpsc::Sender<LiveMessage>,
+    message_tx: mpsc::Sender<LiveBatch>,
     shutdown_rx: &mut mpsc::Receiver<()>,
 ) -> Result<()> {
     // Connect to WebSocket
@@ -89,6 +122,9 @@ async fn run_connection(
     let mut heartbeat_interval = interval(Duration::from_secs(30));
     heartbeat_interval.tick().await; // Skip first immediate tick
 
+    let mut batch_interval = interval(BATCH_INTERVAL);
+    let mut batch = LiveBatch::default();
+
     loop {
         tokio::select! {
             // Check for shutdown signal
@@ -104,11 +140,18 @@ async fn run_connection(
                 }
             }
 
+            // Flush the messages collected in this window
+            _ = batch_interval.tick() => {
+                if !flush_batch(&message_tx, &mut batch) {
+                    break;
+                }
+            }
+
             // Receive messages
             msg = read.next() => {
                 match msg {
                     Some(Ok(Message::Binary(data))) => {
-                        let _ = process_message(&data[..], &message_tx).await;
+                        let _ = process_message(&data[..], &mut batch);
                     }
                     Some(Ok(Message::Close(_))) | None => {
                         break;
@@ -125,16 +168,86 @@ async fn run_connection(
     Ok(())
 }
 
-/// Process received WebSocket message
-async fn process_message(data: &[u8], message_tx: &mpsc::Sender<LiveMessage>) -> Result<()> {
+/// Hand the batch to the page. While the channel is full the batch is kept and the next
+/// window's messages join it, so only danmaku over the limit are lost (and counted);
+/// returns false once the page is gone
+fn flush_batch(message_tx: &mpsc::Sender<LiveBatch>, batch: &mut LiveBatch) -> bool {
+    if batch.is_empty() {
+        return true;
+    }
+    match message_tx.try_send(std::mem::take(batch)) {
+        Ok(()) => true,
+        Err(mpsc::error::TrySendError::Full(kept)) => {
+            *batch = kept;
+            true
+        }
+        Err(mpsc::error::TrySendError::Closed(_)) => false,
+    }
+}
+
+/// Process received WebSocket message into the current batch
+fn process_message(data: &[u8], batch: &mut LiveBatch) -> Result<()> {
     let packets = Packet::decode(data)?;
 
     for packet in packets {
         if let Some(msg) = parse_message(&packet) {
-            // Send message (ignore if channel is full)
-            let _ = message_tx.try_send(msg);
+            batch.push(msg);
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn danmaku(content: &str) -> LiveMessage {
+        LiveMessage::Danmaku {
+            uid: 1,
+            uname: String::new(),
+            content: content.to_string(),
+            color: 0xFFFFFF,
+            medal: None,
+            guard_level: 0,
+        }
+    }
+
+    #[test]
+    fn test_batch_limits_danmaku_only() {
+        let mut batch = LiveBatch::default();
+        for i in 0..MAX_DANMAKU_PER_BATCH + 5 {
+            batch.push(danmaku(&i.to_string()));
+        }
+        // Other messages are never dropped
+        batch.push(LiveMessage::Popularity(42));
+
+        assert_eq!(batch.messages.len(), MAX_DANMAKU_PER_BATCH + 1);
+        assert_eq!(batch.omitted, 5);
+        assert!(!batch.is_empty());
+        assert!(LiveBatch::default().is_empty());
+    }
+
+    #[test]
+    fn test_full_channel_keeps_batch() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut batch = LiveBatch::default();
+        batch.push(LiveMessage::Popularity(1));
+        assert!(flush_batch(&tx, &mut batch));
+        assert!(batch.is_empty());
+
+        // The page hasn't drained the channel: the next window is merged, not dropped
+        batch.push(LiveMessage::Popularity(2));
+        assert!(flush_batch(&tx, &mut batch));
+        batch.push(LiveMessage::Popularity(3));
+        assert_eq!(batch.messages.len(), 2);
+
+        assert_eq!(rx.try_recv().unwrap().messages.len(), 1);
+        assert!(flush_batch(&tx, &mut batch));
+        assert_eq!(rx.try_recv().unwrap().messages.len(), 2);
+
+        drop(rx);
+        batch.push(LiveMessage::Popularity(4));
+        assert!(!flush_batch(&tx, &mut batch));
+    }
+}
@@ -214,6 +214,10 @@ pub enum LiveMessage {
         uname: String,
         content: String,
         color: u32,
+        /// 佩戴的粉丝勋章
+        medal: Option<FanMedal>,
+        /// 大航海等级: 0 = 无, 1 = 总督, 2 = 提督, 3 = 舰长
+        guard_level: u8,
     },
     /// User entered room
     Enter { uid: i64, uname: String },
@@ -230,12 +234,23 @@ pub enum LiveMessage {
     Popularity(u32),
     /// Online rank data
     OnlineRank { list: Vec<RankUser> },
+    /// Streamer ended the broadcast
+    Preparing,
     /// Auth reply
     AuthReply { code: i32 },
     /// Unknown/unhandled message
     Unknown(String),
 }
 
+/// Fan medal worn by a danmaku sender
+#[derive(Debug, Clone, PartialEq)]
+pub struct FanMedal {
+    pub name: String,
+    pub level: i64,
+    /// Medal color (RGB)
+    pub color: u32,
+}
+
 /// Rank user info
 #[derive(Debug, Clone, Deserialize)]
 pub struct RankUser {
@@ -300,6 +315,7 @@ fn parse_json_message(json_str: &str) -> Option<LiveMessage> {
         "INTERACT_WORD" => parse_interact_word(&raw.data?),
         "SEND_GIFT" => parse_send_gift(&raw.data?),
         "ONLINE_RANK_V2" => parse_online_rank(&raw.data?),
+        "PREPARING" => Some(LiveMessage::Preparing),
         cmd if cmd.starts_with("DANMU_MSG") => parse_danmu_msg(&raw.info?),
         _ => Some(LiveMessage::Unknown(raw.cmd)),
     }
@@ -325,11 +341,38 @@ fn parse_danmu_msg(info: &serde_json::Value) -> Option<LiveMessage> {
         .as_u64()
         .unwrap_or(0xFFFFFF) as u32;
 
+    // info[3] = fan medal, empty when none is worn
+    let medal = info_arr.get(3).and_then(parse_medal);
+
+    // info[7] = guard level
+    let guard_level = info_arr
+        .get(7)
+        .and_then(|v| v.as_u64())
+        .filter(|level| *level <= 3)
+        .unwrap_or(0) as u8;
+
     Some(LiveMessage::Danmaku {
         uid,
         uname,
         content,
         color,
+        medal,
+        guard_level,
+    })
+}
+
+/// Parse a medal array: [level, name, anchor_uname, room_id, color, ...]
+fn parse_medal(value: &serde_json::Value) -> Option<FanMedal> {
+    let arr = value.as_array()?;
+    let level = arr.first()?.as_i64()?;
+    let name = arr.get(1)?.as_str()?;
+    if name.is_empty() {
+        return None;
+    }
+    Some(FanMedal {
+        name: name.to_string(),
+        level,
+        color: arr.get(4).and_then(|c| c.as_u64()).unwrap_or(0) as u32,
     })
 }
 
@@ -423,4 +466,60 @@ pub struct HistoryDanmakuItem {
     #[serde(default)]
     pub uname_color: String,
     pub timeline: String,
+    #[serde(default)]
+    pub medal: serde_json::Value,
+    #[serde(default)]
+    pub guard_level: u8,
+}
+
+impl HistoryDanmakuItem {
+    /// Fan medal worn by the sender
+    pub fn fan_medal(&self) -> Option<FanMedal> {
+        parse_medal(&self.medal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_danmu_msg_medal_and_guard() {
+        let info = serde_json::json!([
+            [0, 1, 25, 16777215],
+            "晚上好",
+            [123, "老粉", 0],
+            [21, "牌子", "主播", 456, 1725515],
+            [],
+            [],
+            0,
+            3
+        ]);
+        let Some(LiveMessage::Danmaku {
+            medal, guard_level, ..
+        }) = parse_danmu_msg(&info)
+        else {
+            panic!("expected danmaku");
+        };
+        assert_eq!(guard_level, 3);
+        assert_eq!(
+            medal,
+            Some(FanMedal {
+                name: "牌子".to_string(),
+                level: 21,
+                color: 1725515,
+            })
+        );
+
+        // No medal worn, no guard
+        let info = serde_json::json!([[0, 1, 25, 16777215], "hi", [1, "路人"], [], [], [], 0, 0]);
+        let Some(LiveMessage::Danmaku {
+            medal, guard_level, ..
+        }) = parse_danmu_msg(&info)
+        else {
+            panic!("expected danmaku");
+        };
+        assert_eq!(guard_level, 0);
+        assert_eq!(medal, None);
+    }
 }
@@ -0,0 +1,25 @@
+//! Message center (消息中心) API types
+
+use serde::Deserialize;
+
+/// Response data from /x/msgfeed/unread
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnreadData {
+    #[serde(default)]
+    pub at: i64,
+    #[serde(default)]
+    pub like: i64,
+    #[serde(default)]
+    pub reply: i64,
+    #[serde(default)]
+    pub sys_msg: i64,
+    #[serde(default)]
+    pub up: i64,
+}
+
+impl UnreadData {
+    /// Total unread count shown in the status bar
+    pub fn total(&self) -> i64 {
+        self.at + self.like + self.reply + self.sys_msg + self.up
+    }
+}
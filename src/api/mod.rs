@@ -2,14 +2,26 @@ pub mod auth;
 pub mod client;
 pub mod comment;
 pub mod dynamic;
+pub mod error;
+pub mod favorite;
 pub mod heartbeat;
 pub mod history;
+pub mod interactive;
+pub mod link;
 pub mod live;
 pub mod live_client;
 pub mod live_ws;
+pub mod message;
+pub mod playurl;
+pub mod publish;
+pub mod rate_limit;
 pub mod recommend;
 pub mod search;
+pub mod space;
+pub mod stats;
+pub mod transport;
 pub mod video;
+pub mod watch_later;
 pub mod wbi;
 
 pub use client::ApiClient;
@@ -0,0 +1,120 @@
+//! DASH stream URLs (/x/player/wbi/playurl) for playing a chosen quality without yt-dlp
+
+use serde::Deserialize;
+
+/// AVC, the codec every mpv build decodes in hardware
+const CODEC_AVC: i32 = 7;
+
+/// Response data from /x/player/wbi/playurl with fnval=4048
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlayUrlInfo {
+    /// 实际返回的画质
+    #[serde(default)]
+    pub quality: i32,
+    /// 可选画质 qn，从高到低
+    #[serde(default)]
+    pub accept_quality: Vec<i32>,
+    /// 与 `accept_quality` 一一对应的名称，如 "1080P 60帧"
+    #[serde(default)]
+    pub accept_description: Vec<String>,
+    pub dash: Option<DashInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DashInfo {
+    #[serde(default)]
+    pub video: Vec<DashStream>,
+    #[serde(default)]
+    pub audio: Option<Vec<DashStream>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DashStream {
+    /// 视频为画质 qn，音频为音质代码
+    pub id: i32,
+    #[serde(alias = "baseUrl")]
+    pub base_url: String,
+    #[serde(default)]
+    pub bandwidth: i64,
+    #[serde(default)]
+    pub codecid: i32,
+}
+
+/// Separate video and audio URLs to hand to mpv
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashUrls {
+    pub video: String,
+    pub audio: Option<String>,
+}
+
+impl PlayUrlInfo {
+    /// Qualities offered for the video: (qn, name)
+    pub fn qualities(&self) -> Vec<(i32, String)> {
+        self.accept_quality
+            .iter()
+            .copied()
+            .zip(self.accept_description.iter().cloned())
+            .collect()
+    }
+
+    /// Streams for `qn`, or the best one below it when the account can't get `qn`
+    /// (e.g. 1080P+ without 大会员); AVC is preferred over HEVC / AV1 at the same quality
+    pub fn dash_urls(&self, qn: i32) -> Option<DashUrls> {
+        let dash = self.dash.as_ref()?;
+        let quality = dash
+            .video
+            .iter()
+            .map(|v| v.id)
+            .filter(|&id| id <= qn)
+            .max()
+            .or_else(|| dash.video.iter().map(|v| v.id).min())?;
+        let video = dash
+            .video
+            .iter()
+            .filter(|v| v.id == quality)
+            .max_by_key(|v| (v.codecid == CODEC_AVC, v.bandwidth))?;
+        let audio = dash
+            .audio
+            .iter()
+            .flatten()
+            .max_by_key(|a| a.bandwidth)
+            .map(|a| a.base_url.clone());
+        Some(DashUrls {
+            video: video.base_url.clone(),
+            audio,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dash_urls() {
+        let info: PlayUrlInfo = serde_json::from_str(
+            r#"{"quality":80,"accept_quality":[116,80,64],
+                "accept_description":["1080P 60帧","1080P 高清","720P 高清"],
+                "dash":{"video":[
+                    {"id":80,"baseUrl":"v80-hevc","bandwidth":900,"codecid":12},
+                    {"id":80,"baseUrl":"v80-avc","bandwidth":1000,"codecid":7},
+                    {"id":64,"baseUrl":"v64-avc","bandwidth":500,"codecid":7}],
+                "audio":[
+                    {"id":30216,"baseUrl":"a64k","bandwidth":64},
+                    {"id":30280,"baseUrl":"a192k","bandwidth":192}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(info.qualities()[0], (116, "1080P 60帧".to_string()));
+        // 116 isn't in the streams, so the best one below it
+        assert_eq!(
+            info.dash_urls(116),
+            Some(DashUrls {
+                video: "v80-avc".to_string(),
+                audio: Some("a192k".to_string()),
+            })
+        );
+        assert_eq!(info.dash_urls(64).unwrap().video, "v64-avc");
+        // Below every stream: the lowest one
+        assert_eq!(info.dash_urls(16).unwrap().video, "v64-avc");
+    }
+}
@@ -0,0 +1,147 @@
+//! Dynamic publishing (发布动态) API types and request bodies
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// B 站单条动态最多 9 张图
+pub const MAX_DYNAMIC_IMAGES: usize = 9;
+
+/// Size limit of a single uploaded image
+pub const MAX_IMAGE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Response data of /x/dynamic/feed/draw/upload_bfs
+#[derive(Debug, Clone, Deserialize)]
+pub struct UploadedImage {
+    pub image_url: String,
+    pub image_width: i64,
+    pub image_height: i64,
+    /// KB
+    #[serde(default)]
+    pub img_size: f64,
+}
+
+/// Response data of /x/dynamic/feed/create/dyn
+#[derive(Debug, Deserialize)]
+pub struct CreateDynamicData {
+    pub dyn_id_str: Option<String>,
+}
+
+/// MIME type of a supported image, by file extension
+pub fn image_mime(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "png" => Some("image/png"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// Encode text fields plus one file as `multipart/form-data`
+pub fn multipart_body(
+    boundary: &str,
+    fields: &[(&str, &str)],
+    file_field: &str,
+    file_name: &str,
+    mime: &str,
+    bytes: &[u8],
+) -> Vec<u8> {
+    let mut body = Vec::with_capacity(bytes.len() + 512);
+    for (name, value) in fields {
+        body.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{file_field}\"; filename=\"{file_name}\"\r\nContent-Type: {mime}\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(bytes);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+    body
+}
+
+/// JSON body for creating a text dynamic, or an image dynamic when `images` is not empty
+pub fn create_dynamic_body(text: &str, images: &[UploadedImage], upload_id: &str) -> Value {
+    let pics: Vec<Value> = images
+        .iter()
+        .map(|img| {
+            json!({
+                "img_src": img.image_url,
+                "img_width": img.image_width,
+                "img_height": img.image_height,
+                "img_size": img.img_size,
+            })
+        })
+        .collect();
+    json!({
+        "dyn_req": {
+            "content": {
+                "contents": [{ "raw_text": text, "type": 1, "biz_id": "" }]
+            },
+            // 1 纯文字, 2 带图
+            "scene": if pics.is_empty() { 1 } else { 2 },
+            "pics": pics,
+            "attach_card": null,
+            "upload_id": upload_id,
+            "meta": {
+                "app_meta": { "from": "create.dynamic.web", "mobi_app": "web" }
+            }
+        }
+    })
+}
+
+/// JSON body for sharing a video to the user's own dynamic feed (转发视频到动态)
+pub fn repost_video_body(text: &str, aid: i64, upload_id: &str) -> Value {
+    json!({
+        "dyn_req": {
+            "content": {
+                "contents": [{ "raw_text": text, "type": 1, "biz_id": "" }]
+            },
+            // 4 转发
+            "scene": 4,
+            "attach_card": null,
+            "upload_id": upload_id,
+            "meta": {
+                "app_meta": { "from": "create.dynamic.web", "mobi_app": "web" }
+            }
+        },
+        // 8 视频投稿
+        "web_repost_src": {
+            "revs_id": { "dyn_type": 8, "rid": aid }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_body_layout() {
+        let body = multipart_body(
+            "xyz",
+            &[("biz", "new_dyn")],
+            "file_up",
+            "a.png",
+            "image/png",
+            b"PNG",
+        );
+        let text = String::from_utf8(body).unwrap();
+        assert_eq!(
+            text,
+            "--xyz\r\nContent-Disposition: form-data; name=\"biz\"\r\n\r\nnew_dyn\r\n\
+             --xyz\r\nContent-Disposition: form-data; name=\"file_up\"; filename=\"a.png\"\r\nContent-Type: image/png\r\n\r\nPNG\r\n\
+             --xyz--\r\n"
+        );
+        assert_eq!(image_mime(Path::new("/tmp/cat.JPG")), Some("image/jpeg"));
+        assert_eq!(image_mime(Path::new("notes.txt")), None);
+    }
+}
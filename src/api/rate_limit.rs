@@ -0,0 +1,92 @@
+//! Global token bucket limiting how many API requests are sent per second
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default requests per second, bursts of this size are allowed after a quiet period
+pub const DEFAULT_QPS: f64 = 8.0;
+
+struct Bucket {
+    qps: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+/// Requests over the rate wait in line instead of being sent at once
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// `qps` <= 0 disables limiting
+    pub fn new(qps: f64) -> Self {
+        Self {
+            bucket: Mutex::new(Bucket {
+                qps,
+                tokens: qps.max(1.0),
+                last: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn set_qps(&self, qps: f64) {
+        let mut bucket = self.bucket.lock().expect("rate limiter lock poisoned");
+        bucket.qps = qps;
+        bucket.tokens = bucket.tokens.min(qps.max(1.0));
+    }
+
+    /// Wait until a request may be sent
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token and return how long to wait for it; tokens may go negative to queue requests
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().expect("rate limiter lock poisoned");
+        if bucket.qps <= 0.0 {
+            return Duration::ZERO;
+        }
+        let elapsed = now.saturating_duration_since(bucket.last).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * bucket.qps).min(bucket.qps.max(1.0));
+        bucket.last = bucket.last.max(now);
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / bucket.qps)
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_QPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_queues_requests_over_the_rate() {
+        let limiter = RateLimiter::new(2.0);
+        let start = Instant::now();
+
+        // Burst of 2, then one request every 500ms
+        assert_eq!(limiter.reserve(start), Duration::ZERO);
+        assert_eq!(limiter.reserve(start), Duration::ZERO);
+        assert_eq!(limiter.reserve(start), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(start), Duration::from_secs(1));
+
+        // Tokens refill over time
+        let later = start + Duration::from_secs(5);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+
+        limiter.set_qps(0.0);
+        assert_eq!(limiter.reserve(start), Duration::ZERO);
+    }
+}
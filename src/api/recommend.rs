@@ -1,6 +1,11 @@
 //! Video recommendation API types
 
+use crate::format::format_count_opt;
 use serde::Deserialize;
+use std::collections::{HashSet, VecDeque};
+
+/// How many recommended bvids are remembered for de-duplication
+const SEEN_CAPACITY: usize = 500;
 
 #[derive(Debug, Deserialize)]
 pub struct RecommendData {
@@ -22,6 +27,20 @@ pub struct VideoItem {
     pub stat: Option<VideoStat>,
 }
 
+/// Response data from /x/web-interface/dynamic/region (分区最新视频)
+#[derive(Debug, Deserialize)]
+pub struct RegionData {
+    #[serde(default)]
+    pub archives: Vec<RegionArchive>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegionArchive {
+    pub bvid: String,
+    pub title: String,
+    pub owner: Option<VideoOwner>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct VideoOwner {
     pub mid: i64,
@@ -50,19 +69,7 @@ impl VideoItem {
 
     /// Format view count (e.g., 1.2万)
     pub fn format_views(&self) -> String {
-        if let Some(stat) = &self.stat {
-            if let Some(view) = stat.view {
-                if view >= 10000 {
-                    format!("{:.1}万", view as f64 / 10000.0)
-                } else {
-                    view.to_string()
-                }
-            } else {
-                "-".to_string()
-            }
-        } else {
-            "-".to_string()
-        }
+        format_count_opt(self.stat.as_ref().and_then(|s| s.view))
     }
 
     /// Get author name
@@ -70,3 +77,67 @@ impl VideoItem {
         self.owner.as_ref().map(|o| o.name.as_str()).unwrap_or("-")
     }
 }
+
+/// Recently recommended bvids, so pages loaded later don't repeat earlier videos
+#[derive(Default)]
+pub struct SeenBvids {
+    order: VecDeque<String>,
+    set: HashSet<String>,
+}
+
+impl SeenBvids {
+    pub fn clear(&mut self) {
+        self.order.clear();
+        self.set.clear();
+    }
+
+    /// Drop items already seen (or repeated within `items`) and remember the rest
+    pub fn retain_unseen(&mut self, items: &mut Vec<VideoItem>) {
+        items.retain(|item| match &item.bvid {
+            Some(bvid) => self.insert(bvid),
+            None => false,
+        });
+    }
+
+    /// Returns false when the bvid was already seen
+    fn insert(&mut self, bvid: &str) -> bool {
+        if self.set.contains(bvid) {
+            return false;
+        }
+        if self.order.len() >= SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.set.remove(&oldest);
+            }
+        }
+        self.order.push_back(bvid.to_string());
+        self.set.insert(bvid.to_string());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(bvid: &str) -> VideoItem {
+        serde_json::from_value(serde_json::json!({ "id": 1, "bvid": bvid, "goto": "av" })).unwrap()
+    }
+
+    #[test]
+    fn test_seen_bvids_filters_repeats() {
+        let mut seen = SeenBvids::default();
+        let mut first = vec![item("BV1"), item("BV2"), item("BV1")];
+        seen.retain_unseen(&mut first);
+        assert_eq!(first.len(), 2);
+
+        let mut second = vec![item("BV2"), item("BV3")];
+        seen.retain_unseen(&mut second);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].bvid.as_deref(), Some("BV3"));
+
+        seen.clear();
+        let mut third = vec![item("BV1")];
+        seen.retain_unseen(&mut third);
+        assert_eq!(third.len(), 1);
+    }
+}
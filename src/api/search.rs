@@ -1,6 +1,9 @@
 //! Search API types and functions
 
+use crate::format::format_count_opt;
+use crate::i18n::t;
 use serde::Deserialize;
+use std::ops::Range;
 
 /// Search result for video type
 #[derive(Debug, Deserialize)]
@@ -25,28 +28,30 @@ pub struct SearchVideoItem {
     #[serde(rename = "danmaku")]
     pub danmaku: Option<i64>,
     pub mid: Option<i64>,
+    /// 1 = 联合投稿
+    #[serde(default)]
+    pub is_union_video: i32,
+    /// 1 = 充电专属
+    #[serde(default)]
+    pub is_charge_video: i32,
 }
 
 impl SearchVideoItem {
     pub fn display_title(&self) -> String {
-        // Remove HTML tags like <em class="keyword">
-        self.title
-            .as_deref()
-            .unwrap_or("无标题")
-            .replace("<em class=\"keyword\">", "")
-            .replace("</em>", "")
+        self.title_with_highlights().0
+    }
+
+    /// Title without the `<em class="keyword">` marks, plus the char ranges they wrapped
+    pub fn title_with_highlights(&self) -> (String, Vec<Range<usize>>) {
+        parse_keyword_marks(self.title.as_deref().unwrap_or(t("无标题")))
     }
 
     pub fn author_name(&self) -> &str {
-        self.author.as_deref().unwrap_or("未知")
+        self.author.as_deref().unwrap_or(t("未知"))
     }
 
     pub fn format_play(&self) -> String {
-        match self.play {
-            Some(n) if n >= 10000 => format!("{:.1}万", n as f64 / 10000.0),
-            Some(n) => format!("{}", n),
-            None => "-".to_string(),
-        }
+        format_count_opt(self.play)
     }
 
     pub fn cover_url(&self) -> Option<String> {
@@ -91,12 +96,12 @@ impl HotwordItem {
     /// Optional badge based on word_type
     pub fn badge(&self) -> Option<&'static str> {
         match self.word_type.unwrap_or_default() {
-            4 => Some("新"),
-            5 => Some("热"),
-            7 => Some("直播"),
-            9 => Some("梗"),
-            11 => Some("话题"),
-            12 => Some("独家"),
+            4 => Some(t("新")),
+            5 => Some(t("热")),
+            7 => Some(t("直播")),
+            9 => Some(t("梗")),
+            11 => Some(t("话题")),
+            12 => Some(t("独家")),
             _ => None,
         }
     }
@@ -109,3 +114,64 @@ pub struct HotwordResponse {
     pub message: Option<String>,
     pub list: Option<Vec<HotwordItem>>, // Top 10 hot words
 }
+
+/// Strip the `<em>` marks B站 puts around matched keywords, returning the plain text
+/// and the char ranges of the marked parts. Other `<...>` text is kept as is
+pub fn parse_keyword_marks(raw: &str) -> (String, Vec<Range<usize>>) {
+    let mut text = String::with_capacity(raw.len());
+    let mut ranges = Vec::new();
+    let mut chars = 0;
+    let mut mark_start = None;
+    let mut rest = raw;
+
+    while let Some(pos) = rest.find('<') {
+        let before = &rest[..pos];
+        text.push_str(before);
+        chars += before.chars().count();
+        rest = &rest[pos..];
+
+        let tag_end = rest[1..].find(['<', '>']).map(|i| i + 1);
+        let Some(end) = tag_end.filter(|&i| rest.as_bytes()[i] == b'>') else {
+            // A lone '<', not a tag
+            text.push('<');
+            chars += 1;
+            rest = &rest[1..];
+            continue;
+        };
+
+        let tag = &rest[..=end];
+        if tag == "<em>" || tag.starts_with("<em ") {
+            mark_start = Some(chars);
+        } else if tag == "</em>" {
+            if let Some(start) = mark_start.take().filter(|&start| start < chars) {
+                ranges.push(start..chars);
+            }
+        } else {
+            text.push_str(tag);
+            chars += tag.chars().count();
+        }
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+
+    (text, ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keyword_marks() {
+        let (text, ranges) = parse_keyword_marks(
+            "【<em class=\"keyword\">原神</em>】新版本 <em class=\"keyword\">PV</em>",
+        );
+        assert_eq!(text, "【原神】新版本 PV");
+        assert_eq!(ranges, vec![1..3, 8..10]);
+
+        // Unrelated angle brackets stay in the title
+        let (text, ranges) = parse_keyword_marks("a<b <em>c</em> <br>");
+        assert_eq!(text, "a<b c <br>");
+        assert_eq!(ranges, vec![4..5]);
+    }
+}
@@ -0,0 +1,98 @@
+//! User space (UP主空间) API types
+
+use crate::format::format_count_opt;
+use serde::Deserialize;
+
+/// Response data from /x/web-interface/card
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserCardData {
+    pub card: UserCard,
+    /// 当前登录用户是否已关注
+    #[serde(default)]
+    pub following: bool,
+    pub archive_count: Option<i64>,
+    pub follower: Option<i64>,
+}
+
+/// Response data from /x/relation
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct UserRelation {
+    /// 0 未关注, 1 悄悄关注, 2 已关注, 6 互相关注, 128 已拉黑
+    #[serde(default)]
+    pub attribute: i32,
+}
+
+impl UserRelation {
+    pub fn is_following(self) -> bool {
+        matches!(self.attribute, 1 | 2 | 6)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserCard {
+    pub mid: String,
+    pub name: String,
+    pub face: Option<String>,
+    pub sign: Option<String>,
+    pub level_info: Option<UserLevelInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserLevelInfo {
+    pub current_level: Option<i32>,
+}
+
+/// Response data from /x/space/wbi/arc/search
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpaceArcData {
+    pub list: SpaceArcList,
+    pub page: SpaceArcPage,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpaceArcList {
+    #[serde(default)]
+    pub vlist: Vec<SpaceVideo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpaceArcPage {
+    pub pn: i32,
+    pub ps: i32,
+    pub count: i32,
+}
+
+/// A video uploaded by the user
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpaceVideo {
+    pub aid: i64,
+    pub bvid: String,
+    pub title: String,
+    pub pic: Option<String>,
+    pub play: Option<i64>,
+    pub length: Option<String>,
+    pub author: Option<String>,
+    pub created: Option<i64>,
+    /// 1 = 联合投稿
+    #[serde(default)]
+    pub is_union_video: i32,
+    /// 充电专属
+    #[serde(default)]
+    pub is_charging_arc: bool,
+}
+
+impl SpaceVideo {
+    pub fn format_play(&self) -> String {
+        format_count_opt(self.play)
+    }
+
+    pub fn cover_url(&self) -> Option<String> {
+        self.pic.as_ref().map(|url| {
+            if url.starts_with("//") {
+                format!("https:{}", url)
+            } else {
+                url.clone()
+            }
+        })
+    }
+}
@@ -0,0 +1,185 @@
+//! HTTP request statistics for the network stats panel
+
+use super::error::{is_throttled_code, is_throttled_status};
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Number of recent rate-limit / risk-control errors kept
+const MAX_RECENT_ERRORS: usize = 8;
+
+/// A rate-limit / risk-control error seen recently
+#[derive(Debug, Clone)]
+pub struct RiskError {
+    pub time: DateTime<Local>,
+    pub code: i32,
+    pub message: String,
+    pub path: String,
+}
+
+/// Point-in-time copy of [`HttpStats`] for rendering
+#[derive(Debug, Clone, Default)]
+pub struct HttpStatsSnapshot {
+    pub total: u64,
+    pub failed: u64,
+    pub active: u64,
+    pub avg_latency: Duration,
+    pub recent_errors: Vec<RiskError>,
+    /// Whether the most recent request got a response, None before the first one
+    pub last_ok: Option<bool>,
+}
+
+/// Counters updated by every request sent through [`super::ApiClient`]
+#[derive(Default)]
+pub struct HttpStats {
+    total: AtomicU64,
+    failed: AtomicU64,
+    active: AtomicU64,
+    total_latency_ms: AtomicU64,
+    recent_errors: Mutex<VecDeque<RiskError>>,
+    /// Outcome of the most recent request: 0 = none yet, 1 = ok, 2 = failed
+    last_outcome: AtomicU8,
+}
+
+/// Marks a request as active until dropped
+pub struct ActiveRequest<'a> {
+    stats: &'a HttpStats,
+}
+
+impl Drop for ActiveRequest<'_> {
+    fn drop(&mut self) {
+        self.stats.active.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl HttpStats {
+    pub fn begin(&self) -> ActiveRequest<'_> {
+        self.active.fetch_add(1, Ordering::Relaxed);
+        ActiveRequest { stats: self }
+    }
+
+    /// Record a finished request; `status` is None on transport errors
+    pub fn finish(&self, latency: Duration, status: Option<u16>, path: &str) {
+        self.total.fetch_add(1, Ordering::Relaxed);
+        self.total_latency_ms
+            .fetch_add(latency.as_millis() as u64, Ordering::Relaxed);
+
+        let ok = status.is_some_and(|status| status < 400);
+        self.last_outcome
+            .store(if ok { 1 } else { 2 }, Ordering::Relaxed);
+
+        match status {
+            Some(status) if status < 400 => {}
+            Some(status) => {
+                self.failed.fetch_add(1, Ordering::Relaxed);
+                if is_throttled_status(status) {
+                    self.push_error(status as i32, format!("HTTP {}", status), path);
+                }
+            }
+            None => {
+                self.failed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Record the `code` of a JSON API response
+    pub fn record_api_code(&self, code: i32, message: &str, path: &str) {
+        if code == 0 {
+            return;
+        }
+        self.failed.fetch_add(1, Ordering::Relaxed);
+        if is_throttled_code(code) {
+            self.push_error(code, message.to_string(), path);
+        }
+    }
+
+    fn push_error(&self, code: i32, message: String, path: &str) {
+        let mut errors = self.recent_errors.lock().expect("stats lock poisoned");
+        if errors.len() >= MAX_RECENT_ERRORS {
+            errors.pop_front();
+        }
+        errors.push_back(RiskError {
+            time: Local::now(),
+            code,
+            message,
+            path: path.to_string(),
+        });
+    }
+
+    pub fn snapshot(&self) -> HttpStatsSnapshot {
+        let total = self.total.load(Ordering::Relaxed);
+        let avg_ms = self
+            .total_latency_ms
+            .load(Ordering::Relaxed)
+            .checked_div(total)
+            .unwrap_or(0);
+
+        HttpStatsSnapshot {
+            total,
+            failed: self.failed.load(Ordering::Relaxed),
+            active: self.active.load(Ordering::Relaxed),
+            avg_latency: Duration::from_millis(avg_ms),
+            last_ok: match self.last_outcome.load(Ordering::Relaxed) {
+                0 => None,
+                outcome => Some(outcome == 1),
+            },
+            recent_errors: self
+                .recent_errors
+                .lock()
+                .expect("stats lock poisoned")
+                .iter()
+                .rev()
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Path part of a URL, without host and query, for compact display
+pub fn url_path(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme
+        .find('/')
+        .map_or("/", |idx| &without_scheme[idx..]);
+    path.split('?').next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_path() {
+        assert_eq!(
+            url_path("https://api.bilibili.com/x/web-interface/nav?foo=1"),
+            "/x/web-interface/nav"
+        );
+        assert_eq!(url_path("https://b23.tv"), "/");
+    }
+
+    #[test]
+    fn test_counts_and_risk_errors() {
+        let stats = HttpStats::default();
+        assert_eq!(stats.snapshot().last_ok, None);
+        {
+            let _active = stats.begin();
+            assert_eq!(stats.snapshot().active, 1);
+        }
+        stats.finish(Duration::from_millis(100), Some(200), "/a");
+        stats.finish(Duration::from_millis(300), Some(412), "/b");
+        stats.record_api_code(-352, "风控校验失败", "/c");
+        stats.record_api_code(-101, "账号未登录", "/d");
+        stats.record_api_code(-509, "请求过于频繁", "/e");
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.last_ok, Some(false));
+        assert_eq!(snapshot.active, 0);
+        assert_eq!(snapshot.total, 2);
+        assert_eq!(snapshot.failed, 4);
+        assert_eq!(snapshot.avg_latency, Duration::from_millis(200));
+        let codes: Vec<i32> = snapshot.recent_errors.iter().map(|e| e.code).collect();
+        assert_eq!(codes, vec![-509, -352, 412]);
+    }
+}
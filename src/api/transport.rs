@@ -0,0 +1,357 @@
+//! Pluggable HTTP transport for [`super::ApiClient`]
+//!
+//! 默认走真实网络；设置 `BILIBILI_TUI_FIXTURES=<dir>` 时从本地 fixture 回放响应，
+//! 同时设置 `BILIBILI_TUI_RECORD=1` 则把真实响应录制到该目录。
+
+use anyhow::{anyhow, Result};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, COOKIE, REFERER, USER_AGENT};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub(super) const UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// Query parameters that change on every request (WBI signature) and are ignored when matching fixtures
+const VOLATILE_PARAMS: &[&str] = &["w_rid", "wts", "csrf"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
+
+/// A request as seen by the transport
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub cookie: Option<String>,
+    /// Form body, only sent for POST
+    pub form: Vec<(String, String)>,
+    /// Raw POST body with its content type (multipart uploads, JSON), sent instead of `form`
+    pub body: Option<(String, Vec<u8>)>,
+}
+
+impl HttpRequest {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: Method::Get,
+            url: url.into(),
+            cookie: None,
+            form: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn post(url: impl Into<String>, form: Vec<(String, String)>) -> Self {
+        Self {
+            method: Method::Post,
+            url: url.into(),
+            cookie: None,
+            form,
+            body: None,
+        }
+    }
+
+    pub fn post_body(url: impl Into<String>, content_type: &str, body: Vec<u8>) -> Self {
+        Self {
+            method: Method::Post,
+            url: url.into(),
+            cookie: None,
+            form: Vec::new(),
+            body: Some((content_type.to_string(), body)),
+        }
+    }
+
+    pub fn cookie(mut self, cookie: Option<String>) -> Self {
+        self.cookie = cookie;
+        self
+    }
+}
+
+/// A fully read response; also the on-disk fixture format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Final URL after redirects
+    pub url: String,
+    /// Cookies set by the response
+    #[serde(default)]
+    pub cookies: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_str(&self.body)
+            .map_err(|e| super::error::ApiError::Parse(e.to_string()).into())
+    }
+}
+
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// Sends requests for the API client; implement this to mock the network
+pub trait Transport: Send + Sync {
+    fn execute(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+/// Real network transport
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(UA));
+        headers.insert(
+            REFERER,
+            HeaderValue::from_static("https://www.bilibili.com/"),
+        );
+        Self {
+            client: Client::builder()
+                .default_headers(headers)
+                .build()
+                .expect("Failed to create HTTP client"),
+        }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut req = match (request.method, request.body) {
+                (Method::Get, _) => self.client.get(&request.url),
+                (Method::Post, Some((content_type, body))) => self
+                    .client
+                    .post(&request.url)
+                    .header(CONTENT_TYPE, content_type)
+                    .body(body),
+                (Method::Post, None) => self.client.post(&request.url).form(&request.form),
+            };
+            if let Some(cookie) = &request.cookie {
+                req = req.header(COOKIE, cookie.as_str());
+            }
+
+            let resp = req.send().await?;
+            let status = resp.status().as_u16();
+            let url = resp.url().to_string();
+            let cookies = resp
+                .cookies()
+                .map(|c| (c.name().to_string(), c.value().to_string()))
+                .collect();
+            let body = resp.text().await?;
+
+            Ok(HttpResponse {
+                status,
+                url,
+                cookies,
+                body,
+            })
+        })
+    }
+}
+
+/// Replays responses from JSON files in a directory, or records them there
+///
+/// 录制的 fixture 可能包含账号数据（如扫码登录返回的 Cookie），提交前请检查。
+pub struct FixtureTransport {
+    dir: PathBuf,
+    recorder: Option<ReqwestTransport>,
+}
+
+impl FixtureTransport {
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: None,
+        }
+    }
+
+    pub fn record(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: Some(ReqwestTransport::new()),
+        }
+    }
+
+    pub fn fixture_path(&self, request: &HttpRequest) -> PathBuf {
+        self.dir.join(fixture_name(request))
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn execute(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let path = self.fixture_path(&request);
+
+            if let Some(recorder) = &self.recorder {
+                let resp = recorder.execute(request).await?;
+                std::fs::create_dir_all(&self.dir)?;
+                std::fs::write(&path, serde_json::to_string_pretty(&resp)?)?;
+                return Ok(resp);
+            }
+
+            let content = std::fs::read_to_string(&path).map_err(|_| {
+                anyhow!(
+                    "No fixture for {} {} ({})",
+                    request.method.as_str(),
+                    request.url,
+                    path.display()
+                )
+            })?;
+            Ok(serde_json::from_str(&content)?)
+        })
+    }
+}
+
+/// Pick the transport from `BILIBILI_TUI_FIXTURES` / `BILIBILI_TUI_RECORD`
+pub fn from_env() -> Arc<dyn Transport> {
+    match std::env::var_os("BILIBILI_TUI_FIXTURES") {
+        Some(dir) if std::env::var_os("BILIBILI_TUI_RECORD").is_some() => {
+            Arc::new(FixtureTransport::record(dir))
+        }
+        Some(dir) => Arc::new(FixtureTransport::replay(dir)),
+        None => Arc::new(ReqwestTransport::new()),
+    }
+}
+
+/// Stable file name for a request: method, host and path, plus a hash of the
+/// sorted parameters without signature / timestamp noise
+fn fixture_name(request: &HttpRequest) -> String {
+    let (base, query) = request
+        .url
+        .split_once('?')
+        .unwrap_or((request.url.as_str(), ""));
+    let base = base.split_once("://").map_or(base, |(_, rest)| rest);
+
+    let mut params: Vec<String> = query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .chain(request.form.iter().map(|(k, v)| format!("{}={}", k, v)))
+        .filter(|p| {
+            let name = p.split('=').next().unwrap_or_default();
+            !VOLATILE_PARAMS.contains(&name)
+        })
+        .collect();
+    params.sort();
+
+    let slug: String = base
+        .trim_end_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    format!(
+        "{}_{}_{:016x}.json",
+        request.method.as_str(),
+        slug,
+        fnv1a(params.join("&").as_bytes())
+    )
+}
+
+/// FNV-1a, stable across Rust versions unlike `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ApiClient;
+
+    struct StaticTransport(&'static str);
+
+    impl Transport for StaticTransport {
+        fn execute(&self, request: HttpRequest) -> TransportFuture<'_> {
+            let body = self.0.to_string();
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: 200,
+                    url: request.url,
+                    cookies: Vec::new(),
+                    body,
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn test_fixture_name_ignores_signature() {
+        let a = HttpRequest::get(
+            "https://api.bilibili.com/x/space/wbi/arc/search?mid=1&pn=1&w_rid=abc&wts=1",
+        );
+        let b = HttpRequest::get(
+            "https://api.bilibili.com/x/space/wbi/arc/search?pn=1&wts=2&mid=1&w_rid=def",
+        );
+        let c = HttpRequest::get("https://api.bilibili.com/x/space/wbi/arc/search?mid=1&pn=2");
+        assert_eq!(fixture_name(&a), fixture_name(&b));
+        assert_ne!(fixture_name(&a), fixture_name(&c));
+        assert!(fixture_name(&a).starts_with("GET_api_bilibili_com_x_space_wbi_arc_search_"));
+    }
+
+    #[tokio::test]
+    async fn test_client_with_mock_transport() {
+        let client = ApiClient::with_transport(Arc::new(StaticTransport(
+            r#"{"code":0,"message":"0","ttl":1,"data":{"card":{"mid":"2","name":"碧诗"},"following":false,"follower":100}}"#,
+        )));
+
+        let card = client.get_user_card(2).await.unwrap();
+        assert_eq!(card.card.name, "碧诗");
+        assert_eq!(client.stats().total, 1);
+        assert_eq!(client.stats().failed, 0);
+    }
+
+    /// Serves WBI keys for nav and -352 for everything else
+    struct RiskControlTransport;
+
+    impl Transport for RiskControlTransport {
+        fn execute(&self, request: HttpRequest) -> TransportFuture<'_> {
+            let body = if request.url.contains("/x/web-interface/nav") {
+                r#"{"code":0,"message":"0","data":{"wbi_img":{"img_url":"https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png","sub_url":"https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png"}}}"#
+            } else {
+                r#"{"code":-352,"message":"风控校验失败","data":null}"#
+            };
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: 200,
+                    url: request.url,
+                    cookies: Vec::new(),
+                    body: body.to_string(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wbi_request_retries_once_on_risk_control() {
+        let client = ApiClient::with_transport(Arc::new(RiskControlTransport));
+
+        let err = client.search_videos("test", 1).await.unwrap_err();
+        assert!(crate::api::client::is_risk_control(&err));
+        // nav + search, then fresh keys and one retry
+        assert_eq!(client.stats().total, 4);
+    }
+}
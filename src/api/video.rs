@@ -1,5 +1,8 @@
 //! Video info API types
 
+use crate::format::{format_count, format_count_opt};
+use crate::i18n::{t, tf};
+use chrono::{Local, TimeZone};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -12,11 +15,157 @@ pub struct VideoInfo {
     pub pic: Option<String>,
     pub duration: Option<i64>,
     pub pubdate: Option<i64>,
+    /// 分区名
+    #[serde(default)]
+    pub tname: String,
+    /// 1: 自制, 2: 转载
+    #[serde(default)]
+    pub copyright: i32,
+    /// 充电专属
+    #[serde(default)]
+    pub is_upower_exclusive: bool,
+    /// 当前账号已为 UP 充电，可以观看充电专属视频
+    #[serde(default)]
+    pub is_upower_play: bool,
+    /// 付费合集
+    #[serde(default)]
+    pub is_chargeable_season: bool,
+    #[serde(default)]
+    pub rights: VideoRights,
+    /// 荣誉：全站排行、每周必看等
+    #[serde(default)]
+    pub honor_reply: HonorReply,
     pub owner: VideoOwner,
     pub stat: VideoStat,
     pub pages: Option<Vec<VideoPage>>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VideoRights {
+    /// 互动视频
+    pub is_stein_gate: i32,
+    /// 付费视频
+    pub pay: i32,
+    /// UGC 付费
+    pub ugc_pay: i32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HonorReply {
+    #[serde(default)]
+    pub honor: Option<Vec<VideoHonor>>,
+}
+
+/// One honor badge, e.g. "全站排行榜最高第5名"
+#[derive(Debug, Clone, Deserialize)]
+pub struct VideoHonor {
+    /// 1: 入站必刷, 2: 每周必看, 3: 全站排行, 4: 热门
+    #[serde(rename = "type", default)]
+    pub kind: i32,
+    #[serde(default)]
+    pub desc: String,
+}
+
+impl VideoHonor {
+    pub fn icon(&self) -> &'static str {
+        match self.kind {
+            1 => "📌",
+            2 => "📅",
+            3 => "🏆",
+            4 => "🔥",
+            _ => "🏅",
+        }
+    }
+}
+
+impl VideoInfo {
+    /// Publish time relative to now; older than 30 days shows the date
+    pub fn format_pubdate(&self) -> Option<String> {
+        let pubdate = self.pubdate.filter(|&ts| ts > 0)?;
+        let diff = Local::now().timestamp() - pubdate;
+
+        Some(if diff < 60 {
+            t("刚刚").to_string()
+        } else if diff < 3600 {
+            tf("{}分钟前", &[&(diff / 60)])
+        } else if diff < 86400 {
+            tf("{}小时前", &[&(diff / 3600)])
+        } else if diff < 2592000 {
+            tf("{}天前", &[&(diff / 86400)])
+        } else {
+            Local
+                .timestamp_opt(pubdate, 0)
+                .single()?
+                .format("%Y-%m-%d")
+                .to_string()
+        })
+    }
+
+    /// 自制 / 转载 label
+    pub fn copyright_label(&self) -> Option<&'static str> {
+        match self.copyright {
+            1 => Some(t("自制")),
+            2 => Some(t("转载")),
+            _ => None,
+        }
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.rights.is_stein_gate == 1
+    }
+
+    /// Why the current account cannot play the video, the player would fail silently
+    pub fn play_restriction(&self) -> Option<&'static str> {
+        if self.is_upower_exclusive && !self.is_upower_play {
+            Some(t("充电专属，无法播放"))
+        } else if self.rights.pay == 1 || self.rights.ugc_pay == 1 || self.is_chargeable_season {
+            Some(t("付费内容，无法播放"))
+        } else {
+            None
+        }
+    }
+
+    pub fn honors(&self) -> &[VideoHonor] {
+        self.honor_reply.honor.as_deref().unwrap_or_default()
+    }
+
+    /// Coins one account can give: 2 for 自制, 1 for 转载
+    pub fn max_coins(&self) -> i32 {
+        if self.copyright == 2 {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+/// Response data from /x/web-interface/archive/relation: what the logged-in user did to a video
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+pub struct VideoRelation {
+    #[serde(default)]
+    pub like: bool,
+    /// 已投币数
+    #[serde(default)]
+    pub coin: i32,
+    #[serde(default)]
+    pub favorite: bool,
+}
+
+/// Response data from /x/web-interface/archive/like/triple
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct TripleResult {
+    #[serde(default)]
+    pub like: bool,
+    #[serde(default)]
+    pub coin: bool,
+    #[serde(default)]
+    pub fav: bool,
+    /// 投币数
+    #[serde(default)]
+    pub multiply: i32,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct VideoOwner {
     pub mid: i64,
@@ -88,17 +237,7 @@ impl RelatedVideoItem {
     }
 
     pub fn format_views(&self) -> String {
-        self.stat
-            .as_ref()
-            .and_then(|s| s.view)
-            .map(|view| {
-                if view >= 10000 {
-                    format!("{:.1}万", view as f64 / 10000.0)
-                } else {
-                    view.to_string()
-                }
-            })
-            .unwrap_or("-".to_string())
+        format_count_opt(self.stat.as_ref().and_then(|s| s.view))
     }
 
     pub fn cover_url(&self) -> Option<String> {
@@ -114,50 +253,26 @@ impl RelatedVideoItem {
 
 impl VideoStat {
     pub fn format_views(&self) -> String {
-        if self.view >= 10000 {
-            format!("{:.1}万", self.view as f64 / 10000.0)
-        } else {
-            self.view.to_string()
-        }
+        format_count(self.view)
     }
 
     pub fn format_danmaku(&self) -> String {
-        if self.danmaku >= 10000 {
-            format!("{:.1}万", self.danmaku as f64 / 10000.0)
-        } else {
-            self.danmaku.to_string()
-        }
+        format_count(self.danmaku)
     }
 
     pub fn format_like(&self) -> String {
-        if self.like >= 10000 {
-            format!("{:.1}万", self.like as f64 / 10000.0)
-        } else {
-            self.like.to_string()
-        }
+        format_count(self.like)
     }
 
     pub fn format_coin(&self) -> String {
-        if self.coin >= 10000 {
-            format!("{:.1}万", self.coin as f64 / 10000.0)
-        } else {
-            self.coin.to_string()
-        }
+        format_count(self.coin)
     }
 
     pub fn format_favorite(&self) -> String {
-        if self.favorite >= 10000 {
-            format!("{:.1}万", self.favorite as f64 / 10000.0)
-        } else {
-            self.favorite.to_string()
-        }
+        format_count(self.favorite)
     }
 
     pub fn format_reply(&self) -> String {
-        match self.reply {
-            Some(n) if n >= 10000 => format!("{:.1}万", n as f64 / 10000.0),
-            Some(n) => n.to_string(),
-            None => "-".to_string(),
-        }
+        format_count_opt(self.reply)
     }
 }
@@ -0,0 +1,137 @@
+//! Watch later (稍后再看) API types
+//!
+//! API endpoints:
+//! - GET https://api.bilibili.com/x/v2/history/toview
+//! - POST https://api.bilibili.com/x/v2/history/toview/del
+//!
+//! Authentication: Cookie (SESSDATA)
+
+use crate::format::format_count;
+use crate::i18n::{t, tf};
+use serde::Deserialize;
+
+/// Progress from which a video counts as watched
+const FINISHED_PERCENT: i64 = 95;
+
+/// Response data from /x/v2/history/toview
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchLaterData {
+    #[serde(default)]
+    pub count: i32,
+    #[serde(default)]
+    pub list: Option<Vec<WatchLaterItem>>,
+}
+
+/// A video in the watch later list
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchLaterItem {
+    pub aid: i64,
+    #[serde(default)]
+    pub bvid: String,
+    pub title: String,
+    pub pic: Option<String>,
+    #[serde(default)]
+    pub duration: i64,
+    /// Watched seconds, -1 once watched to the end
+    #[serde(default)]
+    pub progress: i64,
+    pub owner: Option<WatchLaterOwner>,
+    pub stat: Option<WatchLaterStat>,
+    /// 分P数
+    #[serde(default)]
+    pub videos: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchLaterOwner {
+    pub mid: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchLaterStat {
+    #[serde(default)]
+    pub view: i64,
+}
+
+impl WatchLaterItem {
+    /// Watched percent in 0..=100
+    pub fn progress_percent(&self) -> i64 {
+        if self.progress == -1 {
+            100
+        } else if self.duration > 0 {
+            (self.progress * 100 / self.duration).clamp(0, 100)
+        } else {
+            0
+        }
+    }
+
+    /// Watched to the end, or at least 95%
+    pub fn is_finished(&self) -> bool {
+        self.progress_percent() >= FINISHED_PERCENT
+    }
+
+    /// 「已看完」/「已看 N%」, empty when not started
+    pub fn format_progress(&self) -> String {
+        match self.progress_percent() {
+            0 => String::new(),
+            _ if self.is_finished() => t("已看完").to_string(),
+            p => tf("已看 {}%", &[&p]),
+        }
+    }
+
+    pub fn format_play(&self) -> String {
+        format_count(self.stat.as_ref().map(|s| s.view).unwrap_or(0))
+    }
+
+    pub fn format_duration(&self) -> String {
+        let (h, m, s) = (
+            self.duration / 3600,
+            (self.duration % 3600) / 60,
+            self.duration % 60,
+        );
+        if h > 0 {
+            format!("{}:{:02}:{:02}", h, m, s)
+        } else {
+            format!("{:02}:{:02}", m, s)
+        }
+    }
+
+    pub fn cover_url(&self) -> Option<String> {
+        self.pic.as_ref().map(|url| {
+            if url.starts_with("//") {
+                format!("https:{}", url)
+            } else {
+                url.clone()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(progress: i64, duration: i64) -> WatchLaterItem {
+        WatchLaterItem {
+            aid: 1,
+            bvid: "BV1".to_string(),
+            title: String::new(),
+            pic: None,
+            duration,
+            progress,
+            owner: None,
+            stat: None,
+            videos: 1,
+        }
+    }
+
+    #[test]
+    fn test_finished_by_progress() {
+        assert!(item(-1, 0).is_finished());
+        assert!(item(95, 100).is_finished());
+        assert!(!item(94, 100).is_finished());
+        assert!(!item(0, 0).is_finished());
+        assert_eq!(item(30, 120).progress_percent(), 25);
+    }
+}
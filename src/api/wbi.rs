@@ -1,6 +1,7 @@
 //! WBI Signature implementation for Bilibili API
 //! See: https://socialsisteryi.github.io/bilibili-API-collect/docs/misc/sign/wbi.html
 
+use md5::{Digest, Md5};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 const MIXIN_KEY_ENC_TAB: [usize; 64] = [
@@ -72,7 +73,7 @@ pub fn encode_wbi_with_timestamp(
         .join("&");
 
     // Calculate w_rid (keep in sync with tests/wbi.rs logic)
-    let w_rid = format!("{:?}", md5::compute(query.clone() + &mixin_key));
+    let w_rid = format!("{:x}", Md5::digest(query.clone() + &mixin_key));
 
     // Return final query
     format!("{}&w_rid={}", query, w_rid)
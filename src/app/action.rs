@@ -1,5 +1,8 @@
+use crate::api::comment::CommentVote;
+use crate::api::interactive::StoryChoice;
+use crate::i18n::Language;
 use crate::storage::{Credentials, Keybindings};
-use crate::ui::ThemeVariant;
+use crate::ui::{Component, ThemeVariant, Toast, VideoAction};
 
 /// Actions that can be triggered from UI components
 #[derive(Debug, Clone)]
@@ -14,6 +17,12 @@ pub enum AppAction {
     SwitchToLogin,
     /// Switch to settings page
     SwitchToSettings,
+    /// Open a URL in the system browser
+    OpenInBrowser(String),
+    /// Copy the RSSHub feed link of a UP (by mid) to the clipboard
+    CopyRssLink(i64),
+    /// Explain in an error toast why a key did nothing
+    ShowError(String),
     /// Switch to history page
     SwitchToHistory,
     /// Login was successful with credentials
@@ -25,6 +34,15 @@ pub enum AppAction {
         cid: i64,
         duration: i64,
     },
+    /// Play a video from a time point (seconds), e.g. one mentioned in a comment
+    PlayVideoAt {
+        bvid: String,
+        aid: i64,
+        cid: i64,
+        duration: i64,
+        page_num: Option<i32>,
+        start: i64,
+    },
     /// Play a video with page info for auto-play next episode
     PlayVideoWithPages {
         bvid: String,
@@ -32,6 +50,30 @@ pub enum AppAction {
         pages: Vec<crate::api::video::VideoPage>,
         current_index: usize,
     },
+    /// Open the video action popup (play / audio / queue / download / watch later)
+    OpenActionMenu {
+        bvid: String,
+        aid: i64,
+        title: String,
+    },
+    /// Close the video action popup
+    CloseActionMenu,
+    /// Run an action chosen from the video action popup
+    RunVideoAction {
+        bvid: String,
+        aid: i64,
+        action: VideoAction,
+    },
+    /// Continue an interactive video with the chosen branch
+    ChooseBranch(StoryChoice),
+    /// Close the story choice popup and stop the interactive session
+    CloseBranchMenu,
+    /// Open the quality popup with the qualities offered for a video (bvid, cid)
+    OpenQualityMenu { bvid: String, cid: i64 },
+    /// Play videos at this quality (qn) from now on, None hands the web page to yt-dlp
+    ChooseQuality(Option<i32>),
+    /// Close the quality popup
+    CloseQualityMenu,
     /// Navigate to next sidebar item
     NavNext,
     /// Navigate to previous sidebar item
@@ -42,18 +84,78 @@ pub enum AppAction {
     RefreshDynamic,
     /// Open video detail page (bvid, aid)
     OpenVideoDetail(String, i64),
+    /// Close the "open link" popup
+    CloseOpenDialog,
+    /// Close the keybinding cheat sheet
+    CloseHelp,
+    /// Open a BV号 / av号 / video URL / b23.tv short link
+    OpenLink(String),
     /// Open dynamic detail page for image/text dynamics (dynamic_id)
     OpenDynamicDetail(String),
     /// Go back to previous page
     BackToList,
+    /// Open a user's space page (mid)
+    OpenUserSpace(i64),
+    /// Search a user's uploads for a keyword, opening their space page if needed
+    SearchUserSpace { mid: i64, keyword: String },
+    /// Leave the user space page and return to where it was opened from
+    CloseUserSpace,
+    /// Load more videos in user space page
+    LoadMoreUserSpace,
     /// Load more recommendations
     LoadMoreRecommendations,
     /// Load more search results
     LoadMoreSearch,
     /// Load more dynamic items
     LoadMoreDynamic,
+    /// Upload the images and publish a dynamic in the background
+    PublishDynamic {
+        text: String,
+        images: Vec<std::path::PathBuf>,
+    },
+    /// Share a video to the user's dynamic feed with a comment
+    ShareToDynamic { aid: i64, text: String },
     /// Load more history items
     LoadMoreHistory,
+    /// Reload the selected favorites folder
+    LoadFavorites,
+    /// Load more videos in the selected favorites folder
+    LoadMoreFavorites,
+    /// Export the selected favorites folder to CSV / JSON
+    ExportFavorites,
+    /// Move a video (by aid) between favorites folders, or copy it when `copy` is set
+    TransferFavorite {
+        aid: i64,
+        from: i64,
+        to: i64,
+        copy: bool,
+    },
+    /// Create a favorites folder, or rename the one with `media_id`
+    SaveFavFolder {
+        media_id: Option<i64>,
+        title: String,
+        private: bool,
+    },
+    /// Delete a favorites folder (by media_id)
+    DeleteFavFolder(i64),
+    /// Refetch the uploads of followed UPs on the subscription page
+    RefreshSubscriptions,
+    /// Export all followed UPs as an OPML file of RSSHub feeds
+    ExportFollowingsOpml,
+    /// Reload the watch later list
+    LoadWatchLater,
+    /// Remove the watched videos (by aid) from watch later
+    ClearWatchedLater(Vec<i64>),
+    /// Remove the videos checked in multi-select mode (by aid) from watch later
+    RemoveWatchLater(Vec<i64>),
+    /// Remove the videos checked in multi-select mode (by aid) from a favorite folder
+    RemoveFavorites { media_id: i64, aids: Vec<i64> },
+    /// Delete history records by kid (e.g. `archive_170001`)
+    DeleteHistory(Vec<String>),
+    /// Export the whole watch history to a JSONL file
+    ExportHistory,
+    /// Add several videos to the play queue
+    EnqueueVideos(Vec<String>),
     /// Load more comments in video detail page
     LoadMoreComments,
     /// Toggle comment replies expansion
@@ -62,19 +164,41 @@ pub enum AppAction {
     SwitchDynamicTab(crate::ui::DynamicTab),
     /// Select UP master (0 = all, 1+ = specific UP)
     SelectUpMaster(usize),
+    /// Switch the dynamic page UP bar between frequent UPs and all followings
+    ToggleUpSource,
     /// Switch to next theme variant
     NextTheme,
     /// Set a specific theme
     SetTheme(ThemeVariant),
+    /// Switch the interface language
+    SetLanguage(Language),
+    /// List deleted videos in favorites and history instead of filtering them
+    SetShowInvalidVideos(bool),
     /// Save keybindings to config
     SaveKeybindings(Box<Keybindings>),
     /// Logout and return to login page
     Logout,
-    /// Like or unlike a comment (oid, rpid, comment_type)
-    LikeComment {
+    /// Copy the login cookie to the clipboard
+    CopyCookie,
+    /// Save the login cookie as a Netscape cookies.txt
+    ExportCookies,
+    /// Like or unlike the video open in the detail page
+    LikeVideo,
+    /// Give one more coin to the video open in the detail page
+    CoinVideo,
+    /// Add the open video to the default favorites folder, or remove it from every folder
+    FavoriteVideo,
+    /// Like, coin and favorite the open video at once (一键三连)
+    TripleVideo,
+    /// Follow the user, or unfollow when already following (mid)
+    ToggleFollow(i64),
+    /// Change the vote on a comment (oid, rpid, comment_type) from one state to another
+    VoteComment {
         oid: i64,
         rpid: i64,
         comment_type: i32,
+        from: CommentVote,
+        to: CommentVote,
     },
     /// Add a comment (oid, comment_type, message, optional root rpid for replies)
     AddComment {
@@ -93,6 +217,24 @@ pub enum AppAction {
     LoadMoreLive,
     /// Play live stream
     PlayLive { room_id: i64, title: String },
+    /// Turn the live alert of an anchor on or off
+    ToggleLiveAlert { uid: i64, uname: String },
+    /// Live detail layout was adjusted, save it to the config
+    SaveLiveLayout { info_width: u16, danmaku_wrap: bool },
     /// No action
     None,
 }
+
+/// What a domain action handler asks the app to do once it is done
+pub(super) enum ActionResult {
+    /// Handled, nothing left to do
+    Done,
+    /// Handled, show a message at the bottom
+    Toast(Toast),
+    /// Handled, replace all open pages with this one and initialize it
+    SwitchPage(Box<dyn Component>),
+    /// Handled, show this page over the current one and initialize it
+    OpenPage(Box<dyn Component>),
+    /// Not an action of this handler's domain, try the next one
+    Unhandled(AppAction),
+}
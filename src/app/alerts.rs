@@ -0,0 +1,409 @@
+//! 开播与更新提醒：后台轮询关注主播的开播状态和特别关注 UP 的新投稿，发送桌面通知
+
+use super::{App, AppAction};
+use crate::api::dynamic::DynamicItem;
+use crate::api::live::FollowingLive;
+use crate::i18n::{t, tf};
+use crate::storage::Session;
+use crate::ui::Toast;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot};
+
+/// How often the live status of followed anchors is polled
+const LIVE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+/// Characters of a dynamic's text shown in its notification
+const SUMMARY_CHARS: usize = 40;
+
+/// What an alert opens, from the notification or with the open_alert key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlertTarget {
+    LiveRoom(i64),
+    Dynamic(String),
+    /// Where the last run ended, offered on startup
+    Session(Session),
+}
+
+/// A new video or dynamic of a special follow UP
+#[derive(Debug, Clone)]
+pub struct UpUpdate {
+    pub dynamic_id: String,
+    pub uname: String,
+    pub title: String,
+    pub pub_ts: i64,
+}
+
+impl UpUpdate {
+    fn from_item(item: &DynamicItem) -> Option<Self> {
+        let text = item
+            .video_title()
+            .or_else(|| item.opus_text())
+            .or_else(|| item.desc_text())
+            .unwrap_or_default();
+        Some(Self {
+            dynamic_id: item.id_str.clone()?,
+            uname: item.author_name().to_string(),
+            title: text.chars().take(SUMMARY_CHARS).collect(),
+            pub_ts: item.pub_ts()?,
+        })
+    }
+}
+
+/// State of both alerts between polls
+pub struct Alerts {
+    /// Rooms live at the last poll, None before the first one so rooms already live at
+    /// startup are not announced
+    live_rooms: Option<HashSet<i64>>,
+    live_polled_at: Option<Instant>,
+    live_rx: Option<oneshot::Receiver<Vec<FollowingLive>>>,
+    /// Publish time of the newest update seen, None before the first poll
+    updates_seen_until: Option<i64>,
+    updates_polled_at: Option<Instant>,
+    updates_rx: Option<oneshot::Receiver<Vec<UpUpdate>>>,
+    /// Target of the most recent alert
+    pub latest: Option<AlertTarget>,
+    /// Targets whose desktop notification was clicked
+    click_tx: mpsc::UnboundedSender<AlertTarget>,
+    click_rx: mpsc::UnboundedReceiver<AlertTarget>,
+}
+
+impl Default for Alerts {
+    fn default() -> Self {
+        let (click_tx, click_rx) = mpsc::unbounded_channel();
+        Self {
+            live_rooms: None,
+            live_polled_at: None,
+            live_rx: None,
+            updates_seen_until: None,
+            updates_polled_at: None,
+            updates_rx: None,
+            latest: None,
+            click_tx,
+            click_rx,
+        }
+    }
+}
+
+impl Alerts {
+    /// Record the rooms live now and return the ones that were not live at the last poll
+    pub fn update_lives(&mut self, lives: Vec<FollowingLive>) -> Vec<FollowingLive> {
+        let now: HashSet<i64> = lives.iter().map(|live| live.room_id).collect();
+        let started = match &self.live_rooms {
+            Some(before) => lives
+                .into_iter()
+                .filter(|live| !before.contains(&live.room_id))
+                .collect(),
+            None => Vec::new(),
+        };
+        self.live_rooms = Some(now);
+        started
+    }
+
+    /// Return the updates published after the last poll, oldest first
+    pub fn update_ups(&mut self, mut updates: Vec<UpUpdate>) -> Vec<UpUpdate> {
+        let newest = updates.iter().map(|u| u.pub_ts).max();
+        let Some(seen_until) = self.updates_seen_until else {
+            // Nothing in the first page yet: only what is published from now on is new
+            self.updates_seen_until =
+                Some(newest.unwrap_or_else(|| chrono::Local::now().timestamp()));
+            return Vec::new();
+        };
+        updates.retain(|u| u.pub_ts > seen_until);
+        updates.sort_by_key(|u| u.pub_ts);
+        self.updates_seen_until = Some(newest.map_or(seen_until, |n| n.max(seen_until)));
+        updates
+    }
+}
+
+/// Show a desktop notification; clicking it (where supported) sends the target back
+fn notify_desktop(
+    summary: String,
+    body: String,
+    target: AlertTarget,
+    click_tx: mpsc::UnboundedSender<AlertTarget>,
+) {
+    tokio::task::spawn_blocking(move || {
+        let mut notification = notify_rust::Notification::new();
+        notification
+            .appname("bilibili-tui")
+            .summary(&summary)
+            .body(&body);
+
+        // Only the freedesktop notification servers report clicks
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            notification.action("default", t("打开"));
+            if let Ok(handle) = notification.show() {
+                handle.wait_for_action(|action| {
+                    if action == "default" {
+                        let _ = click_tx.send(target);
+                    }
+                });
+            }
+        }
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        {
+            let _ = (notification.show(), click_tx, target);
+        }
+    });
+}
+
+/// Newest dynamics of the special follow UPs
+async fn fetch_up_updates(
+    client: &crate::api::client::ApiClient,
+    with_dynamics: bool,
+) -> anyhow::Result<Vec<UpUpdate>> {
+    let special: HashSet<i64> = client
+        .get_special_followings()
+        .await?
+        .into_iter()
+        .filter_map(|user| user.mid)
+        .collect();
+    if special.is_empty() {
+        return Ok(Vec::new());
+    }
+    let feed_type = if with_dynamics { "all" } else { "video" };
+    let items = client
+        .get_dynamic_feed(None, Some(feed_type), None)
+        .await?
+        .items
+        .unwrap_or_default();
+    Ok(items
+        .iter()
+        .filter(|item| item.author_mid().is_some_and(|mid| special.contains(&mid)))
+        .filter(|item| item.is_video() || (with_dynamics && (item.is_draw() || item.is_opus())))
+        .filter_map(UpUpdate::from_item)
+        .collect())
+}
+
+impl App {
+    /// Poll followed anchors and special follow UPs when due and announce what is new
+    pub(super) async fn poll_alerts(&mut self) {
+        if let Ok(target) = self.alerts.click_rx.try_recv() {
+            self.alerts.latest = None;
+            self.open_alert_target(target).await;
+        }
+        self.poll_live_alerts();
+        self.poll_up_alerts();
+    }
+
+    fn poll_live_alerts(&mut self) {
+        if let Some(rx) = &mut self.alerts.live_rx {
+            match rx.try_recv() {
+                Ok(lives) => {
+                    self.alerts.live_rx = None;
+                    self.announce_lives(lives);
+                }
+                Err(oneshot::error::TryRecvError::Closed) => self.alerts.live_rx = None,
+                Err(oneshot::error::TryRecvError::Empty) => return,
+            }
+        }
+
+        if !self.config.live_alerts || self.credentials.is_none() {
+            return;
+        }
+        let due = self
+            .alerts
+            .live_polled_at
+            .is_none_or(|at| at.elapsed() >= LIVE_POLL_INTERVAL);
+        if !due {
+            return;
+        }
+        self.alerts.live_polled_at = Some(Instant::now());
+
+        let client = Arc::clone(&self.api_client);
+        let (tx, rx) = oneshot::channel();
+        self.alerts.live_rx = Some(rx);
+        tokio::spawn(async move {
+            // A failed poll keeps the previous state instead of announcing every room again
+            if let Ok(lives) = client.get_following_lives().await {
+                let _ = tx.send(lives);
+            }
+        });
+    }
+
+    fn poll_up_alerts(&mut self) {
+        if let Some(rx) = &mut self.alerts.updates_rx {
+            match rx.try_recv() {
+                Ok(updates) => {
+                    self.alerts.updates_rx = None;
+                    self.announce_updates(updates);
+                }
+                Err(oneshot::error::TryRecvError::Closed) => self.alerts.updates_rx = None,
+                Err(oneshot::error::TryRecvError::Empty) => return,
+            }
+        }
+
+        if !self.config.up_alerts || self.credentials.is_none() {
+            return;
+        }
+        let interval = Duration::from_secs(self.config.up_alert_interval_mins.max(1) * 60);
+        let due = self
+            .alerts
+            .updates_polled_at
+            .is_none_or(|at| at.elapsed() >= interval);
+        if !due {
+            return;
+        }
+        self.alerts.updates_polled_at = Some(Instant::now());
+
+        let client = Arc::clone(&self.api_client);
+        let with_dynamics = self.config.up_alert_dynamics;
+        let (tx, rx) = oneshot::channel();
+        self.alerts.updates_rx = Some(rx);
+        tokio::spawn(async move {
+            if let Ok(updates) = fetch_up_updates(&client, with_dynamics).await {
+                let _ = tx.send(updates);
+            }
+        });
+    }
+
+    fn announce_lives(&mut self, lives: Vec<FollowingLive>) {
+        let started: Vec<FollowingLive> = self
+            .alerts
+            .update_lives(lives)
+            .into_iter()
+            .filter(|live| !self.config.live_alert_muted.contains(&live.uid))
+            .collect();
+        let Some(latest) = started.last() else {
+            return;
+        };
+        self.alerts.latest = Some(AlertTarget::LiveRoom(latest.room_id));
+        // Only remembered during the quiet hours, the open_alert key still opens it
+        if self.in_quiet_hours() {
+            return;
+        }
+        for live in &started {
+            notify_desktop(
+                tf("{} 开播了", &[&live.uname]),
+                live.title.clone(),
+                AlertTarget::LiveRoom(live.room_id),
+                self.alerts.click_tx.clone(),
+            );
+        }
+        self.toast = Some(Toast::info(tf(
+            "🔴 {} 开播了：{}  按 {} 进入直播间",
+            &[&latest.uname, &latest.title, &self.keybindings.open_alert],
+        )));
+    }
+
+    fn announce_updates(&mut self, updates: Vec<UpUpdate>) {
+        let updates = self.alerts.update_ups(updates);
+        let Some(latest) = updates.last() else {
+            return;
+        };
+        self.alerts.latest = Some(AlertTarget::Dynamic(latest.dynamic_id.clone()));
+        if self.in_quiet_hours() {
+            return;
+        }
+        for update in &updates {
+            notify_desktop(
+                tf("{} 发布了新动态", &[&update.uname]),
+                update.title.clone(),
+                AlertTarget::Dynamic(update.dynamic_id.clone()),
+                self.alerts.click_tx.clone(),
+            );
+        }
+        self.toast = Some(Toast::info(tf(
+            "🔔 {} 发布了：{}  按 {} 查看",
+            &[&latest.uname, &latest.title, &self.keybindings.open_alert],
+        )));
+    }
+
+    /// Open the target of the most recent alert
+    pub(super) async fn open_latest_alert(&mut self) {
+        match self.alerts.latest.take() {
+            Some(target) => self.open_alert_target(target).await,
+            None => self.toast = Some(Toast::info(t("暂无新的提醒"))),
+        }
+    }
+
+    async fn open_alert_target(&mut self, target: AlertTarget) {
+        match target {
+            AlertTarget::LiveRoom(room_id) => self.open_live_detail(room_id).await,
+            AlertTarget::Dynamic(id) => {
+                Box::pin(self.handle_action(AppAction::OpenDynamicDetail(id))).await
+            }
+            AlertTarget::Session(session) => self.restore_session(session).await,
+        }
+    }
+
+    /// Turn the live alert of an anchor on or off
+    pub(super) fn toggle_live_alert(&mut self, uid: i64, uname: &str) -> Toast {
+        let muted = &mut self.config.live_alert_muted;
+        let enabled = if let Some(pos) = muted.iter().position(|&m| m == uid) {
+            muted.remove(pos);
+            true
+        } else {
+            muted.push(uid);
+            false
+        };
+        let _ = crate::storage::save_config(&self.config);
+        if !enabled {
+            Toast::info(tf("已关闭 {} 的开播提醒", &[&uname]))
+        } else if self.config.live_alerts {
+            Toast::info(tf("已开启 {} 的开播提醒", &[&uname]))
+        } else {
+            Toast::info(tf(
+                "已开启 {} 的开播提醒（需在配置中打开 live_alerts）",
+                &[&uname],
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn live(room_id: i64) -> FollowingLive {
+        FollowingLive {
+            room_id,
+            uid: room_id,
+            uname: String::new(),
+            title: String::new(),
+        }
+    }
+
+    fn update(id: &str, pub_ts: i64) -> UpUpdate {
+        UpUpdate {
+            dynamic_id: id.to_string(),
+            uname: String::new(),
+            title: String::new(),
+            pub_ts,
+        }
+    }
+
+    #[test]
+    fn test_only_new_rooms_are_announced() {
+        let mut alerts = Alerts::default();
+        // Rooms already live at startup are not announced
+        assert!(alerts.update_lives(vec![live(1), live(2)]).is_empty());
+
+        let started = alerts.update_lives(vec![live(1), live(2), live(3)]);
+        assert_eq!(started.iter().map(|l| l.room_id).collect::<Vec<_>>(), [3]);
+
+        // Going offline and live again is announced again
+        alerts.update_lives(vec![live(3)]);
+        let started = alerts.update_lives(vec![live(1), live(3)]);
+        assert_eq!(started.iter().map(|l| l.room_id).collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn test_only_updates_after_last_poll() {
+        let mut alerts = Alerts::default();
+        assert!(alerts.update_ups(vec![update("a", 100)]).is_empty());
+
+        let new = alerts.update_ups(vec![update("c", 300), update("b", 200), update("a", 100)]);
+        assert_eq!(
+            new.iter()
+                .map(|u| u.dynamic_id.as_str())
+                .collect::<Vec<_>>(),
+            ["b", "c"]
+        );
+        // An empty poll keeps the mark
+        assert!(alerts.update_ups(Vec::new()).is_empty());
+        assert!(alerts.update_ups(vec![update("c", 300)]).is_empty());
+    }
+}
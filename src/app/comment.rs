@@ -0,0 +1,126 @@
+//! Comment actions shared by the video and dynamic detail pages
+
+use super::optimistic::Rollback;
+use super::{ActionResult, App, AppAction};
+use crate::api::comment::{add_comment_error_reason, CommentVote};
+use crate::api::error::ApiError;
+use crate::i18n::{t, tf};
+use crate::ui::{DynamicDetailPage, Toast, VideoDetailPage};
+use std::collections::HashMap;
+
+impl App {
+    /// Load, vote on and post comments
+    pub(super) async fn handle_comment_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::LoadMoreComments => {
+                if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more_comments(&client).await;
+                    // Jumping to a far floor or filtering for the UP keeps loading
+                    while page.needs_more_comments() {
+                        page.load_more_comments(&client).await;
+                    }
+                } else if let Some(page) = self.current_page.downcast_mut::<DynamicDetailPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more_comments(&client).await;
+                }
+            }
+            AppAction::ToggleCommentReplies => {
+                if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                    let client = self.api_client.clone();
+                    page.toggle_comment_replies(&client).await;
+                }
+            }
+            AppAction::VoteComment {
+                oid,
+                rpid,
+                comment_type,
+                from,
+                to,
+            } => {
+                let Some(comment_votes) = self.comment_votes_mut() else {
+                    return ActionResult::Done;
+                };
+                comment_votes.insert(rpid, to);
+                let what = match (from, to) {
+                    (_, CommentVote::Like) => t("点赞"),
+                    (_, CommentVote::Dislike) => t("点踩"),
+                    (CommentVote::Like, _) => t("取消点赞"),
+                    _ => t("取消点踩"),
+                };
+                let client = self.api_client.clone();
+                self.spawn_optimistic(
+                    what,
+                    Rollback::CommentVote { rpid, vote: from },
+                    async move {
+                        // Voting one way clears the other on the server side
+                        match (from, to) {
+                            (_, CommentVote::Like) => {
+                                client.like_comment(oid, rpid, comment_type, true).await
+                            }
+                            (_, CommentVote::Dislike) => {
+                                client.dislike_comment(oid, rpid, comment_type, true).await
+                            }
+                            (CommentVote::Like, _) => {
+                                client.like_comment(oid, rpid, comment_type, false).await
+                            }
+                            _ => client.dislike_comment(oid, rpid, comment_type, false).await,
+                        }
+                    },
+                );
+            }
+            AppAction::AddComment {
+                oid,
+                comment_type,
+                message,
+                root,
+            } => {
+                let client = self.api_client.clone();
+                match client
+                    .add_comment(oid, comment_type, &message, root, root)
+                    .await
+                {
+                    Ok(_response) => {
+                        self.toast = Some(Toast::info(t("评论已发送")));
+                        // Reload comments to show new comment
+                        if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                            page.load_data(&client);
+                        } else if let Some(page) =
+                            self.current_page.downcast_mut::<DynamicDetailPage>()
+                        {
+                            page.load_data(&client).await;
+                        }
+                    }
+                    Err(e) => {
+                        let reason = ApiError::of(&e)
+                            .and_then(ApiError::code)
+                            .and_then(add_comment_error_reason)
+                            .map_or_else(|| e.to_string(), str::to_string);
+                        self.toast = Some(Toast::error(tf("评论发送失败: {}", &[&reason])));
+                        // Reopen the editor with the text so it can be fixed and sent again
+                        if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                            page.restore_comment(message);
+                        } else if let Some(page) =
+                            self.current_page.downcast_mut::<DynamicDetailPage>()
+                        {
+                            page.restore_comment(message);
+                        }
+                    }
+                }
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    /// Comment votes changed on the open video or dynamic detail page
+    pub(super) fn comment_votes_mut(&mut self) -> Option<&mut HashMap<i64, CommentVote>> {
+        if self.current_page.is::<VideoDetailPage>() {
+            let page = self.current_page.downcast_mut::<VideoDetailPage>()?;
+            Some(&mut page.comment_votes)
+        } else {
+            let page = self.current_page.downcast_mut::<DynamicDetailPage>()?;
+            Some(&mut page.comment_votes)
+        }
+    }
+}
@@ -0,0 +1,438 @@
+//! List page actions: searching, paging, dynamics, history and favorites
+
+use super::alerts::AlertTarget;
+use super::optimistic::Rollback;
+use super::{ActionResult, App, AppAction};
+use crate::i18n::{t, tf};
+use crate::ui::{
+    failure_notice, DynamicPage, FavoritesPage, HistoryPage, HomePage, SearchPage,
+    SubscriptionsPage, Toast, UserSpacePage, WatchLaterPage,
+};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Progress of a dynamic being published in the background
+pub(super) enum PublishEvent {
+    Uploading { done: usize, total: usize },
+    Finished(Result<(), String>),
+}
+
+impl App {
+    /// Load, refresh and export the content of list pages
+    pub(super) async fn handle_feed_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::Search(keyword) => {
+                if let Some(page) = self.current_page.downcast_mut::<SearchPage>() {
+                    // Keywords searched shortly before come back from the cache
+                    if page.restore_cached(&keyword) {
+                        return ActionResult::Done;
+                    }
+                    let client = self.api_client.clone();
+                    match client.search_videos(&keyword, 1).await {
+                        Ok(data) => {
+                            let results = data.result.unwrap_or_default();
+                            let total = data.num_results.unwrap_or(0);
+                            page.set_results(&keyword, results, total);
+                        }
+                        Err(e) => {
+                            page.set_search_error(&e);
+                        }
+                    }
+                }
+            }
+            AppAction::RefreshDynamic => {
+                self.reload_dynamic_feed().await;
+            }
+            AppAction::PublishDynamic { text, images } => self.publish_dynamic(text, images),
+            AppAction::ShareToDynamic { aid, text } => {
+                self.toast = Some(match self.api_client.repost_video(&text, aid).await {
+                    Ok(dynamic_id) => {
+                        self.alerts.latest = Some(AlertTarget::Dynamic(dynamic_id));
+                        Toast::info(tf(
+                            "已转发到动态  按 {} 查看",
+                            &[&self.keybindings.open_alert],
+                        ))
+                    }
+                    Err(e) => Toast::error(tf("转发失败: {}", &[&e])),
+                });
+            }
+            AppAction::LoadMoreUserSpace => {
+                if let Some(page) = self.current_page.downcast_mut::<UserSpacePage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+            }
+            AppAction::LoadMoreRecommendations => {
+                if let Some(page) = self.current_page.downcast_mut::<HomePage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+            }
+            AppAction::LoadMoreSearch => {
+                if let Some(page) = self.current_page.downcast_mut::<SearchPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+            }
+            AppAction::LoadMoreDynamic => {
+                if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+            }
+            AppAction::LoadMoreHistory => {
+                if let Some(page) = self.current_page.downcast_mut::<HistoryPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+            }
+            AppAction::DeleteHistory(kids) => {
+                let mut deleted = Vec::new();
+                let mut errors = Vec::new();
+                for kid in kids {
+                    match self.api_client.delete_history(&kid).await {
+                        Ok(()) => deleted.push(kid),
+                        Err(e) => errors.push(e.to_string()),
+                    }
+                }
+                if let Some(page) = self.current_page.downcast_mut::<HistoryPage>() {
+                    page.finish_delete(&deleted, &errors);
+                }
+            }
+            AppAction::ExportHistory => {
+                if let Some(page) = self.current_page.downcast_mut::<HistoryPage>() {
+                    page.start_export(&self.api_client);
+                }
+            }
+            AppAction::LoadFavorites => {
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    let client = self.api_client.clone();
+                    page.load_folder(&client).await;
+                }
+                self.apply_watched();
+            }
+            AppAction::LoadMoreFavorites => {
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    let client = self.api_client.clone();
+                    page.load_more(&client).await;
+                }
+                self.apply_watched();
+            }
+            AppAction::ExportFavorites => {
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    page.start_export(&self.api_client);
+                }
+            }
+            AppAction::TransferFavorite {
+                aid,
+                from,
+                to,
+                copy,
+            } => {
+                let Some(mid) = self.user_mid() else {
+                    return ActionResult::Done;
+                };
+                let result = if copy {
+                    self.api_client
+                        .copy_fav_resources(from, to, mid, &[aid])
+                        .await
+                } else {
+                    self.api_client
+                        .move_fav_resources(from, to, mid, &[aid])
+                        .await
+                };
+                if let Err(e) = result {
+                    return ActionResult::Toast(Toast::error(if copy {
+                        tf("复制失败: {}", &[&e])
+                    } else {
+                        tf("移动失败: {}", &[&e])
+                    }));
+                }
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    let title = page.finish_transfer(aid, from, to, copy);
+                    return ActionResult::Toast(Toast::info(if copy {
+                        tf("已复制到「{}」", &[&title])
+                    } else {
+                        tf("已移动到「{}」", &[&title])
+                    }));
+                }
+            }
+            AppAction::RemoveFavorites { media_id, aids } => {
+                let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() else {
+                    return ActionResult::Done;
+                };
+                let cards = page.remove_cards(media_id, &aids);
+                let client = self.api_client.clone();
+                self.spawn_optimistic_with(t("移出收藏夹"), async move {
+                    client
+                        .remove_fav_resources(media_id, &aids)
+                        .await
+                        .map_err(|e| (Rollback::Favorites { media_id, cards }, e))
+                });
+            }
+            AppAction::SaveFavFolder {
+                media_id,
+                title,
+                private,
+            } => {
+                let result = match media_id {
+                    Some(id) => self
+                        .api_client
+                        .edit_fav_folder(id, &title, private)
+                        .await
+                        .map(|()| id),
+                    None => self
+                        .api_client
+                        .create_fav_folder(&title, private)
+                        .await
+                        .map(|folder| folder.id),
+                };
+                let id = match result {
+                    Ok(id) => id,
+                    Err(e) => {
+                        return ActionResult::Toast(Toast::error(tf("保存收藏夹失败: {}", &[&e])))
+                    }
+                };
+                let mid = self.user_mid();
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    let client = self.api_client.clone();
+                    page.reload_folders(&client, mid, Some(id)).await;
+                }
+                self.apply_watched();
+                return ActionResult::Toast(Toast::info(if media_id.is_some() {
+                    tf("已重命名为「{}」", &[&title])
+                } else {
+                    tf("已新建收藏夹「{}」", &[&title])
+                }));
+            }
+            AppAction::DeleteFavFolder(id) => {
+                if let Err(e) = self.api_client.delete_fav_folders(&[id]).await {
+                    return ActionResult::Toast(Toast::error(tf("删除收藏夹失败: {}", &[&e])));
+                }
+                let mid = self.user_mid();
+                let mut title = String::new();
+                if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                    title = page.folder_title(id).unwrap_or_default().to_string();
+                    let client = self.api_client.clone();
+                    page.load_folders(&client, mid).await;
+                }
+                self.apply_watched();
+                return ActionResult::Toast(Toast::info(tf("已删除收藏夹「{}」", &[&title])));
+            }
+            AppAction::RefreshSubscriptions => {
+                let mid = self.user_mid();
+                if let Some(page) = self.current_page.downcast_mut::<SubscriptionsPage>() {
+                    let client = self.api_client.clone();
+                    page.load(&client, mid).await;
+                }
+            }
+            AppAction::ExportFollowingsOpml => {
+                let mid = self.user_mid();
+                if let Some(page) = self.current_page.downcast_mut::<SubscriptionsPage>() {
+                    page.start_export(&self.api_client, mid, &self.config.rsshub_base);
+                }
+            }
+            AppAction::LoadWatchLater => {
+                if let Some(page) = self.current_page.downcast_mut::<WatchLaterPage>() {
+                    let client = self.api_client.clone();
+                    page.load(&client).await;
+                }
+            }
+            AppAction::ClearWatchedLater(aids) => {
+                self.remove_watch_later(aids, true);
+            }
+            AppAction::RemoveWatchLater(aids) => {
+                self.remove_watch_later(aids, false);
+            }
+            AppAction::SwitchDynamicTab(tab) => {
+                if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+                    page.switch_tab(tab);
+                }
+                self.reload_dynamic_feed().await;
+            }
+            AppAction::SelectUpMaster(index) => {
+                if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+                    page.select_up(index);
+                    self.reload_dynamic_feed().await;
+                }
+            }
+            AppAction::ToggleUpSource => {
+                let mid = self.user_mid();
+                if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+                    if page.needs_followings() {
+                        let Some(mid) = mid else {
+                            page.set_error(t("请先登录").to_string());
+                            return ActionResult::Done;
+                        };
+                        let client = self.api_client.clone();
+                        page.load_followings(&client, mid).await;
+                    }
+                    if page.toggle_up_source() {
+                        page.select_up(0);
+                        self.reload_dynamic_feed().await;
+                    }
+                }
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    /// Upload the images one by one, then create the dynamic; progress goes to the toast
+    pub(super) fn publish_dynamic(&mut self, text: String, images: Vec<std::path::PathBuf>) {
+        if self.publish_rx.is_some() {
+            self.toast = Some(Toast::error(t("上一条动态还在发布中")));
+            return;
+        }
+        self.toast = Some(Toast::info(t("正在发布动态...")));
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.publish_rx = Some(rx);
+        let client = Arc::clone(&self.api_client);
+        tokio::spawn(async move {
+            let total = images.len();
+            let mut uploaded = Vec::with_capacity(total);
+            for (done, path) in images.iter().enumerate() {
+                let _ = tx.send(PublishEvent::Uploading { done, total });
+                match client.upload_dynamic_image(path).await {
+                    Ok(image) => uploaded.push(image),
+                    Err(e) => {
+                        let name = path.file_name().unwrap_or_default().to_string_lossy();
+                        let _ = tx.send(PublishEvent::Finished(Err(format!("{}: {}", name, e))));
+                        return;
+                    }
+                }
+            }
+            let result = client
+                .create_dynamic(&text, &uploaded)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+            let _ = tx.send(PublishEvent::Finished(result));
+        });
+    }
+
+    /// Take videos out of the watch later page right away and remove them on the server,
+    /// putting back the ones that failed
+    fn remove_watch_later(&mut self, aids: Vec<i64>, finished: bool) {
+        let Some(page) = self.current_page.downcast_mut::<WatchLaterPage>() else {
+            return;
+        };
+        let removed = page.remove_items(&aids, finished);
+        let client = self.api_client.clone();
+        let what = if finished {
+            t("清除已看完的视频")
+        } else {
+            t("移出稍后再看")
+        };
+        self.spawn_optimistic_with(what, async move {
+            let mut failed = Vec::new();
+            let mut errors = Vec::new();
+            for (idx, item) in removed {
+                if let Err(e) = client.remove_from_watch_later(item.aid).await {
+                    errors.push(e.to_string());
+                    failed.push((idx, item));
+                }
+            }
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err((
+                    Rollback::WatchLater(failed),
+                    anyhow::anyhow!(failure_notice(&errors)),
+                ))
+            }
+        });
+    }
+
+    /// Show publishing progress and refresh the feed once the dynamic is out
+    pub(super) async fn poll_publish_events(&mut self) {
+        let Some(rx) = &mut self.publish_rx else {
+            return;
+        };
+        let mut published = false;
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                PublishEvent::Uploading { done, total } => {
+                    self.toast = Some(Toast::info(tf(
+                        "正在上传图片 {}/{}...",
+                        &[&(done + 1), &total],
+                    )));
+                }
+                PublishEvent::Finished(Ok(())) => {
+                    self.toast = Some(Toast::info(t("动态已发布")));
+                    published = true;
+                    self.publish_rx = None;
+                    break;
+                }
+                PublishEvent::Finished(Err(e)) => {
+                    self.toast = Some(Toast::error(tf("发布动态失败: {}", &[&e])));
+                    self.publish_rx = None;
+                    break;
+                }
+            }
+        }
+        if published && self.current_page.is::<DynamicPage>() {
+            self.reload_dynamic_feed().await;
+        }
+    }
+
+    /// Reload the dynamic feed for the selected tab and UP
+    pub(super) async fn reload_dynamic_feed(&mut self) {
+        if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+            let feed_type = page.current_tab.get_feed_type();
+            let host_mid = page.get_selected_up_mid();
+            match self
+                .api_client
+                .get_dynamic_feed(None, feed_type, host_mid)
+                .await
+            {
+                Ok(data) => {
+                    let items = data.items.unwrap_or_default();
+                    let offset = data.offset;
+                    let has_more = data.has_more.unwrap_or(false);
+                    page.set_feed(items, offset, has_more);
+                    page.update_baseline = data.update_baseline;
+                }
+                Err(e) => {
+                    page.set_error(tf("加载动态失败: {}", &[&e]));
+                }
+            }
+        }
+    }
+
+    /// Show the cached dynamic feed right away and only fetch what was published since,
+    /// falling back to a full load the first time
+    pub(super) async fn restore_dynamic_page(&mut self) {
+        let Some(page) = self.cached_dynamic.take() else {
+            self.replace_keeping_feeds(Box::new(DynamicPage::new()));
+            self.init_current_page().await;
+            return;
+        };
+        self.replace_keeping_feeds(page);
+        let Some(page) = self.current_page.downcast_mut::<DynamicPage>() else {
+            return;
+        };
+        if !page.can_check_updates() {
+            return;
+        }
+
+        let feed_type = page.current_tab.get_feed_type();
+        let baseline = page.update_baseline.clone().unwrap_or_default();
+        let new_count = self
+            .api_client
+            .get_dynamic_update_num(feed_type, &baseline)
+            .await
+            .unwrap_or(0);
+        if new_count <= 0 {
+            return;
+        }
+        // A failed refresh keeps the cached feed as it is
+        if let Ok(data) = self
+            .api_client
+            .get_dynamic_feed(None, feed_type, None)
+            .await
+        {
+            page.prepend_feed(data.items.unwrap_or_default(), data.update_baseline);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! 私人 FM：从音乐分区和默认收藏夹随机抽取视频，仅音频连播
+
+use crate::api::client::ApiClient;
+use std::collections::{HashSet, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 音乐分区
+const MUSIC_RID: i32 = 3;
+/// Region pages to pick from, deeper pages are older and less relevant
+const MUSIC_PAGES: i32 = 5;
+const PAGE_SIZE: i32 = 30;
+/// Fetch more tracks when fewer than this are left
+const REFILL_BELOW: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct FmTrack {
+    pub bvid: String,
+    pub title: String,
+    pub author: String,
+}
+
+/// Shuffled queue of the radio
+#[derive(Default)]
+pub struct FmSession {
+    queue: VecDeque<FmTrack>,
+    /// Track being played
+    pub current: Option<FmTrack>,
+    /// Played bvids, not picked again in this session
+    played: HashSet<String>,
+}
+
+impl FmSession {
+    /// Take the next track and make it current, None (and no current track) when the queue is empty
+    pub fn advance(&mut self) -> Option<FmTrack> {
+        self.current = self.queue.pop_front();
+        if let Some(track) = &self.current {
+            self.played.insert(track.bvid.clone());
+        }
+        self.current.clone()
+    }
+
+    pub fn needs_refill(&self) -> bool {
+        self.queue.len() < REFILL_BELOW
+    }
+
+    /// Shuffle new tracks into the queue, skipping played and already queued ones
+    pub fn refill(&mut self, mut tracks: Vec<FmTrack>) {
+        let mut known: HashSet<String> = self.queue.iter().map(|t| t.bvid.clone()).collect();
+        known.extend(self.played.iter().cloned());
+        tracks.retain(|t| known.insert(t.bvid.clone()));
+        shuffle(&mut tracks, seed());
+        self.queue.extend(tracks);
+    }
+}
+
+/// A random page of the music region, plus the default favorites folder when logged in
+pub async fn fetch_tracks(client: &ApiClient, mid: Option<i64>) -> Vec<FmTrack> {
+    let page = (seed() % MUSIC_PAGES as u64) as i32 + 1;
+    let mut tracks: Vec<FmTrack> = client
+        .get_region_videos(MUSIC_RID, page, PAGE_SIZE)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|video| FmTrack {
+            bvid: video.bvid,
+            title: video.title,
+            author: video.owner.map(|o| o.name).unwrap_or_default(),
+        })
+        .collect();
+
+    let Some(mid) = mid else {
+        return tracks;
+    };
+    let folder = client
+        .get_fav_folders(mid)
+        .await
+        .ok()
+        .and_then(|folders| folders.into_iter().next());
+    if let Some(folder) = folder {
+        if let Ok(data) = client.get_fav_resources(folder.id, 1, 20).await {
+            tracks.extend(
+                data.medias
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|m| m.media_type == 2 && !m.bvid.is_empty())
+                    .map(|m| FmTrack {
+                        bvid: m.bvid,
+                        title: m.title,
+                        author: m.upper.name,
+                    }),
+            );
+        }
+    }
+    tracks
+}
+
+fn seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(1)
+}
+
+/// Fisher-Yates with xorshift, good enough for picking songs
+fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed | 1;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(bvid: &str) -> FmTrack {
+        FmTrack {
+            bvid: bvid.to_string(),
+            title: String::new(),
+            author: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_refill_skips_played_and_queued() {
+        let mut fm = FmSession::default();
+        fm.refill(vec![track("BV1"), track("BV1"), track("BV2")]);
+        let first = fm.advance().unwrap().bvid;
+        assert_eq!(fm.queue.len(), 1);
+
+        // The played one and the queued one are dropped, only BV3 is new
+        fm.refill(vec![track("BV1"), track("BV2"), track("BV3")]);
+        assert_eq!(fm.queue.len(), 2);
+        assert!(fm.queue.iter().all(|t| t.bvid != first));
+    }
+
+    #[test]
+    fn test_shuffle_keeps_items() {
+        let mut items: Vec<i32> = (0..20).collect();
+        shuffle(&mut items, 42);
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,65 @@
+//! Follow and unfollow UPs from the video detail and dynamic pages; the state shown on the
+//! page changes at once and goes back when the request fails
+
+use super::optimistic::Rollback;
+use super::{ActionResult, App, AppAction};
+use crate::i18n::{t, tf};
+use crate::ui::{DynamicPage, Toast, VideoDetailPage};
+
+impl App {
+    /// Follow or unfollow an UP
+    pub(super) async fn handle_follow_action(&mut self, action: AppAction) -> ActionResult {
+        let AppAction::ToggleFollow(mid) = action else {
+            return ActionResult::Unhandled(action);
+        };
+        let Some(user_mid) = self.user_mid() else {
+            return ActionResult::Toast(Toast::error(t("请先登录")));
+        };
+        if mid == user_mid {
+            return ActionResult::Toast(Toast::info(t("不能关注自己")));
+        }
+        let client = self.api_client.clone();
+        // Authors in the dynamic feed are only known once their state was asked for
+        let following = match self.follow_state(mid) {
+            Some(following) => following,
+            None => match client.get_user_relation(mid).await {
+                Ok(relation) => relation.is_following(),
+                Err(e) => {
+                    return ActionResult::Toast(Toast::error(tf("获取关注状态失败: {}", &[&e])));
+                }
+            },
+        };
+        self.set_follow_state(mid, !following);
+        let what = if following {
+            t("取消关注")
+        } else {
+            t("关注")
+        };
+        self.spawn_optimistic(what, Rollback::Follow { mid, following }, async move {
+            client.follow_user(mid, !following).await
+        });
+        ActionResult::Done
+    }
+
+    /// Whether the user follows `mid`, as far as the open page knows
+    fn follow_state(&self, mid: i64) -> Option<bool> {
+        if let Some(page) = self.current_page.downcast_ref::<VideoDetailPage>() {
+            page.following.filter(|_| page.up_mid() == Some(mid))
+        } else if let Some(page) = self.current_page.downcast_ref::<DynamicPage>() {
+            page.following(mid)
+        } else {
+            None
+        }
+    }
+
+    /// Show the follow state of `mid` on the open page
+    pub(super) fn set_follow_state(&mut self, mid: i64, following: bool) {
+        if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+            if page.up_mid() == Some(mid) {
+                page.following = Some(following);
+            }
+        } else if let Some(page) = self.current_page.downcast_mut::<DynamicPage>() {
+            page.set_following(mid, following);
+        }
+    }
+}
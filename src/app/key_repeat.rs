@@ -0,0 +1,68 @@
+//! Scroll acceleration while a movement key is held down
+
+use ratatui::crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
+
+/// Presses of the same key closer than this are treated as auto-repeat
+const REPEAT_WINDOW: Duration = Duration::from_millis(150);
+/// (repeats so far, rows per event): the longer the key is held, the faster lists move
+const SPEED_STEPS: [(u32, usize); 2] = [(20, 4), (8, 2)];
+
+/// Tracks auto-repeat of the last pressed key
+#[derive(Default)]
+pub struct KeyRepeat {
+    last: Option<(KeyCode, Instant)>,
+    streak: u32,
+}
+
+impl KeyRepeat {
+    /// Record a press and return how many rows it should move
+    pub fn steps(&mut self, key: KeyCode, now: Instant) -> usize {
+        let repeating = self.last.is_some_and(|(last_key, at)| {
+            last_key == key && now.saturating_duration_since(at) < REPEAT_WINDOW
+        });
+        self.streak = if repeating { self.streak + 1 } else { 0 };
+        self.last = Some((key, now));
+
+        SPEED_STEPS
+            .iter()
+            .find(|(repeats, _)| self.streak >= *repeats)
+            .map_or(1, |(_, steps)| *steps)
+    }
+
+    /// Forget the streak, e.g. after another key was pressed
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.streak = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_accelerate_while_held() {
+        let mut repeat = KeyRepeat::default();
+        let start = Instant::now();
+        let tick = Duration::from_millis(30);
+
+        let steps: Vec<usize> = (0..25)
+            .map(|i| repeat.steps(KeyCode::Char('j'), start + tick * i))
+            .collect();
+        assert_eq!(steps[0], 1);
+        assert_eq!(steps[7], 1);
+        assert_eq!(steps[8], 2);
+        assert_eq!(steps[20], 4);
+
+        // A pause or a different key starts over
+        assert_eq!(
+            repeat.steps(KeyCode::Char('j'), start + Duration::from_secs(5)),
+            1
+        );
+        assert_eq!(
+            repeat.steps(KeyCode::Char('k'), start + Duration::from_secs(5)),
+            1
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Screen layout of the app frame: status bar, FM player row, sidebar and page content.
+//! It is worked out once per frame in `draw` and kept, so mouse events use the same areas
+
+use ratatui::layout::{Constraint, Layout, Rect};
+
+/// Width of the sidebar column
+const SIDEBAR_WIDTH: u16 = 16;
+
+/// What the frame shows besides the page
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutOptions {
+    pub status_bar: bool,
+    pub mini_player: bool,
+    pub sidebar: bool,
+    /// The page is drawn without the sidebar (login, details, user space)
+    pub full_screen: bool,
+}
+
+/// Areas of the last drawn frame
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppLayout {
+    /// The whole terminal
+    pub screen: Rect,
+    /// Everything above the status bar and FM player row, where overlays are drawn
+    pub main: Rect,
+    pub status_bar: Option<Rect>,
+    pub mini_player: Option<Rect>,
+    pub sidebar: Option<Rect>,
+    /// The current page and the popups over it
+    pub content: Rect,
+}
+
+impl AppLayout {
+    pub fn new(area: Rect, options: LayoutOptions) -> Self {
+        let (main, status_bar) = split_bottom_row(area, options.status_bar);
+        let (main, mini_player) = split_bottom_row(main, options.mini_player);
+
+        let (sidebar, content) = if options.sidebar && !options.full_screen {
+            let [sidebar, content] =
+                Layout::horizontal([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(40)])
+                    .areas(main);
+            (Some(sidebar), content)
+        } else {
+            (None, main)
+        };
+
+        Self {
+            screen: area,
+            main,
+            status_bar,
+            mini_player,
+            sidebar,
+            content,
+        }
+    }
+}
+
+/// Split off the bottom row when it is wanted and there is room left for the rest
+fn split_bottom_row(area: Rect, wanted: bool) -> (Rect, Option<Rect>) {
+    if !wanted || area.height < 2 {
+        return (area, None);
+    }
+    let [main, row] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+    (main, Some(row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_areas() {
+        let options = LayoutOptions {
+            status_bar: true,
+            mini_player: true,
+            sidebar: true,
+            full_screen: false,
+        };
+        let layout = AppLayout::new(Rect::new(0, 0, 100, 30), options);
+        assert_eq!(layout.status_bar, Some(Rect::new(0, 29, 100, 1)));
+        assert_eq!(layout.mini_player, Some(Rect::new(0, 28, 100, 1)));
+        assert_eq!(layout.sidebar, Some(Rect::new(0, 0, 16, 28)));
+        assert_eq!(layout.content, Rect::new(16, 0, 84, 28));
+
+        let full = AppLayout::new(
+            Rect::new(0, 0, 100, 30),
+            LayoutOptions {
+                full_screen: true,
+                status_bar: false,
+                ..options
+            },
+        );
+        assert_eq!(full.sidebar, None);
+        assert_eq!(full.content, Rect::new(0, 0, 100, 29));
+    }
+}
@@ -0,0 +1,69 @@
+//! Live room actions
+
+use super::{ActionResult, App, AppAction};
+use crate::i18n::t;
+use crate::player::Playing;
+use crate::ui::{LiveDetailPage, LivePage, Toast};
+
+impl App {
+    /// Open, refresh and play live rooms
+    pub(super) async fn handle_live_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::OpenLiveDetail(room_id) => {
+                self.open_live_detail(room_id).await;
+            }
+            AppAction::RefreshLive => {
+                if let Some(page) = self.current_page.downcast_mut::<LivePage>() {
+                    let client = &self.api_client;
+                    page.refresh(client).await;
+                }
+            }
+            AppAction::LoadMoreLive => {
+                if let Some(page) = self.current_page.downcast_mut::<LivePage>() {
+                    let client = &self.api_client;
+                    page.load_more(client).await;
+                }
+            }
+            AppAction::PlayLive { room_id, title: _ } => {
+                if self.player_sessions.contains(&Playing::Live(room_id)) {
+                    return ActionResult::Toast(Toast::info(t("该直播间已在播放")));
+                }
+                let _ = crate::player::play_live(
+                    self.api_client.clone(),
+                    room_id,
+                    &self.player_sessions,
+                    self.player_tx.clone(),
+                )
+                .await;
+            }
+            AppAction::ToggleLiveAlert { uid, uname } => {
+                return ActionResult::Toast(self.toggle_live_alert(uid, &uname));
+            }
+            AppAction::SaveLiveLayout {
+                info_width,
+                danmaku_wrap,
+            } => {
+                self.config.live_info_width = info_width;
+                self.config.danmaku_wrap = danmaku_wrap;
+                let _ = crate::storage::save_config(&self.config);
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    pub(super) async fn open_live_detail(&mut self, room_id: i64) {
+        let mut detail_page = LiveDetailPage::new(room_id);
+        detail_page.set_highlight(&self.config.danmaku_highlight, self.config.danmaku_bell);
+        detail_page.set_layout(self.config.live_info_width, self.config.danmaku_wrap);
+        let client = &self.api_client;
+        detail_page.load_room_info(client).await;
+        if self.config.live_heartbeat && self.credentials.is_some() {
+            detail_page.start_heartbeat(client);
+        }
+        // Connect WebSocket for real-time messages
+        let uid = self.user_mid().unwrap_or(0);
+        detail_page.connect_ws(client, uid).await;
+        self.push_page(Box::new(detail_page));
+    }
+}
@@ -0,0 +1,160 @@
+//! Key macros: one key runs several operations on the video open in the detail page,
+//! and the toast fills in each result as it comes back
+
+use super::App;
+use crate::api::client::ApiClient;
+use crate::i18n::{t, tf};
+use crate::storage::MacroStep;
+use crate::ui::{Toast, VideoDetailPage};
+use anyhow::anyhow;
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// A macro being run in the background
+pub(super) struct MacroRun {
+    name: String,
+    total: usize,
+    /// Finished steps and their outcome
+    results: Vec<(MacroStep, Result<(), String>)>,
+    rx: mpsc::UnboundedReceiver<(MacroStep, Result<(), String>)>,
+}
+
+/// What the steps act on
+struct MacroTarget {
+    aid: i64,
+    /// UP of the video, None until the video info is loaded
+    up_mid: Option<i64>,
+    /// Logged-in user, owner of the default favorites folder
+    user_mid: Option<i64>,
+}
+
+fn step_label(step: MacroStep) -> &'static str {
+    match step {
+        MacroStep::Like => t("点赞"),
+        MacroStep::Coin => t("投币"),
+        MacroStep::Coin2 => t("投两个币"),
+        MacroStep::Favorite => t("收藏"),
+        MacroStep::Follow => t("关注"),
+        MacroStep::WatchLater => t("稍后再看"),
+    }
+}
+
+async fn run_step(client: &ApiClient, step: MacroStep, target: &MacroTarget) -> anyhow::Result<()> {
+    let aid = target.aid;
+    match step {
+        MacroStep::Like => client.like_video(aid, true).await,
+        MacroStep::Coin => client.coin_video(aid, 1, false).await,
+        MacroStep::Coin2 => client.coin_video(aid, 2, false).await,
+        MacroStep::Favorite => {
+            let mid = target.user_mid.ok_or_else(|| anyhow!(t("请先登录")))?;
+            // The default folder always comes first
+            let folders = client.get_fav_folders(mid).await?;
+            let folder = folders
+                .first()
+                .ok_or_else(|| anyhow!("No favorites folder"))?;
+            client.favorite_video(aid, &[folder.id], &[]).await
+        }
+        MacroStep::Follow => {
+            let mid = target
+                .up_mid
+                .ok_or_else(|| anyhow!(t("视频信息还在加载")))?;
+            client.follow_user(mid, true).await
+        }
+        MacroStep::WatchLater => client.add_to_watch_later(aid).await,
+    }
+}
+
+impl App {
+    /// Start the macro bound to `key` on the open video; false when no macro applies here
+    pub(super) fn run_macro(&mut self, key: KeyCode) -> bool {
+        let Some(key_macro) = self
+            .config
+            .macros
+            .iter()
+            .find(|m| !m.steps.is_empty() && self.keybindings.matches(&m.key, key))
+        else {
+            return false;
+        };
+        let Some(page) = self.current_page.downcast_ref::<VideoDetailPage>() else {
+            return false;
+        };
+        if self.macro_run.is_some() {
+            self.toast = Some(Toast::error(t("上一个宏还在执行")));
+            return true;
+        }
+        if page.aid == 0 {
+            self.toast = Some(Toast::error(t("视频信息还在加载")));
+            return true;
+        }
+
+        let target = MacroTarget {
+            aid: page.aid,
+            up_mid: page.video_info.as_ref().map(|info| info.owner.mid),
+            user_mid: self.user_mid(),
+        };
+        let name = if key_macro.name.is_empty() {
+            key_macro.key.clone()
+        } else {
+            key_macro.name.clone()
+        };
+        let steps = key_macro.steps.clone();
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.toast = Some(Toast::info(tf("正在执行 {}...", &[&name])));
+        self.macro_run = Some(MacroRun {
+            name,
+            total: steps.len(),
+            results: Vec::new(),
+            rx,
+        });
+
+        let client = Arc::clone(&self.api_client);
+        tokio::spawn(async move {
+            // One after another in the configured order
+            for step in steps {
+                let result = run_step(&client, step, &target)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send((step, result));
+            }
+        });
+        true
+    }
+
+    /// Add finished steps to the toast
+    pub(super) fn poll_macro_events(&mut self) {
+        let Some(run) = &mut self.macro_run else {
+            return;
+        };
+        let mut updated = false;
+        while let Ok(result) = run.rx.try_recv() {
+            run.results.push(result);
+            updated = true;
+        }
+        if !updated {
+            return;
+        }
+
+        let mut summary = format!("{}:", run.name);
+        for (step, result) in &run.results {
+            let mark = if result.is_ok() { "✓" } else { "✗" };
+            summary.push_str(&format!("  {} {}", step_label(*step), mark));
+        }
+        if run.results.len() < run.total {
+            self.toast = Some(Toast::info(summary + "  …"));
+            return;
+        }
+
+        let failure = run.results.iter().find_map(|(step, result)| {
+            result
+                .as_ref()
+                .err()
+                .map(|e| format!("{}: {}", step_label(*step), e))
+        });
+        self.toast = Some(match failure {
+            Some(failure) => Toast::error(format!("{}  ({})", summary, failure)),
+            None => Toast::info(summary),
+        });
+        self.macro_run = None;
+    }
+}
@@ -1,73 +1,199 @@
 mod action;
-
+mod alerts;
+mod comment;
+mod feed;
+mod fm;
+mod follow;
+mod key_repeat;
+mod layout;
+mod live;
+mod macros;
+mod navigation;
+mod optimistic;
+mod playback;
+mod settings;
+mod video;
+mod wheel;
+
+use action::ActionResult;
 pub use action::AppAction;
+use alerts::AlertTarget;
+use feed::PublishEvent;
 
 use crate::api::client::ApiClient;
-use crate::storage::{AppConfig, Credentials, Keybindings};
+use crate::i18n::tf;
+use crate::player::{PlayRequest, PlayerEvent, PlayerSessions, Playing};
+use crate::storage::{AppConfig, Credentials, Keybindings, Session, SessionPage, TitleBlocklist};
 use crate::ui::{
-    Component, DynamicPage, HistoryPage, HomePage, LiveDetailPage, LivePage, LoginPage, NavItem,
-    Page, SearchPage, SettingsPage, Sidebar, Theme, ThemeVariant, VideoDetailPage,
+    invalidate_covers, render_mini_player, render_stats_panel, ActionMenu, BranchMenu, Component,
+    DynamicPage, FavoritesPage, HelpOverlay, HistoryPage, HomePage, LivePage, LoginPage, NavItem,
+    OpenDialog, PageContext, QualityMenu, SearchPage, Sidebar, StatusBar, Theme, ThemeVariant,
+    Toast,
 };
+use key_repeat::KeyRepeat;
+use layout::{AppLayout, LayoutOptions};
 use ratatui::{
     crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseEvent},
     prelude::*,
     DefaultTerminal, Frame,
 };
+use std::collections::{HashSet, VecDeque};
 use std::io;
 use std::sync::Arc;
-
-/// Previous page for back navigation
-#[derive(Clone)]
-pub enum PreviousPage {
-    Home,
-    Search,
-    Dynamic,
-    History,
-    Live,
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot};
+use wheel::WheelScroll;
+
+/// Event poll timeout in normal operation
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Event poll timeout while mpv is playing
+const THROTTLED_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Minimum redraw interval while mpv is playing and there is no input
+const THROTTLED_REDRAW: Duration = Duration::from_secs(1);
+/// Event poll timeout while the terminal is in the background
+const UNFOCUSED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How often the status bar re-fetches the unread message count
+const STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+/// Minimum gap between tab switches from horizontal / Shift + wheel scrolling
+const TAB_SCROLL_COOLDOWN: Duration = Duration::from_millis(300);
+/// Pages kept under the current one for back navigation
+const MAX_PAGE_STACK: usize = 16;
+
+/// Story state of the interactive video being played
+pub struct InteractiveSession {
+    pub bvid: String,
+    pub aid: i64,
+    pub graph_version: i64,
+    /// Node currently playing; None for the first node
+    pub edge_id: Option<i64>,
 }
 
 /// Main application state
 pub struct App {
-    pub current_page: Page,
+    pub current_page: Box<dyn Component>,
     pub should_quit: bool,
     pub api_client: Arc<ApiClient>,
     pub credentials: Option<Credentials>,
     pub sidebar: Sidebar,
     pub show_sidebar: bool,
 
-    pub previous_page: Option<PreviousPage>,
+    /// Pages under the current one, returned to with back (newest last)
+    page_stack: Vec<Box<dyn Component>>,
     pub theme: Theme,
     pub theme_variant: ThemeVariant,
     pub config: AppConfig,
     pub keybindings: Keybindings,
 
     /// Cached home page to avoid refresh when switching tabs
-    pub cached_home: Option<HomePage>,
+    pub cached_home: Option<Box<dyn Component>>,
+
+    /// Cached dynamic feed, refreshed incrementally when coming back to it
+    pub cached_dynamic: Option<Box<dyn Component>>,
+
+    /// Recently watched bvids from history, used for watched markers
+    pub watched_bvids: Arc<HashSet<String>>,
+    /// Compiled title blocklist shared with list pages
+    pub blocklist: Arc<TitleBlocklist>,
+
+    /// Video action popup shown over the current page
+    pub action_menu: Option<ActionMenu>,
+    /// "Open link" popup shown over the current page
+    pub open_dialog: Option<OpenDialog>,
+    /// Show the network stats overlay
+    pub show_stats: bool,
+    /// Keybinding cheat sheet overlay
+    pub help: Option<HelpOverlay>,
+    /// Story choice popup for interactive videos
+    pub branch_menu: Option<BranchMenu>,
+    /// Quality popup of the video detail page
+    pub quality_menu: Option<QualityMenu>,
+    /// Interactive video waiting for the current node to finish
+    pub interactive: Option<InteractiveSession>,
+    /// Videos waiting to be played after the current one
+    pub play_queue: VecDeque<PlayRequest>,
+    /// Running mpv instances
+    pub player_sessions: PlayerSessions,
+    /// Message shown at the bottom for a few seconds
+    toast: Option<Toast>,
+    player_tx: mpsc::UnboundedSender<PlayerEvent>,
+    player_rx: mpsc::UnboundedReceiver<PlayerEvent>,
+    /// Session loaded on startup, applied once the first page is initialized
+    pending_session: Option<Session>,
+    /// Pending result of the startup update check
+    update_rx: Option<oneshot::Receiver<String>>,
+    /// Held up/down keys scroll faster the longer they repeat
+    key_repeat: KeyRepeat,
+    /// Bottom status bar with login, network and message state
+    status_bar: StatusBar,
+    /// Pending background refresh of the status bar: (user name, unread count)
+    status_rx: Option<oneshot::Receiver<(Option<String>, Option<i64>)>>,
+    /// Last time the status bar refresh was started, None to refresh on the next tick
+    status_refreshed_at: Option<Instant>,
+    /// Progress of the dynamic being published, None when idle
+    publish_rx: Option<mpsc::UnboundedReceiver<PublishEvent>>,
+    /// Key macro being run, its results go to the toast
+    macro_run: Option<macros::MacroRun>,
+    /// Requests of changes already shown on the page, undone when they fail
+    optimistic: optimistic::Optimistic,
+    /// Private FM radio, None when off
+    fm: Option<fm::FmSession>,
+    /// Pending background fetch of more FM tracks
+    fm_rx: Option<oneshot::Receiver<Vec<fm::FmTrack>>>,
+    /// Pending fetch of the history record of a video that just finished playing
+    finished_history_rx: Option<oneshot::Receiver<crate::api::history::HistoryItem>>,
+    /// Followed anchors going live and new uploads of special follow UPs
+    alerts: alerts::Alerts,
+    /// Whether the terminal has focus; terminals without focus reporting always count as focused
+    focused: bool,
+    /// Areas of the last drawn frame, mouse events go to the page in its content area
+    layout: AppLayout,
 }
 
 impl App {
     pub fn new() -> Self {
+        // Load config first so API host overrides apply to every request
+        let config = crate::storage::load_config().unwrap_or_default();
+        crate::api::client::set_api_hosts(config.api_hosts.clone());
+        crate::i18n::set_language(config.language);
+
         let credentials = crate::storage::load_credentials().ok();
+        crate::storage::clean_stale_cookie_files();
         let api_client = if let Some(ref creds) = credentials {
             ApiClient::with_cookies(creds)
         } else {
             ApiClient::new()
         };
+        api_client.set_rate_limit(config.api_qps);
 
-        // Load config and apply saved theme
-        let config = crate::storage::load_config().unwrap_or_default();
+        // Apply saved theme
         let keybindings = config.keybindings.clone();
         let theme_variant = config
             .theme
             .parse()
             .unwrap_or(ThemeVariant::CatppuccinMocha);
         let theme = Theme::from_variant(theme_variant);
+        let blocklist = Arc::new(TitleBlocklist::new(
+            &config.title_blocklist,
+            config.collapse_blocked,
+        ));
+
+        let (player_tx, player_rx) = mpsc::unbounded_channel();
+
+        let mut sidebar = Sidebar::new();
+        sidebar.credentials_too_open = crate::storage::credentials_too_open();
+
+        // Offered after startup, see offer_session
+        let pending_session = if credentials.is_some() && config.restore_session {
+            crate::storage::load_session().ok()
+        } else {
+            None
+        };
 
-        // Start on login page if no credentials, otherwise go to home
+        // Start on login page if no credentials, otherwise go to the configured start page
         let current_page = if credentials.is_some() {
-            Page::Home(HomePage::new())
+            Self::list_page(config.start_page.into(), &mut sidebar)
         } else {
-            Page::Login(LoginPage::new())
+            Box::new(LoginPage::new())
         };
 
         Self {
@@ -75,75 +201,225 @@ impl App {
             should_quit: false,
             api_client: Arc::new(api_client),
             credentials,
-            sidebar: Sidebar::new(),
+            sidebar,
             show_sidebar: true,
-            previous_page: None,
+            page_stack: Vec::new(),
             theme,
             theme_variant,
             config,
             keybindings,
             cached_home: None,
+            cached_dynamic: None,
+            watched_bvids: Arc::default(),
+            blocklist,
+            action_menu: None,
+            open_dialog: None,
+            show_stats: false,
+            help: None,
+            branch_menu: None,
+            quality_menu: None,
+            interactive: None,
+            play_queue: VecDeque::new(),
+            player_sessions: PlayerSessions::default(),
+            toast: None,
+            player_tx,
+            player_rx,
+            pending_session,
+            update_rx: None,
+            key_repeat: KeyRepeat::default(),
+            status_bar: StatusBar::default(),
+            status_rx: None,
+            status_refreshed_at: None,
+            publish_rx: None,
+            macro_run: None,
+            optimistic: optimistic::Optimistic::default(),
+            fm: None,
+            fm_rx: None,
+            finished_history_rx: None,
+            alerts: alerts::Alerts::default(),
+            focused: true,
+            layout: AppLayout::default(),
+        }
+    }
+
+    /// Sidebar entry of a list page
+    fn session_nav_item(page: SessionPage) -> NavItem {
+        match page {
+            SessionPage::Home => NavItem::Home,
+            SessionPage::Search => NavItem::Search,
+            SessionPage::Dynamic => NavItem::Dynamic,
+            SessionPage::Live => NavItem::Live,
+            SessionPage::History => NavItem::History,
+            SessionPage::Favorites => NavItem::Favorites,
+        }
+    }
+
+    /// Build a list page and highlight it in the sidebar
+    fn list_page(page: SessionPage, sidebar: &mut Sidebar) -> Box<dyn Component> {
+        sidebar.select(Self::session_nav_item(page));
+        match page {
+            SessionPage::Home => Box::new(HomePage::new()),
+            SessionPage::Search => Box::new(SearchPage::new()),
+            SessionPage::Dynamic => Box::new(DynamicPage::new()),
+            SessionPage::Live => Box::new(LivePage::new()),
+            SessionPage::History => Box::new(HistoryPage::new()),
+            SessionPage::Favorites => Box::<FavoritesPage>::default(),
+        }
+    }
+
+    /// Snapshot the current list page for session restore; detail pages fall back to
+    /// the list page they were opened from
+    fn session_snapshot(&self) -> Option<Session> {
+        std::iter::once(&self.current_page)
+            .chain(self.page_stack.iter().rev())
+            .find_map(|page| page.session())
+    }
+
+    /// Offer the session saved on the last exit unless the start page already shows it;
+    /// the open_alert key restores it
+    fn offer_session(&mut self) {
+        let Some(session) = self.pending_session.take() else {
+            return;
+        };
+        let start = SessionPage::from(self.config.start_page);
+        if session.page == start && session.selected == 0 && session.search_query.is_empty() {
+            return;
+        }
+        self.toast = Some(Toast::info(tf(
+            "上次停留在 {}  按 {} 恢复",
+            &[
+                &Self::session_nav_item(session.page).label(),
+                &self.keybindings.open_alert,
+            ],
+        )));
+        self.alerts.latest = Some(AlertTarget::Session(session));
+    }
+
+    /// Open the page of a saved session and re-apply its selection and search keyword
+    async fn restore_session(&mut self, session: Session) {
+        let page = Self::list_page(session.page, &mut self.sidebar);
+        self.replace_keeping_feeds(page);
+        self.init_current_page().await;
+
+        if !session.search_query.is_empty() {
+            if let Some(page) = self.current_page.downcast_mut::<SearchPage>() {
+                page.query = session.search_query.clone();
+                self.handle_action(AppAction::Search(session.search_query))
+                    .await;
+            }
         }
+
+        self.current_page.restore_selection(session.selected);
     }
 
-    /// 记录当前页面以便返回导航
-    fn save_previous_page(&mut self) {
-        self.previous_page = match &self.current_page {
-            Page::Home(_) => Some(PreviousPage::Home),
-            Page::Search(_) => Some(PreviousPage::Search),
-            Page::Dynamic(_) => Some(PreviousPage::Dynamic),
-            Page::History(_) => Some(PreviousPage::History),
-            Page::Live(_) => Some(PreviousPage::Live),
-            _ => None,
+    /// Show `page` over the current one, which back returns to
+    fn push_page(&mut self, page: Box<dyn Component>) {
+        let below = std::mem::replace(&mut self.current_page, page);
+        self.page_stack.push(below);
+        if self.page_stack.len() > MAX_PAGE_STACK {
+            self.page_stack.remove(0);
+        }
+    }
+
+    /// Return to the page under the current one, false when there is none
+    fn pop_page(&mut self) -> bool {
+        let Some(page) = self.page_stack.pop() else {
+            return false;
         };
+        self.current_page = page;
+        self.apply_watched();
+        true
+    }
+
+    /// Make `page` the only page, dropping everything that was open
+    fn replace_pages(&mut self, page: Box<dyn Component>) {
+        self.page_stack.clear();
+        self.current_page = page;
     }
 
     /// Main run loop
     pub async fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         // Initialize the first page
         self.init_current_page().await;
+        self.offer_session();
 
-        // Store the last content area for mouse handling
-        let mut last_content_area = Rect::default();
+        if self.config.check_update {
+            let (tx, rx) = oneshot::channel();
+            self.update_rx = Some(rx);
+            tokio::spawn(async move {
+                if let Ok(Some(version)) = crate::update::check_latest().await {
+                    let _ = tx.send(version);
+                }
+            });
+        }
 
-        // Scroll accumulator for high-resolution mouse wheel throttling
-        // Many modern mice generate multiple scroll events per physical "click"
-        const SCROLL_THRESHOLD: i32 = 15; // Accumulate 15 events before scrolling
-        let mut scroll_accumulator: i32 = 0;
+        // Notched wheels scroll a row per notch, touchpad bursts are merged by event rate
+        let mut wheel = WheelScroll::default();
+        let mut last_tab_scroll: Option<Instant> = None;
 
-        while !self.should_quit {
-            terminal.draw(|frame| {
-                last_content_area = self.get_content_area(frame.area());
-                self.draw(frame);
-            })?;
+        // While mpv is playing, poll less often and only redraw on input or once a second;
+        // in the background only redraw on input
+        let mut last_draw: Option<Instant> = None;
+        let mut dirty = true;
 
-            if event::poll(std::time::Duration::from_millis(100))? {
+        while !self.should_quit {
+            let throttled = self.is_throttled();
+            let hidden = self.is_hidden();
+            let redraw_due = !hidden && last_draw.is_none_or(|t| t.elapsed() >= THROTTLED_REDRAW);
+            if dirty || !throttled || redraw_due {
+                terminal.draw(|frame| self.draw(frame))?;
+                last_draw = Some(Instant::now());
+                dirty = false;
+            }
+
+            let poll_timeout = if hidden {
+                UNFOCUSED_POLL_INTERVAL
+            } else if throttled {
+                THROTTLED_POLL_INTERVAL
+            } else {
+                POLL_INTERVAL
+            };
+            if event::poll(poll_timeout)? {
+                dirty = true;
                 match event::read()? {
                     Event::Key(key) => {
-                        if key.kind == KeyEventKind::Press {
-                            self.handle_input(key.code, key.modifiers).await;
+                        if matches!(key.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
+                            for _ in 0..self.key_steps(key.code) {
+                                self.handle_input(key.code, key.modifiers).await;
+                            }
                         }
                     }
-                    Event::Mouse(mouse) => {
+                    Event::FocusLost => self.focused = false,
+                    Event::FocusGained => self.focused = true,
+                    Event::Mouse(mut mouse) => {
                         use crossterm::event::MouseEventKind;
+                        // Shift + wheel acts as horizontal scrolling: switch tabs / sections
+                        if mouse.modifiers.contains(KeyModifiers::SHIFT) {
+                            mouse.kind = match mouse.kind {
+                                MouseEventKind::ScrollUp => MouseEventKind::ScrollLeft,
+                                MouseEventKind::ScrollDown => MouseEventKind::ScrollRight,
+                                kind => kind,
+                            };
+                        }
                         match mouse.kind {
-                            MouseEventKind::ScrollDown => {
-                                scroll_accumulator += 1;
-                                if scroll_accumulator >= SCROLL_THRESHOLD {
-                                    scroll_accumulator = 0;
-                                    self.handle_mouse(mouse, last_content_area).await;
+                            MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
+                                // One notch can produce a burst of events, switch at most once per cooldown
+                                if last_tab_scroll
+                                    .is_none_or(|t| t.elapsed() >= TAB_SCROLL_COOLDOWN)
+                                {
+                                    last_tab_scroll = Some(Instant::now());
+                                    self.handle_mouse(mouse).await;
                                 }
                             }
-                            MouseEventKind::ScrollUp => {
-                                scroll_accumulator -= 1;
-                                if scroll_accumulator <= -SCROLL_THRESHOLD {
-                                    scroll_accumulator = 0;
-                                    self.handle_mouse(mouse, last_content_area).await;
+                            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                                let down = mouse.kind == MouseEventKind::ScrollDown;
+                                for _ in 0..wheel.rows(down, Instant::now()) {
+                                    self.handle_mouse(mouse).await;
                                 }
                             }
                             _ => {
                                 // Other mouse events (clicks) are handled immediately
-                                self.handle_mouse(mouse, last_content_area).await;
+                                self.handle_mouse(mouse).await;
                             }
                         }
                     }
@@ -154,691 +430,504 @@ impl App {
             // Handle background tasks (like QR code polling)
             self.tick().await;
         }
-        Ok(())
-    }
 
-    /// Get the content area excluding sidebar
-    fn get_content_area(&self, area: Rect) -> Rect {
-        // Login page, VideoDetail, and DynamicDetail use full area
-        if matches!(
-            self.current_page,
-            Page::Login(_) | Page::VideoDetail(_) | Page::DynamicDetail(_)
-        ) {
-            return area;
-        }
-
-        // Main layout with sidebar
-        if self.show_sidebar {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Length(16), // Sidebar
-                    Constraint::Min(40),    // Content
-                ])
-                .split(area)[1]
-        } else {
-            area
+        if let Some(session) = self.session_snapshot() {
+            let _ = crate::storage::save_session(&session);
         }
+        Ok(())
     }
 
     fn draw(&mut self, frame: &mut Frame) {
-        let area = frame.area();
-
-        // Login page, VideoDetail, and DynamicDetail don't show sidebar
-        if matches!(
-            self.current_page,
-            Page::Login(_) | Page::VideoDetail(_) | Page::DynamicDetail(_)
-        ) {
-            match &mut self.current_page {
-                Page::Login(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-                Page::VideoDetail(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-                Page::DynamicDetail(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-                _ => {}
-            }
-            return;
+        let resized = self.layout.screen != frame.area();
+        self.layout = AppLayout::new(
+            frame.area(),
+            LayoutOptions {
+                status_bar: self.config.show_status_bar,
+                mini_player: self.fm.is_some(),
+                sidebar: self.show_sidebar,
+                full_screen: self.current_page.is_full_screen(),
+            },
+        );
+        let AppLayout {
+            main: area,
+            status_bar,
+            mini_player,
+            sidebar,
+            content: content_area,
+            ..
+        } = self.layout;
+
+        if let (Some(mini_area), Some(fm)) = (mini_player, &self.fm) {
+            let track = fm
+                .current
+                .as_ref()
+                .map(|track| (track.title.as_str(), track.author.as_str()));
+            render_mini_player(frame, mini_area, &self.theme, &self.keybindings, track);
+        }
+        if let Some(status_area) = status_bar {
+            let stats = self.api_client.stats();
+            let tasks = stats.active as usize + self.player_sessions.len();
+            self.status_bar.quiet = self.in_quiet_hours();
+            self.status_bar.draw(
+                frame,
+                status_area,
+                &self.theme,
+                self.credentials.is_some(),
+                &stats,
+                tasks,
+            );
         }
+        if let Some(sidebar_area) = sidebar {
+            self.sidebar
+                .draw(frame, sidebar_area, &self.theme, &self.keybindings);
+        }
+        self.draw_page(frame, content_area);
 
-        // Main layout with sidebar
-        let chunks = if self.show_sidebar {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Length(16), // Sidebar
-                    Constraint::Min(40),    // Content
-                ])
-                .split(area)
-        } else {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Min(40)])
-                .split(area)
-        };
+        // Popups
+        if let Some(menu) = &self.action_menu {
+            menu.draw(frame, content_area, &self.theme);
+        }
+        if let Some(dialog) = &self.open_dialog {
+            dialog.draw(frame, content_area, &self.theme);
+        }
+        if let Some(menu) = &self.branch_menu {
+            menu.draw(frame, content_area, &self.theme);
+        }
+        if let Some(menu) = &self.quality_menu {
+            menu.draw(frame, content_area, &self.theme);
+        }
+        if let Some(help) = &mut self.help {
+            help.draw(frame, area, &self.theme, &self.keybindings);
+        }
+        if self.show_stats {
+            render_stats_panel(frame, area, &self.theme, &self.api_client.stats());
+        }
+        if let Some(toast) = &self.toast {
+            toast.draw(frame, area, &self.theme);
+        }
 
-        if self.show_sidebar && chunks.len() > 1 {
-            self.sidebar.draw(frame, chunks[0], &self.theme);
-            self.draw_page(frame, chunks[1]);
-        } else {
-            self.draw_page(frame, chunks[0]);
+        if resized {
+            invalidate_covers(frame);
         }
     }
 
     fn draw_page(&mut self, frame: &mut Frame, area: Rect) {
-        match &mut self.current_page {
-            Page::Login(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::Home(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::Search(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::Dynamic(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::DynamicDetail(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::VideoDetail(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::History(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::Live(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::LiveDetail(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-            Page::Settings(page) => page.draw(frame, area, &self.theme, &self.keybindings),
-        }
+        self.current_page
+            .draw(frame, area, &self.theme, &self.keybindings);
     }
 
     async fn handle_input(&mut self, key: KeyCode, modifiers: KeyModifiers) {
-        let keys = &self.keybindings;
-        let action = match &mut self.current_page {
-            Page::Login(page) => page.handle_input(key, keys),
-            Page::Home(page) => page.handle_input(key, keys),
-            Page::Search(page) => page.handle_input(key, keys),
-            Page::Dynamic(page) => page.handle_input_with_modifiers(key, modifiers, keys),
-            Page::DynamicDetail(page) => page.handle_input(key, keys),
-            Page::VideoDetail(page) => page.handle_input(key, keys),
-            Page::History(page) => page.handle_input(key, keys),
-            Page::Live(page) => page.handle_input(key, keys),
-            Page::LiveDetail(page) => page.handle_input(key, keys),
-            Page::Settings(page) => page.handle_input(key, keys),
-        };
+        if self.keybindings.matches_toggle_stats(key) {
+            self.show_stats = !self.show_stats;
+            return;
+        }
+
+        if let Some(menu) = &mut self.branch_menu {
+            if let Some(action) = menu.handle_input(key, &self.keybindings) {
+                self.handle_action(action).await;
+            }
+            return;
+        }
+        if let Some(menu) = &mut self.quality_menu {
+            if let Some(action) = menu.handle_input(key, &self.keybindings) {
+                self.handle_action(action).await;
+            }
+            return;
+        }
+
+        // The action popup takes all input while open
+        if let Some(menu) = &mut self.action_menu {
+            if let Some(action) = menu.handle_input(key, &self.keybindings) {
+                self.handle_action(action).await;
+            }
+            return;
+        }
+
+        if let Some(dialog) = &mut self.open_dialog {
+            if let Some(action) = dialog.handle_input(key) {
+                self.handle_action(action).await;
+            }
+            return;
+        }
+
+        if let Some(help) = &mut self.help {
+            if let Some(action) = help.handle_input(key, &self.keybindings) {
+                self.handle_action(action).await;
+            }
+            return;
+        }
+
+        if self.keybindings.matches_help(key) && !self.is_text_input_active() {
+            self.help = Some(HelpOverlay::new());
+            return;
+        }
+
+        if self.keybindings.matches_open_release(key)
+            && self.sidebar.update_available.is_some()
+            && !self.is_text_input_active()
+        {
+            let _ = crate::player::open_url(crate::update::RELEASES_URL);
+            return;
+        }
+
+        if self.keybindings.matches_open_alert(key) && !self.is_text_input_active() {
+            self.open_latest_alert().await;
+            return;
+        }
+
+        if self.keybindings.matches_fm_toggle(key) && !self.is_text_input_active() {
+            self.toggle_fm();
+            return;
+        }
+        if self.keybindings.matches_fm_skip(key)
+            && self.fm.is_some()
+            && !self.is_text_input_active()
+        {
+            // The Finished event of the stopped track plays the next one
+            if let Some(track) = self.fm.as_ref().and_then(|fm| fm.current.as_ref()) {
+                self.player_sessions
+                    .stop(&Playing::Video(track.bvid.clone()));
+            }
+            return;
+        }
+
+        if !self.is_text_input_active() && self.run_macro(key) {
+            return;
+        }
+
+        // Global "open link" popup, unless the page is taking text input
+        if self.keybindings.matches_open_link(key)
+            && self.credentials.is_some()
+            && !self.is_text_input_active()
+        {
+            self.open_dialog = Some(OpenDialog::new());
+            return;
+        }
+
+        let action =
+            self.current_page
+                .handle_input_with_modifiers(key, modifiers, &self.keybindings);
 
         if let Some(action) = action {
             self.handle_action(action).await;
         }
     }
 
-    async fn handle_mouse(&mut self, event: MouseEvent, area: Rect) {
-        let action = match &mut self.current_page {
-            Page::Login(page) => page.handle_mouse(event, area),
-            Page::Home(page) => page.handle_mouse(event, area),
-            Page::Search(page) => page.handle_mouse(event, area),
-            Page::Dynamic(page) => page.handle_mouse(event, area),
-            Page::DynamicDetail(page) => page.handle_mouse(event, area),
-            Page::VideoDetail(page) => page.handle_mouse(event, area),
-            Page::History(page) => page.handle_mouse(event, area),
-            Page::Live(page) => page.handle_mouse(event, area),
-            Page::LiveDetail(page) => page.handle_mouse(event, area),
-            Page::Settings(page) => page.handle_mouse(event, area),
-        };
+    async fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.action_menu.is_some()
+            || self.open_dialog.is_some()
+            || self.help.is_some()
+            || self.branch_menu.is_some()
+            || self.quality_menu.is_some()
+        {
+            return;
+        }
+
+        let action = self.current_page.handle_mouse(event);
 
         if let Some(action) = action {
             self.handle_action(action).await;
         }
     }
 
+    /// Let each domain handler try the action in turn, then apply what it asks for
     async fn handle_action(&mut self, action: AppAction) {
-        match action {
-            AppAction::Quit => self.should_quit = true,
-            AppAction::SwitchToHome => {
-                self.sidebar.select(NavItem::Home);
-                // Use cached home page if available
-                if let Some(cached) = self.cached_home.take() {
-                    self.current_page = Page::Home(cached);
-                } else {
-                    self.current_page = Page::Home(HomePage::new());
-                    self.init_current_page().await;
-                }
-            }
-            AppAction::RefreshHome => {
-                self.sidebar.select(NavItem::Home);
-                // Clear cache and create fresh home page
-                self.cached_home = None;
-                self.current_page = Page::Home(HomePage::new());
+        let mut result = self.handle_navigation_action(action).await;
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_playback_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_feed_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_comment_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_video_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_follow_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_live_action(action).await;
+        }
+        if let ActionResult::Unhandled(action) = result {
+            result = self.handle_settings_action(action).await;
+        }
+
+        match result {
+            ActionResult::Done => {}
+            ActionResult::Toast(toast) => self.toast = Some(toast),
+            ActionResult::SwitchPage(page) => {
+                self.replace_pages(page);
                 self.init_current_page().await;
             }
-            AppAction::SwitchToLogin => {
-                self.current_page = Page::Login(LoginPage::new());
+            ActionResult::OpenPage(page) => {
+                self.push_page(page);
                 self.init_current_page().await;
             }
-            AppAction::LoginSuccess(creds) => {
-                // Save credentials
-                let _ = crate::storage::save_credentials(&creds);
-                self.credentials = Some(creds.clone());
-                // Update API client with new cookies
-                {
-                    let client = self.api_client.clone();
-                    client.set_credentials(&creds);
+            // No handler took it, e.g. an action of a page that was closed meanwhile; there
+            // is no log file, so debug builds report it in a toast and release builds ignore it
+            ActionResult::Unhandled(AppAction::None) => {}
+            ActionResult::Unhandled(action) => {
+                if cfg!(debug_assertions) {
+                    self.toast = Some(Toast::error(format!("Unhandled action: {:?}", action)));
                 }
-                // Switch to home
-                self.current_page = Page::Home(HomePage::new());
-                self.init_current_page().await;
             }
-            AppAction::PlayVideo {
-                bvid,
-                aid,
-                cid,
-                duration,
-            } => {
-                let api_client = self.api_client.clone();
-                let _ = crate::player::play_video(
-                    api_client,
-                    &bvid,
-                    aid,
-                    cid,
-                    duration,
-                    None,
-                    self.credentials.as_ref(),
-                )
-                .await;
-            }
-            AppAction::PlayVideoWithPages {
-                bvid,
-                aid,
-                pages,
-                current_index,
-            } => {
-                // Play only the selected episode
-                if current_index < pages.len() {
-                    let page = &pages[current_index];
-                    let api_client = self.api_client.clone();
-                    let _ = crate::player::play_video(
-                        api_client,
-                        &bvid,
-                        aid,
-                        page.cid,
-                        page.duration,
-                        Some(page.page),
-                        self.credentials.as_ref(),
-                    )
-                    .await;
-                    // Update current page index in video detail page
-                    if let Page::VideoDetail(detail_page) = &mut self.current_page {
-                        if detail_page.bvid == bvid {
-                            detail_page.current_page_index = current_index;
-                        }
-                    }
-                }
-            }
-            AppAction::NavNext => {
-                // Don't navigate if on video detail page
-                if !matches!(self.current_page, Page::VideoDetail(_)) {
-                    self.sidebar.next();
-                    self.switch_to_nav_page().await;
-                }
-            }
-            AppAction::NavPrev => {
-                if !matches!(self.current_page, Page::VideoDetail(_)) {
-                    self.sidebar.prev();
-                    self.switch_to_nav_page().await;
-                }
-            }
-            AppAction::Search(keyword) => {
-                if let Page::Search(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    match client.search_videos(&keyword, 1).await {
-                        Ok(data) => {
-                            let results = data.result.unwrap_or_default();
-                            let total = data.num_results.unwrap_or(0);
-                            page.set_results(results, total);
-                        }
-                        Err(e) => {
-                            page.set_error(format!("搜索失败: {}", e));
-                        }
-                    }
-                }
-            }
-            AppAction::RefreshDynamic => {
-                if let Page::Dynamic(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    let feed_type = page.current_tab.get_feed_type();
-                    let host_mid = page.get_selected_up_mid();
-                    match client.get_dynamic_feed(None, feed_type, host_mid).await {
-                        Ok(data) => {
-                            let items = data.items.unwrap_or_default();
-                            let offset = data.offset;
-                            let has_more = data.has_more.unwrap_or(false);
-                            page.set_feed(items, offset, has_more);
-                        }
-                        Err(e) => {
-                            page.set_error(format!("加载动态失败: {}", e));
-                        }
-                    }
-                }
-            }
-            AppAction::OpenVideoDetail(bvid, aid) => {
-                self.save_previous_page();
-                // Cache home page before navigating to video detail
-                if let Page::Home(home_page) =
-                    std::mem::replace(&mut self.current_page, Page::Home(HomePage::new()))
-                {
-                    self.cached_home = Some(home_page);
-                }
-                let mut detail_page = VideoDetailPage::new(bvid, aid);
-                let client = &self.api_client;
-                detail_page.load_data(client).await;
-                self.current_page = Page::VideoDetail(Box::new(detail_page));
+        }
+    }
+
+    async fn init_current_page(&mut self) {
+        if self.current_page.marks_watched() {
+            self.ensure_watched_cache().await;
+        }
+        self.current_page.set_blocklist(self.blocklist.clone());
+        self.current_page
+            .set_show_invalid(self.config.show_invalid_videos);
+
+        let ctx = PageContext {
+            client: &self.api_client,
+            mid: self.user_mid(),
+            throttled: self.is_throttled(),
+            quiet: self.in_quiet_hours(),
+        };
+        self.current_page.init(ctx).await;
+        if let Some(page) = self.current_page.downcast_ref::<HistoryPage>() {
+            Arc::make_mut(&mut self.watched_bvids).extend(page.watched_bvids().map(str::to_string));
+        }
+
+        self.apply_watched();
+    }
+
+    /// Load the recent history once so list pages can mark watched videos
+    async fn ensure_watched_cache(&mut self) {
+        if self.credentials.is_none() || !self.watched_bvids.is_empty() {
+            return;
+        }
+        if let Ok(data) = self
+            .api_client
+            .get_history(None, None, Some("archive"))
+            .await
+        {
+            self.watched_bvids = Arc::new(
+                data.list
+                    .iter()
+                    .filter_map(|item| item.get_bvid().map(str::to_string))
+                    .collect(),
+            );
+        }
+    }
+
+    /// After playback ends, mark the video watched and, with the history page open, fetch its
+    /// record in the background for [`Self::poll_finished_history`]
+    fn sync_finished_history(&mut self, bvid: &str) {
+        Arc::make_mut(&mut self.watched_bvids).insert(bvid.to_string());
+        self.apply_watched();
+
+        if !self.current_page.is::<HistoryPage>() {
+            return;
+        }
+        let (tx, rx) = oneshot::channel();
+        let client = self.api_client.clone();
+        let bvid = bvid.to_string();
+        tokio::spawn(async move {
+            // The end heartbeat was reported before the event, so the first page already has it
+            let Ok(data) = client.get_history(None, None, None).await else {
+                return;
+            };
+            if let Some(item) = data
+                .list
+                .into_iter()
+                .find(|item| item.get_bvid() == Some(bvid.as_str()))
+            {
+                let _ = tx.send(item);
             }
-            AppAction::OpenDynamicDetail(dynamic_id) => {
-                self.save_previous_page();
-                // Cache home page before navigating to dynamic detail
-                if let Page::Home(home_page) =
-                    std::mem::replace(&mut self.current_page, Page::Home(HomePage::new()))
-                {
-                    self.cached_home = Some(home_page);
-                }
-                use crate::ui::DynamicDetailPage;
-                let mut detail_page = DynamicDetailPage::new(dynamic_id);
-                let client = &self.api_client;
-                detail_page.load_data(client).await;
-                self.current_page = Page::DynamicDetail(Box::new(detail_page));
+        });
+        self.finished_history_rx = Some(rx);
+    }
+
+    /// Move the record of the video that just finished to the top of the history page
+    fn poll_finished_history(&mut self) {
+        let Some(rx) = &mut self.finished_history_rx else {
+            return;
+        };
+        let item = match rx.try_recv() {
+            Ok(item) => item,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.finished_history_rx = None;
+                return;
             }
-            AppAction::BackToList => {
-                match self.previous_page.take() {
-                    Some(PreviousPage::Home) => {
-                        self.sidebar.select(NavItem::Home);
-                        // Use cached home page if available
-                        if let Some(cached) = self.cached_home.take() {
-                            self.current_page = Page::Home(cached);
-                        } else {
-                            self.current_page = Page::Home(HomePage::new());
-                            self.init_current_page().await;
-                        }
-                    }
-                    Some(PreviousPage::Search) => {
-                        self.sidebar.select(NavItem::Search);
-                        self.current_page = Page::Search(SearchPage::new());
-                        self.init_current_page().await;
-                    }
-                    Some(PreviousPage::Dynamic) => {
-                        self.sidebar.select(NavItem::Dynamic);
-                        self.current_page = Page::Dynamic(DynamicPage::new());
-                        self.init_current_page().await;
-                    }
-                    Some(PreviousPage::History) => {
-                        self.sidebar.select(NavItem::History);
-                        self.current_page = Page::History(HistoryPage::new());
-                        self.init_current_page().await;
-                    }
-                    Some(PreviousPage::Live) => {
-                        self.sidebar.select(NavItem::Live);
-                        self.current_page = Page::Live(LivePage::new());
-                        self.init_current_page().await;
-                    }
-                    None => {
-                        // Default to home
-                        self.sidebar.select(NavItem::Home);
-                        if let Some(cached) = self.cached_home.take() {
-                            self.current_page = Page::Home(cached);
-                        } else {
-                            self.current_page = Page::Home(HomePage::new());
-                            self.init_current_page().await;
-                        }
+        };
+        self.finished_history_rx = None;
+        if let Some(page) = self.current_page.downcast_mut::<HistoryPage>() {
+            page.upsert_latest(item);
+        }
+    }
+
+    /// Push the watched cache to the current list page
+    fn apply_watched(&mut self) {
+        let dim = self.config.dim_watched;
+        self.current_page
+            .set_watched(Arc::clone(&self.watched_bvids), dim);
+    }
+
+    /// Apply a finished status bar refresh and start the next one when due
+    fn refresh_status_bar(&mut self) {
+        if let Some(rx) = &mut self.status_rx {
+            match rx.try_recv() {
+                Ok((uname, unread)) => {
+                    if uname.is_some() {
+                        self.status_bar.uname = uname;
                     }
-                }
-            }
-            AppAction::LoadMoreRecommendations => {
-                if let Page::Home(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more(&client).await;
-                }
-            }
-            AppAction::LoadMoreSearch => {
-                if let Page::Search(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more(&client).await;
-                }
-            }
-            AppAction::LoadMoreDynamic => {
-                if let Page::Dynamic(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more(&client).await;
-                }
-            }
-            AppAction::LoadMoreHistory => {
-                if let Page::History(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more(&client).await;
-                }
-            }
-            AppAction::SwitchToHistory => {
-                self.sidebar.select(NavItem::History);
-                self.current_page = Page::History(HistoryPage::new());
-                self.init_current_page().await;
-            }
-            AppAction::LoadMoreComments => {
-                if let Page::VideoDetail(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more_comments(&client).await;
-                } else if let Page::DynamicDetail(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.load_more_comments(&client).await;
-                }
-            }
-            AppAction::ToggleCommentReplies => {
-                if let Page::VideoDetail(page) = &mut self.current_page {
-                    let client = self.api_client.clone();
-                    page.toggle_comment_replies(&client).await;
-                }
-            }
-            AppAction::SwitchDynamicTab(tab) => {
-                if let Page::Dynamic(page) = &mut self.current_page {
-                    page.switch_tab(tab);
-                    let client = self.api_client.clone();
-                    let feed_type = page.current_tab.get_feed_type();
-                    let host_mid = page.get_selected_up_mid();
-                    match client.get_dynamic_feed(None, feed_type, host_mid).await {
-                        Ok(data) => {
-                            let items = data.items.unwrap_or_default();
-                            let offset = data.offset;
-                            let has_more = data.has_more.unwrap_or(false);
-                            page.set_feed(items, offset, has_more);
-                        }
-                        Err(e) => {
-                            page.set_error(format!("加载动态失败: {}", e));
-                        }
+                    if unread.is_some() {
+                        self.status_bar.unread = unread;
                     }
+                    self.status_rx = None;
                 }
+                Err(oneshot::error::TryRecvError::Closed) => self.status_rx = None,
+                Err(oneshot::error::TryRecvError::Empty) => return,
             }
-            AppAction::SelectUpMaster(index) => {
-                if let Page::Dynamic(page) = &mut self.current_page {
-                    page.select_up(index);
-                    let client = self.api_client.clone();
-                    let feed_type = page.current_tab.get_feed_type();
-                    let host_mid = page.get_selected_up_mid();
-                    match client.get_dynamic_feed(None, feed_type, host_mid).await {
-                        Ok(data) => {
-                            let items = data.items.unwrap_or_default();
-                            let offset = data.offset;
-                            let has_more = data.has_more.unwrap_or(false);
-                            page.set_feed(items, offset, has_more);
-                        }
-                        Err(e) => {
-                            page.set_error(format!("加载动态失败: {}", e));
-                        }
-                    }
+        }
+
+        let Some(creds) = &self.credentials else {
+            return;
+        };
+        let due = self
+            .status_refreshed_at
+            .is_none_or(|at| at.elapsed() >= STATUS_REFRESH_INTERVAL);
+        if !self.config.show_status_bar || !due {
+            return;
+        }
+        self.status_refreshed_at = Some(Instant::now());
+
+        let client = Arc::clone(&self.api_client);
+        let mid = creds
+            .dede_user_id
+            .parse::<i64>()
+            .ok()
+            .filter(|_| self.status_bar.uname.is_none());
+        let (tx, rx) = oneshot::channel();
+        self.status_rx = Some(rx);
+        tokio::spawn(async move {
+            let uname = match mid {
+                Some(mid) => client.get_user_card(mid).await.ok().map(|d| d.card.name),
+                None => None,
+            };
+            let unread = client.get_unread_count().await.ok().map(|u| u.total());
+            let _ = tx.send((uname, unread));
+        });
+    }
+
+    async fn tick(&mut self) {
+        if let Some(rx) = &mut self.update_rx {
+            match rx.try_recv() {
+                Ok(version) => {
+                    self.sidebar.update_available = Some(version);
+                    self.update_rx = None;
                 }
+                Err(oneshot::error::TryRecvError::Closed) => self.update_rx = None,
+                Err(oneshot::error::TryRecvError::Empty) => {}
             }
-            AppAction::NextTheme => {
-                self.theme_variant = self.theme_variant.next();
-                self.theme = Theme::from_variant(self.theme_variant);
-                self.save_theme_to_config();
-            }
-            AppAction::SetTheme(variant) => {
-                self.theme_variant = variant;
-                self.theme = Theme::from_variant(variant);
-                self.save_theme_to_config();
-            }
-            AppAction::SwitchToSettings => {
-                self.sidebar.select(NavItem::Settings);
-                let page = SettingsPage::new(self.keybindings.clone(), self.theme_variant);
-                self.current_page = Page::Settings(Box::new(page));
-            }
-            AppAction::Logout => {
-                let _ = crate::storage::delete_credentials();
-                self.credentials = None;
-                self.current_page = Page::Login(LoginPage::new());
-                self.init_current_page().await;
-            }
-            AppAction::LikeComment {
-                oid,
-                rpid,
-                comment_type,
-            } => {
-                let client = self.api_client.clone();
-                // Toggle like - if already liked, unlike
-                if let Page::VideoDetail(page) = &mut self.current_page {
-                    let is_liked = page.liked_comments.contains(&rpid);
-                    if let Ok(()) = client
-                        .like_comment(oid, rpid, comment_type, !is_liked)
-                        .await
+        }
+
+        if self.toast.as_ref().is_some_and(Toast::is_expired) {
+            self.toast = None;
+        }
+
+        self.refresh_status_bar();
+        self.poll_publish_events().await;
+        self.poll_macro_events();
+        self.poll_optimistic();
+        self.poll_fm_tracks().await;
+        self.poll_finished_history();
+        self.poll_alerts().await;
+
+        while let Ok(event) = self.player_rx.try_recv() {
+            match event {
+                PlayerEvent::Finished { bvid } => {
+                    self.sync_finished_history(&bvid);
+                    if self
+                        .interactive
+                        .as_ref()
+                        .is_some_and(|session| session.bvid == bvid)
                     {
-                        if is_liked {
-                            page.liked_comments.remove(&rpid);
-                        } else {
-                            page.liked_comments.insert(rpid);
-                        }
+                        self.show_story_choices().await;
                     }
-                } else if let Page::DynamicDetail(page) = &mut self.current_page {
-                    let is_liked = page.liked_comments.contains(&rpid);
-                    if let Ok(()) = client
-                        .like_comment(oid, rpid, comment_type, !is_liked)
-                        .await
+                    if self
+                        .fm
+                        .as_ref()
+                        .and_then(|fm| fm.current.as_ref())
+                        .is_some_and(|track| track.bvid == bvid)
                     {
-                        if is_liked {
-                            page.liked_comments.remove(&rpid);
-                        } else {
-                            page.liked_comments.insert(rpid);
+                        self.play_next_fm().await;
+                    } else if self.player_sessions.video_count() == 0 {
+                        if let Some(next) = self.play_queue.pop_front() {
+                            self.start_playback(next).await;
                         }
                     }
                 }
-            }
-            AppAction::AddComment {
-                oid,
-                comment_type,
-                message,
-                root,
-            } => {
-                let client = self.api_client.clone();
-                if let Ok(_response) = client
-                    .add_comment(oid, comment_type, &message, root, root)
-                    .await
-                {
-                    // Reload comments to show new comment
-                    if let Page::VideoDetail(page) = &mut self.current_page {
-                        page.load_data(&client).await;
-                    } else if let Page::DynamicDetail(page) = &mut self.current_page {
-                        page.load_data(&client).await;
-                    }
-                }
-            }
-            AppAction::SaveKeybindings(new_keybindings) => {
-                self.keybindings = (*new_keybindings).clone();
-                self.config.keybindings = *new_keybindings;
-                let _ = crate::storage::save_config(&self.config);
-            }
-            AppAction::SwitchToLive => {
-                self.sidebar.select(NavItem::Live);
-                self.current_page = Page::Live(LivePage::new());
-                self.init_current_page().await;
-            }
-            AppAction::OpenLiveDetail(room_id) => {
-                self.save_previous_page();
-                let mut detail_page = LiveDetailPage::new(room_id);
-                let client = &self.api_client;
-                detail_page.load_room_info(client).await;
-                // Connect WebSocket for real-time messages
-                let uid = self
-                    .credentials
-                    .as_ref()
-                    .and_then(|c| c.dede_user_id.parse::<i64>().ok())
-                    .unwrap_or(0);
-                detail_page.connect_ws(client, uid).await;
-                self.current_page = Page::LiveDetail(Box::new(detail_page));
-            }
-            AppAction::RefreshLive => {
-                if let Page::Live(page) = &mut self.current_page {
-                    let client = &self.api_client;
-                    page.refresh(client).await;
+                PlayerEvent::LiveFinished { .. } => {}
+                PlayerEvent::Failed { error, .. } => {
+                    self.toast = Some(Toast::error(tf("播放失败: {}", &[&error])));
                 }
             }
-            AppAction::LoadMoreLive => {
-                if let Page::Live(page) = &mut self.current_page {
-                    let client = &self.api_client;
-                    page.load_more(client).await;
-                }
-            }
-            AppAction::PlayLive { room_id, title: _ } => {
-                let _ = crate::player::play_live(room_id).await;
-            }
-            AppAction::None => {}
         }
-    }
 
-    async fn switch_to_nav_page(&mut self) {
-        // First, cache home page if we're leaving it
-        if matches!(self.current_page, Page::Home(_)) && self.sidebar.selected != NavItem::Home {
-            if let Page::Home(home_page) =
-                std::mem::replace(&mut self.current_page, Page::Home(HomePage::new()))
-            {
-                self.cached_home = Some(home_page);
-            }
-        }
-
-        match self.sidebar.selected {
-            NavItem::Home => {
-                if !matches!(self.current_page, Page::Home(_)) {
-                    // Use cached home page if available
-                    if let Some(cached) = self.cached_home.take() {
-                        self.current_page = Page::Home(cached);
-                    } else {
-                        self.current_page = Page::Home(HomePage::new());
-                        self.init_current_page().await;
-                    }
-                }
-            }
-            NavItem::Search => {
-                if !matches!(self.current_page, Page::Search(_)) {
-                    self.current_page = Page::Search(SearchPage::new());
-                    self.init_current_page().await;
-                }
-            }
-            NavItem::Dynamic => {
-                if !matches!(self.current_page, Page::Dynamic(_)) {
-                    self.current_page = Page::Dynamic(DynamicPage::new());
-                    self.init_current_page().await;
-                }
-            }
-            NavItem::History => {
-                if !matches!(self.current_page, Page::History(_)) {
-                    self.current_page = Page::History(HistoryPage::new());
-                    self.init_current_page().await;
-                }
-            }
-            NavItem::Settings => {
-                if !matches!(self.current_page, Page::Settings(_)) {
-                    let page = SettingsPage::new(self.keybindings.clone(), self.theme_variant);
-                    self.current_page = Page::Settings(Box::new(page));
-                }
-            }
-            NavItem::Live => {
-                if !matches!(self.current_page, Page::Live(_)) {
-                    self.current_page = Page::Live(LivePage::new());
-                    self.init_current_page().await;
-                }
-            }
+        // Leave bandwidth and CPU to mpv or the foreground app: no new cover downloads while throttled
+        let ctx = PageContext {
+            client: &self.api_client,
+            mid: self.user_mid(),
+            throttled: self.is_throttled(),
+            quiet: self.in_quiet_hours(),
+        };
+        if let Some(action) = self.current_page.tick(ctx).await {
+            self.handle_action(action).await;
         }
     }
 
-    async fn init_current_page(&mut self) {
-        match &mut self.current_page {
-            Page::Login(page) => {
-                let client = self.api_client.clone();
-                page.load_qrcode(&client).await;
-            }
-            Page::Home(page) => {
-                let client = self.api_client.clone();
-                page.load_recommendations(&client).await;
-            }
-            Page::Search(page) => {
-                let client = self.api_client.clone();
-                page.start_hotword_loading();
+    /// UID of the logged-in user
+    fn user_mid(&self) -> Option<i64> {
+        self.credentials
+            .as_ref()
+            .and_then(|c| c.dede_user_id.parse::<i64>().ok())
+    }
 
-                match client.get_hot_search().await {
-                    Ok(list) => page.set_hotwords(list),
-                    Err(e) => page.set_hotword_error(format!("加载热搜失败: {}", e)),
-                }
-            }
-            Page::Dynamic(page) => {
-                let client = self.api_client.clone();
-
-                // First load portal to get frequently watched UPs
-                page.loading_up_list = true;
-                match client.get_dynamic_portal().await {
-                    Ok(portal) => {
-                        if let Some(up_list) = portal.up_list {
-                            page.set_up_list(up_list);
-                        }
-                    }
-                    Err(_) => {
-                        page.loading_up_list = false;
-                    }
-                }
+    /// Whether the current page is capturing text (search box, comment input, floor number, key editing)
+    fn is_text_input_active(&self) -> bool {
+        self.current_page.is_text_input_active()
+    }
 
-                // Then load dynamic feed
-                let feed_type = page.current_tab.get_feed_type();
-                let host_mid = page.get_selected_up_mid();
-                match client.get_dynamic_feed(None, feed_type, host_mid).await {
-                    Ok(data) => {
-                        let items = data.items.unwrap_or_default();
-                        let offset = data.offset;
-                        let has_more = data.has_more.unwrap_or(false);
-                        page.set_feed(items, offset, has_more);
-                    }
-                    Err(e) => {
-                        page.set_error(format!("加载动态失败: {}", e));
-                    }
-                }
-            }
-            Page::VideoDetail(_) => {
-                // VideoDetail is initialized when created
-            }
-            Page::DynamicDetail(_) => {
-                // DynamicDetail is initialized when created
-            }
-            Page::History(page) => {
-                let client = self.api_client.clone();
-                page.load_history(&client).await;
-            }
-            Page::Live(page) => {
-                let client = self.api_client.clone();
-                page.load_recommendations(&client).await;
-            }
-            Page::LiveDetail(page) => {
-                let client = self.api_client.clone();
-                page.load_room_info(&client).await;
-            }
-            Page::Settings(_) => {
-                // Settings doesn't need async initialization
-            }
+    /// How many times to apply a key press: held up/down keys move several rows per event
+    fn key_steps(&mut self, key: KeyCode) -> usize {
+        let scrolling = (self.keybindings.matches_down(key) || self.keybindings.matches_up(key))
+            && !self.is_text_input_active();
+        if scrolling {
+            self.key_repeat.steps(key, Instant::now())
+        } else {
+            self.key_repeat.reset();
+            1
         }
     }
 
-    async fn tick(&mut self) {
-        match &mut self.current_page {
-            Page::Login(page) => {
-                let client = &self.api_client;
-                if let Some(action) = page.tick(client).await {
-                    self.handle_action(action).await;
-                }
-            }
-            Page::Home(page) => {
-                // Non-blocking: poll completed downloads and start new ones
-                page.poll_cover_results();
-                page.start_cover_downloads();
-            }
-            Page::Search(page) => {
-                page.poll_cover_results();
-                page.start_cover_downloads();
-            }
-            Page::Dynamic(page) => {
-                page.poll_cover_results();
-                page.start_cover_downloads();
-            }
-            Page::VideoDetail(page) => {
-                page.poll_cover_results();
-                page.start_cover_downloads();
-            }
-            Page::History(page) => {
-                page.poll_cover_results();
-                page.start_cover_downloads();
-            }
-            _ => {}
-        }
+    /// Inside the do-not-disturb hours: no desktop notifications, bell, alert toasts or
+    /// highlighted unread count, browsing works as usual
+    fn in_quiet_hours(&self) -> bool {
+        self.config
+            .quiet_hours
+            .as_ref()
+            .is_some_and(|quiet| quiet.is_now())
+    }
+
+    /// Whether mpv is running or the terminal is in the background and the TUI should back off
+    fn is_throttled(&self) -> bool {
+        (self.config.throttle_during_playback && !self.player_sessions.is_empty())
+            || self.is_hidden()
     }
 
-    fn save_theme_to_config(&mut self) {
-        self.config.theme = self.theme_variant.to_string();
-        if crate::storage::save_config(&self.config).is_err() {}
+    /// Whether the terminal lost focus and background work should pause until it is back
+    fn is_hidden(&self) -> bool {
+        self.config.throttle_when_unfocused && !self.focused
     }
 }
 
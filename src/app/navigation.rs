@@ -0,0 +1,274 @@
+//! Page switching and back navigation actions
+
+use super::{ActionResult, App, AppAction};
+use crate::api::comment::is_comment_gone;
+use crate::api::error::ApiError;
+use crate::api::link::{self, BiliLink};
+use crate::export;
+use crate::i18n::{t, tf};
+use crate::ui::{
+    Component, DynamicDetailPage, DynamicPage, FavoritesPage, HistoryPage, HomePage, LivePage,
+    LoginPage, NavItem, OpenDialog, SearchPage, SettingsPage, SubscriptionsPage, Toast,
+    UserSpacePage, VideoDetailPage, WatchLaterPage, WatchStatsPage,
+};
+
+impl App {
+    /// Switch pages, open detail pages and go back
+    pub(super) async fn handle_navigation_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::SwitchToHome => {
+                self.sidebar.select(NavItem::Home);
+                return self.show_home();
+            }
+            AppAction::RefreshHome => {
+                self.sidebar.select(NavItem::Home);
+                // Clear cache and create fresh home page
+                self.cached_home = None;
+                return ActionResult::SwitchPage(Box::new(HomePage::new()));
+            }
+            AppAction::OpenInBrowser(url) => {
+                if let Err(e) = crate::player::open_url(&url) {
+                    return ActionResult::Toast(Toast::error(tf("打开浏览器失败: {}", &[&e])));
+                }
+            }
+            AppAction::CopyRssLink(mid) => {
+                let url = export::rsshub_user_url(&self.config.rsshub_base, mid);
+                return ActionResult::Toast(match crate::player::copy_to_clipboard(&url) {
+                    Ok(()) => Toast::info(tf("已复制 RSS 链接: {}", &[&url])),
+                    Err(e) => Toast::error(tf("复制失败: {}", &[&e])),
+                });
+            }
+            AppAction::ShowError(message) => return ActionResult::Toast(Toast::error(message)),
+            AppAction::SwitchToLogin => {
+                return ActionResult::SwitchPage(Box::new(LoginPage::new()));
+            }
+            AppAction::SwitchToHistory => {
+                self.sidebar.select(NavItem::History);
+                return ActionResult::SwitchPage(Box::new(HistoryPage::new()));
+            }
+            AppAction::SwitchToSettings => {
+                self.sidebar.select(NavItem::Settings);
+                let page = SettingsPage::new(self.keybindings.clone(), self.theme_variant)
+                    .with_show_invalid_videos(self.config.show_invalid_videos)
+                    .with_credentials(self.credentials.as_ref());
+                return ActionResult::SwitchPage(Box::new(page));
+            }
+            AppAction::SwitchToLive => {
+                self.sidebar.select(NavItem::Live);
+                return ActionResult::SwitchPage(Box::new(LivePage::new()));
+            }
+            AppAction::NavNext => {
+                // Don't navigate if on video detail page
+                if !self.current_page.is::<VideoDetailPage>() {
+                    self.sidebar.next();
+                    self.switch_to_nav_page().await;
+                }
+            }
+            AppAction::NavPrev => {
+                if !self.current_page.is::<VideoDetailPage>() {
+                    self.sidebar.prev();
+                    self.switch_to_nav_page().await;
+                }
+            }
+            AppAction::OpenVideoDetail(bvid, aid) => {
+                self.open_video_detail(bvid, aid).await;
+            }
+            AppAction::CloseHelp => {
+                self.help = None;
+            }
+            AppAction::CloseOpenDialog => {
+                self.open_dialog = None;
+            }
+            AppAction::OpenLink(input) => {
+                self.open_link(input).await;
+            }
+            AppAction::OpenDynamicDetail(dynamic_id) => {
+                let mut detail_page = DynamicDetailPage::new(dynamic_id);
+                detail_page.load_data(&self.api_client).await;
+                self.push_page(Box::new(detail_page));
+            }
+            AppAction::BackToList | AppAction::CloseUserSpace => {
+                if !self.pop_page() {
+                    self.sidebar.select(NavItem::Home);
+                    return self.show_home();
+                }
+            }
+            AppAction::OpenUserSpace(mid) => {
+                return ActionResult::OpenPage(Box::new(UserSpacePage::new(mid)));
+            }
+            AppAction::SearchUserSpace { mid, keyword } => {
+                match self.current_page.downcast_mut::<UserSpacePage>() {
+                    Some(page) if page.mid == mid => {
+                        page.keyword = keyword;
+                        page.load_videos(&self.api_client).await;
+                    }
+                    _ => {
+                        let mut space = UserSpacePage::new(mid);
+                        space.keyword = keyword;
+                        return ActionResult::OpenPage(Box::new(space));
+                    }
+                }
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    /// Show the cached home page, or a new one when there is none
+    fn show_home(&mut self) -> ActionResult {
+        match self.cached_home.take() {
+            Some(home) => {
+                self.replace_keeping_feeds(home);
+                self.apply_watched();
+                ActionResult::Done
+            }
+            None => ActionResult::SwitchPage(Box::new(HomePage::new())),
+        }
+    }
+
+    /// Replace all open pages with `page`, keeping the home and dynamic feeds among them
+    /// so switching back does not load them again
+    pub(super) fn replace_keeping_feeds(&mut self, page: Box<dyn Component>) {
+        let old = std::mem::replace(&mut self.current_page, page);
+        for page in self.page_stack.drain(..).chain(std::iter::once(old)) {
+            if page.is::<HomePage>() {
+                self.cached_home = Some(page);
+            } else if page.is::<DynamicPage>() {
+                self.cached_dynamic = Some(page);
+            }
+        }
+    }
+
+    /// Open `page` from the sidebar and load it
+    async fn open_nav_page(&mut self, page: Box<dyn Component>) {
+        self.replace_keeping_feeds(page);
+        self.init_current_page().await;
+    }
+
+    pub(super) async fn switch_to_nav_page(&mut self) {
+        match self.sidebar.selected {
+            NavItem::Home => {
+                if !self.current_page.is::<HomePage>() {
+                    if let ActionResult::SwitchPage(page) = self.show_home() {
+                        self.open_nav_page(page).await;
+                    }
+                }
+            }
+            NavItem::Search => {
+                if !self.current_page.is::<SearchPage>() {
+                    self.open_nav_page(Box::new(SearchPage::new())).await;
+                }
+            }
+            NavItem::Dynamic => {
+                if !self.current_page.is::<DynamicPage>() {
+                    self.restore_dynamic_page().await;
+                }
+            }
+            NavItem::Subscriptions => {
+                if !self.current_page.is::<SubscriptionsPage>() {
+                    self.open_nav_page(Box::new(SubscriptionsPage::new())).await;
+                }
+            }
+            NavItem::History => {
+                if !self.current_page.is::<HistoryPage>() {
+                    self.open_nav_page(Box::new(HistoryPage::new())).await;
+                }
+            }
+            NavItem::Favorites => {
+                if !self.current_page.is::<FavoritesPage>() {
+                    self.open_nav_page(Box::<FavoritesPage>::default()).await;
+                }
+            }
+            NavItem::WatchLater => {
+                if !self.current_page.is::<WatchLaterPage>() {
+                    self.open_nav_page(Box::new(WatchLaterPage::new())).await;
+                }
+            }
+            NavItem::WatchStats => {
+                if !self.current_page.is::<WatchStatsPage>() {
+                    self.open_nav_page(Box::new(WatchStatsPage::new())).await;
+                }
+            }
+            NavItem::Settings => {
+                if !self.current_page.is::<SettingsPage>() {
+                    let page = SettingsPage::new(self.keybindings.clone(), self.theme_variant)
+                        .with_show_invalid_videos(self.config.show_invalid_videos)
+                        .with_credentials(self.credentials.as_ref());
+                    self.open_nav_page(Box::new(page)).await;
+                }
+            }
+            NavItem::Live => {
+                if !self.current_page.is::<LivePage>() {
+                    self.open_nav_page(Box::new(LivePage::new())).await;
+                }
+            }
+        }
+    }
+
+    pub(super) async fn open_video_detail(&mut self, bvid: String, aid: i64) {
+        let mut detail_page = VideoDetailPage::new(bvid, aid);
+        detail_page.set_blocklist(self.blocklist.clone());
+        detail_page.load_data(&self.api_client);
+        self.push_page(Box::new(detail_page));
+    }
+
+    /// Resolve a link from the open-link popup or a comment and navigate to it.
+    /// Failures are shown in the popup, opening it with the input if needed.
+    pub(super) async fn open_link(&mut self, input: String) {
+        let resolved = match link::find_short_link(&input) {
+            Some(short) => match self.api_client.resolve_short_link(short).await {
+                Ok(url) => url,
+                Err(e) => {
+                    self.show_open_error(input.clone(), tf("短链解析失败: {}", &[&e]));
+                    return;
+                }
+            },
+            None => input.clone(),
+        };
+
+        match link::parse_link(&resolved) {
+            Some(BiliLink::Video { bvid, aid }) => {
+                self.open_dialog = None;
+                self.open_video_detail(bvid, aid).await;
+                if let Some(rpid) = link::comment_anchor(&resolved) {
+                    self.locate_comment(aid, rpid).await;
+                }
+            }
+            Some(BiliLink::Live { room_id }) => {
+                self.open_dialog = None;
+                self.open_live_detail(room_id).await;
+            }
+            None => {
+                self.show_open_error(input, t("无法识别的 BV号 / av号 / 链接").to_string());
+            }
+        }
+    }
+
+    /// Scroll the just opened detail page to a comment, e.g. from a reply notification link
+    async fn locate_comment(&mut self, aid: i64, rpid: i64) {
+        match self.api_client.get_comment_detail(aid, 1, rpid).await {
+            Ok(comment) => {
+                if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                    page.locate_comment(comment);
+                }
+            }
+            Err(e) => {
+                let gone = ApiError::of(&e)
+                    .and_then(ApiError::code)
+                    .is_some_and(is_comment_gone);
+                self.toast = Some(if gone {
+                    Toast::info(t("评论已被删除"))
+                } else {
+                    Toast::error(tf("定位评论失败: {}", &[&e]))
+                });
+            }
+        }
+    }
+
+    pub(super) fn show_open_error(&mut self, input: String, msg: String) {
+        let dialog = self
+            .open_dialog
+            .get_or_insert(OpenDialog { input, error: None });
+        dialog.set_error(msg);
+    }
+}
@@ -0,0 +1,117 @@
+//! Optimistic updates: the page changes as soon as the key is pressed, the request runs in
+//! the background, and a failure puts the page back and shows why in a toast
+
+use super::App;
+use crate::api::comment::CommentVote;
+use crate::api::video::VideoRelation;
+use crate::api::watch_later::WatchLaterItem;
+use crate::i18n::tf;
+use crate::ui::{FavoritesPage, Toast, VideoCard, VideoDetailPage, WatchLaterPage};
+use std::future::Future;
+use tokio::sync::mpsc;
+
+/// How to undo a change that was shown before its request finished
+pub(super) enum Rollback {
+    /// Set a comment's like / dislike state back
+    CommentVote { rpid: i64, vote: CommentVote },
+    /// Set the like / coin / favorite state of a video back
+    VideoRelation { aid: i64, relation: VideoRelation },
+    /// Set whether the user follows an UP back
+    Follow { mid: i64, following: bool },
+    /// Put back watch later videos that could not be removed, with their old positions
+    WatchLater(Vec<(usize, WatchLaterItem)>),
+    /// Put back videos that could not be removed from a favorites folder
+    Favorites {
+        media_id: i64,
+        cards: Vec<(usize, VideoCard)>,
+    },
+    /// Nothing on the page to undo, only report the failure
+    Report,
+}
+
+/// A request that failed after its change was shown
+struct Failure {
+    what: &'static str,
+    rollback: Rollback,
+    error: String,
+}
+
+/// Failures of requests running in the background
+pub(super) struct Optimistic {
+    tx: mpsc::UnboundedSender<Failure>,
+    rx: mpsc::UnboundedReceiver<Failure>,
+}
+
+impl Default for Optimistic {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self { tx, rx }
+    }
+}
+
+impl App {
+    /// Send the request for a change the page already shows; `what` names it in the toast
+    pub(super) fn spawn_optimistic<F>(&self, what: &'static str, rollback: Rollback, request: F)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.spawn_optimistic_with(
+            what,
+            async move { request.await.map_err(|e| (rollback, e)) },
+        );
+    }
+
+    /// Like [`App::spawn_optimistic`], for requests that work out what to undo themselves,
+    /// e.g. batches where only some items fail
+    pub(super) fn spawn_optimistic_with<F>(&self, what: &'static str, request: F)
+    where
+        F: Future<Output = Result<(), (Rollback, anyhow::Error)>> + Send + 'static,
+    {
+        let tx = self.optimistic.tx.clone();
+        tokio::spawn(async move {
+            if let Err((rollback, e)) = request.await {
+                let _ = tx.send(Failure {
+                    what,
+                    rollback,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+
+    /// Undo the changes whose request failed, on the page if it is still open
+    pub(super) fn poll_optimistic(&mut self) {
+        while let Ok(failure) = self.optimistic.rx.try_recv() {
+            match failure.rollback {
+                Rollback::CommentVote { rpid, vote } => {
+                    if let Some(comment_votes) = self.comment_votes_mut() {
+                        comment_votes.insert(rpid, vote);
+                    }
+                }
+                Rollback::VideoRelation { aid, relation } => {
+                    if let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                        if page.aid == aid {
+                            page.relation = relation;
+                        }
+                    }
+                }
+                Rollback::Follow { mid, following } => self.set_follow_state(mid, following),
+                Rollback::WatchLater(items) => {
+                    if let Some(page) = self.current_page.downcast_mut::<WatchLaterPage>() {
+                        page.restore_items(items);
+                    }
+                }
+                Rollback::Favorites { media_id, cards } => {
+                    if let Some(page) = self.current_page.downcast_mut::<FavoritesPage>() {
+                        page.restore_removed(media_id, cards);
+                    }
+                }
+                Rollback::Report => {}
+            }
+            self.toast = Some(Toast::error(tf(
+                "{}失败: {}",
+                &[&failure.what, &failure.error],
+            )));
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! Playback actions: mpv sessions, play queue, interactive videos and the private FM
+
+use super::optimistic::Rollback;
+use super::{fm, ActionResult, App, AppAction, InteractiveSession};
+use crate::i18n::{t, tf};
+use crate::player::{PlayRequest, Playing};
+use crate::storage::watch_log::WatchEntry;
+use crate::ui::{ActionMenu, BranchMenu, QualityMenu, Toast, VideoAction, VideoDetailPage};
+use tokio::sync::oneshot;
+
+impl App {
+    /// Start playback, manage the play queue and interactive story choices
+    pub(super) async fn handle_playback_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::PlayVideo {
+                bvid,
+                aid,
+                cid,
+                duration,
+            } => {
+                let interactive = self
+                    .current_page
+                    .downcast_ref::<VideoDetailPage>()
+                    .is_some_and(|page| {
+                        page.bvid == bvid
+                            && page.video_info.as_ref().is_some_and(|i| i.is_interactive())
+                    });
+                if interactive {
+                    self.start_interactive(&bvid, aid, cid).await;
+                }
+                self.start_playback(PlayRequest {
+                    bvid,
+                    aid,
+                    cid,
+                    duration,
+                    page_num: None,
+                    audio_only: false,
+                    direct_url: None,
+                    start: None,
+                    quality: None,
+                })
+                .await;
+            }
+            AppAction::PlayVideoAt {
+                bvid,
+                aid,
+                cid,
+                duration,
+                page_num,
+                start,
+            } => {
+                self.start_playback(PlayRequest {
+                    bvid,
+                    aid,
+                    cid,
+                    duration,
+                    page_num,
+                    audio_only: false,
+                    direct_url: None,
+                    start: Some(start),
+                    quality: None,
+                })
+                .await;
+            }
+            AppAction::ChooseBranch(choice) => {
+                self.branch_menu = None;
+                self.play_branch(choice).await;
+            }
+            AppAction::CloseBranchMenu => {
+                self.branch_menu = None;
+                self.interactive = None;
+            }
+            AppAction::OpenQualityMenu { bvid, cid } => {
+                // Ask for the best quality so every accepted one is listed
+                match self.api_client.get_playurl(&bvid, cid, 127).await {
+                    Ok(info) => {
+                        self.quality_menu = Some(QualityMenu::new(
+                            info.qualities(),
+                            self.config.video_quality,
+                        ));
+                    }
+                    Err(e) => {
+                        return ActionResult::Toast(Toast::error(tf("获取画质失败: {}", &[&e])))
+                    }
+                }
+            }
+            AppAction::ChooseQuality(quality) => {
+                let name = self
+                    .quality_menu
+                    .take()
+                    .map(|menu| menu.name(quality).to_string())
+                    .unwrap_or_default();
+                self.config.video_quality = quality;
+                let _ = crate::storage::save_config(&self.config);
+                return ActionResult::Toast(Toast::info(tf(
+                    "画质已设为 {}，下次播放生效",
+                    &[&name],
+                )));
+            }
+            AppAction::CloseQualityMenu => {
+                self.quality_menu = None;
+            }
+            AppAction::PlayVideoWithPages {
+                bvid,
+                aid,
+                pages,
+                current_index,
+            } => {
+                // Play only the selected episode
+                if current_index < pages.len() {
+                    let page = &pages[current_index];
+                    self.start_playback(PlayRequest {
+                        bvid: bvid.clone(),
+                        aid,
+                        cid: page.cid,
+                        duration: page.duration,
+                        page_num: Some(page.page),
+                        audio_only: false,
+                        direct_url: None,
+                        start: None,
+                        quality: None,
+                    })
+                    .await;
+                    // Update current page index in video detail page
+                    if let Some(detail_page) = self.current_page.downcast_mut::<VideoDetailPage>() {
+                        if detail_page.bvid == bvid {
+                            detail_page.current_page_index = current_index;
+                        }
+                    }
+                }
+            }
+            AppAction::OpenActionMenu { bvid, aid, title } => {
+                self.action_menu = Some(ActionMenu::new(bvid, aid, title));
+            }
+            AppAction::CloseActionMenu => {
+                self.action_menu = None;
+            }
+            AppAction::RunVideoAction { bvid, aid, action } => {
+                self.action_menu = None;
+                self.run_video_action(bvid, aid, action).await;
+            }
+            AppAction::EnqueueVideos(bvids) => {
+                for bvid in bvids {
+                    self.run_video_action(bvid, 0, VideoAction::Enqueue).await;
+                }
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    /// Turn the private FM on or off; it does not take over a video that is already playing
+    pub(super) fn toggle_fm(&mut self) {
+        if let Some(fm) = self.fm.take() {
+            self.fm_rx = None;
+            if let Some(track) = fm.current {
+                self.player_sessions.stop(&Playing::Video(track.bvid));
+            }
+            self.toast = Some(Toast::info(t("私人 FM 已关闭")));
+            return;
+        }
+        if self.player_sessions.video_count() > 0 {
+            self.toast = Some(Toast::info(t("请先关闭正在播放的视频再开启私人 FM")));
+            return;
+        }
+        self.fm = Some(fm::FmSession::default());
+        self.request_fm_tracks();
+    }
+
+    /// Fetch more tracks in the background unless a fetch is already running
+    pub(super) fn request_fm_tracks(&mut self) {
+        if self.fm_rx.is_some() {
+            return;
+        }
+        let (tx, rx) = oneshot::channel();
+        let client = self.api_client.clone();
+        let mid = self.user_mid();
+        tokio::spawn(async move {
+            let _ = tx.send(fm::fetch_tracks(&client, mid).await);
+        });
+        self.fm_rx = Some(rx);
+    }
+
+    /// Queue fetched tracks, starting playback if the radio was waiting for them
+    pub(super) async fn poll_fm_tracks(&mut self) {
+        let Some(rx) = &mut self.fm_rx else {
+            return;
+        };
+        let tracks = match rx.try_recv() {
+            Ok(tracks) => tracks,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => Vec::new(),
+        };
+        self.fm_rx = None;
+        let Some(fm) = &mut self.fm else {
+            return;
+        };
+        let waiting = fm.current.is_none();
+        if tracks.is_empty() && waiting {
+            self.fm = None;
+            self.toast = Some(Toast::error(t("私人 FM 没有找到可播放的曲目")));
+            return;
+        }
+        fm.refill(tracks);
+        if waiting {
+            self.play_next_fm().await;
+        }
+    }
+
+    /// Play the next FM track audio-only, skipping tracks that fail to resolve
+    pub(super) async fn play_next_fm(&mut self) {
+        loop {
+            let Some(fm) = &mut self.fm else {
+                return;
+            };
+            let next = fm.advance();
+            if fm.needs_refill() {
+                self.request_fm_tracks();
+            }
+            // An empty queue waits for the fetch, poll_fm_tracks starts the next track
+            let Some(track) = next else {
+                return;
+            };
+            if let Ok(request) = self.resolve_play_request(&track.bvid, true).await {
+                self.start_playback(request).await;
+                return;
+            }
+        }
+    }
+
+    /// Spawn mpv for a video and track it until it exits. Only one video plays at a time,
+    /// so heartbeats of several instances don't overwrite each other's progress
+    pub(super) async fn start_playback(&mut self, mut request: PlayRequest) {
+        let playing = Playing::Video(request.bvid.clone());
+        if self.player_sessions.contains(&playing) {
+            if request.start.is_none() {
+                self.toast = Some(Toast::info(t("该视频已在播放")));
+                return;
+            }
+            // Jumping to a time point restarts the running player there
+            self.player_sessions.stop(&playing);
+        } else if self.player_sessions.video_count() > 0 {
+            self.toast = Some(Toast::info(t("已有视频在播放，可在操作菜单中加入播放队列")));
+            return;
+        }
+
+        if self.config.resume_playback && request.start.is_none() && request.direct_url.is_none() {
+            let saved = crate::storage::positions::load_positions()
+                .ok()
+                .and_then(|positions| positions.get(&request.bvid, request.cid));
+            if let Some(position) = saved {
+                request.start = Some(position);
+                let clock = format!("{:02}:{:02}", position / 60, position % 60);
+                self.toast = Some(Toast::info(tf("从 {} 继续播放", &[&clock])));
+            }
+        }
+        request.quality = request.quality.or(self.config.video_quality);
+        let api_client = self.api_client.clone();
+        // 互动视频的后续节点属于同一个视频，不重复记录
+        let record = (self.config.record_watch_stats && request.direct_url.is_none())
+            .then(|| (request.bvid.clone(), request.duration));
+        if crate::player::play_video(
+            api_client,
+            request,
+            self.credentials.as_ref(),
+            &self.player_sessions,
+            self.player_tx.clone(),
+        )
+        .await
+        .is_ok()
+        {
+            if let Some((bvid, duration)) = record {
+                self.record_watch(bvid, duration);
+            }
+        }
+    }
+
+    /// Append a playback to the local watch log in the background
+    pub(super) fn record_watch(&self, bvid: String, duration: i64) {
+        let client = self.api_client.clone();
+        tokio::spawn(async move {
+            let Ok(info) = client.get_video_info(&bvid).await else {
+                return;
+            };
+            let _ = crate::storage::watch_log::append_watch_entry(WatchEntry {
+                bvid,
+                title: info.title,
+                author: info.owner.name,
+                author_mid: info.owner.mid,
+                tname: info.tname,
+                duration: if duration > 0 {
+                    duration
+                } else {
+                    info.duration.unwrap_or(0)
+                },
+                played_at: chrono::Local::now().timestamp(),
+            });
+        });
+    }
+
+    /// Remember an interactive video so its choices are offered when the first node ends
+    pub(super) async fn start_interactive(&mut self, bvid: &str, aid: i64, cid: i64) {
+        self.interactive = match self
+            .api_client
+            .get_interactive_graph_version(aid, cid)
+            .await
+        {
+            Ok(graph_version) => Some(InteractiveSession {
+                bvid: bvid.to_string(),
+                aid,
+                graph_version,
+                edge_id: None,
+            }),
+            Err(_) => None,
+        };
+    }
+
+    /// Open the choice popup for the node that just finished; an ending closes the session
+    pub(super) async fn show_story_choices(&mut self) {
+        let Some(session) = &self.interactive else {
+            return;
+        };
+        let edge = self
+            .api_client
+            .get_interactive_edge(session.aid, session.graph_version, session.edge_id)
+            .await;
+
+        let choices = edge.as_ref().map(|e| e.choices()).unwrap_or_default();
+        match edge {
+            Ok(edge) if !choices.is_empty() => {
+                self.branch_menu = Some(BranchMenu::new(edge.title, choices));
+            }
+            _ => self.interactive = None,
+        }
+    }
+
+    /// Play the node a story choice leads to
+    pub(super) async fn play_branch(&mut self, choice: crate::api::interactive::StoryChoice) {
+        let Some(session) = &mut self.interactive else {
+            return;
+        };
+        session.edge_id = Some(choice.id);
+        let (bvid, aid) = (session.bvid.clone(), session.aid);
+
+        match self.api_client.get_play_url(&bvid, choice.cid).await {
+            Ok(url) => {
+                self.start_playback(PlayRequest {
+                    bvid,
+                    aid,
+                    cid: choice.cid,
+                    duration: 0,
+                    page_num: None,
+                    audio_only: false,
+                    direct_url: Some(url),
+                    start: None,
+                    quality: None,
+                })
+                .await;
+            }
+            Err(_) => self.interactive = None,
+        }
+    }
+
+    /// Resolve cid and duration of a video's first page for playback
+    pub(super) async fn resolve_play_request(
+        &self,
+        bvid: &str,
+        audio_only: bool,
+    ) -> anyhow::Result<PlayRequest> {
+        let info = self.api_client.get_video_info(bvid).await?;
+        Ok(PlayRequest {
+            bvid: info.bvid,
+            aid: info.aid,
+            cid: info.cid,
+            duration: info.duration.unwrap_or(0),
+            page_num: None,
+            audio_only,
+            direct_url: None,
+            start: None,
+            quality: None,
+        })
+    }
+
+    pub(super) async fn run_video_action(&mut self, bvid: String, aid: i64, action: VideoAction) {
+        match action {
+            VideoAction::Play | VideoAction::AudioOnly => {
+                let audio_only = action == VideoAction::AudioOnly;
+                match self.resolve_play_request(&bvid, audio_only).await {
+                    Ok(request) => self.start_playback(request).await,
+                    Err(e) => {
+                        self.toast = Some(Toast::error(tf("加载视频信息失败: {}", &[&e])));
+                    }
+                }
+            }
+            VideoAction::Enqueue => match self.resolve_play_request(&bvid, false).await {
+                Ok(request) if self.player_sessions.video_count() == 0 => {
+                    self.start_playback(request).await;
+                }
+                Ok(request) => {
+                    self.play_queue.push_back(request);
+                    self.toast = Some(Toast::info(t("已加入播放队列")));
+                }
+                Err(e) => {
+                    self.toast = Some(Toast::error(tf("加载视频信息失败: {}", &[&e])));
+                }
+            },
+            VideoAction::Download => {
+                self.toast = Some(
+                    match crate::player::download_video(&bvid, self.credentials.as_ref()).await {
+                        Ok(()) => Toast::info(t("已开始下载")),
+                        Err(e) => Toast::error(tf("下载失败: {}", &[&e])),
+                    },
+                );
+            }
+            VideoAction::WatchLater => {
+                self.toast = Some(Toast::info(t("已加入稍后再看")));
+                let client = self.api_client.clone();
+                self.spawn_optimistic(t("加入稍后再看"), Rollback::Report, async move {
+                    // Some list pages don't carry aid, look it up from the video info
+                    let aid = if aid > 0 {
+                        aid
+                    } else {
+                        client.get_video_info(&bvid).await?.aid
+                    };
+                    client.add_to_watch_later(aid).await
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Account and preference actions
+
+use super::{ActionResult, App, AppAction};
+use crate::i18n::{t, tf};
+use crate::ui::{LoginPage, Theme, Toast};
+
+impl App {
+    /// Login state, theme, language and keybindings
+    pub(super) async fn handle_settings_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::Quit => self.should_quit = true,
+            AppAction::LoginSuccess(creds) => {
+                // Save credentials
+                let _ = crate::storage::save_credentials(&creds);
+                self.sidebar.credentials_too_open = crate::storage::credentials_too_open();
+                self.credentials = Some(creds.clone());
+                self.status_refreshed_at = None;
+                // Update API client with new cookies
+                {
+                    let client = self.api_client.clone();
+                    client.set_credentials(&creds);
+                }
+                // Switch to the configured start page
+                let page = Self::list_page(self.config.start_page.into(), &mut self.sidebar);
+                return ActionResult::SwitchPage(page);
+            }
+            AppAction::Logout => {
+                let _ = crate::storage::delete_credentials();
+                self.credentials = None;
+                self.cached_home = None;
+                self.cached_dynamic = None;
+                self.status_bar.clear();
+                self.status_rx = None;
+                self.status_refreshed_at = None;
+                return ActionResult::SwitchPage(Box::new(LoginPage::new()));
+            }
+            AppAction::CopyCookie => {
+                if let Some(creds) = &self.credentials {
+                    self.toast = Some(
+                        match crate::player::copy_to_clipboard(&creds.cookie_string()) {
+                            Ok(()) => Toast::info(t("已复制 Cookie，请勿分享给他人")),
+                            Err(e) => Toast::error(tf("复制失败: {}", &[&e])),
+                        },
+                    );
+                }
+            }
+            AppAction::ExportCookies => {
+                if let Some(creds) = &self.credentials {
+                    self.toast = Some(match crate::storage::save_cookies_txt(creds) {
+                        Ok(path) => Toast::info(tf("已导出到 {}", &[&path.display()])),
+                        Err(e) => Toast::error(tf("导出失败: {}", &[&e])),
+                    });
+                }
+            }
+            AppAction::NextTheme => {
+                self.theme_variant = self.theme_variant.next();
+                self.theme = Theme::from_variant(self.theme_variant);
+                self.save_theme_to_config();
+            }
+            AppAction::SetTheme(variant) => {
+                self.theme_variant = variant;
+                self.theme = Theme::from_variant(variant);
+                self.save_theme_to_config();
+            }
+            AppAction::SetLanguage(lang) => {
+                crate::i18n::set_language(lang);
+                self.config.language = lang;
+                let _ = crate::storage::save_config(&self.config);
+            }
+            AppAction::SetShowInvalidVideos(show) => {
+                self.config.show_invalid_videos = show;
+                let _ = crate::storage::save_config(&self.config);
+            }
+            AppAction::SaveKeybindings(new_keybindings) => {
+                self.keybindings = (*new_keybindings).clone();
+                self.config.keybindings = *new_keybindings;
+                let _ = crate::storage::save_config(&self.config);
+            }
+            action => return ActionResult::Unhandled(action),
+        }
+        ActionResult::Done
+    }
+
+    pub(super) fn save_theme_to_config(&mut self) {
+        self.config.theme = self.theme_variant.to_string();
+        if crate::storage::save_config(&self.config).is_err() {}
+    }
+}
@@ -0,0 +1,183 @@
+//! Like, coin and favorite the video open in the detail page; the indicators change at
+//! once and go back when the request fails
+
+use super::optimistic::Rollback;
+use super::{ActionResult, App, AppAction};
+use crate::api::client::ApiClient;
+use crate::api::video::{TripleResult, VideoRelation};
+use crate::i18n::{t, tf};
+use crate::ui::{Toast, VideoDetailPage};
+use anyhow::anyhow;
+
+/// What to do to the open video
+enum Reaction {
+    Like,
+    Coin,
+    Favorite,
+    Triple,
+}
+
+/// Add the video to the default folder, or take it out of every folder it is in
+async fn set_favorite(
+    client: &ApiClient,
+    mid: i64,
+    aid: i64,
+    favorite: bool,
+) -> anyhow::Result<()> {
+    let folders = client.get_fav_folders_with(mid, aid).await?;
+    if favorite {
+        // The default folder always comes first
+        let folder = folders
+            .first()
+            .ok_or_else(|| anyhow!("No favorites folder"))?;
+        client.favorite_video(aid, &[folder.id], &[]).await
+    } else {
+        let ids: Vec<i64> = folders
+            .iter()
+            .filter(|f| f.fav_state == 1)
+            .map(|f| f.id)
+            .collect();
+        client.favorite_video(aid, &[], &ids).await
+    }
+}
+
+/// State after a triple that only partly went through, and what did not
+fn triple_outcome(
+    before: VideoRelation,
+    result: TripleResult,
+) -> (VideoRelation, Vec<&'static str>) {
+    let relation = VideoRelation {
+        like: before.like || result.like,
+        coin: before.coin + if result.coin { result.multiply } else { 0 },
+        favorite: before.favorite || result.fav,
+    };
+    let missed = [
+        (result.like, t("点赞")),
+        (result.coin, t("投币")),
+        (result.fav, t("收藏")),
+    ]
+    .into_iter()
+    .filter(|(done, _)| !done)
+    .map(|(_, label)| label)
+    .collect();
+    (relation, missed)
+}
+
+impl App {
+    /// Like, coin, favorite and triple the open video
+    pub(super) async fn handle_video_action(&mut self, action: AppAction) -> ActionResult {
+        match action {
+            AppAction::LikeVideo => self.react(Reaction::Like),
+            AppAction::CoinVideo => self.react(Reaction::Coin),
+            AppAction::FavoriteVideo => self.react(Reaction::Favorite),
+            AppAction::TripleVideo => self.react(Reaction::Triple),
+            _ => ActionResult::Unhandled(action),
+        }
+    }
+
+    fn react(&mut self, reaction: Reaction) -> ActionResult {
+        let mid = self.user_mid();
+        let Some(page) = self.current_page.downcast_mut::<VideoDetailPage>() else {
+            return ActionResult::Done;
+        };
+        let Some(mid) = mid else {
+            return ActionResult::Toast(Toast::error(t("请先登录")));
+        };
+        let aid = page.aid;
+        if aid == 0 {
+            return ActionResult::Toast(Toast::error(t("视频信息还在加载")));
+        }
+        let max_coins = page.video_info.as_ref().map_or(2, |info| info.max_coins());
+        let before = page.relation;
+        let rollback = Rollback::VideoRelation {
+            aid,
+            relation: before,
+        };
+        let client = self.api_client.clone();
+
+        match reaction {
+            Reaction::Like => {
+                let like = !before.like;
+                page.relation.like = like;
+                let what = if like { t("点赞") } else { t("取消点赞") };
+                self.spawn_optimistic(
+                    what,
+                    rollback,
+                    async move { client.like_video(aid, like).await },
+                );
+            }
+            Reaction::Coin => {
+                if before.coin >= max_coins {
+                    return ActionResult::Toast(Toast::info(tf("已投满 {} 个币", &[&max_coins])));
+                }
+                page.relation.coin += 1;
+                self.spawn_optimistic(t("投币"), rollback, async move {
+                    client.coin_video(aid, 1, false).await
+                });
+            }
+            Reaction::Favorite => {
+                let favorite = !before.favorite;
+                page.relation.favorite = favorite;
+                let what = if favorite {
+                    t("收藏")
+                } else {
+                    t("取消收藏")
+                };
+                self.spawn_optimistic(what, rollback, async move {
+                    set_favorite(&client, mid, aid, favorite).await
+                });
+            }
+            Reaction::Triple => {
+                page.relation = VideoRelation {
+                    like: true,
+                    coin: before.coin.max(max_coins),
+                    favorite: true,
+                };
+                self.spawn_optimistic_with(t("一键三连"), async move {
+                    match client.triple_video(aid).await {
+                        Err(e) => Err((rollback, e)),
+                        Ok(result) => {
+                            let (relation, missed) = triple_outcome(before, result);
+                            if missed.is_empty() {
+                                return Ok(());
+                            }
+                            let error = anyhow!(tf("{}未成功", &[&missed.join("/")]));
+                            Err((Rollback::VideoRelation { aid, relation }, error))
+                        }
+                    }
+                });
+            }
+        }
+        ActionResult::Done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triple_outcome() {
+        let before = VideoRelation {
+            like: true,
+            coin: 0,
+            favorite: false,
+        };
+        let result = TripleResult {
+            like: true,
+            coin: false,
+            fav: true,
+            multiply: 0,
+        };
+        let (relation, missed) = triple_outcome(before, result);
+        assert_eq!(
+            relation,
+            VideoRelation {
+                like: true,
+                coin: 0,
+                favorite: true,
+            }
+        );
+        assert_eq!(missed, vec![t("投币")]);
+    }
+}
@@ -0,0 +1,74 @@
+//! Mouse wheel speed model: a notched wheel scrolls one row per notch, while the
+//! dense event bursts of touchpads and free-spinning wheels are merged into fewer rows
+
+use std::time::{Duration, Instant};
+
+/// Events this far apart are separate wheel notches and move a full row each
+const NOTCH_GAP: Duration = Duration::from_millis(50);
+/// Least a single event counts for, so even the densest bursts still move
+const MIN_WEIGHT: f32 = 1.0 / 15.0;
+
+/// Turns wheel events into list rows based on the time between them
+#[derive(Default)]
+pub struct WheelScroll {
+    last: Option<(bool, Instant)>,
+    /// Fraction of a row scrolled but not applied yet
+    pending: f32,
+}
+
+impl WheelScroll {
+    /// Record a wheel event and return how many rows it should move (0 or 1)
+    pub fn rows(&mut self, down: bool, now: Instant) -> usize {
+        // Each event counts for the share of a notch gap since the previous one
+        let weight = match self.last {
+            Some((last_down, at)) if last_down == down => {
+                let gap = now.saturating_duration_since(at);
+                (gap.as_secs_f32() / NOTCH_GAP.as_secs_f32()).clamp(MIN_WEIGHT, 1.0)
+            }
+            // First event or a direction change starts over and responds at once
+            _ => {
+                self.pending = 0.0;
+                1.0
+            }
+        };
+        self.last = Some((down, now));
+
+        self.pending += weight;
+        if self.pending >= 1.0 {
+            self.pending -= 1.0;
+            1
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notches_scroll_and_bursts_merge() {
+        let mut wheel = WheelScroll::default();
+        let start = Instant::now();
+
+        // A slow notched wheel: one row per event
+        let notches: usize = (0..5)
+            .map(|i| wheel.rows(true, start + Duration::from_millis(120) * i))
+            .sum();
+        assert_eq!(notches, 5);
+
+        // A touchpad burst every 5ms moves a row per 50ms
+        let burst_start = start + Duration::from_secs(5);
+        let burst: usize = (0..100)
+            .map(|i| wheel.rows(true, burst_start + Duration::from_millis(5) * i))
+            .sum();
+        assert!((9..=11).contains(&burst), "{burst}");
+
+        // Turning around responds immediately
+        assert_eq!(
+            wheel.rows(false, burst_start + Duration::from_millis(501)),
+            1
+        );
+    }
+}
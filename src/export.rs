@@ -0,0 +1,236 @@
+//! 把收藏夹、观看历史等个人数据导出为本地文件，便于备份和在其他工具里处理
+
+use crate::api::history::HistoryItem;
+use anyhow::{anyhow, Result};
+use chrono::{Local, TimeZone};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// Progress of a background export task
+#[derive(Debug)]
+pub enum ExportEvent {
+    /// Number of records fetched so far
+    Progress(usize),
+    /// Written file paths, or the error message
+    Finished(Result<Vec<PathBuf>, String>),
+}
+
+/// One video of an exported favorites folder
+#[derive(Debug, Clone, Serialize)]
+pub struct FavoriteRecord {
+    pub title: String,
+    pub bvid: String,
+    pub up: String,
+    pub link: String,
+    /// 收藏时间，本地时区 `YYYY-MM-DD HH:MM:SS`
+    pub fav_time: String,
+}
+
+/// One watch history entry, written as a JSONL line
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryRecord {
+    pub title: String,
+    /// archive / pgc / live / article ...
+    pub business: String,
+    pub bvid: Option<String>,
+    pub oid: i64,
+    pub author: String,
+    pub author_mid: i64,
+    pub link: Option<String>,
+    /// 观看时间 (Unix timestamp)
+    pub view_at: i64,
+    /// 观看时间，本地时区 `YYYY-MM-DD HH:MM:SS`
+    pub view_time: String,
+    /// Watch progress in seconds, -1 when finished
+    pub progress: i64,
+    pub duration: i64,
+}
+
+impl From<&HistoryItem> for HistoryRecord {
+    fn from(item: &HistoryItem) -> Self {
+        Self {
+            title: item.title.clone(),
+            business: item.history.business.clone(),
+            bvid: item.get_bvid().map(str::to_string),
+            oid: item.history.oid,
+            author: item.author_name.clone(),
+            author_mid: item.author_mid,
+            link: item.uri.clone().filter(|uri| !uri.is_empty()),
+            view_at: item.view_at,
+            view_time: format_timestamp(item.view_at),
+            progress: item.progress,
+            duration: item.duration,
+        }
+    }
+}
+
+/// A followed UP in the exported OPML
+#[derive(Debug, Clone)]
+pub struct FollowedUp {
+    pub mid: i64,
+    pub name: String,
+}
+
+/// RSSHub route for the uploads of a UP, e.g. `https://rsshub.app/bilibili/user/video/2`
+pub fn rsshub_user_url(base: &str, mid: i64) -> String {
+    format!("{}/bilibili/user/video/{}", base.trim_end_matches('/'), mid)
+}
+
+/// `<下载目录>/bilibili-tui/exports`, created if missing
+pub fn export_dir() -> Result<PathBuf> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| anyhow!("Could not find download directory"))?
+        .join("bilibili-tui")
+        .join("exports");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Format a Unix timestamp as local `YYYY-MM-DD HH:MM:SS`
+pub fn format_timestamp(ts: i64) -> String {
+    Local
+        .timestamp_opt(ts, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/// Write a favorites folder as both CSV and JSON, returning the two paths
+pub fn write_favorites(folder: &str, records: &[FavoriteRecord]) -> Result<Vec<PathBuf>> {
+    let dir = export_dir()?;
+    let stem = file_stem("favorites", folder);
+
+    // BOM 让 Excel 按 UTF-8 打开中文标题
+    let mut csv = String::from("\u{feff}title,bvid,up,link,fav_time\n");
+    for record in records {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{}",
+            csv_field(&record.title),
+            csv_field(&record.bvid),
+            csv_field(&record.up),
+            csv_field(&record.link),
+            csv_field(&record.fav_time),
+        );
+    }
+    let csv_path = dir.join(format!("{}.csv", stem));
+    std::fs::write(&csv_path, csv)?;
+
+    let json_path = dir.join(format!("{}.json", stem));
+    std::fs::write(&json_path, serde_json::to_string_pretty(records)?)?;
+
+    Ok(vec![csv_path, json_path])
+}
+
+/// Write watch history as JSON Lines, one record per line
+pub fn write_history(records: &[HistoryRecord]) -> Result<PathBuf> {
+    let mut jsonl = String::new();
+    for record in records {
+        jsonl.push_str(&serde_json::to_string(record)?);
+        jsonl.push('\n');
+    }
+    let path = export_dir()?.join(format!("{}.jsonl", file_stem("history", "all")));
+    std::fs::write(&path, jsonl)?;
+    Ok(path)
+}
+
+/// Write the followed UPs as an OPML outline of RSSHub feeds, for importing into RSS readers
+pub fn write_opml(base: &str, ups: &[FollowedUp]) -> Result<PathBuf> {
+    let path = export_dir()?.join(format!("{}.opml", file_stem("followings", "rsshub")));
+    std::fs::write(&path, opml(base, ups))?;
+    Ok(path)
+}
+
+fn opml(base: &str, ups: &[FollowedUp]) -> String {
+    let mut opml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n  <head>\n    <title>bilibili-tui followings</title>\n  </head>\n  <body>\n    <outline text=\"bilibili\" title=\"bilibili\">\n",
+    );
+    for up in ups {
+        let name = xml_escape(&up.name);
+        let _ = writeln!(
+            opml,
+            "      <outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\" htmlUrl=\"https://space.bilibili.com/{}\"/>",
+            name,
+            name,
+            xml_escape(&rsshub_user_url(base, up.mid)),
+            up.mid,
+        );
+    }
+    opml.push_str("    </outline>\n  </body>\n</opml>\n");
+    opml
+}
+
+/// Escape text for an XML attribute value
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `<kind>-<name>-<timestamp>` with characters that are unsafe in file names replaced
+fn file_stem(kind: &str, name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    format!(
+        "{}-{}-{}",
+        kind,
+        name.trim(),
+        Local::now().format("%Y%m%d-%H%M%S")
+    )
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("BV1xx411c7mD"), "BV1xx411c7mD");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn test_opml_escapes_names() {
+        let ups = [FollowedUp {
+            mid: 2,
+            name: "碧诗 & <\"友人\">".to_string(),
+        }];
+        let opml = opml("https://rsshub.app/", &ups);
+        assert!(opml.contains("text=\"碧诗 &amp; &lt;&quot;友人&quot;&gt;\""));
+        assert!(opml.contains("xmlUrl=\"https://rsshub.app/bilibili/user/video/2\""));
+    }
+
+    #[test]
+    fn test_file_stem_sanitizes() {
+        let stem = file_stem("favorites", "默认/收藏:夹");
+        assert!(stem.starts_with("favorites-默认_收藏_夹-"));
+    }
+}
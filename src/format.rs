@@ -0,0 +1,56 @@
+//! Number and text formatting shared by all pages
+
+use crate::i18n::{language, Language};
+
+/// Compact count in the interface language: `1.2万` / `3.4亿`, or `1.2K` / `3.4M` in English
+pub fn format_count(n: i64) -> String {
+    format_count_in(n, language())
+}
+
+/// [`format_count`] showing "-" for a missing value
+pub fn format_count_opt(n: Option<i64>) -> String {
+    n.map_or_else(|| "-".to_string(), format_count)
+}
+
+pub fn format_count_in(n: i64, lang: Language) -> String {
+    let units: &[(i64, &str)] = match lang {
+        Language::Zh => &[(100_000_000, "亿"), (10_000, "万")],
+        Language::En => &[(1_000_000_000, "B"), (1_000_000, "M"), (1_000, "K")],
+    };
+    units.iter().find(|(unit, _)| n.abs() >= *unit).map_or_else(
+        || n.to_string(),
+        |(unit, suffix)| format!("{:.1}{}", n as f64 / *unit as f64, suffix),
+    )
+}
+
+/// Cut `s` to at most `max_len` chars, ending with "…" when something was cut
+pub fn truncate_chars(s: &str, max_len: usize) -> String {
+    if s.chars().count() > max_len {
+        let kept: String = s.chars().take(max_len.saturating_sub(1)).collect();
+        kept + "…"
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("哔哩哔哩干杯", 6), "哔哩哔哩干杯");
+        assert_eq!(truncate_chars("哔哩哔哩干杯", 4), "哔哩哔…");
+    }
+
+    #[test]
+    fn test_format_count_in() {
+        assert_eq!(format_count_in(9999, Language::Zh), "9999");
+        assert_eq!(format_count_in(12_345, Language::Zh), "1.2万");
+        assert_eq!(format_count_in(340_000_000, Language::Zh), "3.4亿");
+        assert_eq!(format_count_in(999, Language::En), "999");
+        assert_eq!(format_count_in(12_345, Language::En), "12.3K");
+        assert_eq!(format_count_in(1_200_000, Language::En), "1.2M");
+        assert_eq!(format_count_in(3_400_000_000, Language::En), "3.4B");
+    }
+}
@@ -0,0 +1,666 @@
+//! Lightweight i18n: UI strings are written in Chinese and looked up by that text
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::LazyLock;
+
+/// Interface language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Zh,
+    En,
+}
+
+impl Language {
+    pub fn all() -> &'static [Language] {
+        &[Language::Zh, Language::En]
+    }
+
+    /// Name shown in the settings page, always in the language itself
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Zh => "简体中文",
+            Language::En => "English",
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(lang: Language) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Language::En,
+        _ => Language::Zh,
+    }
+}
+
+/// Translate a UI string; the Chinese text is both the key and the fallback
+pub fn t(key: &'static str) -> &'static str {
+    match language() {
+        Language::Zh => key,
+        Language::En => EN.get(key).copied().unwrap_or(key),
+    }
+}
+
+/// Translate a template and fill its `{}` placeholders in order
+pub fn tf(key: &'static str, args: &[&dyn Display]) -> String {
+    fill(t(key), args)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut parts = template.split("{}");
+    let mut out = parts.next().unwrap_or_default().to_string();
+    for (part, arg) in parts.zip(
+        args.iter()
+            .map(|a| a.to_string())
+            .chain(std::iter::repeat(String::new())),
+    ) {
+        out.push_str(&arg);
+        out.push_str(part);
+    }
+    out
+}
+
+static EN: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| EN_TABLE.iter().copied().collect());
+
+const EN_TABLE: &[(&str, &str)] = &[
+    ("匿名", "Anonymous"),
+    ("刚刚", "just now"),
+    ("{}分钟前", "{} min ago"),
+    ("{}小时前", "{} h ago"),
+    ("{}天前", "{} d ago"),
+    ("{}月前", "{} mo ago"),
+    ("未知", "Unknown"),
+    ("{}年", "{}"),
+    ("未开播", "Offline"),
+    ("直播中", "Live"),
+    ("轮播中", "Replay"),
+    ("无标题", "Untitled"),
+    ("新", "New"),
+    ("热", "Hot"),
+    ("直播", "Live"),
+    ("梗", "Meme"),
+    ("话题", "Topic"),
+    ("独家", "Exclusive"),
+    ("搜索失败: {}", "Search failed: {}"),
+    ("加载动态失败: {}", "Failed to load feed: {}"),
+    ("加载热搜失败: {}", "Failed to load trending: {}"),
+    ("短链解析失败: {}", "Failed to resolve short link: {}"),
+    ("无法识别的 BV号 / av号 / 链接", "Unrecognized BV id / av id / link"),
+    ("▶ 立即播放", "▶ Play now"),
+    ("🎵 仅音频", "🎵 Audio only"),
+    ("➕ 加入播放队列", "➕ Add to queue"),
+    ("⬇ 下载", "⬇ Download"),
+    ("🕒 稍后再看", "🕒 Watch later"),
+    (" 1-5 快选  Esc 取消 ", " 1-5 Quick pick  Esc Cancel "),
+    ("全部", "All"),
+    ("视频", "Videos"),
+    ("图文", "Posts"),
+    ("图片动态", "Image post"),
+    ("📷 图片动态", "📷 Image post"),
+    ("图文动态", "Text post"),
+    ("📝 图文", "📝 Post"),
+    (" [全部] ", " [All] "),
+    (" 关注的UP主 ", " Following "),
+    ("关注动态", "Following feed"),
+    (" 加载中...", " Loading..."),
+    ("⏳ 加载动态中...", "⏳ Loading feed..."),
+    ("暂无动态，请先登录并关注UP主", "No posts yet. Log in and follow some uploaders first"),
+    ("加载动态详情失败: {}", "Failed to load post: {}"),
+    ("加载评论失败: {}", "Failed to load comments: {}"),
+    ("👤 UP主: {}", "👤 Uploader: {}"),
+    ("🕒 发布时间: {}", "🕒 Published: {}"),
+    ("🖼️  图片数量: {} 张", "🖼️  Images: {}"),
+    ("📝 动态内容:", "📝 Content:"),
+    ("加载中...", "Loading..."),
+    ("   └─ {} 条回复", "   └─ {} replies"),
+    ("暂无评论", "No comments yet"),
+    ("📷 图文动态详情", "📷 Image post"),
+    ("📄 动态详情", "📄 Post details"),
+    ("错误: {}", "Error: {}"),
+    (" ✏️ 发表评论 ", " ✏️ Write a comment "),
+    (" 图片 {}/{} [h/l 切换] ", " Image {}/{} [h/l to switch] "),
+    ("等待加载...", "Waiting..."),
+    (" 动态详情 ", " Post details "),
+    (" 评论 ({}) ", " Comments ({}) "),
+    ("加载历史记录失败: {}", "Failed to load history: {}"),
+    ("加载更多失败: {}", "Failed to load more: {}"),
+    (" 📜 观看历史 ", " 📜 Watch history "),
+    ("暂无历史记录", "No history yet"),
+    ("加载更多...", "Loading more..."),
+    ("加载推荐视频失败: {}", "Failed to load recommendations: {}"),
+    ("推荐", "Recommended"),
+    (" 首页 ", " Home "),
+    ("⏳ 加载中...", "⏳ Loading..."),
+    ("📭 暂无推荐视频", "📭 No recommendations"),
+    ("导航", "Navigate"),
+    ("播放", "Play"),
+    ("更多", "More"),
+    ("刷新", "Refresh"),
+    ("退出", "Quit"),
+    ("切换主题", "Theme"),
+    ("已屏蔽：{}", "Blocked: {}"),
+    ("📺 加载中...", "📺 Loading..."),
+    (" ✓已看", " ✓Watched"),
+    ("加载直播推荐失败: {}", "Failed to load live rooms: {}"),
+    ("📺 直播推荐", "📺 Live recommendations"),
+    (" 直播 ", " Live "),
+    ("❌ 加载失败: {}", "❌ Failed to load: {}"),
+    ("📭 暂无直播推荐", "📭 No live rooms"),
+    ("进入", "Enter"),
+    ("加载房间信息失败: {}", "Failed to load room info: {}"),
+    ("WS连接失败: {}", "WebSocket connection failed: {}"),
+    ("WebSocket认证失败: {}", "WebSocket auth failed: {}"),
+    (" 📺 直播详情 ", " 📺 Live room "),
+    ("加载失败: {}", "Failed to load: {}"),
+    ("播放  ", "Play  "),
+    (" 返回", " Back"),
+    (" 直播画面 ", " Stream "),
+    (" 封面 ", " Cover "),
+    ("📺 暂无封面", "📺 No cover"),
+    (" 主播 ", " Streamer "),
+    (" 房间信息 ", " Room info "),
+    ("分区: ", "Area: "),
+    (" 弹幕 ({}) ", " Danmaku ({}) "),
+    ("等待弹幕...", "Waiting for danmaku..."),
+    ("连接失败: {}", "Connection failed: {}"),
+    ("弹幕功能加载中...", "Connecting to danmaku..."),
+    (" 高能榜 ({}) ", " Top viewers ({}) "),
+    (" 高能榜 ", " Top viewers "),
+    ("暂无高能用户", "No top viewers"),
+    (" 入场 ", " Entries "),
+    ("等待入场消息...", "Waiting for viewers..."),
+    ("欢迎 {} 进入直播间", "{} entered the room"),
+    ("获取二维码失败: {}", "Failed to get QR code: {}"),
+    ("轮询失败: {}", "Polling failed: {}"),
+    ("⏳ 等待扫描二维码...", "⏳ Waiting for QR code scan..."),
+    ("📱 已扫描，请在手机上确认登录", "📱 Scanned, confirm the login on your phone"),
+    ("✅ 登录成功！", "✅ Logged in!"),
+    ("❓ 未知状态", "❓ Unknown status"),
+    ("登录", "Login"),
+    (" 扫码登录 ", " Scan to log in "),
+    (" 状态 ", " Status "),
+    ("刷新二维码", "Refresh QR code"),
+    (" 🔗 打开链接 ", " 🔗 Open link "),
+    (" Enter 打开  Esc 取消 ", " Enter Open  Esc Cancel "),
+    ("支持 BV号 / av号 / 视频或直播间链接 / b23.tv 短链", "BV id / av id / video or live URL / b23.tv short link"),
+    (" 热搜榜 ", " Trending "),
+    ("⏳ 正在获取热搜...", "⏳ Loading trending..."),
+    ("暂无热搜数据", "No trending searches"),
+    (" 🔍 搜索视频 ", " 🔍 Search videos "),
+    ("⏳ 搜索中...", "⏳ Searching..."),
+    (" 结果 ({}) ", " Results ({}) "),
+    ("输入关键词开始搜索", "Type a keyword to search"),
+    ("没有找到相关视频", "No videos found"),
+    (" 搜索结果 ", " Results "),
+    ("[{}] 搜索  [{}] 取消  [{}] 导航", "[{}] Search  [{}] Cancel  [{}] Navigate"),
+    ("🎨 主题", "🎨 Theme"),
+    ("⌨️ 快捷键", "⌨️ Keys"),
+    ("👤 账户", "👤 Account"),
+    ("🌐 语言", "🌐 Language"),
+    ("确认", "Confirm"),
+    ("返回", "Back"),
+    ("向上", "Up"),
+    ("向下", "Down"),
+    ("向左", "Left"),
+    ("向右", "Right"),
+    ("下一页面", "Next page"),
+    ("上一页面", "Prev page"),
+    ("上一分区", "Prev section"),
+    ("下一分区", "Next section"),
+    ("标签1", "Tab 1"),
+    ("标签2", "Tab 2"),
+    ("标签3", "Tab 3"),
+    ("设置", "Settings"),
+    ("搜索", "Search"),
+    ("打开链接", "Open link"),
+    ("网络统计", "Net stats"),
+    ("评论", "Comment"),
+    ("展开回复", "Replies"),
+    ("UP空间", "Uploader"),
+    ("跳转链接", "Follow link"),
+    ("上一UP", "Prev uploader"),
+    ("下一UP", "Next uploader"),
+    ("切换分类", "Section"),
+    ("选择", "Select"),
+    ("切页面", "Page"),
+    (" 分类 ", " Sections "),
+    (" 🎨 选择主题 ", " 🎨 Choose theme "),
+    (" ⌨️ 快捷键 ", " ⌨️ Keybindings "),
+    (" 👤 账户 ", " 👤 Account "),
+    (" 🌐 界面语言 ", " 🌐 Interface language "),
+    ("🏠 首页", "🏠 Home"),
+    ("🔍 搜索", "🔍 Search"),
+    ("📺 动态", "📺 Feed"),
+    ("📜 历史", "📜 History"),
+    ("📡 直播", "📡 Live"),
+    ("⚙️ 设置", "⚙️ Settings"),
+    (" 📊 网络统计 ", " 📊 Network stats "),
+    ("请求总数  ", "Requests  "),
+    ("失败      ", "Failed    "),
+    ("平均耗时  ", "Avg time  "),
+    ("进行中    ", "In flight "),
+    ("最近限流/风控", "Recent throttling / risk control"),
+    ("  无", "  None"),
+    ("默认暗色", "Default dark"),
+    ("加载投稿失败: {}", "Failed to load uploads: {}"),
+    (" 👤 UP主空间 ", " 👤 Uploader "),
+    ("  ✓已关注", "  ✓Following"),
+    ("粉丝 ", "Followers "),
+    ("  投稿 ", "  Videos "),
+    ("这个人很神秘，什么都没有写", "No bio yet"),
+    ("该用户还没有投稿", "No uploads yet"),
+    (" 投稿视频 ", " Uploads "),
+    ("加载视频信息失败: {}", "Failed to load video info: {}"),
+    ("加载相关视频失败: {}", "Failed to load related videos: {}"),
+    (" 📹 视频信息 ", " 📹 Video info "),
+    (" 💬 评论 ", " 💬 Comments "),
+    ("  ⏳ 加载回复中...", "  ⏳ Loading replies..."),
+    ("👍 {}  💬 {} 回复", "👍 {}  💬 {} replies"),
+    (" 📺 相关推荐 ", " 📺 Related "),
+    ("暂无相关视频", "No related videos"),
+    (" 📑 选集 ({}) ", " 📑 Episodes ({}) "),
+    ("快捷键速查", "Cheat sheet"),
+    (" ⌨️ 快捷键速查 ", " ⌨️ Keybinding cheat sheet "),
+    ("滚动", "Scroll"),
+    ("关闭", "Close"),
+    ("全局", "Global"),
+    ("列表页", "List pages"),
+    ("视频详情", "Video details"),
+    ("动态页", "Feed"),
+    ("设置页", "Settings"),
+    ("打开详情", "Open details"),
+    ("更多操作", "More actions"),
+    ("切换焦点", "Switch focus"),
+    ("切换标签", "Switch tab"),
+    ("标签1/2/3", "Tab 1/2/3"),
+    ("修改/应用", "Edit/Apply"),
+    (" 👀 预览评论 ", " 👀 Preview comment "),
+    ("[{}] 确认发送  [{}] 返回编辑", "[{}] Send  [{}] Back to edit"),
+    ("超出 {} 字上限，无法发送  [{}] 取消", "Over the {}-character limit, cannot send  [{}] Cancel"),
+    ("[{}] 预览  [Shift/Alt+Enter] 换行  [{}] 取消", "[{}] Preview  [Shift/Alt+Enter] Newline  [{}] Cancel"),
+    ("⏳ 加载评论中...", "⏳ Loading comments..."),
+    ("⏳ 加载相关视频中...", "⏳ Loading related videos..."),
+    ("热搜榜", "Trending"),
+    ("搜索页", "Search"),
+    ("输入关键词", "Type keywords"),
+    ("显示/隐藏热搜榜", "Show/hide trending"),
+    ("多选模式", "Multi-select"),
+    ("历史页", "History"),
+    ("进入/退出多选", "Enter/leave multi-select"),
+    ("勾选", "Check"),
+    ("删除选中记录", "Delete checked entries"),
+    ("选中项加入播放队列", "Queue checked videos"),
+    ("已加入播放队列 {} 个视频", "Queued {} videos"),
+    ("{} 项失败: {}", "{} failed: {}"),
+    ("{}：已选 {} 项，再按 [{}] 确认，其他键取消", "{}: {} checked, press [{}] again to confirm, any other key to cancel"),
+    ("☑ 已选 {} 项  [{}] 勾选  [{}] {}  [{}] 加入播放队列  [{}] 退出多选", "☑ {} checked  [{}] Check  [{}] {}  [{}] Queue  [{}] Leave multi-select"),
+    ("删除历史", "Delete history"),
+    ("移出稍后再看", "Remove from watch later"),
+    ("移出收藏夹", "Remove from folder"),
+    ("移出选中视频", "Remove checked videos"),
+    ("多选勾选", "Check in multi-select"),
+    ("批量删除", "Batch delete"),
+    (" ☑ 已选 ", " ☑ Checked "),
+    ("✓ 已移出 {} 个视频", "✓ Removed {} videos"),
+    ("自制", "Original"),
+    ("转载", "Repost"),
+    (" · 发布于 {}", " · Published {}"),
+    (" ⚡充电专属", " ⚡Supporters only"),
+    (" 🎮互动视频", " 🎮Interactive"),
+    ("选择剧情分支", "Choose a branch"),
+    (" 数字键快选  Esc 结束互动 ", " Number keys to pick  Esc to stop "),
+    ("新版本发布页", "Release page"),
+    (" [{}] 查看更新", " [{}] View update"),
+    ("⭐ 收藏", "⭐ Favorites"),
+    ("导出", "Export"),
+    ("请先登录", "Please log in first"),
+    ("加载收藏夹失败: {}", "Failed to load favorites: {}"),
+    ("正在导出「{}」...", "Exporting \"{}\"..."),
+    ("正在导出... 已获取 {} 条", "Exporting... {} fetched"),
+    ("✓ 已导出 CSV 和 JSON 到 {}", "✓ Exported CSV and JSON to {}"),
+    ("导出失败: {}", "Export failed: {}"),
+    (" ⭐ 我的收藏 ", " ⭐ My Favorites "),
+    ("收藏夹是空的", "This folder is empty"),
+    ("收藏页", "Favorites"),
+    ("切换收藏夹", "Switch folder"),
+    ("导出当前收藏夹", "Export current folder"),
+    ("正在导出观看历史...", "Exporting watch history..."),
+    ("正在导出观看历史... 已获取 {} 条", "Exporting watch history... {} fetched"),
+    ("✓ 已导出 {} 条到 {}", "✓ Exported {} entries to {}"),
+    ("导出全部历史", "Export all history"),
+    ("📊 统计", "📊 Stats"),
+    ("读取观看记录失败: {}", "Failed to read watch log: {}"),
+    ("共播放 ", "Played "),
+    (" 次 · 累计时长 ", " times · total "),
+    (" · 自 {} 起", " · since {}"),
+    (" 📊 观看统计 ", " 📊 Watch Stats "),
+    ("其他", "Other"),
+    ("还没有播放记录，播放视频后会在这里生成统计", "No playback recorded yet. Stats appear here after you play videos"),
+    ("分区分布", "Categories"),
+    ("常看 UP", "Top uploaders"),
+    ("[{}] 刷新  [{}] 切换页面", "[{}] Refresh  [{}] Switch page"),
+    ("{} 小时 {} 分", "{} h {} min"),
+    ("{} 分钟", "{} min"),
+    ("切换UP列表", "Toggle UP list"),
+    ("常看/全部关注", "Frequent / all followings"),
+    ("查找UP", "Find UP"),
+    ("加载关注列表失败: {}", "Failed to load followings: {}"),
+    (" 查找UP: {}▏ (无匹配) ", " Find UP: {}▏ (no match) "),
+    (" 查找UP: {}▏ ", " Find UP: {}▏ "),
+    (" 加载关注列表中... ", " Loading followings... "),
+    (" 全部关注 ({}) · [{}] 切换常看 · [{}] 查找 ", " All followings ({}) · [{}] frequent · [{}] find "),
+    (" 关注的UP主 · [{}] 全部关注 · [{}] 查找 ", " Followed UPs · [{}] all followings · [{}] find "),
+    (" · 新增 {} 条动态", " · {} new posts"),
+    (" ⚠ 凭据文件权限过宽", " ⚠ Credentials readable by others"),
+    (" 重新登录或 chmod 600", " Log in again or chmod 600"),
+    ("该直播间已在播放", "This live room is already playing"),
+    ("该视频已在播放", "This video is already playing"),
+    ("已有视频在播放，可在操作菜单中加入播放队列", "A video is already playing, add this one to the queue from the action menu"),
+    ("从时间点播放", "Play at time"),
+    ("从评论时间点播放", "Play at comment time"),
+    ("弹幕换行", "Wrap danmaku"),
+    ("直播详情", "Live room"),
+    ("调整信息栏宽度", "Resize info panel"),
+    ("信息栏宽度  ", "Info width  "),
+    ("弹幕换行  ", "Wrap danmaku  "),
+    ("总督", "Governor"),
+    ("提督", "Admiral"),
+    ("舰长", "Captain"),
+    ("已下播", "Stream ended"),
+    ("已开播 {} 小时 {} 分", "Live for {} h {} min"),
+    ("已开播 {} 分", "Live for {} min"),
+    (" 竖屏 ", " Portrait "),
+    ("👤 已登录", "👤 Logged in"),
+    ("👤 未登录", "👤 Not logged in"),
+    ("● 网络正常", "● Online"),
+    ("● 网络异常", "● Network error"),
+    ("○ 暂无请求", "○ No requests yet"),
+    ("✉ 未读 {}", "✉ Unread {}"),
+    ("⟳ 后台任务 {}", "⟳ Background tasks {}"),
+    ("跳转楼层", "Go to floor"),
+    ("跳转到楼层", "Go to comment floor"),
+    ("跳转到楼层: {}_  [Enter] 确认  [Esc] 取消", "Go to floor: {}_  [Enter] Confirm  [Esc] Cancel"),
+    (" 💬 评论 · 第 {} / 约 {} 条 ", " 💬 Comments · {} / ~{} "),
+    ("请求被 B 站风控拦截，已自动刷新签名重试一次仍失败", "Blocked by Bilibili risk control, still failing after refreshing the signature and retrying once"),
+    ("可尝试重新登录刷新凭据，或在浏览器中打开 B 站完成人机验证后重试", "Try logging in again to refresh credentials, or complete the captcha on bilibili.com in a browser and retry"),
+    ("重试", "Retry"),
+    ("重新登录", "Log in again"),
+    ("在浏览器中验证", "Verify in browser"),
+    ("风控时重新登录", "Log in again on risk control"),
+    ("打开浏览器失败: {}", "Failed to open browser: {}"),
+    ("终端太小，请调大窗口或按 [{}] 保存二维码图片", "Terminal too small, enlarge the window or press [{}] to save the QR code image"),
+    ("二维码已保存到 {}", "QR code saved to {}"),
+    ("保存二维码失败: {}", "Failed to save QR code: {}"),
+    ("保存二维码图片", "Save QR image"),
+    ("打开第 N 张可见卡片", "Open the N-th visible card"),
+    (" 直播中 ", " LIVE "),
+    (" 充电专属 ", " Charge-only "),
+    (" {}P ", " {}P "),
+    (" 合作 ", " Collab "),
+    (" 投稿中搜索「{}」 ", " Uploads matching \"{}\" "),
+    ("没有标题包含「{}」的投稿", "No uploads with \"{}\" in the title"),
+    ("在投稿中搜索: {}_  [Enter] 搜索（留空显示全部）  [Esc] 取消", "Search uploads: {}_  [Enter] Search (empty shows all)  [Esc] Cancel"),
+    ("[{}/{}] 导航  [{}] 详情  [{}] 更多  [{}] 搜索投稿  [{}] 复制 RSS  [{}] 返回", "[{}/{}] Navigate  [{}] Details  [{}] More  [{}] Search uploads  [{}] Copy RSS  [{}] Back"),
+    ("[{}/{}] 导航  [{}] 详情  [{}] 搜索  [{}] 在UP投稿中搜  [{}] 热搜  [{}] 切换", "[{}/{}] Navigate  [{}] Details  [{}] Search  [{}] Search in uploader  [{}] Trending  [{}] Switch"),
+    ("在该UP投稿中搜索", "Search in uploader's videos"),
+    ("搜索投稿", "Search uploads"),
+    (" 图片 {}/{} ", " Image {}/{} "),
+    (" [{}/{}] 切换  [{}] 点赞  [{}] 关闭 ", " [{}/{}] Switch  [{}] Like  [{}] Close "),
+    ("点赞/选择/看评论图片", "Like / select / view comment images"),
+    ("最多 {} 张图片", "At most {} images"),
+    ("找不到文件: {}", "File not found: {}"),
+    ("只支持 jpg/png/gif/webp 图片", "Only jpg/png/gif/webp images are supported"),
+    ("这张图片已经添加过了", "This image is already added"),
+    (" 📝 发布动态 ", " 📝 New dynamic "),
+    (" ✏️ 内容 ", " ✏️ Content "),
+    (" 👀 预览动态 ", " 👀 Preview dynamic "),
+    (" 无图片，纯文字动态", " No images, text only"),
+    ("图片路径: {}_  [Enter] 添加  [Esc] 取消", "Image path: {}_  [Enter] Add  [Esc] Cancel"),
+    ("{}  [Tab] 添加图片  [Shift+Tab] 移除图片", "{}  [Tab] Add image  [Shift+Tab] Remove image"),
+    ("上一条动态还在发布中", "The previous dynamic is still being published"),
+    ("正在发布动态...", "Publishing dynamic..."),
+    ("正在上传图片 {}/{}...", "Uploading image {}/{}..."),
+    ("动态已发布", "Dynamic published"),
+    ("发布动态失败: {}", "Failed to publish dynamic: {}"),
+    ("发布动态", "Publish dynamic"),
+    (" 📻 私人 FM ", " 📻 Private FM "),
+    ("正在挑选曲目...", "Picking tracks..."),
+    ("[{}] 下一首  [{}] 关闭 ", "[{}] Next  [{}] Close "),
+    ("开关私人FM", "Toggle private FM"),
+    ("FM下一首", "FM next track"),
+    ("私人 FM 已关闭", "Private FM turned off"),
+    ("请先关闭正在播放的视频再开启私人 FM", "Close the playing video before starting the private FM"),
+    ("私人 FM 没有找到可播放的曲目", "Private FM found no tracks to play"),
+    (" 弹幕过快，已省略 {} 条 ", " Danmaku too fast, {} omitted "),
+    ("{} 条匹配", "{} matches"),
+    ("向后翻页查找中...", "Searching older pages..."),
+    ("无匹配", "No match"),
+    (" 🔍 过滤: {}{} · {} ", " 🔍 Filter: {}{} · {} "),
+    ("没有匹配的历史记录", "No matching history"),
+    ("按标题/UP过滤", "Filter by title/UP"),
+    ("添加时间", "Date added"),
+    ("观看进度", "Progress"),
+    ("加载稍后再看失败: {}", "Failed to load watch later: {}"),
+    ("✓ 已清除 {} 个已看完的视频", "✓ Cleared {} watched videos"),
+    ("排序: ", "Sort: "),
+    (" 🕒 稍后再看 ", " 🕒 Watch later "),
+    ("稍后再看是空的", "Watch later is empty"),
+    ("没有已看完的视频", "No watched videos"),
+    ("已看完", "Watched"),
+    ("已看 {}%", "Watched {}%"),
+    ("    共 {} 个，已看完 {} 个", "    {} videos, {} watched"),
+    ("确认清除 {} 个已看完的视频？再按 [{}] 确认，其他键取消", "Clear {} watched videos? Press [{}] again to confirm, any other key to cancel"),
+    ("[{}/{}] 导航  [{}/{}] 切换排序  [{}] 详情  [{}] 更多  [{}] 多选  [{}] 清除已看完  [{}] 刷新", "[{}/{}] Navigate  [{}/{}] Sort  [{}] Details  [{}] More  [{}] Multi-select  [{}] Clear watched  [{}] Refresh"),
+    ("稍后再看", "Watch later"),
+    ("切换排序", "Switch sort"),
+    ("清除已看完", "Clear watched"),
+    ("📬 订阅", "📬 Subscriptions"),
+    (" 📬 订阅 {}/{} ", " 📬 Subscriptions {}/{} "),
+    (" 📬 订阅 ", " 📬 Subscriptions "),
+    ("正在获取关注 UP 的投稿...", "Fetching uploads of followed UPs..."),
+    ("关注的 UP 最近没有投稿", "No recent uploads from followed UPs"),
+    (" 新 ", " New "),
+    ("[{}/{}] 导航  [{}/{}] 切换 UP（离开时标为已读）  [{}] 详情  [{}] 更多  [{}] 刷新  [{}] 导出 OPML  [{}] 复制 RSS", "[{}/{}] Navigate  [{}/{}] Switch UP (marks read on leave)  [{}] Details  [{}] More  [{}] Refresh  [{}] Export OPML  [{}] Copy RSS"),
+    ("订阅页", "Subscriptions"),
+    ("切换 UP（离开时标为已读）", "Switch UP (marks read on leave)"),
+    ("刷新投稿", "Refresh uploads"),
+    ("已复制 RSS 链接: {}", "Copied RSS link: {}"),
+    ("复制失败: {}", "Copy failed: {}"),
+    ("正在导出关注列表...", "Exporting followings..."),
+    ("正在导出... 已获取 {} 位 UP", "Exporting... {} UPs fetched"),
+    ("✓ 已导出 OPML 到 {}", "✓ Exported OPML to {}"),
+    ("复制链接", "Copy link"),
+    ("导出关注列表 OPML", "Export followings as OPML"),
+    ("复制 UP 的 RSS 链接", "Copy RSS link of the UP"),
+    ("开播提醒  ", "Live alert  "),
+    ("开关主播开播提醒", "Toggle live alert"),
+    ("打开最新提醒", "Open latest alert"),
+    ("{} 开播了", "{} is live"),
+    ("暂无新的提醒", "No new alerts"),
+    ("已关闭 {} 的开播提醒", "Live alert for {} turned off"),
+    ("已开启 {} 的开播提醒", "Live alert for {} turned on"),
+    ("🔴 {} 开播了：{}  按 {} 进入直播间", "🔴 {} is live: {}  Press {} to enter"),
+    ("已开启 {} 的开播提醒（需在配置中打开 live_alerts）", "Live alert for {} turned on (enable live_alerts in the config)"),
+    ("打开", "Open"),
+    ("{} 发布了新动态", "{} posted an update"),
+    ("🔔 {} 发布了：{}  按 {} 查看", "🔔 {} posted: {}  Press {} to view"),
+    (" 🔁 转发到动态 ", " 🔁 Share to dynamic "),
+    (" 👀 预览转发 ", " 👀 Preview share "),
+    ("转发到动态", "Share to dynamic"),
+    ("转发失败: {}", "Failed to share: {}"),
+    ("已转发到动态  按 {} 查看", "Shared to your dynamics  press {} to view"),
+    ("充电专属，无法播放", "Charging-exclusive, cannot play"),
+    ("付费内容，无法播放", "Paid content, cannot play"),
+    ("已失效", "Unavailable"),
+    (" 已失效 ", " Unavailable "),
+    ("🗂️ 内容", "🗂️ Content"),
+    (" 🗂️ 内容 ", " 🗂️ Content "),
+    ("[开]", "[On]"),
+    ("[关]", "[Off]"),
+    ("显示已失效视频", "Show unavailable videos"),
+    ("  收藏夹和历史中被删除或下架的视频：开启时置灰显示，关闭时过滤", "  Deleted or removed videos in favorites and history: greyed out when on, hidden when off"),
+    ("登录状态已失效", "Login has expired"),
+    ("请重新扫码登录后再试", "Scan the QR code to log in again and retry"),
+    ("网络连接失败", "Network connection failed"),
+    ("请检查网络、代理或 api_hosts 配置", "Check your network, proxy or api_hosts setting"),
+    ("B 站返回了无法识别的数据", "Bilibili returned data that could not be understood"),
+    ("接口可能已变更，可尝试更新到最新版本", "The API may have changed, try updating to the latest version"),
+    ("{} 秒后自动重试", "Retrying in {}s"),
+    (" 💬 只看 UP · {} 条 ", " 💬 UP only · {} comments "),
+    ("⏳ 正在查找 UP 主的评论...", "⏳ Looking for comments by the uploader..."),
+    ("已加载的评论中没有 UP 主的发言", "No comments by the uploader among the loaded ones"),
+    ("只看UP评论", "Uploader comments only"),
+    ("点赞", "Like"),
+    ("投币", "Coin"),
+    ("投两个币", "Two coins"),
+    ("收藏", "Favorite"),
+    ("关注", "Follow"),
+    ("视频信息还在加载", "Video info is still loading"),
+    ("上一个宏还在执行", "The previous macro is still running"),
+    ("正在执行 {}...", "Running {}..."),
+    ("▶️ 播放器", "▶️ Player"),
+    (" ▶️ 播放器 ", " ▶️ Player "),
+    ("未找到", "Not found"),
+    ("  在 PATH 和常见安装位置（Windows 的 Program Files、scoop、winget、chocolatey）中查找", "  Searched PATH and common install locations (Program Files, scoop, winget, chocolatey on Windows)"),
+    ("  播放需要 mpv 和 yt-dlp，安装后重启生效", "  Playback needs mpv and yt-dlp, restart after installing them"),
+    ("分区人气榜", "Area ranking"),
+    ("人气榜", "Ranking"),
+    (" 🔥 {} 人气榜 ", " 🔥 {} Ranking "),
+    ("📭 暂无开播主播", "📭 No one is live"),
+    ("直播页", "Live page"),
+    ("{} 个视频清除失败，已恢复", "{} videos could not be removed and were restored"),
+    ("取消点赞", "Unlike"),
+    ("清除已看完的视频", "Clearing watched videos"),
+    ("已加入稍后再看", "Added to watch later"),
+    ("加入稍后再看", "Adding to watch later"),
+    ("{}失败: {}", "{} failed: {}"),
+    ("📋 复制 Cookie", "📋 Copy cookie"),
+    ("💾 导出 cookies.txt", "💾 Export cookies.txt"),
+    ("🚪 退出登录", "🚪 Log out"),
+    ("  已登录 · UID {}", "  Logged in · UID {}"),
+    ("  未登录", "  Not logged in"),
+    ("  Cookie 过期时间: {}（剩余 {} 天）", "  Cookie expires: {} ({} days left)"),
+    ("  Cookie 已于 {} 过期", "  Cookie expired on {}"),
+    ("  Cookie 过期时间未知", "  Cookie expiry unknown"),
+    ("  Cookie 可以直接登录你的账号，只在自己信任的工具里使用", "  A cookie logs straight into your account, only use it in tools you trust"),
+    ("已复制 Cookie，请勿分享给他人", "Cookie copied, do not share it"),
+    ("已导出到 {}", "Exported to {}"),
+    ("评论已发送", "Comment sent"),
+    ("评论发送失败: {}", "Failed to send comment: {}"),
+    ("发送太频繁，请稍后再试", "Sending too often, try again later"),
+    ("账号已被封禁", "The account is banned"),
+    ("评论区已关闭", "Comments are closed"),
+    ("该评论区禁止回复", "Replies are disabled here"),
+    ("评论包含敏感词，请修改后重发", "The comment contains blocked words, edit it and send again"),
+    ("评论字数超出上限", "The comment is too long"),
+    ("你已被 UP 主加入评论黑名单", "The uploader has blocked you from commenting"),
+    ("重复评论，请勿刷屏", "Duplicate comment, please do not spam"),
+    ("登录响应中没有 Cookie", "No cookie in the login response"),
+    ("⏳ 二维码已过期，正在获取新的二维码...", "⏳ QR code expired, getting a new one..."),
+    (" 二维码剩余 {}:{} ", " Expires in {}:{} "),
+    ("浏览器打开评论", "Open comment in browser"),
+    ("没有匹配的选集", "No matching episodes"),
+    (" 📑 选集 ({}/{}) 过滤: {} ", " 📑 Episodes ({}/{}) filter: {} "),
+    ("选集: {}_  输入 P 号跳转，其他文字按标题过滤  [Enter] 确认  [Esc] 取消", "Episode: {}_  Type a P number to jump, other text filters by title  [Enter] Confirm  [Esc] Cancel"),
+    ("选集跳转/过滤", "Jump to / filter episodes"),
+    ("移动到收藏夹", "Move to folder"),
+    ("复制到收藏夹", "Copy to folder"),
+    ("没有其他收藏夹", "No other favorites folder"),
+    (" 📋 复制到收藏夹 ", " 📋 Copy to folder "),
+    (" 📦 移动到收藏夹 ", " 📦 Move to folder "),
+    ("移动失败: {}", "Move failed: {}"),
+    ("已复制到「{}」", "Copied to \"{}\""),
+    ("已移动到「{}」", "Moved to \"{}\""),
+    ("[{}/{}] 导航  [{}/{}] 切换收藏夹  [{}] 详情  [{}] 更多  [{}/{}] 移动/复制  [{}/{}/{}] 新建/重命名/删除  [{}] 多选  [{}] 导出", "[{}/{}] Navigate  [{}/{}] Switch folder  [{}] Details  [{}] More  [{}/{}] Move/Copy  [{}/{}/{}] New/Rename/Delete  [{}] Multi-select  [{}] Export"),
+    ("新建收藏夹", "New folder"),
+    ("重命名收藏夹", "Rename folder"),
+    ("删除收藏夹", "Delete folder"),
+    ("默认收藏夹不能删除", "The default folder can't be deleted"),
+    (" ✏️ 重命名收藏夹 ", " ✏️ Rename folder "),
+    (" 📁 新建收藏夹 ", " 📁 New folder "),
+    (" [Enter] 保存  [Esc] 取消 ", " [Enter] Save  [Esc] Cancel "),
+    ("🔒 私密", "🔒 Private"),
+    ("🌐 公开", "🌐 Public"),
+    ("名称: ", "Name: "),
+    ("  [Tab] 切换", "  [Tab] Toggle"),
+    ("保存收藏夹失败: {}", "Failed to save folder: {}"),
+    ("已重命名为「{}」", "Renamed to \"{}\""),
+    ("已新建收藏夹「{}」", "Created folder \"{}\""),
+    ("删除收藏夹失败: {}", "Failed to delete folder: {}"),
+    ("已删除收藏夹「{}」", "Deleted folder \"{}\""),
+    ("确认删除收藏夹「{}」及其中的视频？再按 [{}] 确认，其他键取消", "Delete folder \"{}\" and its videos? Press [{}] again to confirm, any other key cancels"),
+    ("点赞视频", "Like video"),
+    ("收藏视频", "Favorite video"),
+    ("一键三连", "Triple (like + coin + favorite)"),
+    ("已投满 {} 个币", "Already gave {} coins"),
+    ("取消收藏", "Unfavorite"),
+    ("{}未成功", "{} did not go through"),
+    ("[{}/{}] 图片  [{}/{}] 滚动  [{}] 点赞  [{}] 点踩  [{}] 评论  [n] 加载更多  [{}] 返回", "[{}/{}] Image  [{}/{}] Scroll  [{}] Like  [{}] Dislike  [{}] Comment  [n] More  [{}] Back"),
+    ("[{}/{}] 滚动  [{}] 点赞  [{}] 点踩  [{}] 评论  [n] 加载更多  [{}] 返回", "[{}/{}] Scroll  [{}] Like  [{}] Dislike  [{}] Comment  [n] More  [{}] Back"),
+    ("[{}/{}] 滚动  [{}] 切换  [{}] 点赞/选择  [{}] 点踩  [{}] 评论  [{}] 回复  [{}] UP空间  [{}] 播放  [{}/{}/{}/{}] 视频点赞/投币/收藏/三连  [{}] 返回", "[{}/{}] Scroll  [{}] Switch  [{}] Like/Select  [{}] Dislike  [{}] Comment  [{}] Replies  [{}] Uploader  [{}] Play  [{}/{}/{}/{}] Like/Coin/Fav/Triple video  [{}] Back"),
+    ("点踩评论", "Dislike comment"),
+    ("点踩", "Dislike"),
+    ("取消点踩", "Undo dislike"),
+    ("👍 已赞", "👍 Liked"),
+    ("👎 已踩", "👎 Disliked"),
+    ("{}:卡片导航 | {}/{}:切UP主 | {}/{}:切标签 | {}:切页面 | {}:详情 | {}:刷新 | {}:发动态 | {}:关注/取关", "{}:Navigate | {}/{}:Uploader | {}/{}:Tab | {}:Page | {}:Details | {}:Refresh | {}:Post | {}:Follow/Unfollow"),
+    (" ✓已关注", " ✓Following"),
+    (" +关注", " +Follow"),
+    (" 未关注 ", " Unfollowed "),
+    ("关注/取关UP主", "Follow/unfollow uploader"),
+    ("关注/取关作者", "Follow/unfollow author"),
+    ("不能关注自己", "You cannot follow yourself"),
+    ("获取关注状态失败: {}", "Failed to get follow state: {}"),
+    ("取消关注", "Unfollow"),
+    ("评论已被删除", "The comment has been deleted"),
+    ("定位评论失败: {}", "Failed to locate comment: {}"),
+    ("🌙 免打扰", "🌙 Do not disturb"),
+    ("自动（yt-dlp 网页地址）", "Auto (web page via yt-dlp)"),
+    (" 🎞 画质 ", " 🎞 Quality "),
+    (" 高于账号权限时自动降档 ", " Falls back when above your account tier "),
+    ("选择画质", "Choose quality"),
+    ("获取画质失败: {}", "Failed to get qualities: {}"),
+    ("画质已设为 {}，下次播放生效", "Quality set to {}, applies from the next playback"),
+    ("从 {} 继续播放", "Resuming from {}"),
+    ("播放失败: {}", "Playback failed: {}"),
+    ("已加入播放队列", "Added to the play queue"),
+    ("已开始下载", "Download started"),
+    ("下载失败: {}", "Download failed: {}"),
+    ("上次停留在 {}  按 {} 恢复", "Last time you were on {}  press {} to go back"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        assert_eq!(fill("{} min ago", &[&5]), "5 min ago");
+        assert_eq!(fill("[{}/{}] Scroll", &[&"k", &"j"]), "[k/j] Scroll");
+        assert_eq!(fill("no args", &[]), "no args");
+    }
+
+    #[test]
+    fn test_en_table_placeholders() {
+        let mut seen = std::collections::HashSet::new();
+        for (zh, en) in EN_TABLE {
+            assert!(seen.insert(zh), "duplicate key {:?}", zh);
+            assert_eq!(
+                zh.matches("{}").count(),
+                en.matches("{}").count(),
+                "{:?}",
+                zh
+            );
+        }
+    }
+}
@@ -1,5 +1,9 @@
 pub mod api;
 pub mod app;
+pub mod export;
+pub mod format;
+pub mod i18n;
 pub mod player;
 pub mod storage;
 pub mod ui;
+pub mod update;
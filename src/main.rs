@@ -1,7 +1,11 @@
 use bilibili_tui::app::App;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
+    terminal::supports_keyboard_enhancement,
 };
 use std::io;
 
@@ -14,12 +18,28 @@ async fn main() -> io::Result<()> {
     // Enable mouse capture
     execute!(std::io::stdout(), EnableMouseCapture)?;
 
+    // Report FocusGained / FocusLost so background work can pause while the terminal is hidden
+    let _ = execute!(std::io::stdout(), EnableFocusChange);
+
+    // Let terminals that support it report Shift+Enter (newline in comment input)
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        let _ = execute!(
+            std::io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        );
+    }
+
     // Run the application
     let app = App::new();
     let result = app.run(&mut terminal).await;
 
     // Disable mouse capture before restoring
     let _ = execute!(std::io::stdout(), DisableMouseCapture);
+    let _ = execute!(std::io::stdout(), DisableFocusChange);
+    if keyboard_enhanced {
+        let _ = execute!(std::io::stdout(), PopKeyboardEnhancementFlags);
+    }
 
     // Restore terminal
     ratatui::restore();
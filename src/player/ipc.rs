@@ -0,0 +1,130 @@
+//! mpv JSON IPC: read the real playback position of a running instance
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+
+/// mpv answers instantly; a stalled socket shouldn't hold up heartbeats
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// IPC server path handed to one mpv instance, removed when dropped
+#[derive(Debug)]
+pub struct MpvIpc {
+    path: PathBuf,
+}
+
+impl MpvIpc {
+    /// A path no other instance of this or another bilibili-tui uses
+    pub fn new() -> Self {
+        let name = format!(
+            "bilibili-tui-mpv-{}-{}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = if cfg!(windows) {
+            PathBuf::from(format!(r"\\.\pipe\{}", name))
+        } else {
+            std::env::temp_dir().join(format!("{}.sock", name))
+        };
+        Self { path }
+    }
+
+    /// mpv argument that opens the IPC server
+    pub fn arg(&self) -> String {
+        format!("--input-ipc-server={}", self.path.display())
+    }
+
+    /// Current position in seconds, None while mpv is still loading or unreachable
+    pub async fn time_pos(&self) -> Option<f64> {
+        timeout(REPLY_TIMEOUT, async {
+            let stream = self.connect().await?;
+            get_property(stream, "time-pos").await
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    #[cfg(unix)]
+    async fn connect(&self) -> Option<tokio::net::UnixStream> {
+        tokio::net::UnixStream::connect(&self.path).await.ok()
+    }
+
+    #[cfg(windows)]
+    async fn connect(&self) -> Option<tokio::net::windows::named_pipe::NamedPipeClient> {
+        tokio::net::windows::named_pipe::ClientOptions::new()
+            .open(&self.path)
+            .ok()
+    }
+}
+
+impl Drop for MpvIpc {
+    fn drop(&mut self) {
+        if cfg!(unix) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Send one get_property command and wait for its reply, skipping event lines
+async fn get_property(stream: impl AsyncRead + AsyncWrite + Unpin, property: &str) -> Option<f64> {
+    const REQUEST_ID: i64 = 1;
+    let mut stream = BufReader::new(stream);
+    let command = serde_json::json!({
+        "command": ["get_property", property],
+        "request_id": REQUEST_ID,
+    });
+    stream
+        .write_all(format!("{}\n", command).as_bytes())
+        .await
+        .ok()?;
+
+    let mut lines = stream.lines();
+    while let Some(line) = lines.next_line().await.ok()? {
+        if let Some(reply) = parse_reply(&line, REQUEST_ID) {
+            return reply;
+        }
+    }
+    None
+}
+
+/// The numeric value of the reply to `request_id`: None for other lines (events, other
+/// replies), Some(None) when mpv answered with an error such as "property unavailable"
+fn parse_reply(line: &str, request_id: i64) -> Option<Option<f64>> {
+    let reply: serde_json::Value = serde_json::from_str(line).ok()?;
+    if reply.get("request_id").and_then(|id| id.as_i64()) != Some(request_id) {
+        return None;
+    }
+    if reply.get("error").and_then(|e| e.as_str()) != Some("success") {
+        return Some(None);
+    }
+    Some(reply.get("data").and_then(|d| d.as_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(
+            parse_reply(r#"{"data":83.5,"error":"success","request_id":1}"#, 1),
+            Some(Some(83.5))
+        );
+        assert_eq!(
+            parse_reply(r#"{"error":"property unavailable","request_id":1}"#, 1),
+            Some(None)
+        );
+        // Events and replies to other requests are skipped
+        assert_eq!(parse_reply(r#"{"event":"playback-restart"}"#, 1), None);
+        assert_eq!(
+            parse_reply(r#"{"data":1.0,"error":"success","request_id":2}"#, 1),
+            None
+        );
+        assert_eq!(parse_reply("not json", 1), None);
+    }
+}
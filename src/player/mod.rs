@@ -1,107 +1,353 @@
+mod ipc;
+mod tools;
+
+pub use tools::tools;
+
 use crate::api::client::ApiClient;
-use crate::storage::Credentials;
+use crate::api::heartbeat::{LiveSession, MediaSession, VideoSession, HEARTBEAT_INTERVAL};
+use crate::storage::{CookieFile, Credentials};
 use anyhow::Result;
 use std::process::Stdio;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::process::Command;
-use tokio::time::{interval, Instant};
+use tokio::process::{Child, Command};
+use tokio::sync::{mpsc, Notify};
+use tokio::time::interval;
 
-/// Play a video using mpv with yt-dlp and report watch progress
-/// This function spawns mpv in a background task to avoid blocking the TUI
+use ipc::MpvIpc;
+
+/// How often the position of a playing video is read from mpv
+const POSITION_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A video to be played by mpv
+#[derive(Debug, Clone)]
+pub struct PlayRequest {
+    pub bvid: String,
+    pub aid: i64,
+    pub cid: i64,
+    pub duration: i64,
+    pub page_num: Option<i32>,
+    /// 仅播放音频，不打开视频窗口
+    pub audio_only: bool,
+    /// 直接播放的流地址，用于 yt-dlp 无法按分P定位的互动视频节点
+    pub direct_url: Option<String>,
+    /// 从该秒数开始播放
+    pub start: Option<i64>,
+    /// 画质 qn：设置时直接播放 DASH 音视频流，不经过 yt-dlp
+    pub quality: Option<i32>,
+}
+
+/// Events sent back to the app from background player tasks
+#[derive(Debug, Clone)]
+pub enum PlayerEvent {
+    /// mpv exited
+    Finished { bvid: String },
+    /// mpv playing a live stream exited
+    LiveFinished { room_id: i64 },
+    /// mpv could not be started for a video
+    Failed { bvid: String, error: String },
+}
+
+/// What a running mpv instance is playing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Playing {
+    Video(String),
+    Live(i64),
+}
+
+/// A running mpv instance
+#[derive(Debug)]
+struct Session {
+    id: u64,
+    playing: Playing,
+    /// Notified to kill the process
+    stop: Arc<Notify>,
+}
+
+/// Table of running mpv instances, shared with the tasks that wait for them to exit
+#[derive(Debug, Clone, Default)]
+pub struct PlayerSessions(Arc<Mutex<(u64, Vec<Session>)>>);
+
+impl PlayerSessions {
+    pub fn contains(&self, playing: &Playing) -> bool {
+        self.lock().1.iter().any(|s| s.playing == *playing)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().1.is_empty()
+    }
+
+    /// Number of running mpv instances
+    pub fn len(&self) -> usize {
+        self.lock().1.len()
+    }
+
+    /// Number of videos (not live streams) being played
+    pub fn video_count(&self) -> usize {
+        self.lock()
+            .1
+            .iter()
+            .filter(|s| matches!(s.playing, Playing::Video(_)))
+            .count()
+    }
+
+    /// Close every mpv instance playing `playing`
+    pub fn stop(&self, playing: &Playing) {
+        for session in self.lock().1.iter().filter(|s| s.playing == *playing) {
+            session.stop.notify_one();
+        }
+    }
+
+    /// Register a started instance, returning its id and stop signal
+    fn insert(&self, playing: Playing) -> (u64, Arc<Notify>) {
+        let mut guard = self.lock();
+        let (next_id, sessions) = &mut *guard;
+        *next_id += 1;
+        let stop = Arc::new(Notify::new());
+        sessions.push(Session {
+            id: *next_id,
+            playing,
+            stop: Arc::clone(&stop),
+        });
+        (*next_id, stop)
+    }
+
+    fn remove(&self, id: u64) {
+        self.lock().1.retain(|s| s.id != id);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, (u64, Vec<Session>)> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Play a video using mpv and report watch progress: DASH streams when a quality is chosen,
+/// otherwise the web page through yt-dlp
+/// The streams are resolved and mpv is spawned in a background task to avoid blocking the TUI;
+/// a failure there is reported as [`PlayerEvent::Failed`] instead of `Finished`
 pub async fn play_video(
     api_client: Arc<ApiClient>,
-    bvid: &str,
-    aid: i64,
-    cid: i64,
-    duration: i64,
-    page_num: Option<i32>,
+    request: PlayRequest,
     credentials: Option<&Credentials>,
+    sessions: &PlayerSessions,
+    events: mpsc::UnboundedSender<PlayerEvent>,
 ) -> Result<()> {
-    let video_url = match page_num {
-        Some(p) if p > 1 => format!("https://www.bilibili.com/video/{}?p={}", bvid, p),
-        _ => format!("https://www.bilibili.com/video/{}", bvid),
-    };
+    // Removed when dropped, including when mpv fails to start
+    let cookie_file = credentials
+        .map(crate::storage::export_cookies_for_ytdlp)
+        .transpose()?;
+    // Registered right away, so the video counts as playing while the streams load
+    let (session_id, stop) = sessions.insert(Playing::Video(request.bvid.clone()));
+    let sessions = sessions.clone();
+
+    tokio::spawn(async move {
+        let bvid = request.bvid.clone();
+        let result = run_video(api_client, request, cookie_file, &stop).await;
+        sessions.remove(session_id);
+        let event = match result {
+            Ok(()) => PlayerEvent::Finished { bvid },
+            Err(e) => PlayerEvent::Failed {
+                bvid,
+                error: e.to_string(),
+            },
+        };
+        let _ = events.send(event);
+    });
 
-    // Report watch start
-    let _ = crate::api::heartbeat::report_watch_start(&api_client, aid, cid, bvid, duration).await;
+    Ok(())
+}
 
-    let start_ts = chrono::Utc::now().timestamp();
+/// Resolve the streams, start mpv and report heartbeats until it exits
+async fn run_video(
+    api_client: Arc<ApiClient>,
+    request: PlayRequest,
+    cookie_file: Option<CookieFile>,
+    stop: &Notify,
+) -> Result<()> {
+    let PlayRequest {
+        bvid,
+        aid,
+        cid,
+        duration,
+        page_num,
+        audio_only,
+        direct_url,
+        start,
+        quality,
+    } = request;
 
-    let mut cmd = Command::new("mpv");
+    // Falls back to yt-dlp when the streams can't be fetched
+    let dash = match quality {
+        Some(qn) if direct_url.is_none() && cid > 0 => api_client
+            .get_playurl(&bvid, cid, qn)
+            .await
+            .ok()
+            .and_then(|info| info.dash_urls(qn)),
+        _ => None,
+    };
 
-    cmd.stdout(Stdio::null());
-    cmd.stderr(Stdio::null());
+    // 互动视频节点没有各自的进度
+    let resumable = direct_url.is_none();
+    let is_direct = direct_url.is_some() || dash.is_some();
+    let mut audio_file = None;
+    let video_url = match (dash, direct_url, page_num) {
+        (Some(dash), _, _) if audio_only => dash.audio.unwrap_or(dash.video),
+        (Some(dash), _, _) => {
+            audio_file = dash.audio;
+            dash.video
+        }
+        (None, Some(url), _) => url,
+        (None, None, Some(p)) if p > 1 => {
+            format!("https://www.bilibili.com/video/{}?p={}", bvid, p)
+        }
+        (None, None, _) => format!("https://www.bilibili.com/video/{}", bvid),
+    };
+
+    let mut session = VideoSession::new(aid, cid, &bvid, duration);
+    let _ = session.start(&api_client).await;
 
-    let cookie_path_to_clean = if let Some(creds) = credentials {
-        let cookie_path = crate::storage::export_cookies_for_ytdlp(creds)?;
+    let mut cmd = mpv_command();
+    if let Some(cookie_file) = &cookie_file {
         cmd.arg(format!(
             "--ytdl-raw-options=cookies={}",
-            cookie_path.display()
+            cookie_file.path().display()
         ));
-        Some(cookie_path)
-    } else {
-        None
-    };
+    }
 
-    cmd.arg("--force-window=immediate");
+    // Bilibili's CDN rejects stream requests without a referer
+    if is_direct {
+        cmd.arg("--referrer=https://www.bilibili.com/");
+    }
+    if let Some(audio) = audio_file {
+        cmd.arg(format!("--audio-file={}", audio));
+    }
+
+    if audio_only {
+        cmd.arg("--no-video");
+    } else {
+        cmd.arg("--force-window=immediate");
+    }
+    if let Some(start) = start {
+        cmd.arg(format!("--start={}", start));
+    }
+    let ipc = MpvIpc::new();
+    cmd.arg(ipc.arg());
     cmd.arg(&video_url);
 
     let mut child = cmd.spawn()?;
+    let position = track_session(
+        &mut session,
+        &api_client,
+        &mut child,
+        stop,
+        start.unwrap_or(0),
+        Some(&ipc),
+    )
+    .await;
 
-    // Clone bvid for the background task (needs 'static lifetime)
-    let bvid = bvid.to_string();
+    drop(cookie_file);
+    drop(ipc);
+    if resumable {
+        let _ = crate::storage::positions::save_position(&bvid, cid, position, duration);
+    }
+    Ok(())
+}
 
-    // Spawn a background task to handle heartbeat and cleanup
-    // This prevents blocking the TUI
-    tokio::spawn(async move {
-        let start_time = Instant::now();
-        let mut played_time: i64 = 0;
-        let mut heartbeat_interval = interval(Duration::from_secs(15));
-
-        loop {
-            tokio::select! {
-                _ = heartbeat_interval.tick() => {
-                    played_time += 15;
-                    let real_played_time = start_time.elapsed().as_secs() as i64;
-
-                    let _ = crate::api::heartbeat::report_heartbeat(
-                        &api_client,
-                        aid,
-                        cid,
-                        &bvid,
-                        played_time,
-                        real_played_time,
-                        real_played_time,
-                        start_ts,
-                        0, // play_type: 0 = playing
-                    ).await;
+/// mpv with its output silenced, pointed at the detected yt-dlp since mpv only looks in
+/// PATH and its own directory
+fn mpv_command() -> Command {
+    let mut cmd = Command::new(tools::mpv_program());
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::null());
+    if let Some(ytdlp) = &tools().ytdlp {
+        cmd.arg(format!(
+            "--script-opts=ytdl_hook-ytdl_path={}",
+            ytdlp.display()
+        ));
+    }
+    cmd
+}
+
+/// Report heartbeats through `session` until mpv exits (or is stopped), then the final one,
+/// and return the last position. With `ipc` the position is read from mpv, so seeking and
+/// pausing are reported as they are; otherwise (or until mpv answers) it starts at `start`
+/// and advances by [`HEARTBEAT_INTERVAL`] per report
+async fn track_session(
+    session: &mut impl MediaSession,
+    api_client: &ApiClient,
+    child: &mut Child,
+    stop: &Notify,
+    start: i64,
+    ipc: Option<&MpvIpc>,
+) -> i64 {
+    let step = HEARTBEAT_INTERVAL.as_secs() as i64;
+    let mut played = start;
+    let mut synced = false;
+    let mut heartbeat_interval = interval(HEARTBEAT_INTERVAL);
+    let mut position_interval = interval(POSITION_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = heartbeat_interval.tick() => {
+                if !synced {
+                    played += step;
                 }
-                result = child.wait() => {
-                    let real_played_time = start_time.elapsed().as_secs() as i64;
-
-                    let _ = crate::api::heartbeat::report_heartbeat(
-                        &api_client,
-                        aid,
-                        cid,
-                        &bvid,
-                        played_time,
-                        real_played_time,
-                        real_played_time,
-                        start_ts,
-                        4, // play_type: 4 = end
-                    ).await;
-
-                    let _ = result;
-                    break;
+                let _ = session.heartbeat(api_client, played).await;
+            }
+            _ = position_interval.tick(), if ipc.is_some() => {
+                if let Some(ipc) = ipc {
+                    // Unreachable mpv: keep counting from the last known position
+                    match ipc.time_pos().await {
+                        Some(position) => {
+                            played = position as i64;
+                            synced = true;
+                        }
+                        None => synced = false,
+                    }
                 }
             }
+            _ = stop.notified() => {
+                let _ = child.start_kill();
+            }
+            _ = child.wait() => {
+                let _ = session.stop(api_client, played).await;
+                break;
+            }
         }
+    }
+    played
+}
 
-        // Cleanup cookie file
-        if let Some(path) = cookie_path_to_clean {
-            let _ = tokio::fs::remove_file(path).await;
-        }
+/// Download a video with yt-dlp into the user's download directory
+/// This function spawns yt-dlp in a background task to avoid blocking the TUI
+pub async fn download_video(bvid: &str, credentials: Option<&Credentials>) -> Result<()> {
+    let video_url = format!("https://www.bilibili.com/video/{}", bvid);
+    let output_dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| anyhow::anyhow!("Could not find download directory"))?
+        .join("bilibili-tui");
+
+    let mut cmd = Command::new(tools::ytdlp_program());
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::null());
+    cmd.arg("-P").arg(&output_dir);
+    cmd.arg("-o").arg("%(title)s [%(id)s].%(ext)s");
+
+    let cookie_file = if let Some(creds) = credentials {
+        let cookie_file = crate::storage::export_cookies_for_ytdlp(creds)?;
+        cmd.arg("--cookies").arg(cookie_file.path());
+        Some(cookie_file)
+    } else {
+        None
+    };
+
+    cmd.arg(&video_url);
+
+    let mut child = cmd.spawn()?;
+
+    tokio::spawn(async move {
+        let _ = child.wait().await;
+        drop(cookie_file);
     });
 
     Ok(())
@@ -109,19 +355,61 @@ pub async fn play_video(
 
 /// Play a live stream using mpv
 /// This function spawns mpv in a background task to avoid blocking the TUI
-pub async fn play_live(room_id: i64) -> Result<()> {
+pub async fn play_live(
+    api_client: Arc<ApiClient>,
+    room_id: i64,
+    sessions: &PlayerSessions,
+    events: mpsc::UnboundedSender<PlayerEvent>,
+) -> Result<()> {
     let live_url = format!("https://live.bilibili.com/{}", room_id);
 
-    let mut cmd = Command::new("mpv");
-    cmd.stdout(Stdio::null());
-    cmd.stderr(Stdio::null());
+    let mut cmd = mpv_command();
     cmd.arg("--force-window=immediate");
     cmd.arg(&live_url);
 
     let mut child = cmd.spawn()?;
+    let (session_id, stop) = sessions.insert(Playing::Live(room_id));
+    let sessions = sessions.clone();
+
+    let mut session = LiveSession::new(room_id);
+    let _ = session.start(&api_client).await;
 
     // Spawn a background task to wait for the process
     // This prevents blocking the TUI
+    tokio::spawn(async move {
+        track_session(&mut session, &api_client, &mut child, &stop, 0, None).await;
+        sessions.remove(session_id);
+        let _ = events.send(PlayerEvent::LiveFinished { room_id });
+    });
+
+    Ok(())
+}
+
+/// Copy text to the system clipboard through the terminal (OSC 52), which also works over SSH
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::clipboard::CopyToClipboard::to_clipboard_from(text)
+    )?;
+    Ok(())
+}
+
+/// Open a URL in the system browser
+pub fn open_url(url: &str) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else {
+        Command::new("xdg-open")
+    };
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::null());
+    cmd.arg(url);
+
+    let mut child = cmd.spawn()?;
     tokio::spawn(async move {
         let _ = child.wait().await;
     });
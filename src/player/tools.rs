@@ -0,0 +1,95 @@
+//! Locating mpv and yt-dlp: PATH first, then the usual install places, so Windows
+//! installs that never touched PATH (installer, scoop, winget, chocolatey) still work
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Where the external programs were found, detected once per run
+#[derive(Debug, Clone, Default)]
+pub struct Tools {
+    pub mpv: Option<PathBuf>,
+    pub ytdlp: Option<PathBuf>,
+}
+
+/// The detected programs, looked up on first use
+pub fn tools() -> &'static Tools {
+    static TOOLS: OnceLock<Tools> = OnceLock::new();
+    TOOLS.get_or_init(|| Tools {
+        mpv: find_program("mpv"),
+        ytdlp: find_program("yt-dlp"),
+    })
+}
+
+/// Program to run for mpv; the bare name when not found, so spawning reports the usual error
+pub fn mpv_program() -> PathBuf {
+    tools().mpv.clone().unwrap_or_else(|| PathBuf::from("mpv"))
+}
+
+/// Program to run for yt-dlp, like [`mpv_program`]
+pub fn ytdlp_program() -> PathBuf {
+    tools()
+        .ytdlp
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("yt-dlp"))
+}
+
+/// Find an executable by name in PATH, then in the common install directories
+pub fn find_program(name: &str) -> Option<PathBuf> {
+    let file_name = executable_name(name);
+    let path_dirs = env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+        .unwrap_or_default();
+    path_dirs
+        .into_iter()
+        .chain(install_dirs(name))
+        .map(|dir| dir.join(&file_name))
+        .find(|candidate| is_executable(candidate))
+}
+
+fn executable_name(name: &str) -> String {
+    if cfg!(windows) {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Directories installers put the program in when it is not in PATH
+fn install_dirs(name: &str) -> Vec<PathBuf> {
+    let env_dir = |var: &str| env::var_os(var).map(PathBuf::from);
+    let mut dirs = Vec::new();
+    if cfg!(windows) {
+        for var in ["ProgramFiles", "ProgramFiles(x86)"] {
+            if let Some(dir) = env_dir(var) {
+                dirs.push(dir.join(name));
+            }
+        }
+        if let Some(local) = env_dir("LOCALAPPDATA") {
+            dirs.push(local.join("Programs").join(name));
+            dirs.push(local.join("Microsoft").join("WinGet").join("Links"));
+        }
+        if let Some(home) = env_dir("USERPROFILE") {
+            dirs.push(home.join("scoop").join("shims"));
+        }
+        if let Some(data) = env_dir("ProgramData") {
+            dirs.push(data.join("chocolatey").join("bin"));
+        }
+    } else if cfg!(target_os = "macos") {
+        // Apps started outside a login shell miss Homebrew's PATH
+        dirs.push(PathBuf::from("/opt/homebrew/bin"));
+        dirs.push(PathBuf::from("/usr/local/bin"));
+    }
+    dirs
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    path.is_file()
+}
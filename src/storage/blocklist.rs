@@ -0,0 +1,97 @@
+//! Title keyword/regex blocklist
+//!
+//! 规则写在 config.json 的 `title_blocklist` 中：
+//! - 普通字符串按关键词匹配（忽略大小写）
+//! - `/.../` 包裹的字符串按正则匹配
+
+use regex::Regex;
+
+enum Rule {
+    Keyword(String),
+    Pattern(Regex),
+}
+
+/// Compiled title blocklist
+#[derive(Default)]
+pub struct TitleBlocklist {
+    rules: Vec<(String, Rule)>,
+    /// 折叠显示命中的视频，而不是直接过滤
+    pub collapse: bool,
+}
+
+impl TitleBlocklist {
+    /// Compile rules from config, invalid regexes are skipped
+    pub fn new(rules: &[String], collapse: bool) -> Self {
+        let rules = rules
+            .iter()
+            .filter_map(|raw| {
+                let raw_trimmed = raw.trim();
+                if raw_trimmed.is_empty() {
+                    return None;
+                }
+                let rule = match raw_trimmed
+                    .strip_prefix('/')
+                    .and_then(|r| r.strip_suffix('/'))
+                {
+                    Some(pattern) if !pattern.is_empty() => {
+                        Rule::Pattern(Regex::new(pattern).ok()?)
+                    }
+                    _ => Rule::Keyword(raw_trimmed.to_lowercase()),
+                };
+                Some((raw_trimmed.to_string(), rule))
+            })
+            .collect();
+
+        Self { rules, collapse }
+    }
+
+    /// Return the first rule that matches the title
+    pub fn matched(&self, title: &str) -> Option<&str> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        let lower = title.to_lowercase();
+        self.rules
+            .iter()
+            .find(|(_, rule)| match rule {
+                Rule::Keyword(keyword) => lower.contains(keyword.as_str()),
+                Rule::Pattern(re) => re.is_match(title),
+            })
+            .map(|(raw, _)| raw.as_str())
+    }
+
+    /// Whether a matching item should be dropped from the list entirely
+    pub fn hides(&self, title: &str) -> bool {
+        !self.collapse && self.matched(title).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyword_and_regex() {
+        let list = TitleBlocklist::new(
+            &[
+                "原神".to_string(),
+                "/^【.*搬运】/".to_string(),
+                "/[/".to_string(),
+            ],
+            false,
+        );
+
+        assert_eq!(list.matched("原神新版本前瞻"), Some("原神"));
+        assert_eq!(list.matched("【熟肉搬运】一个视频"), Some("/^【.*搬运】/"));
+        assert_eq!(list.matched("普通视频"), None);
+        assert!(list.hides("原神"));
+    }
+
+    #[test]
+    fn test_keyword_ignores_case() {
+        let list = TitleBlocklist::new(&["Minecraft".to_string()], true);
+
+        assert_eq!(list.matched("minecraft 生存"), Some("Minecraft"));
+        assert!(!list.hides("minecraft 生存"));
+    }
+}
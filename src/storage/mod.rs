@@ -1,9 +1,22 @@
 //! Credential storage and persistence
 
+pub mod blocklist;
+pub mod positions;
+pub mod session;
+pub mod subscriptions;
+pub mod watch_log;
+
+pub use blocklist::TitleBlocklist;
+pub use session::{load_session, save_session, Session, SessionPage};
+
+use crate::i18n::Language;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::hash::{BuildHasher, RandomState};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
 
 /// User credentials from Bilibili login
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +49,25 @@ impl Credentials {
             refresh_token,
         })
     }
+
+    /// Cookie header value, as sent with API requests
+    pub fn cookie_string(&self) -> String {
+        format!(
+            "SESSDATA={}; bili_jct={}; DedeUserID={}",
+            self.sessdata, self.bili_jct, self.dede_user_id
+        )
+    }
+
+    /// SESSDATA 过期时间（Unix 秒）；SESSDATA 的值是 URL 编码的 `令牌,过期时间,校验`
+    pub fn sessdata_expires(&self) -> Option<i64> {
+        self.sessdata
+            .replace("%2C", ",")
+            .replace("%2c", ",")
+            .split(',')
+            .nth(1)?
+            .parse()
+            .ok()
+    }
 }
 
 /// Keybindings configuration
@@ -68,14 +100,67 @@ pub struct Keybindings {
     pub play: String,
     pub open_settings: String,
     pub search_focus: String,
+    pub open_link: String,
+    pub toggle_stats: String,
+    pub help: String,
+    pub hot_search: String,
+    pub multi_select: String,
+    /// 多选模式下勾选
+    pub mark: String,
+    /// 多选模式下删除/移出选中项，稍后再看页清除已看完
+    pub batch_delete: String,
+    pub open_release: String,
+    pub relogin: String,
+    pub export: String,
 
     // Comments
     pub comment: String,
     pub toggle_replies: String,
+    pub open_space: String,
+    pub follow_link: String,
+    pub play_at: String,
+    pub goto_floor: String,
 
     // Dynamic page specific
     pub up_prev: String,
     pub up_next: String,
+    pub up_source: String,
+
+    // Live detail page
+    pub danmaku_wrap: String,
+    pub live_alert: String,
+
+    // 私人 FM
+    pub fm_toggle: String,
+    pub fm_skip: String,
+
+    // Clipboard
+    pub copy_link: String,
+
+    // 开播 / 更新提醒
+    pub open_alert: String,
+
+    // Video detail page
+    pub share_dynamic: String,
+    pub up_only_comments: String,
+    pub open_comment_web: String,
+    pub like_video: String,
+    pub coin_video: String,
+    pub fav_video: String,
+    pub triple: String,
+    pub dislike_comment: String,
+    pub follow_up: String,
+    pub quality: String,
+
+    // Live page
+    pub live_rank: String,
+
+    // Favorites page
+    pub fav_move: String,
+    pub fav_copy: String,
+    pub fav_new: String,
+    pub fav_rename: String,
+    pub fav_delete: String,
 }
 
 impl Default for Keybindings {
@@ -107,14 +192,63 @@ impl Default for Keybindings {
             play: "p".to_string(),
             open_settings: "s".to_string(),
             search_focus: "/".to_string(),
+            open_link: "o".to_string(),
+            toggle_stats: "F12".to_string(),
+            help: "?".to_string(),
+            hot_search: "H".to_string(),
+            multi_select: "v".to_string(),
+            mark: "Space".to_string(),
+            batch_delete: "d".to_string(),
+            open_release: "U".to_string(),
+            relogin: "L".to_string(),
+            export: "e".to_string(),
 
             // Comments
             comment: "c".to_string(),
             toggle_replies: "r".to_string(),
+            open_space: "u".to_string(),
+            follow_link: "g".to_string(),
+            play_at: "P".to_string(),
+            goto_floor: ":".to_string(),
 
             // Dynamic page
             up_prev: "h".to_string(),
             up_next: "l".to_string(),
+            up_source: "f".to_string(),
+            danmaku_wrap: "w".to_string(),
+            live_alert: "a".to_string(),
+
+            // 私人 FM
+            fm_toggle: "M".to_string(),
+            fm_skip: "N".to_string(),
+
+            // Clipboard
+            copy_link: "y".to_string(),
+
+            // 开播 / 更新提醒
+            open_alert: "G".to_string(),
+
+            // Video detail page
+            share_dynamic: "S".to_string(),
+            up_only_comments: "O".to_string(),
+            open_comment_web: "W".to_string(),
+            like_video: "z".to_string(),
+            coin_video: "x".to_string(),
+            fav_video: "f".to_string(),
+            triple: "T".to_string(),
+            dislike_comment: "d".to_string(),
+            follow_up: "F".to_string(),
+            quality: "Q".to_string(),
+
+            // Live page
+            live_rank: "R".to_string(),
+
+            // Favorites page
+            fav_move: "m".to_string(),
+            fav_copy: "C".to_string(),
+            fav_new: "n".to_string(),
+            fav_rename: "R".to_string(),
+            fav_delete: "D".to_string(),
         }
     }
 }
@@ -258,6 +392,46 @@ impl Keybindings {
         self.matches(&self.search_focus, key) || key == KeyCode::Char('i')
     }
 
+    pub fn matches_open_link(&self, key: KeyCode) -> bool {
+        self.matches(&self.open_link, key)
+    }
+
+    pub fn matches_toggle_stats(&self, key: KeyCode) -> bool {
+        self.matches(&self.toggle_stats, key)
+    }
+
+    pub fn matches_help(&self, key: KeyCode) -> bool {
+        self.matches(&self.help, key)
+    }
+
+    pub fn matches_hot_search(&self, key: KeyCode) -> bool {
+        self.matches(&self.hot_search, key)
+    }
+
+    pub fn matches_multi_select(&self, key: KeyCode) -> bool {
+        self.matches(&self.multi_select, key)
+    }
+
+    pub fn matches_mark(&self, key: KeyCode) -> bool {
+        self.matches(&self.mark, key)
+    }
+
+    pub fn matches_batch_delete(&self, key: KeyCode) -> bool {
+        self.matches(&self.batch_delete, key)
+    }
+
+    pub fn matches_open_release(&self, key: KeyCode) -> bool {
+        self.matches(&self.open_release, key)
+    }
+
+    pub fn matches_relogin(&self, key: KeyCode) -> bool {
+        self.matches(&self.relogin, key)
+    }
+
+    pub fn matches_export(&self, key: KeyCode) -> bool {
+        self.matches(&self.export, key)
+    }
+
     pub fn matches_section_prev(&self, key: KeyCode) -> bool {
         self.matches(&self.section_prev, key)
     }
@@ -274,6 +448,18 @@ impl Keybindings {
         self.matches(&self.toggle_replies, key)
     }
 
+    pub fn matches_open_space(&self, key: KeyCode) -> bool {
+        self.matches(&self.open_space, key)
+    }
+
+    pub fn matches_follow_link(&self, key: KeyCode) -> bool {
+        self.matches(&self.follow_link, key)
+    }
+
+    pub fn matches_play_at(&self, key: KeyCode) -> bool {
+        self.matches(&self.play_at, key)
+    }
+
     pub fn matches_tab_1(&self, key: KeyCode) -> bool {
         self.matches(&self.tab_1, key)
     }
@@ -286,6 +472,10 @@ impl Keybindings {
         self.matches(&self.tab_3, key)
     }
 
+    pub fn matches_goto_floor(&self, key: KeyCode) -> bool {
+        self.matches(&self.goto_floor, key)
+    }
+
     pub fn matches_up_prev(&self, key: KeyCode) -> bool {
         self.matches(&self.up_prev, key)
     }
@@ -294,6 +484,98 @@ impl Keybindings {
         self.matches(&self.up_next, key)
     }
 
+    pub fn matches_up_source(&self, key: KeyCode) -> bool {
+        self.matches(&self.up_source, key)
+    }
+
+    pub fn matches_danmaku_wrap(&self, key: KeyCode) -> bool {
+        self.matches(&self.danmaku_wrap, key)
+    }
+
+    pub fn matches_fm_toggle(&self, key: KeyCode) -> bool {
+        self.matches(&self.fm_toggle, key)
+    }
+
+    pub fn matches_fm_skip(&self, key: KeyCode) -> bool {
+        self.matches(&self.fm_skip, key)
+    }
+
+    pub fn matches_copy_link(&self, key: KeyCode) -> bool {
+        self.matches(&self.copy_link, key)
+    }
+
+    pub fn matches_live_alert(&self, key: KeyCode) -> bool {
+        self.matches(&self.live_alert, key)
+    }
+
+    pub fn matches_open_alert(&self, key: KeyCode) -> bool {
+        self.matches(&self.open_alert, key)
+    }
+
+    pub fn matches_share_dynamic(&self, key: KeyCode) -> bool {
+        self.matches(&self.share_dynamic, key)
+    }
+
+    pub fn matches_up_only_comments(&self, key: KeyCode) -> bool {
+        self.matches(&self.up_only_comments, key)
+    }
+
+    pub fn matches_open_comment_web(&self, key: KeyCode) -> bool {
+        self.matches(&self.open_comment_web, key)
+    }
+
+    pub fn matches_like_video(&self, key: KeyCode) -> bool {
+        self.matches(&self.like_video, key)
+    }
+
+    pub fn matches_coin_video(&self, key: KeyCode) -> bool {
+        self.matches(&self.coin_video, key)
+    }
+
+    pub fn matches_fav_video(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_video, key)
+    }
+
+    pub fn matches_triple(&self, key: KeyCode) -> bool {
+        self.matches(&self.triple, key)
+    }
+
+    pub fn matches_dislike_comment(&self, key: KeyCode) -> bool {
+        self.matches(&self.dislike_comment, key)
+    }
+
+    pub fn matches_follow_up(&self, key: KeyCode) -> bool {
+        self.matches(&self.follow_up, key)
+    }
+
+    pub fn matches_quality(&self, key: KeyCode) -> bool {
+        self.matches(&self.quality, key)
+    }
+
+    pub fn matches_live_rank(&self, key: KeyCode) -> bool {
+        self.matches(&self.live_rank, key)
+    }
+
+    pub fn matches_fav_move(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_move, key)
+    }
+
+    pub fn matches_fav_copy(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_copy, key)
+    }
+
+    pub fn matches_fav_new(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_new, key)
+    }
+
+    pub fn matches_fav_rename(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_rename, key)
+    }
+
+    pub fn matches_fav_delete(&self, key: KeyCode) -> bool {
+        self.matches(&self.fav_delete, key)
+    }
+
     pub fn get_nav_keys_display(&self) -> String {
         format!(
             "{}{}{}{}",
@@ -331,12 +613,217 @@ impl Keybindings {
             ("播放", &self.play),
             ("设置", &self.open_settings),
             ("搜索", &self.search_focus),
+            ("打开链接", &self.open_link),
+            ("网络统计", &self.toggle_stats),
+            ("快捷键速查", &self.help),
+            ("热搜榜", &self.hot_search),
+            ("多选模式", &self.multi_select),
+            ("新版本发布页", &self.open_release),
+            ("重新登录", &self.relogin),
+            ("导出", &self.export),
             // Comments
             ("评论", &self.comment),
             ("展开回复", &self.toggle_replies),
+            ("UP空间", &self.open_space),
+            ("跳转链接", &self.follow_link),
+            ("从时间点播放", &self.play_at),
+            ("跳转楼层", &self.goto_floor),
             // Dynamic page
             ("上一UP", &self.up_prev),
             ("下一UP", &self.up_next),
+            ("切换UP列表", &self.up_source),
+            // Live detail page
+            ("弹幕换行", &self.danmaku_wrap),
+            // 私人 FM
+            ("开关私人FM", &self.fm_toggle),
+            ("FM下一首", &self.fm_skip),
+            // Clipboard
+            ("复制链接", &self.copy_link),
+            // 开播 / 更新提醒
+            ("开关主播开播提醒", &self.live_alert),
+            ("打开最新提醒", &self.open_alert),
+            // Video detail page
+            ("转发到动态", &self.share_dynamic),
+            ("只看UP评论", &self.up_only_comments),
+            ("浏览器打开评论", &self.open_comment_web),
+            ("点赞视频", &self.like_video),
+            ("投币", &self.coin_video),
+            ("收藏视频", &self.fav_video),
+            ("一键三连", &self.triple),
+            ("点踩评论", &self.dislike_comment),
+            ("关注/取关UP主", &self.follow_up),
+            ("选择画质", &self.quality),
+            // Live page
+            ("分区人气榜", &self.live_rank),
+            // Favorites page
+            ("移动到收藏夹", &self.fav_move),
+            ("复制到收藏夹", &self.fav_copy),
+            ("新建收藏夹", &self.fav_new),
+            ("重命名收藏夹", &self.fav_rename),
+            ("删除收藏夹", &self.fav_delete),
+            // List pages
+            ("多选勾选", &self.mark),
+            ("批量删除", &self.batch_delete),
+        ]
+    }
+
+    /// Keybindings grouped by where they apply, for the cheat sheet
+    pub fn cheat_sheet(&self) -> Vec<(&'static str, Vec<(&'static str, String)>)> {
+        let nav = format!(
+            "{}/{}/{}/{}",
+            self.nav_up, self.nav_down, self.nav_left, self.nav_right
+        );
+        let sections = format!("{} / {}", self.section_prev, self.section_next);
+        vec![
+            (
+                "全局",
+                vec![
+                    ("退出", self.quit.clone()),
+                    ("返回", self.back.clone()),
+                    ("下一页面", self.nav_next_page.clone()),
+                    ("上一页面", self.nav_prev_page.clone()),
+                    ("切换主题", self.next_theme.clone()),
+                    ("设置", self.open_settings.clone()),
+                    ("打开链接", self.open_link.clone()),
+                    ("网络统计", self.toggle_stats.clone()),
+                    ("快捷键速查", self.help.clone()),
+                    ("新版本发布页", self.open_release.clone()),
+                    ("风控时重新登录", self.relogin.clone()),
+                    ("开关私人FM", self.fm_toggle.clone()),
+                    ("FM下一首", self.fm_skip.clone()),
+                    ("打开最新提醒", self.open_alert.clone()),
+                ],
+            ),
+            (
+                "列表页",
+                vec![
+                    ("导航", format!("{} / ←↑↓→", nav)),
+                    ("打开详情", self.confirm.clone()),
+                    ("打开第 N 张可见卡片", "1-9".to_string()),
+                    ("更多操作", self.play.clone()),
+                    ("刷新", self.refresh.clone()),
+                    ("搜索", self.search_focus.clone()),
+                ],
+            ),
+            (
+                "历史页",
+                vec![
+                    ("进入/退出多选", self.multi_select.clone()),
+                    ("勾选", self.mark.clone()),
+                    ("删除选中记录", self.batch_delete.clone()),
+                    ("选中项加入播放队列", self.play.clone()),
+                    ("导出全部历史", self.export.clone()),
+                    ("按标题/UP过滤", self.search_focus.clone()),
+                ],
+            ),
+            (
+                "收藏页",
+                vec![
+                    ("切换收藏夹", sections.clone()),
+                    ("导出当前收藏夹", self.export.clone()),
+                    ("进入/退出多选", self.multi_select.clone()),
+                    ("移出选中视频", self.batch_delete.clone()),
+                    ("选中项加入播放队列", self.play.clone()),
+                    ("移动到收藏夹", self.fav_move.clone()),
+                    ("复制到收藏夹", self.fav_copy.clone()),
+                    ("新建收藏夹", self.fav_new.clone()),
+                    ("重命名收藏夹", self.fav_rename.clone()),
+                    ("删除收藏夹", self.fav_delete.clone()),
+                ],
+            ),
+            (
+                "订阅页",
+                vec![
+                    ("切换 UP（离开时标为已读）", sections.clone()),
+                    ("刷新投稿", self.refresh.clone()),
+                    ("导出关注列表 OPML", self.export.clone()),
+                    ("复制 UP 的 RSS 链接", self.copy_link.clone()),
+                ],
+            ),
+            (
+                "稍后再看",
+                vec![
+                    ("切换排序", sections.clone()),
+                    ("清除已看完", self.batch_delete.clone()),
+                    ("进入/退出多选", self.multi_select.clone()),
+                    ("移出选中视频", self.batch_delete.clone()),
+                    ("选中项加入播放队列", self.play.clone()),
+                ],
+            ),
+            (
+                "搜索页",
+                vec![
+                    ("输入关键词", self.search_focus.clone()),
+                    ("在该UP投稿中搜索", self.open_space.clone()),
+                    ("显示/隐藏热搜榜", self.hot_search.clone()),
+                ],
+            ),
+            (
+                "视频详情",
+                vec![
+                    ("滚动", format!("{}/{}", self.nav_up, self.nav_down)),
+                    ("切换焦点", self.nav_next_page.clone()),
+                    ("点赞/选择/看评论图片", self.confirm.clone()),
+                    ("播放", self.play.clone()),
+                    ("评论", self.comment.clone()),
+                    ("展开回复", self.toggle_replies.clone()),
+                    ("UP空间", self.open_space.clone()),
+                    ("跳转链接", self.follow_link.clone()),
+                    ("从评论时间点播放", self.play_at.clone()),
+                    ("跳转到楼层", self.goto_floor.clone()),
+                    ("选集跳转/过滤", self.goto_floor.clone()),
+                    ("转发到动态", self.share_dynamic.clone()),
+                    ("只看UP评论", self.up_only_comments.clone()),
+                    ("浏览器打开评论", self.open_comment_web.clone()),
+                    ("点赞视频", self.like_video.clone()),
+                    ("投币", self.coin_video.clone()),
+                    ("收藏视频", self.fav_video.clone()),
+                    ("一键三连", self.triple.clone()),
+                    ("点踩评论", self.dislike_comment.clone()),
+                    ("关注/取关UP主", self.follow_up.clone()),
+                    ("选择画质", self.quality.clone()),
+                ],
+            ),
+            (
+                "动态页",
+                vec![
+                    ("切换标签", sections.clone()),
+                    (
+                        "标签1/2/3",
+                        format!("{}/{}/{}", self.tab_1, self.tab_2, self.tab_3),
+                    ),
+                    ("上一UP", self.up_prev.clone()),
+                    ("下一UP", self.up_next.clone()),
+                    ("常看/全部关注", self.up_source.clone()),
+                    ("查找UP", self.search_focus.clone()),
+                    ("发布动态", self.comment.clone()),
+                    ("关注/取关作者", self.follow_up.clone()),
+                ],
+            ),
+            (
+                "UP空间",
+                vec![
+                    ("搜索投稿", self.search_focus.clone()),
+                    ("复制 UP 的 RSS 链接", self.copy_link.clone()),
+                ],
+            ),
+            ("直播页", vec![("分区人气榜", self.live_rank.clone())]),
+            (
+                "直播详情",
+                vec![
+                    ("播放", format!("{}/{}", self.confirm, self.play)),
+                    (
+                        "调整信息栏宽度",
+                        format!("{}/{}", self.nav_left, self.nav_right),
+                    ),
+                    ("弹幕换行", self.danmaku_wrap.clone()),
+                    ("开关主播开播提醒", self.live_alert.clone()),
+                ],
+            ),
+            (
+                "设置页",
+                vec![("切换分类", sections), ("修改/应用", self.confirm.clone())],
+            ),
         ]
     }
 
@@ -366,22 +853,260 @@ impl Keybindings {
             16 => self.play = new_key,
             17 => self.open_settings = new_key,
             18 => self.search_focus = new_key,
+            19 => self.open_link = new_key,
+            20 => self.toggle_stats = new_key,
+            21 => self.help = new_key,
+            22 => self.hot_search = new_key,
+            23 => self.multi_select = new_key,
+            24 => self.open_release = new_key,
+            25 => self.relogin = new_key,
+            26 => self.export = new_key,
             // Comments
-            19 => self.comment = new_key,
-            20 => self.toggle_replies = new_key,
+            27 => self.comment = new_key,
+            28 => self.toggle_replies = new_key,
+            29 => self.open_space = new_key,
+            30 => self.follow_link = new_key,
+            31 => self.play_at = new_key,
+            32 => self.goto_floor = new_key,
             // Dynamic page
-            21 => self.up_prev = new_key,
-            22 => self.up_next = new_key,
+            33 => self.up_prev = new_key,
+            34 => self.up_next = new_key,
+            35 => self.up_source = new_key,
+            // Live detail page
+            36 => self.danmaku_wrap = new_key,
+            // 私人 FM
+            37 => self.fm_toggle = new_key,
+            38 => self.fm_skip = new_key,
+            // Clipboard
+            39 => self.copy_link = new_key,
+            // 开播 / 更新提醒
+            40 => self.live_alert = new_key,
+            41 => self.open_alert = new_key,
+            // Video detail page
+            42 => self.share_dynamic = new_key,
+            43 => self.up_only_comments = new_key,
+            44 => self.open_comment_web = new_key,
+            45 => self.like_video = new_key,
+            46 => self.coin_video = new_key,
+            47 => self.fav_video = new_key,
+            48 => self.triple = new_key,
+            49 => self.dislike_comment = new_key,
+            50 => self.follow_up = new_key,
+            51 => self.quality = new_key,
+            // Live page
+            52 => self.live_rank = new_key,
+            // Favorites page
+            53 => self.fav_move = new_key,
+            54 => self.fav_copy = new_key,
+            55 => self.fav_new = new_key,
+            56 => self.fav_rename = new_key,
+            57 => self.fav_delete = new_key,
+            // List pages
+            58 => self.mark = new_key,
+            59 => self.batch_delete = new_key,
             _ => {}
         }
     }
 }
 
+/// Custom API / image hosts, e.g. a reverse proxy mirror
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiHosts {
+    /// 替代 https://api.bilibili.com
+    pub main: Option<String>,
+    /// 替代 https://passport.bilibili.com
+    pub passport: Option<String>,
+    /// 替代 https://api.live.bilibili.com
+    pub live: Option<String>,
+    /// 替代 https://s.search.bilibili.com
+    pub search: Option<String>,
+    /// 替代 https://live-trace.bilibili.com（直播观看时长上报）
+    pub live_trace: Option<String>,
+    /// 替代 *.hdslb.com 图片 CDN
+    pub image_cdn: Option<String>,
+}
+
+/// Daily do-not-disturb time range, e.g. `09:00` - `18:00` or `23:00` - `08:00`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    /// `HH:MM`
+    pub start: String,
+    /// `HH:MM`, may be earlier than `start` to cross midnight
+    pub end: String,
+}
+
+impl QuietHours {
+    /// Whether `time` falls in the range; a malformed range never matches
+    pub fn contains(&self, time: chrono::NaiveTime) -> bool {
+        let parse = |s: &str| chrono::NaiveTime::parse_from_str(s.trim(), "%H:%M").ok();
+        let (Some(start), Some(end)) = (parse(&self.start), parse(&self.end)) else {
+            return false;
+        };
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+
+    /// Whether the local time now falls in the range
+    pub fn is_now(&self) -> bool {
+        self.contains(chrono::Local::now().time())
+    }
+}
+
+/// Page shown after startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StartPage {
+    #[default]
+    Home,
+    Dynamic,
+    Live,
+    History,
+}
+
+/// One operation of a key macro, done on the video open in the detail page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroStep {
+    /// 点赞
+    Like,
+    /// 投 1 个币
+    Coin,
+    /// 投 2 个币
+    Coin2,
+    /// 收藏到默认收藏夹
+    Favorite,
+    /// 关注 UP 主
+    Follow,
+    /// 加入稍后再看
+    WatchLater,
+}
+
+/// A key that runs several operations in a row, e.g. 三连 + 关注
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyMacro {
+    pub key: String,
+    /// Shown in the toast and the cheat sheet
+    #[serde(default)]
+    pub name: String,
+    pub steps: Vec<MacroStep>,
+}
+
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub theme: String,
     pub keybindings: Keybindings,
+    /// 淡化显示已看过的视频
+    #[serde(default)]
+    pub dim_watched: bool,
+    /// 标题屏蔽规则，`/.../` 表示正则
+    #[serde(default)]
+    pub title_blocklist: Vec<String>,
+    /// 折叠显示被屏蔽的视频而不是过滤掉
+    #[serde(default)]
+    pub collapse_blocked: bool,
+    /// 收藏夹和历史中列出已失效的视频（置灰），否则过滤掉
+    #[serde(default)]
+    pub show_invalid_videos: bool,
+    /// 直播弹幕高亮关键词（忽略大小写）
+    #[serde(default)]
+    pub danmaku_highlight: Vec<String>,
+    /// 命中高亮关键词时响铃
+    #[serde(default)]
+    pub danmaku_bell: bool,
+    /// 启动后进入的页面
+    #[serde(default)]
+    pub start_page: StartPage,
+    /// 启动时提示恢复上次会话（页面、选中项、搜索关键词）
+    #[serde(default = "default_true")]
+    pub restore_session: bool,
+    /// 自定义 API / 图片域名
+    #[serde(default)]
+    pub api_hosts: ApiHosts,
+    /// 界面语言
+    #[serde(default)]
+    pub language: Language,
+    /// 播放画质 qn（如 80 = 1080P），设置后直接播放 DASH 流；为空时交给 yt-dlp
+    #[serde(default)]
+    pub video_quality: Option<i32>,
+    /// 从上次退出 mpv 的位置继续播放
+    #[serde(default = "default_true")]
+    pub resume_playback: bool,
+    /// 播放期间降低轮询和重绘频率、暂停封面下载
+    #[serde(default = "default_true")]
+    pub throttle_during_playback: bool,
+    /// 终端失焦时同样降频并暂停封面下载，回到前台恢复
+    #[serde(default = "default_true")]
+    pub throttle_when_unfocused: bool,
+    /// 启动时检查 GitHub 上的新版本
+    #[serde(default = "default_true")]
+    pub check_update: bool,
+    /// 每次播放写入本地观看记录，供统计页使用
+    #[serde(default = "default_true")]
+    pub record_watch_stats: bool,
+    /// 打开直播间时上报观看时长心跳，累计直播观看时长和粉丝勋章亲密度
+    #[serde(default = "default_true")]
+    pub live_heartbeat: bool,
+    /// 直播详情页左侧房间信息栏宽度（列）
+    #[serde(default = "default_live_info_width")]
+    pub live_info_width: u16,
+    /// 直播弹幕换行完整显示，否则每条只占一行
+    #[serde(default)]
+    pub danmaku_wrap: bool,
+    /// 底部显示状态栏（登录用户、网络状态、时间、未读消息、后台任务）
+    #[serde(default = "default_true")]
+    pub show_status_bar: bool,
+    /// 全局 API 请求速率上限（次/秒），超出的请求排队发送；0 表示不限速
+    #[serde(default = "default_api_qps")]
+    pub api_qps: f64,
+    /// 后台轮询关注主播的开播状态，开播时发送桌面通知
+    #[serde(default)]
+    pub live_alerts: bool,
+    /// 关闭了开播提醒的主播 UID
+    #[serde(default)]
+    pub live_alert_muted: Vec<i64>,
+    /// 特别关注的 UP 发布新视频时发送桌面通知
+    #[serde(default)]
+    pub up_alerts: bool,
+    /// 特别关注的 UP 发布图文动态时也提醒
+    #[serde(default)]
+    pub up_alert_dynamics: bool,
+    /// 检查特别关注 UP 更新的间隔（分钟）
+    #[serde(default = "default_up_alert_interval")]
+    pub up_alert_interval_mins: u64,
+    /// 免打扰时段：不发桌面通知、不响终端铃声、不弹开播/更新提示，未读数不高亮
+    #[serde(default, alias = "alert_quiet_hours")]
+    pub quiet_hours: Option<QuietHours>,
+    /// 生成 RSS 订阅链接和 OPML 时使用的 RSSHub 实例
+    #[serde(default = "default_rsshub_base")]
+    pub rsshub_base: String,
+    /// 自定义宏键：在视频详情页按一次依次执行多个操作
+    #[serde(default)]
+    pub macros: Vec<KeyMacro>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_live_info_width() -> u16 {
+    30
+}
+
+fn default_api_qps() -> f64 {
+    crate::api::rate_limit::DEFAULT_QPS
+}
+
+fn default_up_alert_interval() -> u64 {
+    10
+}
+
+fn default_rsshub_base() -> String {
+    "https://rsshub.app".to_string()
 }
 
 impl Default for AppConfig {
@@ -389,6 +1114,35 @@ impl Default for AppConfig {
         Self {
             theme: "CatppuccinMocha".to_string(),
             keybindings: Keybindings::default(),
+            dim_watched: false,
+            title_blocklist: Vec::new(),
+            collapse_blocked: false,
+            show_invalid_videos: false,
+            danmaku_highlight: Vec::new(),
+            danmaku_bell: false,
+            start_page: StartPage::Home,
+            restore_session: true,
+            api_hosts: ApiHosts::default(),
+            language: Language::default(),
+            video_quality: None,
+            resume_playback: true,
+            throttle_during_playback: true,
+            throttle_when_unfocused: true,
+            check_update: true,
+            record_watch_stats: true,
+            live_heartbeat: true,
+            live_info_width: default_live_info_width(),
+            danmaku_wrap: false,
+            show_status_bar: true,
+            api_qps: default_api_qps(),
+            live_alerts: false,
+            live_alert_muted: Vec::new(),
+            up_alerts: false,
+            up_alert_dynamics: false,
+            up_alert_interval_mins: default_up_alert_interval(),
+            quiet_hours: None,
+            rsshub_base: default_rsshub_base(),
+            macros: Vec::new(),
         }
     }
 }
@@ -416,12 +1170,47 @@ fn get_config_path() -> Result<PathBuf> {
     Ok(get_config_dir()?.join("config.json"))
 }
 
+/// Write a file only the current user can read (0600 on Unix), for files holding cookies
+fn write_private(path: &Path, contents: &str) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // mode() only applies to new files, also tighten ones written by older versions
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        file.write_all(contents.as_bytes())?;
+    }
+    #[cfg(not(unix))]
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Whether the saved credentials are readable by other users (always false off Unix)
+pub fn credentials_too_open() -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        get_credentials_path()
+            .and_then(|path| Ok(fs::metadata(path)?))
+            .is_ok_and(|meta| meta.permissions().mode() & 0o077 != 0)
+    }
+    #[cfg(not(unix))]
+    false
+}
+
 /// Save credentials to disk
 pub fn save_credentials(credentials: &Credentials) -> Result<()> {
     let path = get_credentials_path()?;
     let json = serde_json::to_string_pretty(credentials)?;
-    fs::write(path, json)?;
-    Ok(())
+    write_private(&path, &json)
 }
 
 /// Load credentials from disk
@@ -461,18 +1250,181 @@ pub fn load_config() -> Result<AppConfig> {
     }
 }
 
-/// Export cookies in Netscape format for yt-dlp
-pub fn export_cookies_for_ytdlp(credentials: &Credentials) -> Result<PathBuf> {
-    let path = get_config_dir()?.join("cookies.txt");
+/// Cookies exported for one mpv / yt-dlp run, deleted when dropped so the file also goes
+/// away when playback ends abnormally or the app exits
+pub struct CookieFile {
+    path: PathBuf,
+}
 
-    let content = format!(
+impl CookieFile {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for CookieFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Cookie files older than this are leftovers of a crash
+const STALE_COOKIE_FILE_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Prefix of the cookie files in the system temp directory
+const COOKIE_FILE_PREFIX: &str = "bilibili-tui-cookies-";
+
+/// Export cookies in Netscape format for yt-dlp, to a fresh file per run so concurrent
+/// players don't overwrite or delete each other's cookies. The file goes to the system
+/// temp directory (`%TEMP%` on Windows), readable only by the current user
+pub fn export_cookies_for_ytdlp(credentials: &Credentials) -> Result<CookieFile> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let seed = (
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+        SystemTime::now(),
+    );
+    let name = format!(
+        "{}{:016x}.txt",
+        COOKIE_FILE_PREFIX,
+        RandomState::new().hash_one(seed)
+    );
+    let path = std::env::temp_dir().join(name);
+
+    let file = CookieFile { path };
+    write_private(&file.path, &netscape_cookies(credentials))?;
+    Ok(file)
+}
+
+/// Save the cookies as a Netscape `cookies.txt` in the export directory for other tools,
+/// readable only by the current user
+pub fn save_cookies_txt(credentials: &Credentials) -> Result<PathBuf> {
+    let name = format!(
+        "cookies-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let path = crate::export::export_dir()?.join(name);
+    write_private(&path, &netscape_cookies(credentials))?;
+    Ok(path)
+}
+
+fn netscape_cookies(credentials: &Credentials) -> String {
+    format!(
         "# Netscape HTTP Cookie File\n\
         .bilibili.com\tTRUE\t/\tTRUE\t0\tSESSDATA\t{}\n\
         .bilibili.com\tTRUE\t/\tFALSE\t0\tbili_jct\t{}\n\
         .bilibili.com\tTRUE\t/\tFALSE\t0\tDedeUserID\t{}\n",
         credentials.sessdata, credentials.bili_jct, credentials.dede_user_id
-    );
+    )
+}
 
-    fs::write(&path, content)?;
-    Ok(path)
+/// Remove cookie files left behind by a crash or killed process, including those older
+/// versions wrote to the config directory and the old shared `cookies.txt`
+pub fn clean_stale_cookie_files() {
+    if let Ok(dir) = get_config_dir() {
+        remove_stale_files(&dir, |name| name == "cookies.txt", "cookies-");
+    }
+    remove_stale_files(&std::env::temp_dir(), |_| false, COOKIE_FILE_PREFIX);
+}
+
+/// Remove the `prefix*.txt` files of `dir` older than a day, and any file `always` matches
+fn remove_stale_files(dir: &Path, always: impl Fn(&str) -> bool, prefix: &str) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let stale = always(&name)
+            || (name.starts_with(prefix)
+                && name.ends_with(".txt")
+                && entry
+                    .metadata()
+                    .and_then(|meta| meta.modified())
+                    .ok()
+                    .and_then(|modified| modified.elapsed().ok())
+                    .is_some_and(|age| age > STALE_COOKIE_FILE_AGE));
+        if stale {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn at(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_quiet_hours_across_midnight() {
+        let night = QuietHours {
+            start: "23:00".to_string(),
+            end: "08:00".to_string(),
+        };
+        assert!(night.contains(at(23, 30)));
+        assert!(night.contains(at(7, 59)));
+        assert!(!night.contains(at(8, 0)));
+        assert!(!night.contains(at(12, 0)));
+
+        let work = QuietHours {
+            start: "09:00".to_string(),
+            end: "18:00".to_string(),
+        };
+        assert!(work.contains(at(9, 0)));
+        assert!(!work.contains(at(18, 0)));
+
+        let broken = QuietHours {
+            start: "9点".to_string(),
+            end: "18:00".to_string(),
+        };
+        assert!(!broken.contains(at(12, 0)));
+    }
+
+    #[test]
+    fn test_quiet_hours_old_key() {
+        // Configs written before the quiet hours covered more than desktop notifications
+        let mut json = serde_json::to_value(AppConfig::default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("quiet_hours");
+        fields.insert(
+            "alert_quiet_hours".to_string(),
+            serde_json::json!({ "start": "09:00", "end": "18:00" }),
+        );
+        let config: AppConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            config.quiet_hours.map(|q| q.start),
+            Some("09:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sessdata_expires() {
+        let mut creds = Credentials {
+            sessdata: "a1b2c3%2C1767225600%2Cd4e5f*c2".to_string(),
+            bili_jct: String::new(),
+            dede_user_id: String::new(),
+            dede_user_id_ckmd5: None,
+            refresh_token: None,
+        };
+        assert_eq!(creds.sessdata_expires(), Some(1767225600));
+
+        creds.sessdata = "a1b2c3".to_string();
+        assert_eq!(creds.sessdata_expires(), None);
+    }
+
+    #[test]
+    fn test_keybinding_indices_match_labels() {
+        let mut keys = Keybindings::default();
+        let count = keys.get_all_labels().len();
+        for index in 0..count {
+            keys.update_by_index(index, format!("k{}", index));
+        }
+        for (index, (label, key)) in keys.get_all_labels().into_iter().enumerate() {
+            assert_eq!(key, format!("k{}", index), "{}", label);
+        }
+    }
 }
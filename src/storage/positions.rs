@@ -0,0 +1,98 @@
+//! Where each video stopped playing, to resume there next time
+
+use super::get_config_dir;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Least recently played entries are dropped beyond this
+const MAX_ENTRIES: usize = 500;
+/// Stopping this close to the start or the end counts as not started / finished
+const EDGE_SECS: i64 = 10;
+
+/// Last position of one video part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayPosition {
+    pub bvid: String,
+    pub cid: i64,
+    /// Seconds from the start
+    pub position: i64,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Positions of played videos, most recent last
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayPositions(Vec<PlayPosition>);
+
+impl PlayPositions {
+    /// Where to resume a part, None when it was finished or barely started
+    pub fn get(&self, bvid: &str, cid: i64) -> Option<i64> {
+        self.0
+            .iter()
+            .find(|p| p.bvid == bvid && p.cid == cid)
+            .map(|p| p.position)
+    }
+
+    /// Remember where a part stopped; finishing it or stopping right away forgets it
+    pub fn record(&mut self, bvid: &str, cid: i64, position: i64, duration: i64) {
+        self.0.retain(|p| !(p.bvid == bvid && p.cid == cid));
+        let finished = duration > 0 && position >= duration - EDGE_SECS;
+        if position < EDGE_SECS || finished {
+            return;
+        }
+        self.0.push(PlayPosition {
+            bvid: bvid.to_string(),
+            cid,
+            position,
+            updated_at: chrono::Local::now().timestamp(),
+        });
+        if self.0.len() > MAX_ENTRIES {
+            self.0.drain(..self.0.len() - MAX_ENTRIES);
+        }
+    }
+}
+
+fn get_positions_path() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("positions.json"))
+}
+
+/// Load saved positions, empty if none yet
+pub fn load_positions() -> Result<PlayPositions> {
+    let path = get_positions_path()?;
+    if !path.exists() {
+        return Ok(PlayPositions::default());
+    }
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Record where a part stopped playing
+pub fn save_position(bvid: &str, cid: i64, position: i64, duration: i64) -> Result<()> {
+    let mut positions = load_positions().unwrap_or_default();
+    positions.record(bvid, cid, position, duration);
+    fs::write(get_positions_path()?, serde_json::to_string(&positions)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_positions() {
+        let mut positions = PlayPositions::default();
+        positions.record("BV1xx411c7mD", 1, 300, 600);
+        assert_eq!(positions.get("BV1xx411c7mD", 1), Some(300));
+        assert_eq!(positions.get("BV1xx411c7mD", 2), None);
+
+        // Played to the end: start over next time
+        positions.record("BV1xx411c7mD", 1, 595, 600);
+        assert_eq!(positions.get("BV1xx411c7mD", 1), None);
+
+        // Closed right after opening
+        positions.record("BV1xx411c7mD", 2, 3, 600);
+        assert_eq!(positions.get("BV1xx411c7mD", 2), None);
+    }
+}
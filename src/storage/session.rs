@@ -0,0 +1,63 @@
+//! Session state saved on exit and restored on next startup
+
+use super::{get_config_dir, StartPage};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// List page the session was on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionPage {
+    Home,
+    Search,
+    Dynamic,
+    History,
+    Favorites,
+    Live,
+}
+
+impl From<StartPage> for SessionPage {
+    fn from(page: StartPage) -> Self {
+        match page {
+            StartPage::Home => SessionPage::Home,
+            StartPage::Dynamic => SessionPage::Dynamic,
+            StartPage::Live => SessionPage::Live,
+            StartPage::History => SessionPage::History,
+        }
+    }
+}
+
+/// Last session state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub page: SessionPage,
+    /// 选中项下标
+    #[serde(default)]
+    pub selected: usize,
+    /// 搜索关键词（仅搜索页）
+    #[serde(default)]
+    pub search_query: String,
+}
+
+/// Get the session file path
+fn get_session_path() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("session.json"))
+}
+
+/// Save session state to disk
+pub fn save_session(session: &Session) -> Result<()> {
+    let path = get_session_path()?;
+    let json = serde_json::to_string_pretty(session)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// Load session state from disk
+pub fn load_session() -> Result<Session> {
+    let path = get_session_path()?;
+    let json = fs::read_to_string(path)?;
+    let session: Session = serde_json::from_str(&json)?;
+    Ok(session)
+}
@@ -0,0 +1,80 @@
+//! Read state of the subscription page: per UP, uploads up to when have been seen
+
+use super::get_config_dir;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Uploads of a UP never opened before count as unread for this long
+const FIRST_SEEN_WINDOW: i64 = 7 * 24 * 3600;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubscriptionState {
+    /// UP mid -> publish time (Unix timestamp) of the newest upload marked read
+    #[serde(default)]
+    pub read_until: HashMap<i64, i64>,
+}
+
+impl SubscriptionState {
+    /// Whether an upload published at `created` is new to the user at time `now`
+    pub fn is_unread(&self, mid: i64, created: i64, now: i64) -> bool {
+        let read_until = self
+            .read_until
+            .get(&mid)
+            .copied()
+            .unwrap_or(now - FIRST_SEEN_WINDOW);
+        created > read_until
+    }
+
+    /// Mark the uploads of a UP up to `created` as read
+    pub fn mark_read(&mut self, mid: i64, created: i64) {
+        let read_until = self.read_until.entry(mid).or_default();
+        *read_until = (*read_until).max(created);
+    }
+}
+
+/// Get the subscription state file path
+fn get_subscriptions_path() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("subscriptions.json"))
+}
+
+/// Load the read state, empty if none yet
+pub fn load_subscription_state() -> Result<SubscriptionState> {
+    let path = get_subscriptions_path()?;
+    if !path.exists() {
+        return Ok(SubscriptionState::default());
+    }
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Save the read state to disk
+pub fn save_subscription_state(state: &SubscriptionState) -> Result<()> {
+    let path = get_subscriptions_path()?;
+    let json = serde_json::to_string_pretty(state)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unread_after_read_mark() {
+        let now = 1_000_000_000;
+        let mut state = SubscriptionState::default();
+        // Unknown UP: only the last week is new
+        assert!(state.is_unread(1, now - 3600, now));
+        assert!(!state.is_unread(1, now - FIRST_SEEN_WINDOW - 1, now));
+
+        state.mark_read(1, now - 3600);
+        assert!(!state.is_unread(1, now - 3600, now));
+        assert!(state.is_unread(1, now - 60, now));
+        // Marking an older upload never moves the mark back
+        state.mark_read(1, now - 7200);
+        assert!(!state.is_unread(1, now - 3600, now));
+    }
+}
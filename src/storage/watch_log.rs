@@ -0,0 +1,128 @@
+//! Local watch log written on every playback, summarized by the stats page
+
+use super::get_config_dir;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Oldest entries are dropped beyond this
+const MAX_ENTRIES: usize = 10_000;
+
+/// One playback started from the app
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchEntry {
+    pub bvid: String,
+    pub title: String,
+    pub author: String,
+    pub author_mid: i64,
+    /// 分区名
+    #[serde(default)]
+    pub tname: String,
+    /// Length of the played video / part in seconds
+    #[serde(default)]
+    pub duration: i64,
+    /// Unix timestamp
+    pub played_at: i64,
+}
+
+/// Aggregated numbers for the stats page
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WatchSummary {
+    pub plays: usize,
+    pub total_duration: i64,
+    /// (分区, 播放次数), most played first; empty name when unknown
+    pub categories: Vec<(String, usize)>,
+    /// (UP主, 播放次数), most played first
+    pub top_ups: Vec<(String, usize)>,
+}
+
+impl WatchSummary {
+    pub fn from_entries(entries: &[WatchEntry]) -> Self {
+        let mut categories: HashMap<&str, usize> = HashMap::new();
+        let mut ups: HashMap<i64, (&str, usize)> = HashMap::new();
+        for entry in entries {
+            *categories.entry(entry.tname.as_str()).or_default() += 1;
+            // Keyed by mid so renamed UPs are counted once, under the latest name
+            let up = ups.entry(entry.author_mid).or_insert((&entry.author, 0));
+            up.0 = &entry.author;
+            up.1 += 1;
+        }
+
+        Self {
+            plays: entries.len(),
+            total_duration: entries.iter().map(|e| e.duration.max(0)).sum(),
+            categories: ranked(categories.into_iter()),
+            top_ups: ranked(ups.into_values()),
+        }
+    }
+}
+
+/// Sort by count descending, then by name for a stable order
+fn ranked<'a>(counts: impl Iterator<Item = (&'a str, usize)>) -> Vec<(String, usize)> {
+    let mut list: Vec<(String, usize)> = counts.map(|(k, v)| (k.to_string(), v)).collect();
+    list.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    list
+}
+
+/// Get the watch log file path
+fn get_watch_log_path() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("watch_log.json"))
+}
+
+/// Load all recorded playbacks, empty if none yet
+pub fn load_watch_log() -> Result<Vec<WatchEntry>> {
+    let path = get_watch_log_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Append one playback to the log
+pub fn append_watch_entry(entry: WatchEntry) -> Result<()> {
+    let mut entries = load_watch_log().unwrap_or_default();
+    entries.push(entry);
+    if entries.len() > MAX_ENTRIES {
+        entries.drain(..entries.len() - MAX_ENTRIES);
+    }
+    fs::write(get_watch_log_path()?, serde_json::to_string(&entries)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(author: &str, author_mid: i64, tname: &str, duration: i64) -> WatchEntry {
+        WatchEntry {
+            bvid: "BV1xx411c7mD".to_string(),
+            title: String::new(),
+            author: author.to_string(),
+            author_mid,
+            tname: tname.to_string(),
+            duration,
+            played_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_summary_ranks_categories_and_ups() {
+        let entries = vec![
+            entry("老番茄", 1, "搞笑", 600),
+            entry("某幻君", 2, "游戏", 300),
+            entry("老番茄", 1, "游戏", 100),
+            entry("老番茄改名", 1, "", 0),
+        ];
+        let summary = WatchSummary::from_entries(&entries);
+        assert_eq!(summary.plays, 4);
+        assert_eq!(summary.total_duration, 1000);
+        assert_eq!(summary.categories[0], ("游戏".to_string(), 2));
+        assert_eq!(
+            summary.top_ups,
+            vec![("老番茄改名".to_string(), 3), ("某幻君".to_string(), 1)]
+        );
+    }
+}
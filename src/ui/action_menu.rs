@@ -0,0 +1,163 @@
+//! Video action popup shared by list pages (play / audio / queue / download / watch later)
+
+use super::{clear_popup, Theme};
+use crate::app::AppAction;
+use crate::i18n::t;
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Actions available for a video
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoAction {
+    /// 立即播放
+    Play,
+    /// 仅音频
+    AudioOnly,
+    /// 加入播放队列
+    Enqueue,
+    /// 下载
+    Download,
+    /// 稍后再看
+    WatchLater,
+}
+
+impl VideoAction {
+    pub fn all() -> &'static [VideoAction] {
+        &[
+            VideoAction::Play,
+            VideoAction::AudioOnly,
+            VideoAction::Enqueue,
+            VideoAction::Download,
+            VideoAction::WatchLater,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VideoAction::Play => t("▶ 立即播放"),
+            VideoAction::AudioOnly => t("🎵 仅音频"),
+            VideoAction::Enqueue => t("➕ 加入播放队列"),
+            VideoAction::Download => t("⬇ 下载"),
+            VideoAction::WatchLater => t("🕒 稍后再看"),
+        }
+    }
+}
+
+/// Popup menu listing [`VideoAction`]s for one video
+pub struct ActionMenu {
+    pub bvid: String,
+    pub aid: i64,
+    pub title: String,
+    selected: usize,
+}
+
+impl ActionMenu {
+    pub fn new(bvid: String, aid: i64, title: String) -> Self {
+        Self {
+            bvid,
+            aid,
+            title,
+            selected: 0,
+        }
+    }
+
+    fn run(&self, action: VideoAction) -> Option<AppAction> {
+        Some(AppAction::RunVideoAction {
+            bvid: self.bvid.clone(),
+            aid: self.aid,
+            action,
+        })
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        let actions = VideoAction::all();
+        if keys.matches_back(key) || keys.matches_quit(key) {
+            return Some(AppAction::CloseActionMenu);
+        }
+        if keys.matches_up(key) {
+            self.selected = self.selected.saturating_sub(1);
+            return Some(AppAction::None);
+        }
+        if keys.matches_down(key) {
+            if self.selected + 1 < actions.len() {
+                self.selected += 1;
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_confirm(key) || keys.matches_play(key) {
+            return self.run(actions[self.selected]);
+        }
+        // Number shortcuts 1..=N
+        if let KeyCode::Char(c) = key {
+            if let Some(idx) = c.to_digit(10).and_then(|d| (d as usize).checked_sub(1)) {
+                if let Some(action) = actions.get(idx) {
+                    return self.run(*action);
+                }
+            }
+        }
+        Some(AppAction::None)
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let actions = VideoAction::all();
+        let width = 36.min(area.width);
+        let height = (actions.len() as u16 + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+
+        clear_popup(frame, popup);
+
+        let max_title = (width as usize).saturating_sub(6);
+        let title: String = if self.title.chars().count() > max_title {
+            self.title
+                .chars()
+                .take(max_title.saturating_sub(1))
+                .collect::<String>()
+                + "…"
+        } else {
+            self.title.clone()
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                format!(" {} ", title),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                t(" 1-5 快选  Esc 取消 "),
+                Style::default().fg(theme.fg_muted),
+            )));
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let items: Vec<ListItem> = actions
+            .iter()
+            .enumerate()
+            .map(|(idx, action)| {
+                let is_selected = idx == self.selected;
+                let style = if is_selected {
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.selection_bg)
+                } else {
+                    Style::default().fg(theme.fg_secondary)
+                };
+                ListItem::new(format!(" {}. {}", idx + 1, action.label())).style(style)
+            })
+            .collect();
+
+        let list = List::new(items);
+        frame.render_widget(list, inner.inner(Margin::new(0, 1)));
+    }
+}
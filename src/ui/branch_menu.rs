@@ -0,0 +1,115 @@
+//! Story choice popup shown when a node of an interactive video ends
+
+use super::{clear_popup, Theme};
+use crate::api::interactive::StoryChoice;
+use crate::app::AppAction;
+use crate::i18n::t;
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Popup listing the branches of an interactive video node
+pub struct BranchMenu {
+    pub title: String,
+    pub choices: Vec<StoryChoice>,
+    selected: usize,
+}
+
+impl BranchMenu {
+    pub fn new(title: String, choices: Vec<StoryChoice>) -> Self {
+        Self {
+            title,
+            choices,
+            selected: 0,
+        }
+    }
+
+    fn choose(&self, idx: usize) -> Option<AppAction> {
+        self.choices
+            .get(idx)
+            .map(|choice| AppAction::ChooseBranch(choice.clone()))
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if keys.matches_back(key) || keys.matches_quit(key) {
+            return Some(AppAction::CloseBranchMenu);
+        }
+        if keys.matches_up(key) {
+            self.selected = self.selected.saturating_sub(1);
+            return Some(AppAction::None);
+        }
+        if keys.matches_down(key) {
+            if self.selected + 1 < self.choices.len() {
+                self.selected += 1;
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_confirm(key) || keys.matches_play(key) {
+            return self.choose(self.selected);
+        }
+        // Number shortcuts 1..=N
+        if let KeyCode::Char(c) = key {
+            if let Some(idx) = c.to_digit(10).and_then(|d| (d as usize).checked_sub(1)) {
+                if let Some(action) = self.choose(idx) {
+                    return Some(action);
+                }
+            }
+        }
+        Some(AppAction::None)
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = 48.min(area.width);
+        let height = (self.choices.len() as u16 + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+
+        clear_popup(frame, popup);
+
+        let title = if self.title.is_empty() {
+            t("选择剧情分支")
+        } else {
+            self.title.as_str()
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                format!(" 🎮 {} ", title),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                t(" 数字键快选  Esc 结束互动 "),
+                Style::default().fg(theme.fg_muted),
+            )));
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let items: Vec<ListItem> = self
+            .choices
+            .iter()
+            .enumerate()
+            .map(|(idx, choice)| {
+                let style = if idx == self.selected {
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.selection_bg)
+                } else {
+                    Style::default().fg(theme.fg_secondary)
+                };
+                ListItem::new(format!(" {}. {}", idx + 1, choice.option)).style(style)
+            })
+            .collect();
+
+        frame.render_widget(List::new(items), inner.inner(Margin::new(0, 1)));
+    }
+}
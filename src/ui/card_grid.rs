@@ -0,0 +1,501 @@
+//! Generic virtual grid of cover cards: selection, scrolling, click hit-testing
+//! and async cover loading, with card rendering left to the page
+
+use super::cover_pool;
+use super::hit_map::HitMap;
+use super::scrollbar::DragScrollbar;
+use super::Theme;
+use ratatui::{
+    crossterm::event::{KeyCode, MouseEvent},
+    prelude::*,
+    widgets::Clear,
+};
+use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Last frame in which something was drawn over the grids or the screen was resized
+static COVERS_INVALIDATED: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// A card that can be laid out by [`CardGrid`]
+pub trait GridCard {
+    /// Cover to download, None to skip (no cover, collapsed, ...)
+    fn cover_url(&self) -> Option<String>;
+    fn has_cover(&self) -> bool;
+    fn set_cover(&mut self, cover: StatefulProtocol);
+    /// Called with the cover when it turned out to be portrait (竖屏)
+    fn set_portrait(&mut self, _portrait: bool) {}
+}
+
+/// The covers on screen may have been drawn over (popup, toast, resize), so every cover is
+/// drawn again in the next frame
+pub fn invalidate_covers(frame: &Frame) {
+    COVERS_INVALIDATED.store(frame.count(), Ordering::Relaxed);
+}
+
+/// Clear `area` for a popup drawn over the page; the covers are drawn again once it closes
+pub fn clear_popup(frame: &mut Frame, area: Rect) {
+    frame.render_widget(Clear, area);
+    invalidate_covers(frame);
+}
+
+/// Where a card's cover was drawn last, reset whenever the card gets a new cover
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoverPlacement {
+    /// (area, frame count)
+    drawn: Option<(Rect, usize)>,
+}
+
+impl CoverPlacement {
+    /// Whether the cover is still on screen at `area`: drawn there in the previous frame
+    /// and nothing drawn over it since
+    fn is_on_screen(&self, area: Rect, frame: usize, invalidated: usize) -> bool {
+        let Some(previous) = frame.checked_sub(1) else {
+            return false;
+        };
+        self.drawn == Some((area, previous)) && invalidated != previous
+    }
+}
+
+/// Draw a cover unless it is already on screen at the same place. Then its cells are only
+/// marked skip, so the buffer diff neither re-sends the image data (Sixel / Kitty) nor
+/// blanks it out, and the protocol isn't touched at all
+pub fn render_cover(
+    frame: &mut Frame,
+    area: Rect,
+    cover: &mut StatefulProtocol,
+    placement: &mut CoverPlacement,
+) {
+    let count = frame.count();
+    if placement.is_on_screen(area, count, COVERS_INVALIDATED.load(Ordering::Relaxed)) {
+        let buf = frame.buffer_mut();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_skip(true);
+                }
+            }
+        }
+    } else {
+        frame.render_stateful_widget(StatefulImage::new(), area, cover);
+    }
+    placement.drawn = Some((area, count));
+}
+
+/// 1-9 for the quick-open keys
+fn quick_open_number(key: KeyCode) -> Option<usize> {
+    match key {
+        KeyCode::Char(c @ '1'..='9') => c.to_digit(10).map(|d| d as usize),
+        _ => None,
+    }
+}
+
+/// Message for completed cover download
+pub struct CoverResult {
+    /// Grid generation the download was started in, stale results are dropped
+    generation: u64,
+    index: usize,
+    protocol: StatefulProtocol,
+    portrait: bool,
+}
+
+pub struct CardGrid<T> {
+    pub cards: Vec<T>,
+    pub selected_index: usize,
+    pub scroll_row: usize,
+    pub columns: usize,
+    pub card_height: u16,
+    pub cached_visible_rows: usize,
+    pub pending_downloads: HashSet<usize>,
+    picker: Arc<Picker>,
+    cover_tx: mpsc::Sender<CoverResult>,
+    cover_rx: mpsc::Receiver<CoverResult>,
+    /// Bumped on clear so covers of the previous cards never land on new ones
+    generation: u64,
+    /// Card areas of the last render, used for click hit-testing
+    card_areas: HitMap<usize>,
+    scrollbar: DragScrollbar,
+    /// 在可见卡片角落显示 1-9，配合 [`Self::select_visible`] 用数字键快速打开
+    pub number_hints: bool,
+}
+
+impl<T: GridCard> CardGrid<T> {
+    pub fn new(columns: usize, card_height: u16) -> Self {
+        // Try to detect terminal graphics protocol (Kitty/Sixel/iTerm2)
+        // Fall back to halfblocks if detection fails
+        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
+        let (cover_tx, cover_rx) = mpsc::channel(32);
+
+        Self {
+            cards: Vec::new(),
+            selected_index: 0,
+            scroll_row: 0,
+            columns,
+            card_height,
+            cached_visible_rows: 3,
+            pending_downloads: HashSet::new(),
+            picker,
+            cover_tx,
+            cover_rx,
+            generation: 0,
+            card_areas: HitMap::new(),
+            scrollbar: DragScrollbar::default(),
+            number_hints: false,
+        }
+    }
+
+    /// Show 1-9 on the visible cards and open them with the number keys
+    pub fn with_number_hints(mut self) -> Self {
+        self.number_hints = true;
+        self
+    }
+
+    pub fn clear(&mut self) {
+        self.cards.clear();
+        self.selected_index = 0;
+        self.scroll_row = 0;
+        self.pending_downloads.clear();
+        self.card_areas.clear();
+        self.generation += 1;
+    }
+
+    /// Replace all cards and reset the selection
+    pub fn set_cards(&mut self, cards: Vec<T>) {
+        self.clear();
+        self.cards = cards;
+    }
+
+    /// Take a card out, keeping the selection in range; covers still downloading are dropped
+    /// since the cards after it move up
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.cards.len() {
+            return None;
+        }
+        let card = self.cards.remove(index);
+        self.pending_downloads.clear();
+        self.generation += 1;
+        self.select(self.selected_index);
+        Some(card)
+    }
+
+    /// Put a card back at `index` (clamped), the counterpart of [`Self::remove`]
+    pub fn insert(&mut self, index: usize, card: T) {
+        self.cards.insert(index.min(self.cards.len()), card);
+        self.pending_downloads.clear();
+        self.generation += 1;
+        self.select(self.selected_index);
+    }
+
+    /// Put cards above the loaded ones, keeping the same card selected and where it was on
+    /// screen; loaded covers stay, the ones still downloading are dropped like in [`Self::remove`]
+    pub fn insert_front(&mut self, cards: Vec<T>) {
+        let added = cards.len();
+        if added == 0 {
+            return;
+        }
+        let old_row = self.selected_row();
+        self.cards.splice(0..0, cards);
+        self.pending_downloads.clear();
+        self.generation += 1;
+        if self.cards.len() > added {
+            self.selected_index += added;
+            self.scroll_row += self.selected_row() - old_row;
+        }
+    }
+
+    pub fn push(&mut self, card: T) {
+        self.cards.push(card);
+    }
+
+    pub fn visible_rows(&self, height: u16) -> usize {
+        let available_height = height.saturating_sub(1);
+        (available_height / self.card_height).max(1) as usize
+    }
+
+    /// Select a card by index, clamped to the loaded cards
+    pub fn select(&mut self, index: usize) {
+        self.selected_index = index.min(self.cards.len().saturating_sub(1));
+        self.update_scroll(self.cached_visible_rows);
+    }
+
+    pub fn selected_row(&self) -> usize {
+        self.selected_index / self.columns
+    }
+
+    pub fn total_rows(&self) -> usize {
+        self.cards.len().div_ceil(self.columns)
+    }
+
+    pub fn update_scroll(&mut self, visible_rows: usize) {
+        let current_row = self.selected_row();
+        if current_row < self.scroll_row {
+            self.scroll_row = current_row;
+        } else if current_row >= self.scroll_row + visible_rows {
+            self.scroll_row = current_row + 1 - visible_rows;
+        }
+    }
+
+    pub fn move_down(&mut self) -> bool {
+        let new_idx = self.selected_index + self.columns;
+        if new_idx < self.cards.len() {
+            self.selected_index = new_idx;
+            self.update_scroll(self.cached_visible_rows);
+            return true;
+        }
+        false
+    }
+
+    pub fn move_up(&mut self) -> bool {
+        if !self.cards.is_empty() && self.selected_index >= self.columns {
+            self.selected_index -= self.columns;
+            self.update_scroll(self.cached_visible_rows);
+            return true;
+        }
+        false
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        if self.selected_index + 1 < self.cards.len() {
+            self.selected_index += 1;
+            self.update_scroll(self.cached_visible_rows);
+            return true;
+        }
+        false
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        if !self.cards.is_empty() && self.selected_index > 0 {
+            self.selected_index -= 1;
+            self.update_scroll(self.cached_visible_rows);
+            return true;
+        }
+        false
+    }
+
+    /// Check if near bottom for pagination
+    pub fn is_near_bottom(&self, visible_rows: usize) -> bool {
+        if self.cards.is_empty() {
+            return false;
+        }
+        let current_row = self.selected_row();
+        let total = self.total_rows();
+        current_row + 2 >= total.saturating_sub(1) && total > visible_rows
+    }
+
+    pub fn selected_card(&self) -> Option<&T> {
+        self.cards.get(self.selected_index)
+    }
+
+    /// Index of the card rendered at a screen position
+    pub fn index_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.card_areas
+            .at(column, row)
+            .filter(|idx| *idx < self.cards.len())
+    }
+
+    /// Select the n-th card on screen for a 1-9 key press.
+    /// Returns true when a card was selected, so the page can open it right away
+    pub fn select_visible(&mut self, key: KeyCode) -> bool {
+        if !self.number_hints {
+            return false;
+        }
+        let Some(n) = quick_open_number(key) else {
+            return false;
+        };
+        match self.card_areas.get(n - 1) {
+            Some((_, idx)) => {
+                self.select(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Click or drag on the scrollbar: select the same column in the row under the mouse.
+    /// Returns true when the event was consumed by the scrollbar
+    pub fn handle_scrollbar_mouse(&mut self, event: &MouseEvent) -> bool {
+        let Some(row) = self.scrollbar.handle_mouse(event) else {
+            return false;
+        };
+        let column = self.selected_index % self.columns;
+        self.select(row * self.columns + column);
+        true
+    }
+
+    /// Start background downloads for visible covers plus `extra_rows` below them
+    pub fn start_cover_downloads_with_prefetch(&mut self, extra_rows: usize) {
+        let start = self.scroll_row * self.columns;
+        let prefetch_rows = self.cached_visible_rows + extra_rows;
+        let end = (start + self.columns * prefetch_rows).min(self.cards.len());
+
+        for idx in start..end {
+            let card = &self.cards[idx];
+            if card.has_cover() || self.pending_downloads.contains(&idx) {
+                continue;
+            }
+            let Some(url) = card.cover_url() else {
+                continue;
+            };
+
+            self.pending_downloads.insert(idx);
+            let tx = self.cover_tx.clone();
+            let picker = Arc::clone(&self.picker);
+            let generation = self.generation;
+
+            tokio::spawn(async move {
+                if let Some(img) = cover_pool::download_image(&url).await {
+                    let (img, portrait) = cover_pool::pad_portrait(img);
+                    let protocol = picker.new_resize_protocol(img);
+                    let _ = tx
+                        .send(CoverResult {
+                            generation,
+                            index: idx,
+                            protocol,
+                            portrait,
+                        })
+                        .await;
+                }
+            });
+        }
+    }
+
+    /// Poll for completed cover downloads
+    pub fn poll_cover_results(&mut self) {
+        while let Ok(result) = self.cover_rx.try_recv() {
+            if result.generation != self.generation {
+                continue;
+            }
+            if let Some(card) = self.cards.get_mut(result.index) {
+                card.set_cover(result.protocol);
+                card.set_portrait(result.portrait);
+                self.pending_downloads.remove(&result.index);
+            }
+        }
+    }
+
+    /// Lay out the visible rows and draw each card with `render_card(frame, area, card, is_selected)`
+    pub fn render_with(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        mut render_card: impl FnMut(&mut Frame, Rect, &mut T, bool),
+    ) {
+        let visible_rows = self.visible_rows(area.height);
+        self.cached_visible_rows = visible_rows;
+        // Keep the selection on screen after a resize
+        self.update_scroll(visible_rows);
+
+        let total_rows = self.total_rows();
+        self.scrollbar.render(
+            frame,
+            area,
+            total_rows,
+            self.scroll_row,
+            visible_rows,
+            theme,
+        );
+        let area = DragScrollbar::content_area(area, total_rows, visible_rows);
+
+        // Every row gets exactly card_height: with Min the spare lines went to the last row,
+        // so a card scrolling into or out of it changed size and its cover was re-encoded and
+        // retransmitted (visible as flicker with Sixel / Kitty). Same size means the protocol
+        // keeps its encoded data and the unchanged cells are skipped by the buffer diff
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(self.card_height); visible_rows])
+            .split(area);
+        let col_constraints = vec![Constraint::Ratio(1, self.columns as u32); self.columns];
+
+        self.card_areas.clear();
+        for (row_offset, row_area) in rows.iter().enumerate() {
+            let start_idx = (self.scroll_row + row_offset) * self.columns;
+            if start_idx >= self.cards.len() {
+                break;
+            }
+
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(col_constraints.clone())
+                .split(*row_area);
+
+            for (col_idx, col_area) in cols.iter().enumerate() {
+                let idx = start_idx + col_idx;
+                if idx >= self.cards.len() {
+                    break;
+                }
+                self.card_areas.push(*col_area, idx);
+            }
+        }
+
+        for (position, (card_area, idx)) in self.card_areas.iter().enumerate() {
+            let is_selected = idx == self.selected_index;
+            render_card(frame, card_area, &mut self.cards[idx], is_selected);
+
+            // Quick-open number on the bottom-left border
+            if self.number_hints && position < 9 && card_area.height > 1 && card_area.width > 4 {
+                let hint_area = Rect::new(card_area.x + 1, card_area.bottom() - 1, 3, 1);
+                let hint = Span::styled(
+                    format!(" {} ", position + 1),
+                    Style::default().fg(if is_selected {
+                        theme.bilibili_pink
+                    } else {
+                        theme.fg_muted
+                    }),
+                );
+                frame.render_widget(hint, hint_area);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::video_card::VideoCard;
+
+    #[test]
+    fn test_insert_front_keeps_selection() {
+        let card = |title: &str| {
+            VideoCard::new(
+                None,
+                None,
+                title.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                None,
+            )
+        };
+        let mut grid: CardGrid<VideoCard> = CardGrid::new(3, 12);
+        grid.set_cards((0..9).map(|i| card(&i.to_string())).collect());
+        grid.selected_index = 7;
+        grid.scroll_row = 1;
+
+        grid.insert_front(vec![card("a"), card("b")]);
+        assert_eq!(grid.cards.len(), 11);
+        assert_eq!(grid.selected_card().unwrap().title, "7");
+        // Index 7 (row 2) moved to 9 (row 3), so the view scrolls one row with it
+        assert_eq!(grid.scroll_row, 2);
+    }
+
+    #[test]
+    fn test_cover_skipped_only_when_unchanged() {
+        let area = Rect::new(0, 0, 20, 6);
+        let mut placement = CoverPlacement::default();
+        // Never drawn, or a new cover
+        assert!(!placement.is_on_screen(area, 5, usize::MAX));
+
+        placement.drawn = Some((area, 4));
+        assert!(placement.is_on_screen(area, 5, usize::MAX));
+        // Moved or resized
+        assert!(!placement.is_on_screen(Rect::new(0, 6, 20, 6), 5, usize::MAX));
+        assert!(!placement.is_on_screen(Rect::new(0, 0, 20, 5), 5, usize::MAX));
+        // Not drawn in the previous frame (another page was shown)
+        assert!(!placement.is_on_screen(area, 7, usize::MAX));
+        // A popup or toast covered it in the previous frame
+        assert!(!placement.is_on_screen(area, 5, 4));
+    }
+}
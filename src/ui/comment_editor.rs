@@ -0,0 +1,204 @@
+//! Multi-line comment input with a character counter and a preview step before sending
+
+use super::Theme;
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyModifiers},
+    prelude::*,
+    widgets::*,
+};
+
+/// B 站评论字数上限
+pub const MAX_COMMENT_CHARS: usize = 1000;
+
+/// Max text rows shown before the editor starts scrolling
+const MAX_VISIBLE_ROWS: u16 = 6;
+
+/// What the page should do after a key press in the editor
+pub enum EditorEvent {
+    None,
+    Cancel,
+    Submit(String),
+}
+
+#[derive(Default)]
+pub struct CommentEditor {
+    pub buffer: String,
+    /// 发送前的预览确认
+    pub previewing: bool,
+}
+
+impl CommentEditor {
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.previewing = false;
+    }
+
+    /// Put back a comment that failed to send, ready to edit
+    pub fn restore(&mut self, message: String) {
+        self.buffer = message;
+        self.previewing = false;
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.buffer.chars().count()
+    }
+
+    pub fn is_over_limit(&self) -> bool {
+        self.char_count() > MAX_COMMENT_CHARS
+    }
+
+    /// Shift+Enter / Alt+Enter inserts a newline; Enter opens the preview, Enter again sends
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> EditorEvent {
+        if self.previewing {
+            return match key {
+                KeyCode::Enter => {
+                    let message = self.buffer.trim_end().to_string();
+                    self.clear();
+                    EditorEvent::Submit(message)
+                }
+                KeyCode::Esc | KeyCode::Backspace => {
+                    self.previewing = false;
+                    EditorEvent::None
+                }
+                _ => EditorEvent::None,
+            };
+        }
+
+        match key {
+            KeyCode::Esc => {
+                self.clear();
+                EditorEvent::Cancel
+            }
+            KeyCode::Enter if modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::ALT) => {
+                self.buffer.push('\n');
+                EditorEvent::None
+            }
+            KeyCode::Enter => {
+                if !self.buffer.trim().is_empty() && !self.is_over_limit() {
+                    self.previewing = true;
+                }
+                EditorEvent::None
+            }
+            KeyCode::Backspace => {
+                self.buffer.pop();
+                EditorEvent::None
+            }
+            KeyCode::Char(c) => {
+                self.buffer.push(c);
+                EditorEvent::None
+            }
+            _ => EditorEvent::None,
+        }
+    }
+
+    /// Text rows needed at the given inner width
+    fn text_rows(&self, width: u16) -> u16 {
+        let width = width.max(1) as usize;
+        let text = format!("{}_", self.buffer);
+        text.split('\n')
+            .map(|line| Line::raw(line).width().max(1).div_ceil(width) as u16)
+            .sum()
+    }
+
+    /// Total height of the editor box, including borders
+    pub fn height(&self, width: u16) -> u16 {
+        let rows = self.text_rows(width.saturating_sub(2));
+        let max_rows = if self.previewing {
+            MAX_VISIBLE_ROWS * 2
+        } else {
+            MAX_VISIBLE_ROWS
+        };
+        rows.clamp(1, max_rows) + 2
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        self.draw_titled(frame, area, theme, t(" ✏️ 发表评论 "), t(" 👀 预览评论 "));
+    }
+
+    /// Draw with custom titles for editing and previewing, e.g. when composing a dynamic
+    pub fn draw_titled(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        title: &str,
+        preview_title: &str,
+    ) {
+        let over = self.is_over_limit();
+        let accent = if over {
+            theme.error
+        } else if self.previewing {
+            theme.info
+        } else {
+            theme.bilibili_pink
+        };
+
+        let counter = Span::styled(
+            format!(" {}/{} ", self.char_count(), MAX_COMMENT_CHARS),
+            if over {
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.fg_muted)
+            },
+        );
+
+        let title = if self.previewing {
+            preview_title
+        } else {
+            title
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(accent))
+            .title(Span::styled(
+                title,
+                Style::default().fg(accent).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(counter).right_aligned());
+
+        let text = if self.previewing {
+            self.buffer.trim_end().to_string()
+        } else {
+            format!("{}_", self.buffer)
+        };
+
+        // Keep the cursor line visible while typing
+        let inner_height = area.height.saturating_sub(2);
+        let scroll = if self.previewing {
+            0
+        } else {
+            self.text_rows(area.width.saturating_sub(2))
+                .saturating_sub(inner_height)
+        };
+
+        let input = Paragraph::new(text)
+            .style(Style::default().fg(theme.fg_primary))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0))
+            .block(block);
+        frame.render_widget(input, area);
+    }
+
+    /// Help line shown under the editor
+    pub fn help_text(&self, keys: &Keybindings) -> String {
+        if self.previewing {
+            tf("[{}] 确认发送  [{}] 返回编辑", &[&keys.confirm, &keys.back])
+        } else if self.is_over_limit() {
+            tf(
+                "超出 {} 字上限，无法发送  [{}] 取消",
+                &[&MAX_COMMENT_CHARS, &keys.back],
+            )
+        } else {
+            tf(
+                "[{}] 预览  [Shift/Alt+Enter] 换行  [{}] 取消",
+                &[&keys.confirm, &keys.back],
+            )
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Shared cover / image download pool for all pages
+
+use image::{imageops, DynamicImage, RgbaImage};
+use tokio::sync::Semaphore;
+
+/// Max image downloads in flight across all pages, so covers don't starve API requests
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Portrait covers are scaled down to this height before padding, the terminal never needs more
+const PORTRAIT_MAX_HEIGHT: u32 = 360;
+
+static DOWNLOAD_SLOTS: Semaphore = Semaphore::const_new(MAX_CONCURRENT_DOWNLOADS);
+
+/// Download and decode an image, waiting for a free slot in the shared pool
+pub async fn download_image(url: &str) -> Option<DynamicImage> {
+    let _permit = DOWNLOAD_SLOTS.acquire().await.ok()?;
+    let response = reqwest::get(crate::api::client::image_url(url))
+        .await
+        .ok()?;
+    let bytes = response.bytes().await.ok()?;
+    image::load_from_memory(&bytes).ok()
+}
+
+/// Pad a portrait (竖屏) cover with transparent margins to 16:9, centered, so it is not
+/// squeezed into landscape cards. Returns the image and whether it was portrait
+pub fn pad_portrait(img: DynamicImage) -> (DynamicImage, bool) {
+    if img.height() <= img.width() {
+        return (img, false);
+    }
+    let img = if img.height() > PORTRAIT_MAX_HEIGHT {
+        img.resize(
+            u32::MAX,
+            PORTRAIT_MAX_HEIGHT,
+            imageops::FilterType::Triangle,
+        )
+    } else {
+        img
+    };
+    let width = img.height() * 16 / 9;
+    let mut canvas = RgbaImage::new(width, img.height());
+    let x = (width - img.width()) / 2;
+    imageops::overlay(&mut canvas, &img.to_rgba8(), i64::from(x), 0);
+    (DynamicImage::ImageRgba8(canvas), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_portrait_centers_on_landscape_canvas() {
+        let (padded, portrait) = pad_portrait(DynamicImage::new_rgba8(90, 160));
+        assert!(portrait);
+        assert_eq!((padded.width(), padded.height()), (284, 160));
+
+        let (same, portrait) = pad_portrait(DynamicImage::new_rgba8(160, 90));
+        assert!(!portrait);
+        assert_eq!((same.width(), same.height()), (160, 90));
+    }
+}
@@ -1,17 +1,22 @@
 //! Dynamic feed page with video card grid display
 
-use super::video_card::{VideoCard, VideoCardGrid};
-use super::{Component, Theme};
+use super::dynamic_composer::{ComposerEvent, DynamicComposer};
+use super::hit_map::HitMap;
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
-use crate::api::dynamic::DynamicItem;
+use crate::api::dynamic::{DynamicItem, UpListItem};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, Session, SessionPage, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
     crossterm::event::{KeyCode, MouseButton, MouseEvent},
     prelude::*,
     widgets::*,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Dynamic feed tab types
@@ -28,9 +33,9 @@ pub enum DynamicTab {
 impl DynamicTab {
     pub fn label(&self) -> &str {
         match self {
-            DynamicTab::All => "全部",
-            DynamicTab::Videos => "视频",
-            DynamicTab::Images => "图文",
+            DynamicTab::All => t("全部"),
+            DynamicTab::Videos => t("视频"),
+            DynamicTab::Images => t("图文"),
         }
     }
 
@@ -38,6 +43,18 @@ impl DynamicTab {
         [DynamicTab::All, DynamicTab::Videos, DynamicTab::Images]
     }
 
+    /// Next / previous tab, wrapping around
+    pub fn cycle(self, forward: bool) -> DynamicTab {
+        let tabs = Self::all_tabs();
+        let index = tabs.iter().position(|tab| *tab == self).unwrap_or(0);
+        let len = tabs.len();
+        tabs[if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        }]
+    }
+
     /// Get the API feed type parameter for this tab
     pub fn get_feed_type(&self) -> Option<&str> {
         match self {
@@ -48,6 +65,22 @@ impl DynamicTab {
     }
 }
 
+/// UPs shown at once in the selection bar, besides "全部"
+const VISIBLE_UPS: usize = 10;
+/// Page size when fetching the full followings list
+const FOLLOWINGS_PAGE_SIZE: i32 = 50;
+/// Stop after this many pages of followings
+const MAX_FOLLOWINGS_PAGES: i32 = 20;
+
+/// Clickable part of the UP selection bar
+#[derive(Debug, Clone, Copy)]
+enum UpBarHit {
+    PagePrev,
+    PageNext,
+    /// Index into the bar, 0 is "全部"
+    Up(usize),
+}
+
 pub struct DynamicPage {
     pub grid: VideoCardGrid,
     pub loading: bool,
@@ -57,11 +90,33 @@ pub struct DynamicPage {
     pub loading_more: bool,
     pub current_tab: DynamicTab,
     pub tab_offsets: HashMap<DynamicTab, Option<String>>,
-    pub up_list: Vec<crate::api::dynamic::UpListItem>,
+    pub up_list: Vec<UpListItem>,
     pub selected_up_index: usize,
     pub loading_up_list: bool,
     pub up_list_scroll_offset: usize,
+    /// 常看 UP (portal)
+    frequent_ups: Vec<UpListItem>,
+    /// 全部关注, fetched the first time it is shown
+    followings: Option<Vec<UpListItem>>,
+    /// Whether the bar shows all followings instead of the frequent UPs
+    pub show_followings: bool,
+    /// Name filter being typed, None when not filtering
+    up_filter: Option<String>,
+    /// First UP (bar index) matching the filter
+    up_filter_match: Option<usize>,
     pub dynamic_items: Vec<DynamicItem>,
+    /// Followed UPs that are live now, their cards get a 直播中 badge
+    live_mids: HashSet<i64>,
+    /// Follow state of authors asked for or changed on this page
+    follow_states: HashMap<i64, bool>,
+    /// Newest item id of the "全部" feed, for fetching only new items on return
+    pub update_baseline: Option<String>,
+    /// New items inserted by the last incremental refresh, shown in the title
+    new_items: usize,
+    /// Screen areas of the UP bar entries from the last draw
+    up_bar_hits: HitMap<UpBarHit>,
+    /// 发布动态弹窗
+    composer: Option<Box<DynamicComposer>>,
     last_click_time: Option<Instant>,
     last_click_index: Option<usize>,
 }
@@ -81,17 +136,284 @@ impl DynamicPage {
             selected_up_index: 0,
             loading_up_list: false,
             up_list_scroll_offset: 0,
+            frequent_ups: Vec::new(),
+            followings: None,
+            show_followings: false,
+            up_filter: None,
+            up_filter_match: None,
             dynamic_items: Vec::new(),
+            live_mids: HashSet::new(),
+            follow_states: HashMap::new(),
+            update_baseline: None,
+            new_items: 0,
+            up_bar_hits: HitMap::new(),
+            composer: None,
             last_click_time: None,
             last_click_index: None,
         }
     }
 
-    pub fn set_up_list(&mut self, up_list: Vec<crate::api::dynamic::UpListItem>) {
-        self.up_list = up_list;
+    /// Mark cards of UPs that are live right now, including the ones already loaded
+    pub fn set_live_mids(&mut self, mids: HashSet<i64>) {
+        self.live_mids = mids;
+        for (card, item) in self.grid.cards.iter_mut().zip(&self.dynamic_items) {
+            if item
+                .author_mid()
+                .is_some_and(|mid| self.live_mids.contains(&mid))
+            {
+                card.add_badge(CardBadge::Live);
+            }
+        }
+    }
+
+    /// Whether the user follows `mid`, None when not known on this page
+    pub fn following(&self, mid: i64) -> Option<bool> {
+        self.follow_states.get(&mid).copied()
+    }
+
+    /// Remember the follow state of `mid` and mark the author's cards when unfollowed
+    pub fn set_following(&mut self, mid: i64, following: bool) {
+        self.follow_states.insert(mid, following);
+        for (card, item) in self.grid.cards.iter_mut().zip(&self.dynamic_items) {
+            if item.author_mid() == Some(mid) {
+                if following {
+                    card.remove_badge(CardBadge::Unfollowed);
+                } else {
+                    card.add_badge(CardBadge::Unfollowed);
+                }
+            }
+        }
+    }
+
+    /// Card of an item shown in the current tab, None for other tabs and hidden titles. Items
+    /// are only kept along with their card, so `dynamic_items` stays aligned with the grid
+    fn shown_card(&self, item: &DynamicItem) -> Option<VideoCard> {
+        let in_tab = match self.current_tab {
+            DynamicTab::All => item.is_video() || item.is_draw() || item.is_opus(),
+            DynamicTab::Videos => item.is_video(),
+            DynamicTab::Images => item.is_draw() || item.is_opus(),
+        };
+        if !in_tab {
+            return None;
+        }
+        self.dynamic_card(item)
+            .filter(|card| !self.grid.blocklist.hides(&card.title))
+    }
+
+    /// Card of a dynamic with its 直播中 / 充电专属 / 未关注 badges
+    fn dynamic_card(&self, item: &DynamicItem) -> Option<VideoCard> {
+        let mut card = if item.is_video() {
+            VideoCard::new(
+                Some(item.video_bvid()?.to_string()),
+                None,
+                item.video_title().unwrap_or(t("无标题")).to_string(),
+                item.author_name().to_string(),
+                format!("▶ {}", item.video_play()),
+                item.video_duration().to_string(),
+                item.video_cover().map(|s| s.to_string()),
+            )
+        }
+        // Handle image dynamics (带图动态)
+        else if item.is_draw() {
+            let images = item.draw_images();
+            let image_url = images.first().map(|s| s.to_string());
+            let desc = item.desc_text().unwrap_or(t("图片动态"));
+            let image_count = if images.len() > 1 {
+                format!(" [{}P]", images.len())
+            } else {
+                String::new()
+            };
+
+            VideoCard::new(
+                None, // No bvid for images
+                None,
+                format!("{}{}", desc, image_count),
+                item.author_name().to_string(),
+                t("📷 图片动态").to_string(),
+                "".to_string(),
+                image_url,
+            )
+        }
+        // Handle text/opus dynamics (图文动态)
+        else if item.is_opus() {
+            let text = item.opus_text().unwrap_or(t("图文动态"));
+            let images = item.opus_images();
+            let image_url = images.first().map(|s| s.to_string());
+            let image_count = if !images.is_empty() {
+                format!(" [{}P]", images.len())
+            } else {
+                String::new()
+            };
+
+            VideoCard::new(
+                None,
+                None,
+                format!("{}{}", text, image_count),
+                item.author_name().to_string(),
+                t("📝 图文").to_string(),
+                "".to_string(),
+                image_url,
+            )
+        } else {
+            return None;
+        };
+
+        if item
+            .author_mid()
+            .is_some_and(|mid| self.live_mids.contains(&mid))
+        {
+            card.add_badge(CardBadge::Live);
+        }
+        if item
+            .author_mid()
+            .is_some_and(|mid| self.following(mid) == Some(false))
+        {
+            card.add_badge(CardBadge::Unfollowed);
+        }
+        if item.video_charge_only() {
+            card.add_badge(CardBadge::ChargeOnly);
+        }
+        Some(card)
+    }
+
+    /// Append an item of the current tab and its card
+    fn push_item(&mut self, item: DynamicItem) {
+        if let Some(card) = self.shown_card(&item) {
+            self.grid.add_card(card);
+            self.dynamic_items.push(item);
+        }
+    }
+
+    pub fn set_up_list(&mut self, up_list: Vec<UpListItem>) {
+        self.frequent_ups = up_list;
+        if !self.show_followings {
+            self.up_list = self.frequent_ups.clone();
+        }
         self.loading_up_list = false;
     }
 
+    /// Whether switching to all followings still has to fetch them
+    pub fn needs_followings(&self) -> bool {
+        !self.show_followings && self.followings.is_none()
+    }
+
+    /// Fetch the full followings list of `mid`, page by page
+    pub async fn load_followings(&mut self, api_client: &ApiClient, mid: i64) {
+        self.loading_up_list = true;
+        let mut followings = Vec::new();
+        for pn in 1..=MAX_FOLLOWINGS_PAGES {
+            let data = match api_client
+                .get_followings(mid, FOLLOWINGS_PAGE_SIZE, pn)
+                .await
+            {
+                Ok(data) => data,
+                Err(e) => {
+                    self.error_message = Some(tf("加载关注列表失败: {}", &[&e]));
+                    break;
+                }
+            };
+            let list = data.list.unwrap_or_default();
+            if list.is_empty() {
+                break;
+            }
+            followings.extend(list.into_iter().filter_map(|user| {
+                Some(UpListItem {
+                    mid: user.mid?,
+                    uname: user.uname.unwrap_or_default(),
+                    face: user.face.unwrap_or_default(),
+                    has_update: false,
+                    is_reserve_recall: false,
+                })
+            }));
+            if followings.len() >= data.total.unwrap_or(0) as usize {
+                break;
+            }
+        }
+        self.followings = Some(followings);
+        self.loading_up_list = false;
+    }
+
+    /// Switch the bar between frequent UPs and all followings, keeping the selected UP
+    /// when it is in both. Returns true when the selection was lost and the feed must reload
+    pub fn toggle_up_source(&mut self) -> bool {
+        let selected_mid = self.get_selected_up_mid();
+        self.show_followings = !self.show_followings;
+        self.up_list = if self.show_followings {
+            self.followings.clone().unwrap_or_default()
+        } else {
+            self.frequent_ups.clone()
+        };
+
+        let index = selected_mid
+            .and_then(|mid| self.up_list.iter().position(|up| up.mid == mid))
+            .map_or(0, |i| i + 1);
+        let lost = selected_mid.is_some() && index == 0;
+        self.selected_up_index = index;
+        self.up_list_scroll_offset = 0;
+        self.update_up_scroll();
+        lost
+    }
+
+    /// Typing a name to locate an UP in the bar
+    pub fn is_filtering(&self) -> bool {
+        self.up_filter.is_some()
+    }
+
+    /// Writing a new dynamic in the composer popup
+    pub fn is_composing(&self) -> bool {
+        self.composer.is_some()
+    }
+
+    /// Move the bar to the first UP whose name contains the filter
+    fn locate_up(&mut self) {
+        let query = self.up_filter.as_deref().unwrap_or("").to_lowercase();
+        self.up_filter_match = if query.is_empty() {
+            None
+        } else {
+            self.up_list
+                .iter()
+                .position(|up| up.uname.to_lowercase().contains(&query))
+                .map(|i| i + 1)
+        };
+        if let Some(index) = self.up_filter_match {
+            if index <= self.up_list_scroll_offset {
+                self.up_list_scroll_offset = index - 1;
+            } else if index > self.up_list_scroll_offset + VISIBLE_UPS {
+                self.up_list_scroll_offset = index - VISIBLE_UPS;
+            }
+        }
+    }
+
+    fn handle_filter_input(&mut self, key: KeyCode) -> Option<AppAction> {
+        let filter = self.up_filter.as_mut()?;
+        match key {
+            KeyCode::Esc => {
+                self.up_filter = None;
+                self.up_filter_match = None;
+                self.update_up_scroll();
+            }
+            KeyCode::Enter => {
+                self.up_filter = None;
+                if let Some(index) = self.up_filter_match.take() {
+                    if index != self.selected_up_index {
+                        return Some(AppAction::SelectUpMaster(index));
+                    }
+                }
+                self.update_up_scroll();
+            }
+            KeyCode::Backspace => {
+                filter.pop();
+                self.locate_up();
+            }
+            KeyCode::Char(c) => {
+                filter.push(c);
+                self.locate_up();
+            }
+            _ => {}
+        }
+        Some(AppAction::None)
+    }
+
     pub fn select_up(&mut self, index: usize) {
         if index <= self.up_list.len() {
             self.selected_up_index = index;
@@ -103,7 +425,6 @@ impl DynamicPage {
 
     /// Update scroll offset to keep selected UP visible
     fn update_up_scroll(&mut self) {
-        const VISIBLE_UPS: usize = 10;
         // selected_up_index 0 is "全部", so actual UP indices start from 1
         // up_list_scroll_offset is the first UP index (1-based) to show after "全部"
         if self.selected_up_index == 0 {
@@ -143,81 +464,10 @@ impl DynamicPage {
     pub fn set_feed(&mut self, items: Vec<DynamicItem>, offset: Option<String>, has_more: bool) {
         self.grid.clear();
         self.dynamic_items.clear();
+        self.new_items = 0;
 
-        // Process items based on current tab filter
-        for item in items.into_iter() {
-            let should_include = match self.current_tab {
-                DynamicTab::All => item.is_video() || item.is_draw() || item.is_opus(),
-                DynamicTab::Videos => item.is_video(),
-                DynamicTab::Images => item.is_draw() || item.is_opus(),
-            };
-
-            if !should_include {
-                continue;
-            }
-
-            // Store the item
-            self.dynamic_items.push(item.clone());
-
-            // Handle video dynamics
-            if item.is_video() {
-                if let Some(bvid) = item.video_bvid() {
-                    let card = VideoCard::new(
-                        Some(bvid.to_string()),
-                        None,
-                        item.video_title().unwrap_or("无标题").to_string(),
-                        item.author_name().to_string(),
-                        format!("▶ {}", item.video_play()),
-                        item.video_duration().to_string(),
-                        item.video_cover().map(|s| s.to_string()),
-                    );
-                    self.grid.add_card(card);
-                }
-            }
-            // Handle image dynamics (带图动态)
-            else if item.is_draw() {
-                let images = item.draw_images();
-                let image_url = images.first().map(|s| s.to_string());
-                let desc = item.desc_text().unwrap_or("图片动态");
-                let image_count = if images.len() > 1 {
-                    format!(" [{}P]", images.len())
-                } else {
-                    String::new()
-                };
-
-                let card = VideoCard::new(
-                    None, // No bvid for images
-                    None,
-                    format!("{}{}", desc, image_count),
-                    item.author_name().to_string(),
-                    "📷 图片动态".to_string(),
-                    "".to_string(),
-                    image_url,
-                );
-                self.grid.add_card(card);
-            }
-            // Handle text/opus dynamics (图文动态)
-            else if item.is_opus() {
-                let text = item.opus_text().unwrap_or("图文动态");
-                let images = item.opus_images();
-                let image_url = images.first().map(|s| s.to_string());
-                let image_count = if !images.is_empty() {
-                    format!(" [{}P]", images.len())
-                } else {
-                    String::new()
-                };
-
-                let card = VideoCard::new(
-                    None,
-                    None,
-                    format!("{}{}", text, image_count),
-                    item.author_name().to_string(),
-                    "📝 图文".to_string(),
-                    "".to_string(),
-                    image_url,
-                );
-                self.grid.add_card(card);
-            }
+        for item in items {
+            self.push_item(item);
         }
 
         // Save offset for current tab
@@ -227,81 +477,41 @@ impl DynamicPage {
         self.loading = false;
     }
 
-    pub fn append_feed(&mut self, items: Vec<DynamicItem>, offset: Option<String>, has_more: bool) {
-        // Process items based on current tab filter
-        for item in items.into_iter() {
-            let should_include = match self.current_tab {
-                DynamicTab::All => item.is_video() || item.is_draw() || item.is_opus(),
-                DynamicTab::Videos => item.is_video(),
-                DynamicTab::Images => item.is_draw() || item.is_opus(),
-            };
-
-            if !should_include {
-                continue;
-            }
-
-            // Store the item
-            self.dynamic_items.push(item.clone());
-
-            // Handle video dynamics
-            if item.is_video() {
-                if let Some(bvid) = item.video_bvid() {
-                    let card = VideoCard::new(
-                        Some(bvid.to_string()),
-                        None,
-                        item.video_title().unwrap_or("无标题").to_string(),
-                        item.author_name().to_string(),
-                        format!("▶ {}", item.video_play()),
-                        item.video_duration().to_string(),
-                        item.video_cover().map(|s| s.to_string()),
-                    );
-                    self.grid.add_card(card);
-                }
-            }
-            // Handle image dynamics
-            else if item.is_draw() {
-                let images = item.draw_images();
-                let image_url = images.first().map(|s| s.to_string());
-                let desc = item.desc_text().unwrap_or("图片动态");
-                let image_count = if images.len() > 1 {
-                    format!(" [{}P]", images.len())
-                } else {
-                    String::new()
-                };
+    /// Whether the feed is unfiltered by UP, so the update endpoint applies to it
+    pub fn can_check_updates(&self) -> bool {
+        self.get_selected_up_mid().is_none() && self.update_baseline.is_some()
+    }
 
-                let card = VideoCard::new(
-                    None,
-                    None,
-                    format!("{}{}", desc, image_count),
-                    item.author_name().to_string(),
-                    "📷 图片动态".to_string(),
-                    "".to_string(),
-                    image_url,
-                );
-                self.grid.add_card(card);
-            }
-            // Handle text/opus dynamics
-            else if item.is_opus() {
-                let text = item.opus_text().unwrap_or("图文动态");
-                let images = item.opus_images();
-                let image_url = images.first().map(|s| s.to_string());
-                let image_count = if !images.is_empty() {
-                    format!(" [{}P]", images.len())
-                } else {
-                    String::new()
-                };
+    /// Insert newly published items above the cached feed, stopping at the first one
+    /// already shown. Returns how many were added
+    pub fn prepend_feed(
+        &mut self,
+        items: Vec<DynamicItem>,
+        update_baseline: Option<String>,
+    ) -> usize {
+        let known: HashSet<&str> = self
+            .dynamic_items
+            .iter()
+            .filter_map(|item| item.id_str.as_deref())
+            .collect();
+        // The loaded cards keep their covers, only the new ones are built
+        let (added, cards): (Vec<DynamicItem>, Vec<VideoCard>) = items
+            .into_iter()
+            .take_while(|item| item.id_str.as_deref().is_none_or(|id| !known.contains(id)))
+            .filter_map(|item| self.shown_card(&item).map(|card| (item, card)))
+            .unzip();
+        self.grid.insert_front(cards);
+        self.new_items = added.len();
+        self.dynamic_items.splice(0..0, added);
+        if update_baseline.is_some() {
+            self.update_baseline = update_baseline;
+        }
+        self.new_items
+    }
 
-                let card = VideoCard::new(
-                    None,
-                    None,
-                    format!("{}{}", text, image_count),
-                    item.author_name().to_string(),
-                    "📝 图文".to_string(),
-                    "".to_string(),
-                    image_url,
-                );
-                self.grid.add_card(card);
-            }
+    pub fn append_feed(&mut self, items: Vec<DynamicItem>, offset: Option<String>, has_more: bool) {
+        for item in items {
+            self.push_item(item);
         }
 
         // Save offset for current tab
@@ -317,6 +527,43 @@ impl DynamicPage {
         self.loading_more = false;
     }
 
+    /// Load the frequently watched UPs, then the feed
+    pub async fn load_data(&mut self, api_client: &ApiClient) {
+        self.loading_up_list = true;
+        match api_client.get_dynamic_portal().await {
+            Ok(portal) => {
+                if let Some(up_list) = portal.up_list {
+                    self.set_up_list(up_list);
+                }
+                if let Some(live_users) = portal.live_users {
+                    self.set_live_mids(live_users.items.iter().map(|u| u.mid).collect());
+                }
+            }
+            Err(_) => {
+                self.loading_up_list = false;
+            }
+        }
+        self.load_feed(api_client).await;
+    }
+
+    /// Reload the feed for the selected tab and UP
+    pub async fn load_feed(&mut self, api_client: &ApiClient) {
+        let feed_type = self.current_tab.get_feed_type();
+        let host_mid = self.get_selected_up_mid();
+        match api_client.get_dynamic_feed(None, feed_type, host_mid).await {
+            Ok(data) => {
+                let items = data.items.unwrap_or_default();
+                let offset = data.offset;
+                let has_more = data.has_more.unwrap_or(false);
+                self.set_feed(items, offset, has_more);
+                self.update_baseline = data.update_baseline;
+            }
+            Err(e) => {
+                self.set_error(tf("加载动态失败: {}", &[&e]));
+            }
+        }
+    }
+
     pub async fn load_more(&mut self, api_client: &ApiClient) {
         if self.loading_more || !self.has_more {
             return;
@@ -376,18 +623,21 @@ impl Component for DynamicPage {
             .split(area);
 
         // UP master selection bar
-        const VISIBLE_UPS: usize = 10;
         let mut up_spans: Vec<Span> = Vec::new();
+        // Hit target of each span, for mouse clicks
+        let mut span_hits: Vec<UpBarHit> = Vec::new();
 
         // Show left indicator if scrolled
         if self.up_list_scroll_offset > 0 {
             up_spans.push(Span::styled("◀ ", Style::default().fg(theme.fg_secondary)));
+            span_hits.push(UpBarHit::PagePrev);
         }
 
         // "全部" button - always visible
+        span_hits.push(UpBarHit::Up(0));
         if self.selected_up_index == 0 {
             up_spans.push(Span::styled(
-                " [全部] ",
+                t(" [全部] "),
                 Style::default()
                     .fg(theme.fg_accent)
                     .add_modifier(Modifier::BOLD)
@@ -395,7 +645,7 @@ impl Component for DynamicPage {
             ));
         } else {
             up_spans.push(Span::styled(
-                " [全部] ",
+                t(" [全部] "),
                 Style::default().fg(Color::Rgb(120, 120, 120)),
             ));
         }
@@ -409,6 +659,7 @@ impl Component for DynamicPage {
             .take(VISIBLE_UPS)
         {
             let actual_index = i + 1; // +1 because index 0 is "全部"
+            span_hits.push(UpBarHit::Up(actual_index));
             let is_selected = self.selected_up_index == actual_index;
             let name = &user.uname;
             // Add update indicator (●) for UPs with recent updates
@@ -418,7 +669,14 @@ impl Component for DynamicPage {
                 format!(" {} ", name)
             };
 
-            if is_selected {
+            if self.up_filter_match == Some(actual_index) {
+                up_spans.push(Span::styled(
+                    text,
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+                ));
+            } else if is_selected {
                 up_spans.push(Span::styled(
                     text,
                     Style::default()
@@ -439,6 +697,33 @@ impl Component for DynamicPage {
         // Show right indicator if more UPs exist
         if self.up_list_scroll_offset + VISIBLE_UPS < self.up_list.len() {
             up_spans.push(Span::styled(" ▶", Style::default().fg(theme.fg_secondary)));
+            span_hits.push(UpBarHit::PageNext);
+        }
+
+        let up_bar_title = match &self.up_filter {
+            Some(filter) if self.up_filter_match.is_none() && !filter.is_empty() => {
+                tf(" 查找UP: {}▏ (无匹配) ", &[filter])
+            }
+            Some(filter) => tf(" 查找UP: {}▏ ", &[filter]),
+            None if self.loading_up_list => t(" 加载关注列表中... ").to_string(),
+            None if self.show_followings => tf(
+                " 全部关注 ({}) · [{}] 切换常看 · [{}] 查找 ",
+                &[&self.up_list.len(), &keys.up_source, &keys.search_focus],
+            ),
+            None => tf(
+                " 关注的UP主 · [{}] 全部关注 · [{}] 查找 ",
+                &[&keys.up_source, &keys.search_focus],
+            ),
+        };
+
+        let bar_inner = chunks[0].inner(Margin::new(1, 1));
+        let mut x = bar_inner.x;
+        self.up_bar_hits.clear();
+        for (span, hit) in up_spans.iter().zip(span_hits) {
+            let width = span.width() as u16;
+            let area = Rect::new(x, bar_inner.y, width, 1);
+            x = x.saturating_add(width);
+            self.up_bar_hits.push(area.intersection(bar_inner), hit);
         }
 
         let up_bar = Paragraph::new(Line::from(up_spans))
@@ -447,7 +732,7 @@ impl Component for DynamicPage {
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
-                        " 关注的UP主 ",
+                        up_bar_title,
                         Style::default().fg(theme.bilibili_pink),
                     ))
                     .border_style(Style::default().fg(theme.border_subtle)),
@@ -468,13 +753,18 @@ impl Component for DynamicPage {
         let title = Paragraph::new(Line::from(vec![
             Span::styled(" 📺 ", Style::default()),
             Span::styled(
-                "关注动态",
+                t("关注动态"),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
             ),
             if self.loading_more {
-                Span::styled(" 加载中...", Style::default().fg(theme.warning))
+                Span::styled(t(" 加载中..."), Style::default().fg(theme.warning))
+            } else if self.new_items > 0 {
+                Span::styled(
+                    tf(" · 新增 {} 条动态", &[&self.new_items]),
+                    Style::default().fg(theme.success),
+                )
             } else {
                 Span::raw("")
             },
@@ -526,7 +816,7 @@ impl Component for DynamicPage {
 
         // Content
         if self.loading {
-            let loading = Paragraph::new("⏳ 加载动态中...")
+            let loading = Paragraph::new(t("⏳ 加载动态中..."))
                 .style(Style::default().fg(theme.warning))
                 .alignment(Alignment::Center)
                 .block(
@@ -548,7 +838,7 @@ impl Component for DynamicPage {
                 );
             frame.render_widget(error_widget, chunks[2]);
         } else if self.grid.cards.is_empty() {
-            let empty = Paragraph::new("暂无动态，请先登录并关注UP主")
+            let empty = Paragraph::new(t("暂无动态，请先登录并关注UP主"))
                 .style(Style::default().fg(theme.fg_secondary))
                 .alignment(Alignment::Center)
                 .block(
@@ -561,18 +851,25 @@ impl Component for DynamicPage {
         } else {
             self.grid.render(frame, chunks[2], theme);
         }
+        if let Some(composer) = &self.composer {
+            composer.draw(frame, chunks[2], theme, keys);
+        }
 
         // Help
-        let help = Paragraph::new(format!(
-            "{}:卡片导航 | {}/{}:切UP主 | {}/{}:切标签 | {}:切页面 | {}:详情 | {}:刷新",
-            keys.get_nav_keys_display(),
-            keys.get_arrow_keys_display(),
-            keys.get_arrow_keys_display(),
-            keys.section_prev,
-            keys.section_next,
-            keys.nav_next_page,
-            keys.confirm,
-            keys.refresh
+        let help = Paragraph::new(tf(
+            "{}:卡片导航 | {}/{}:切UP主 | {}/{}:切标签 | {}:切页面 | {}:详情 | {}:刷新 | {}:发动态 | {}:关注/取关",
+            &[
+                &keys.get_nav_keys_display(),
+                &keys.get_arrow_keys_display(),
+                &keys.get_arrow_keys_display(),
+                &keys.section_prev,
+                &keys.section_next,
+                &keys.nav_next_page,
+                &keys.confirm,
+                &keys.refresh,
+                &keys.comment,
+                &keys.follow_up,
+            ],
         ))
         .style(Style::default().fg(theme.fg_secondary))
         .alignment(Alignment::Center);
@@ -585,7 +882,33 @@ impl Component for DynamicPage {
         modifiers: crossterm::event::KeyModifiers,
         keys: &crate::storage::Keybindings,
     ) -> Option<AppAction> {
-        let _ = modifiers;
+        if let Some(composer) = &mut self.composer {
+            return Some(match composer.handle_key(key, modifiers) {
+                ComposerEvent::None => AppAction::None,
+                ComposerEvent::Cancel => {
+                    self.composer = None;
+                    AppAction::None
+                }
+                ComposerEvent::Submit { text, images } => {
+                    self.composer = None;
+                    AppAction::PublishDynamic { text, images }
+                }
+            });
+        }
+        if self.up_filter.is_some() {
+            return self.handle_filter_input(key);
+        }
+        if keys.matches_comment(key) {
+            self.composer = Some(Box::default());
+            return Some(AppAction::None);
+        }
+        if keys.matches_follow_up(key) {
+            return Some(
+                self.selected_dynamic_item()
+                    .and_then(|item| item.author_mid())
+                    .map_or(AppAction::None, AppAction::ToggleFollow),
+            );
+        }
 
         // Card navigation
         if keys.matches_down(key) {
@@ -621,6 +944,14 @@ impl Component for DynamicPage {
             }
             return Some(AppAction::None);
         }
+        if keys.matches_up_source(key) {
+            return Some(AppAction::ToggleUpSource);
+        }
+        if keys.matches_search_focus(key) {
+            self.up_filter = Some(String::new());
+            self.up_filter_match = None;
+            return Some(AppAction::None);
+        }
 
         // Page navigation
         if keys.matches_nav_next(key) {
@@ -632,20 +963,10 @@ impl Component for DynamicPage {
 
         // Tab switching
         if keys.matches_section_prev(key) {
-            let new_tab = match self.current_tab {
-                DynamicTab::All => DynamicTab::Images,
-                DynamicTab::Videos => DynamicTab::All,
-                DynamicTab::Images => DynamicTab::Videos,
-            };
-            return Some(AppAction::SwitchDynamicTab(new_tab));
+            return Some(AppAction::SwitchDynamicTab(self.current_tab.cycle(false)));
         }
         if keys.matches_section_next(key) {
-            let new_tab = match self.current_tab {
-                DynamicTab::All => DynamicTab::Videos,
-                DynamicTab::Videos => DynamicTab::Images,
-                DynamicTab::Images => DynamicTab::All,
-            };
-            return Some(AppAction::SwitchDynamicTab(new_tab));
+            return Some(AppAction::SwitchDynamicTab(self.current_tab.cycle(true)));
         }
 
         // Direct tab access
@@ -659,6 +980,20 @@ impl Component for DynamicPage {
             return Some(AppAction::SwitchDynamicTab(DynamicTab::Images));
         }
 
+        // Action popup for video cards
+        if keys.matches_play(key) {
+            if let Some(card) = self.grid.selected_card() {
+                if let Some(ref bvid) = card.bvid {
+                    return Some(AppAction::OpenActionMenu {
+                        bvid: bvid.clone(),
+                        aid: 0,
+                        title: card.title.clone(),
+                    });
+                }
+            }
+            return Some(AppAction::None);
+        }
+
         // Open selected card
         if keys.matches_confirm(key) {
             if let Some(card) = self.grid.selected_card() {
@@ -693,9 +1028,35 @@ impl Component for DynamicPage {
         Some(AppAction::None)
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
         use crossterm::event::MouseEventKind;
 
+        if event.kind == MouseEventKind::Down(MouseButton::Left) {
+            match self.up_bar_hits.at(event.column, event.row) {
+                Some(UpBarHit::Up(index)) if index != self.selected_up_index => {
+                    return Some(AppAction::SelectUpMaster(index));
+                }
+                Some(UpBarHit::PagePrev) => {
+                    self.up_list_scroll_offset =
+                        self.up_list_scroll_offset.saturating_sub(VISIBLE_UPS);
+                    return None;
+                }
+                Some(UpBarHit::PageNext) => {
+                    self.up_list_scroll_offset = (self.up_list_scroll_offset + VISIBLE_UPS)
+                        .min(self.up_list.len().saturating_sub(VISIBLE_UPS));
+                    return None;
+                }
+                Some(UpBarHit::Up(_)) => return None,
+                None => {}
+            }
+        }
+
+        // Dragging the scrollbar jumps straight to a row
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return (self.grid.is_near_bottom(3) && !self.loading_more && self.has_more)
+                .then_some(AppAction::LoadMoreDynamic);
+        }
+
         match event.kind {
             MouseEventKind::ScrollDown => {
                 if self.grid.move_down()
@@ -711,63 +1072,73 @@ impl Component for DynamicPage {
                 self.grid.move_up();
                 None
             }
+            MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
+                let forward = event.kind == MouseEventKind::ScrollRight;
+                Some(AppAction::SwitchDynamicTab(self.current_tab.cycle(forward)))
+            }
             MouseEventKind::Down(MouseButton::Left) => {
-                let chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([
-                        Constraint::Length(3),
-                        Constraint::Length(5),
-                        Constraint::Min(10),
-                        Constraint::Length(2),
-                    ])
-                    .split(area);
-
-                let grid_area = chunks[2];
-
-                if !grid_area.contains(ratatui::layout::Position::new(event.column, event.row)) {
-                    return None;
-                }
-
-                let relative_y = event.row - grid_area.y;
-                let click_row = (relative_y / self.grid.card_height) as usize;
-                let actual_row = self.grid.scroll_row + click_row;
-
-                let card_width = grid_area.width / self.grid.columns as u16;
-                let click_col = (event.column.saturating_sub(grid_area.x) / card_width) as usize;
-
-                let click_idx = actual_row * self.grid.columns + click_col;
-
-                if click_idx < self.grid.cards.len() {
-                    let now = Instant::now();
-                    let is_double_click = self.last_click_index == Some(click_idx)
-                        && self
-                            .last_click_time
-                            .is_some_and(|t| now.duration_since(t).as_millis() < 500);
-
-                    if is_double_click {
-                        self.last_click_time = None;
-                        self.last_click_index = None;
-                        if let Some(card) = self.grid.cards.get(click_idx) {
-                            if let Some(ref bvid) = card.bvid {
-                                return Some(AppAction::OpenVideoDetail(bvid.clone(), 0));
-                            } else if let Some(item) = self.dynamic_items.get(click_idx) {
-                                if item.is_draw() || item.is_opus() {
-                                    if let Some(id) = &item.id_str {
-                                        return Some(AppAction::OpenDynamicDetail(id.clone()));
-                                    }
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some(card) = self.grid.cards.get(click_idx) {
+                        if let Some(ref bvid) = card.bvid {
+                            return Some(AppAction::OpenVideoDetail(bvid.clone(), 0));
+                        } else if let Some(item) = self.dynamic_items.get(click_idx) {
+                            if item.is_draw() || item.is_opus() {
+                                if let Some(id) = &item.id_str {
+                                    return Some(AppAction::OpenDynamicDetail(id.clone()));
                                 }
                             }
                         }
-                    } else {
-                        self.grid.selected_index = click_idx;
-                        self.grid.update_scroll(self.grid.cached_visible_rows);
-                        self.last_click_time = Some(now);
-                        self.last_click_index = Some(click_idx);
                     }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
                 }
                 None
             }
             _ => None,
         }
     }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_data(ctx.client))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.is_filtering() || self.is_composing()
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+
+    fn session(&self) -> Option<Session> {
+        Some(Session {
+            page: SessionPage::Dynamic,
+            selected: self.grid.selected_index,
+            search_query: String::new(),
+        })
+    }
+
+    fn restore_selection(&mut self, selected: usize) {
+        self.grid.select(selected);
+    }
 }
@@ -0,0 +1,183 @@
+//! Popup for publishing a dynamic: text plus local images to upload
+
+use super::comment_editor::{CommentEditor, EditorEvent};
+use super::{clear_popup, Theme};
+use crate::api::publish::{image_mime, MAX_DYNAMIC_IMAGES};
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyModifiers},
+    prelude::*,
+    widgets::*,
+};
+use std::path::PathBuf;
+
+/// What the page should do after a key press in the composer
+pub enum ComposerEvent {
+    None,
+    Cancel,
+    Submit { text: String, images: Vec<PathBuf> },
+}
+
+#[derive(Default)]
+pub struct DynamicComposer {
+    editor: CommentEditor,
+    images: Vec<PathBuf>,
+    /// Image path being typed, None while editing the text
+    path_input: Option<String>,
+    error: Option<String>,
+}
+
+impl DynamicComposer {
+    /// Tab adds an image path, Shift+Tab removes the last one, everything else edits the text
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> ComposerEvent {
+        if let Some(input) = &mut self.path_input {
+            match key {
+                KeyCode::Enter => {
+                    let input = std::mem::take(input);
+                    self.path_input = None;
+                    match self.checked_image(&input) {
+                        Ok(path) => {
+                            self.images.push(path);
+                            self.error = None;
+                        }
+                        Err(e) => self.error = Some(e),
+                    }
+                }
+                KeyCode::Esc => self.path_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return ComposerEvent::None;
+        }
+
+        if !self.editor.previewing {
+            match key {
+                KeyCode::Tab => {
+                    if self.images.len() < MAX_DYNAMIC_IMAGES {
+                        self.path_input = Some(String::new());
+                    } else {
+                        self.error = Some(tf("最多 {} 张图片", &[&MAX_DYNAMIC_IMAGES]));
+                    }
+                    return ComposerEvent::None;
+                }
+                KeyCode::BackTab => {
+                    self.images.pop();
+                    return ComposerEvent::None;
+                }
+                _ => {}
+            }
+        }
+
+        match self.editor.handle_key(key, modifiers) {
+            EditorEvent::None => ComposerEvent::None,
+            EditorEvent::Cancel => ComposerEvent::Cancel,
+            EditorEvent::Submit(text) => ComposerEvent::Submit {
+                text,
+                images: std::mem::take(&mut self.images),
+            },
+        }
+    }
+
+    /// Resolve a typed path (quotes from drag and drop and a leading ~ are allowed)
+    fn checked_image(&self, input: &str) -> Result<PathBuf, String> {
+        let input = input.trim().trim_matches(['\'', '"']);
+        let path = match input.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+            None => PathBuf::from(input),
+        };
+        if !path.is_file() {
+            return Err(tf("找不到文件: {}", &[&path.display()]));
+        }
+        if image_mime(&path).is_none() {
+            return Err(t("只支持 jpg/png/gif/webp 图片").to_string());
+        }
+        if self.images.contains(&path) {
+            return Err(t("这张图片已经添加过了").to_string());
+        }
+        Ok(path)
+    }
+
+    /// Draw centered over `area`
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let width = area.width.saturating_sub(4).min(72);
+        let editor_height = self.editor.height(width.saturating_sub(2));
+        let list_height = self.images.len().max(1) as u16;
+        let height = (editor_height + list_height + 4).min(area.height);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_focused))
+            .title(Span::styled(
+                t(" 📝 发布动态 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        let inner = block.inner(popup);
+        clear_popup(frame, popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(editor_height),
+                Constraint::Min(list_height),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        self.editor
+            .draw_titled(frame, chunks[0], theme, t(" ✏️ 内容 "), t(" 👀 预览动态 "));
+
+        let muted = Style::default().fg(theme.fg_muted);
+        let mut lines: Vec<Line> = self
+            .images
+            .iter()
+            .enumerate()
+            .map(|(i, path)| {
+                Line::from(vec![
+                    Span::styled(format!(" 🖼 {}. ", i + 1), muted),
+                    Span::styled(
+                        path.display().to_string(),
+                        Style::default().fg(theme.fg_primary),
+                    ),
+                ])
+            })
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(t(" 无图片，纯文字动态"), muted)));
+        }
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+        let (status, style) = if let Some(input) = &self.path_input {
+            (
+                tf("图片路径: {}_  [Enter] 添加  [Esc] 取消", &[input]),
+                Style::default().fg(theme.fg_primary),
+            )
+        } else if let Some(error) = &self.error {
+            (error.clone(), Style::default().fg(theme.error))
+        } else if self.editor.previewing {
+            (self.editor.help_text(keys), muted)
+        } else {
+            (
+                tf(
+                    "{}  [Tab] 添加图片  [Shift+Tab] 移除图片",
+                    &[&self.editor.help_text(keys)],
+                ),
+                muted,
+            )
+        };
+        frame.render_widget(Paragraph::new(status).style(style), chunks[2]);
+    }
+}
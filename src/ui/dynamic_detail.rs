@@ -1,27 +1,21 @@
 //! Dynamic detail page for viewing image/text dynamics
 
+use super::comment_editor::{CommentEditor, EditorEvent};
+use super::image_viewer::ImageLoader;
+use super::scrollbar::DragScrollbar;
 use super::{Component, Theme};
 use crate::api::client::ApiClient;
-use crate::api::comment::CommentItem;
+use crate::api::comment::{CommentItem, CommentVote};
 use crate::api::dynamic::DynamicItem;
 use crate::app::AppAction;
+use crate::i18n::{t, tf};
 use crate::storage::Keybindings;
-use image::DynamicImage;
 use ratatui::{
-    crossterm::event::{KeyCode, MouseEvent, MouseEventKind},
+    crossterm::event::{KeyCode, KeyModifiers, MouseEvent, MouseEventKind},
     prelude::*,
     widgets::*,
 };
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
-use std::collections::HashSet;
-use std::sync::Arc;
-use tokio::sync::mpsc;
-
-/// Image download result
-pub struct ImageResult {
-    pub index: usize,
-    pub protocol: StatefulProtocol,
-}
+use std::collections::HashMap;
 
 pub struct DynamicDetailPage {
     pub dynamic_id: String,
@@ -32,28 +26,22 @@ pub struct DynamicDetailPage {
     pub comment_page: i32,
     pub content_scroll: usize,
     pub comment_scroll: usize,
+    comment_scrollbar: DragScrollbar,
     pub has_more_comments: bool,
     pub loading_more_comments: bool,
     pub image_urls: Vec<String>,
-    pub image_protocols: Vec<Option<StatefulProtocol>>,
     pub current_image_index: usize,
-    pub picker: Arc<Picker>,
-    // Async image loading
-    image_tx: mpsc::Sender<ImageResult>,
-    image_rx: mpsc::Receiver<ImageResult>,
-    pending_downloads: HashSet<usize>,
+    images: ImageLoader,
     // Comment action support
-    pub liked_comments: HashSet<i64>,
+    /// Votes changed on this page, over what the comment list says
+    pub comment_votes: HashMap<i64, CommentVote>,
     pub input_mode: bool,
-    pub input_buffer: String,
+    pub comment_editor: CommentEditor,
     pub selected_comment: usize,
 }
 
 impl DynamicDetailPage {
     pub fn new(dynamic_id: String) -> Self {
-        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
-        let (image_tx, image_rx) = mpsc::channel(8);
-
         Self {
             dynamic_id,
             dynamic_item: None,
@@ -63,22 +51,45 @@ impl DynamicDetailPage {
             comment_page: 1,
             content_scroll: 0,
             comment_scroll: 0,
+            comment_scrollbar: DragScrollbar::default(),
             has_more_comments: true,
             loading_more_comments: false,
             image_urls: Vec::new(),
-            image_protocols: Vec::new(),
             current_image_index: 0,
-            picker,
-            image_tx,
-            image_rx,
-            pending_downloads: HashSet::new(),
-            liked_comments: HashSet::new(),
+            images: ImageLoader::new(),
+            comment_votes: HashMap::new(),
             input_mode: false,
-            input_buffer: String::new(),
+            comment_editor: CommentEditor::default(),
             selected_comment: 0,
         }
     }
 
+    /// Reopen the comment editor with a comment that failed to send
+    pub fn restore_comment(&mut self, message: String) {
+        self.input_mode = true;
+        self.comment_editor.restore(message);
+    }
+
+    /// Like or dislike the selected comment, or take the vote back
+    fn vote_selected_comment(&self, pressed: CommentVote) -> AppAction {
+        let (Some(item), Some(comment)) =
+            (&self.dynamic_item, self.comments.get(self.selected_comment))
+        else {
+            return AppAction::None;
+        };
+        let Some(oid) = item.comment_oid(&self.dynamic_id) else {
+            return AppAction::None;
+        };
+        let from = comment.vote(&self.comment_votes);
+        AppAction::VoteComment {
+            oid,
+            rpid: comment.rpid,
+            comment_type: item.comment_type(),
+            from,
+            to: from.toggled(pressed),
+        }
+    }
+
     pub async fn load_data(&mut self, api_client: &ApiClient) {
         self.loading = true;
         self.error_message = None;
@@ -89,7 +100,7 @@ impl DynamicDetailPage {
                 self.dynamic_item = Some(item);
             }
             Err(e) => {
-                self.error_message = Some(format!("加载动态详情失败: {}", e));
+                self.error_message = Some(tf("加载动态详情失败: {}", &[&e]));
                 self.loading = false;
                 return;
             }
@@ -110,7 +121,7 @@ impl DynamicDetailPage {
                     }
                     Err(e) => {
                         if self.error_message.is_none() {
-                            self.error_message = Some(format!("加载评论失败: {}", e));
+                            self.error_message = Some(tf("加载评论失败: {}", &[&e]));
                         }
                     }
                 }
@@ -132,55 +143,11 @@ impl DynamicDetailPage {
             }
 
             self.image_urls = urls.into_iter().map(|s| s.to_string()).collect();
-            self.image_protocols = (0..self.image_urls.len()).map(|_| None).collect();
         }
 
         self.loading = false;
     }
 
-    /// Start background downloads for images (non-blocking)
-    pub fn start_image_downloads(&mut self) {
-        for (idx, url) in self.image_urls.iter().enumerate() {
-            // Skip if already has protocol or is pending
-            if self.image_protocols[idx].is_some() || self.pending_downloads.contains(&idx) {
-                continue;
-            }
-
-            self.pending_downloads.insert(idx);
-            let tx = self.image_tx.clone();
-            let picker = Arc::clone(&self.picker);
-            let url = url.clone();
-
-            tokio::spawn(async move {
-                if let Some(img) = Self::download_image(&url).await {
-                    let protocol = picker.new_resize_protocol(img);
-                    let _ = tx
-                        .send(ImageResult {
-                            index: idx,
-                            protocol,
-                        })
-                        .await;
-                }
-            });
-        }
-    }
-
-    /// Poll for completed image downloads (non-blocking)
-    pub fn poll_image_results(&mut self) {
-        while let Ok(result) = self.image_rx.try_recv() {
-            if result.index < self.image_protocols.len() {
-                self.image_protocols[result.index] = Some(result.protocol);
-                self.pending_downloads.remove(&result.index);
-            }
-        }
-    }
-
-    async fn download_image(url: &str) -> Option<DynamicImage> {
-        let response = reqwest::get(url).await.ok()?;
-        let bytes = response.bytes().await.ok()?;
-        image::load_from_memory(&bytes).ok()
-    }
-
     pub async fn load_more_comments(&mut self, api_client: &ApiClient) {
         if !self.has_more_comments || self.loading_more_comments {
             return;
@@ -220,13 +187,13 @@ impl DynamicDetailPage {
 
         if let Some(ref item) = self.dynamic_item {
             // Author and time
-            lines.push(format!("👤 UP主: {}", item.author_name()));
-            lines.push(format!("🕒 发布时间: {}", item.pub_time()));
+            lines.push(tf("👤 UP主: {}", &[&item.author_name()]));
+            lines.push(tf("🕒 发布时间: {}", &[&item.pub_time()]));
             lines.push(String::new());
 
             // Image count
             if !self.image_urls.is_empty() {
-                lines.push(format!("🖼️  图片数量: {} 张", self.image_urls.len()));
+                lines.push(tf("🖼️  图片数量: {} 张", &[&self.image_urls.len()]));
                 lines.push(String::new());
             }
 
@@ -239,7 +206,7 @@ impl DynamicDetailPage {
 
             if let Some(text) = content_text {
                 if !text.is_empty() {
-                    lines.push("📝 动态内容:".to_string());
+                    lines.push(t("📝 动态内容:").to_string());
                     lines.push(String::new());
                     for line in wrap_text(text, 60) {
                         lines.push(format!("  {}", line));
@@ -248,7 +215,7 @@ impl DynamicDetailPage {
                 }
             }
         } else {
-            lines.push("加载中...".to_string());
+            lines.push(t("加载中...").to_string());
         }
 
         lines
@@ -268,13 +235,18 @@ impl DynamicDetailPage {
                 .and_then(|l| l.current_level)
                 .unwrap_or(0);
 
-            block.push(format!(
+            let mut header = format!(
                 "💬 {} [Lv.{}]  👍 {}  {}",
                 comment.author_name(),
                 level,
                 comment.format_like(),
                 comment.format_time()
-            ));
+            );
+            if let Some(label) = comment.vote(&self.comment_votes).label() {
+                header.push_str("  ");
+                header.push_str(label);
+            }
+            block.push(header);
 
             // Comment content
             let message = comment.message();
@@ -284,7 +256,7 @@ impl DynamicDetailPage {
 
             // Reply count
             if comment.reply_count() > 0 {
-                block.push(format!("   └─ {} 条回复", comment.reply_count()));
+                block.push(tf("   └─ {} 条回复", &[&comment.reply_count()]));
             }
 
             // Add separator except for last comment
@@ -298,21 +270,45 @@ impl DynamicDetailPage {
         }
 
         if comment_blocks.is_empty() {
-            comment_blocks.push(vec!["暂无评论".to_string()]);
+            comment_blocks.push(vec![t("暂无评论").to_string()]);
         }
 
         comment_blocks
     }
+
+    /// Keys while the comment editor is open
+    fn handle_comment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> AppAction {
+        match self.comment_editor.handle_key(key, modifiers) {
+            EditorEvent::None => AppAction::None,
+            EditorEvent::Cancel => {
+                self.input_mode = false;
+                AppAction::None
+            }
+            EditorEvent::Submit(message) => {
+                self.input_mode = false;
+                let Some(item) = &self.dynamic_item else {
+                    return AppAction::None;
+                };
+                match item.comment_oid(&self.dynamic_id) {
+                    Some(oid) => AppAction::AddComment {
+                        oid,
+                        comment_type: item.comment_type(),
+                        message,
+                        root: None,
+                    },
+                    None => AppAction::None,
+                }
+            }
+        }
+    }
 }
 
 impl Component for DynamicDetailPage {
     fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
-        // Poll for completed image downloads
-        self.poll_image_results();
-
-        // Start image downloads if needed
-        if !self.image_urls.is_empty() {
-            self.start_image_downloads();
+        // Poll for completed image downloads, then start the rest in the background
+        self.images.poll();
+        for url in &self.image_urls {
+            self.images.request(url);
         }
 
         // Adjust layout based on input mode
@@ -320,10 +316,10 @@ impl Component for DynamicDetailPage {
             Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(3), // Title
-                    Constraint::Min(8),    // Main content
-                    Constraint::Length(3), // Input box
-                    Constraint::Length(2), // Help
+                    Constraint::Length(3),                                      // Title
+                    Constraint::Min(8),                                         // Main content
+                    Constraint::Length(self.comment_editor.height(area.width)), // Input box
+                    Constraint::Length(2),                                      // Help
                 ])
                 .split(area)
         } else {
@@ -340,12 +336,12 @@ impl Component for DynamicDetailPage {
         // Title
         let title_text = if let Some(ref item) = self.dynamic_item {
             if item.is_draw() || item.is_opus() {
-                "📷 图文动态详情"
+                t("📷 图文动态详情")
             } else {
-                "📄 动态详情"
+                t("📄 动态详情")
             }
         } else {
-            "📄 动态详情"
+            t("📄 动态详情")
         };
 
         let title = Paragraph::new(title_text)
@@ -365,7 +361,7 @@ impl Component for DynamicDetailPage {
 
         // Main content
         if self.loading {
-            let loading_text = "加载中...";
+            let loading_text = t("加载中...");
             let loading = Paragraph::new(loading_text)
                 .style(Style::default().fg(theme.fg_secondary))
                 .alignment(Alignment::Center)
@@ -377,7 +373,7 @@ impl Component for DynamicDetailPage {
                 );
             frame.render_widget(loading, chunks[1]);
         } else if let Some(ref err) = self.error_message {
-            let error_text = format!("错误: {}", err);
+            let error_text = tf("错误: {}", &[&err]);
             let error = Paragraph::new(error_text)
                 .style(Style::default().fg(Color::Red))
                 .alignment(Alignment::Center)
@@ -394,22 +390,7 @@ impl Component for DynamicDetailPage {
 
         // Input box (only in input mode)
         if self.input_mode {
-            let input_block = Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(theme.bilibili_pink))
-                .title(Span::styled(
-                    " ✏️ 发表评论 ",
-                    Style::default()
-                        .fg(theme.bilibili_pink)
-                        .add_modifier(Modifier::BOLD),
-                ));
-
-            let input_text = format!("{}_", self.input_buffer);
-            let input = Paragraph::new(input_text)
-                .style(Style::default().fg(theme.fg_primary))
-                .block(input_block);
-            frame.render_widget(input, chunks[2]);
+            self.comment_editor.draw(frame, chunks[2], theme);
         }
 
         // Help
@@ -419,22 +400,32 @@ impl Component for DynamicDetailPage {
             chunks[2]
         };
         let help_text = if self.input_mode {
-            format!("[{}] 发送评论  [{}] 取消", keys.confirm, keys.back)
+            self.comment_editor.help_text(keys)
         } else if !self.image_urls.is_empty() {
-            format!(
-                "[{}/{}] 图片  [{}/{}] 滚动  [{}] 点赞  [{}] 评论  [n] 加载更多  [{}] 返回",
-                keys.nav_left,
-                keys.nav_right,
-                keys.nav_up,
-                keys.nav_down,
-                keys.confirm,
-                keys.comment,
-                keys.back
+            tf(
+                "[{}/{}] 图片  [{}/{}] 滚动  [{}] 点赞  [{}] 点踩  [{}] 评论  [n] 加载更多  [{}] 返回",
+                &[
+                    &keys.nav_left,
+                    &keys.nav_right,
+                    &keys.nav_up,
+                    &keys.nav_down,
+                    &keys.confirm,
+                    &keys.dislike_comment,
+                    &keys.comment,
+                    &keys.back,
+                ],
             )
         } else {
-            format!(
-                "[{}/{}] 滚动  [{}] 点赞  [{}] 评论  [n] 加载更多  [{}] 返回",
-                keys.nav_up, keys.nav_down, keys.confirm, keys.comment, keys.back
+            tf(
+                "[{}/{}] 滚动  [{}] 点赞  [{}] 点踩  [{}] 评论  [n] 加载更多  [{}] 返回",
+                &[
+                    &keys.nav_up,
+                    &keys.nav_down,
+                    &keys.confirm,
+                    &keys.dislike_comment,
+                    &keys.comment,
+                    &keys.back,
+                ],
             )
         };
         let help = Paragraph::new(help_text)
@@ -448,43 +439,8 @@ impl Component for DynamicDetailPage {
         key: KeyCode,
         keys: &crate::storage::Keybindings,
     ) -> Option<AppAction> {
-        // Handle input mode for adding comments
         if self.input_mode {
-            match key {
-                KeyCode::Esc => {
-                    self.input_mode = false;
-                    self.input_buffer.clear();
-                    return Some(AppAction::None);
-                }
-                KeyCode::Enter => {
-                    if !self.input_buffer.is_empty() {
-                        if let Some(ref item) = self.dynamic_item {
-                            let comment_type = item.comment_type();
-                            if let Some(oid) = item.comment_oid(&self.dynamic_id) {
-                                let message = self.input_buffer.clone();
-                                self.input_buffer.clear();
-                                self.input_mode = false;
-                                return Some(AppAction::AddComment {
-                                    oid,
-                                    comment_type,
-                                    message,
-                                    root: None,
-                                });
-                            }
-                        }
-                    }
-                    return Some(AppAction::None);
-                }
-                KeyCode::Backspace => {
-                    self.input_buffer.pop();
-                    return Some(AppAction::None);
-                }
-                KeyCode::Char(c) => {
-                    self.input_buffer.push(c);
-                    return Some(AppAction::None);
-                }
-                _ => return Some(AppAction::None),
-            }
+            return Some(self.handle_comment_input(key, KeyModifiers::NONE));
         }
 
         if keys.matches_quit(key) || keys.matches_back(key) {
@@ -492,7 +448,7 @@ impl Component for DynamicDetailPage {
         }
         if keys.matches_comment(key) {
             self.input_mode = true;
-            self.input_buffer.clear();
+            self.comment_editor.clear();
             return Some(AppAction::None);
         }
         if keys.matches_left(key) {
@@ -536,31 +492,38 @@ impl Component for DynamicDetailPage {
             return Some(AppAction::None);
         }
         if keys.matches_confirm(key) {
-            // Like the currently selected comment
-            if let Some(ref item) = self.dynamic_item {
-                if self.selected_comment < self.comments.len() {
-                    let comment = &self.comments[self.selected_comment];
-                    let comment_type = item.comment_type();
-                    if let Some(oid) = item.comment_oid(&self.dynamic_id) {
-                        return Some(AppAction::LikeComment {
-                            oid,
-                            rpid: comment.rpid,
-                            comment_type,
-                        });
-                    }
-                }
-            }
-            return Some(AppAction::None);
+            return Some(self.vote_selected_comment(CommentVote::Like));
+        }
+        if keys.matches_dislike_comment(key) {
+            return Some(self.vote_selected_comment(CommentVote::Dislike));
         }
         Some(AppAction::None)
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, _area: Rect) -> Option<AppAction> {
+    fn handle_input_with_modifiers(
+        &mut self,
+        key: KeyCode,
+        modifiers: KeyModifiers,
+        keys: &Keybindings,
+    ) -> Option<AppAction> {
+        if self.input_mode {
+            return Some(self.handle_comment_input(key, modifiers));
+        }
+        self.handle_input(key, keys)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
         // Don't handle mouse in input mode
         if self.input_mode {
             return None;
         }
 
+        // Dragging the scrollbar jumps straight to a line
+        if let Some(line) = self.comment_scrollbar.handle_mouse(&event) {
+            self.comment_scroll = line;
+            return None;
+        }
+
         match event.kind {
             MouseEventKind::ScrollDown => {
                 // Scroll down comments
@@ -587,6 +550,14 @@ impl Component for DynamicDetailPage {
             _ => None,
         }
     }
+
+    fn is_text_input_active(&self) -> bool {
+        self.input_mode
+    }
+
+    fn is_full_screen(&self) -> bool {
+        true
+    }
 }
 
 impl DynamicDetailPage {
@@ -637,31 +608,16 @@ impl DynamicDetailPage {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_focused))
-            .title(format!(
+            .title(tf(
                 " 图片 {}/{} [h/l 切换] ",
-                self.current_image_index + 1,
-                self.image_urls.len()
+                &[&(self.current_image_index + 1), &self.image_urls.len()],
             ));
 
         let inner_area = block.inner(area);
         frame.render_widget(block, area);
 
-        // Render the current image if loaded
-        if let Some(Some(protocol)) = self.image_protocols.get_mut(self.current_image_index) {
-            use ratatui_image::StatefulImage;
-            let image_widget = StatefulImage::new();
-            frame.render_stateful_widget(image_widget, inner_area, protocol);
-        } else {
-            // Show loading text
-            let loading_text = if self.pending_downloads.contains(&self.current_image_index) {
-                "加载中..."
-            } else {
-                "等待加载..."
-            };
-            let loading = Paragraph::new(loading_text)
-                .style(Style::default().fg(theme.fg_secondary))
-                .alignment(Alignment::Center);
-            frame.render_widget(loading, inner_area);
+        if let Some(url) = self.image_urls.get(self.current_image_index) {
+            self.images.render(frame, inner_area, url, theme);
         }
     }
 
@@ -683,13 +639,13 @@ impl DynamicDetailPage {
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(theme.border_focused))
-                    .title(" 动态详情 "),
+                    .title(t(" 动态详情 ")),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(content, area);
     }
 
-    fn draw_comments(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+    fn draw_comments(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let comment_blocks = self.get_comment_lines();
 
         // Flatten all comment blocks into a single vector of lines
@@ -714,10 +670,20 @@ impl DynamicDetailPage {
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(theme.border_focused))
-                    .title(format!(" 评论 ({}) ", self.comments.len())),
+                    .title(tf(" 评论 ({}) ", &[&self.comments.len()])),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(comments, area);
+
+        // Drawn over the right border
+        self.comment_scrollbar.render(
+            frame,
+            area.inner(Margin::new(0, 1)),
+            all_lines.len(),
+            self.comment_scroll,
+            visible_height,
+            theme,
+        );
     }
 }
 
@@ -0,0 +1,111 @@
+//! Error view of list pages: what went wrong and what to do, by kind of API error
+
+use super::Theme;
+use crate::api::error::ApiErrorKind;
+use crate::app::AppAction;
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+use std::time::Instant;
+
+/// Page opened in the browser to pass the captcha with the same account
+const VERIFY_URL: &str = "https://www.bilibili.com";
+
+/// Draw the error with the explanation and keys that fit its kind; `retry_at` is when
+/// the page retries by itself
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    keys: &Keybindings,
+    kind: ApiErrorKind,
+    error: &str,
+    retry_at: Option<Instant>,
+) {
+    let key_style = Style::default()
+        .fg(theme.fg_accent)
+        .add_modifier(Modifier::BOLD);
+    let text = Style::default().fg(theme.fg_secondary);
+    let warning = Style::default().fg(theme.warning);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("❌ {}", error),
+            Style::default().fg(theme.error),
+        )),
+        Line::from(""),
+    ];
+    let (explanation, advice) = match kind {
+        ApiErrorKind::RiskControl => (
+            t("请求被 B 站风控拦截，已自动刷新签名重试一次仍失败"),
+            Some(t(
+                "可尝试重新登录刷新凭据，或在浏览器中打开 B 站完成人机验证后重试",
+            )),
+        ),
+        ApiErrorKind::NotLoggedIn => (t("登录状态已失效"), Some(t("请重新扫码登录后再试"))),
+        ApiErrorKind::Network => (
+            t("网络连接失败"),
+            Some(t("请检查网络、代理或 api_hosts 配置")),
+        ),
+        ApiErrorKind::Parse => (
+            t("B 站返回了无法识别的数据"),
+            Some(t("接口可能已变更，可尝试更新到最新版本")),
+        ),
+        ApiErrorKind::Other => ("", None),
+    };
+    if !explanation.is_empty() {
+        lines.push(Line::from(Span::styled(explanation, warning)));
+    }
+    if let Some(advice) = advice {
+        lines.push(Line::from(Span::styled(advice, text)));
+    }
+    if let Some(at) = retry_at {
+        let secs = at.saturating_duration_since(Instant::now()).as_secs() + 1;
+        lines.push(Line::from(Span::styled(
+            tf("{} 秒后自动重试", &[&secs]),
+            text,
+        )));
+    }
+    lines.push(Line::from(""));
+
+    let mut hints = vec![
+        Span::styled(format!("[{}] ", keys.refresh), key_style),
+        Span::styled(t("重试"), text),
+    ];
+    if matches!(kind, ApiErrorKind::RiskControl | ApiErrorKind::NotLoggedIn) {
+        hints.push(Span::styled(format!("   [{}] ", keys.relogin), key_style));
+        hints.push(Span::styled(t("重新登录"), text));
+    }
+    if kind == ApiErrorKind::RiskControl {
+        hints.push(Span::styled(format!("   [{}] ", keys.confirm), key_style));
+        hints.push(Span::styled(t("在浏览器中验证"), text));
+    }
+    lines.push(Line::from(hints));
+
+    let height = (lines.len() as u16).min(area.height);
+    let [_, middle, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(height),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        middle,
+    );
+}
+
+/// Re-login and browser verification keys; retrying is left to the page
+pub fn handle_key(kind: ApiErrorKind, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+    match kind {
+        ApiErrorKind::RiskControl | ApiErrorKind::NotLoggedIn if keys.matches_relogin(key) => {
+            Some(AppAction::SwitchToLogin)
+        }
+        ApiErrorKind::RiskControl if keys.matches_confirm(key) => {
+            Some(AppAction::OpenInBrowser(VERIFY_URL.to_string()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,1035 @@
+//! Favorites page: the user's own folders as tabs and their videos in a grid
+
+use super::multi_select::{MultiSelect, MultiSelectKey};
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{clear_popup, Component, PageContext, Theme};
+use crate::api::client::ApiClient;
+use crate::api::favorite::{FavFolder, FavMedia};
+use crate::app::AppAction;
+use crate::export::{self, ExportEvent, FavoriteRecord};
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, Session, SessionPage, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
+use ratatui::{
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::*,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+const PAGE_SIZE: i32 = 20;
+
+/// Popup choosing the folder the selected video is moved or copied to
+struct FolderPicker {
+    /// Copy the video instead of moving it
+    copy: bool,
+    aid: i64,
+    title: String,
+    /// Indices into `folders` of every folder but the current one
+    targets: Vec<usize>,
+    selected: usize,
+}
+
+/// Popup typing the name of a new or renamed folder
+struct FolderForm {
+    /// Folder being renamed, None for a new one
+    media_id: Option<i64>,
+    title: String,
+    private: bool,
+}
+
+pub struct FavoritesPage {
+    pub folders: Vec<FavFolder>,
+    pub current_folder: usize,
+    pub grid: VideoCardGrid,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    page: i32,
+    has_more: bool,
+    pub loading_more: bool,
+    /// List deleted videos greyed out instead of hiding them
+    show_invalid: bool,
+
+    /// Running export, drained in [`Self::poll_export`]
+    export_rx: Option<mpsc::UnboundedReceiver<ExportEvent>>,
+    /// Export progress or result line
+    export_status: Option<String>,
+    /// Target folder popup of a move or copy
+    picker: Option<FolderPicker>,
+    /// Name popup of a new or renamed folder
+    form: Option<FolderForm>,
+    /// The delete key was pressed once, the next press deletes the current folder
+    confirm_delete: bool,
+    /// 多选模式，按 aid 勾选
+    select: MultiSelect<i64>,
+
+    last_click_time: Option<Instant>,
+    last_click_index: Option<usize>,
+}
+
+impl FavoritesPage {
+    pub fn new() -> Self {
+        Self {
+            folders: Vec::new(),
+            current_folder: 0,
+            grid: VideoCardGrid::new().with_number_hints(),
+            loading: true,
+            error_message: None,
+            page: 1,
+            has_more: false,
+            loading_more: false,
+            show_invalid: false,
+            export_rx: None,
+            export_status: None,
+            picker: None,
+            form: None,
+            confirm_delete: false,
+            select: MultiSelect::default(),
+            last_click_time: None,
+            last_click_index: None,
+        }
+    }
+
+    /// Load the folders of the logged-in user, then the videos of the selected one
+    pub async fn load_folders(&mut self, api_client: &ApiClient, mid: Option<i64>) {
+        let selected = self.folders.get(self.current_folder).map(|f| f.id);
+        self.reload_folders(api_client, mid, selected).await;
+    }
+
+    /// Refetch the folder list after it changed and show the folder `select` (a media_id),
+    /// or the one at the same position when it is gone
+    pub async fn reload_folders(
+        &mut self,
+        api_client: &ApiClient,
+        mid: Option<i64>,
+        select: Option<i64>,
+    ) {
+        let Some(mid) = mid else {
+            self.loading = false;
+            self.error_message = Some(t("请先登录").to_string());
+            return;
+        };
+
+        self.loading = true;
+        match api_client.get_fav_folders(mid).await {
+            Ok(folders) => {
+                self.folders = folders;
+                self.current_folder = select
+                    .and_then(|id| self.folders.iter().position(|f| f.id == id))
+                    .unwrap_or(self.current_folder)
+                    .min(self.folders.len().saturating_sub(1));
+                self.load_folder(api_client).await;
+            }
+            Err(e) => {
+                self.error_message = Some(tf("加载收藏夹失败: {}", &[&e]));
+                self.loading = false;
+            }
+        }
+    }
+
+    /// Load the first page of the selected folder
+    pub async fn load_folder(&mut self, api_client: &ApiClient) {
+        self.grid.clear();
+        self.select.exit();
+        self.page = 1;
+        self.has_more = false;
+
+        let Some(folder) = self.folders.get(self.current_folder) else {
+            self.loading = false;
+            return;
+        };
+
+        self.loading = true;
+        match api_client.get_fav_resources(folder.id, 1, PAGE_SIZE).await {
+            Ok(data) => {
+                self.has_more = data.has_more;
+                self.append_medias(data.medias.unwrap_or_default());
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.error_message = Some(tf("加载收藏夹失败: {}", &[&e]));
+            }
+        }
+        self.loading = false;
+    }
+
+    pub async fn load_more(&mut self, api_client: &ApiClient) {
+        if self.loading_more || !self.has_more {
+            return;
+        }
+        let Some(folder) = self.folders.get(self.current_folder) else {
+            return;
+        };
+
+        self.loading_more = true;
+        if let Ok(data) = api_client
+            .get_fav_resources(folder.id, self.page + 1, PAGE_SIZE)
+            .await
+        {
+            self.page += 1;
+            self.has_more = data.has_more;
+            self.append_medias(data.medias.unwrap_or_default());
+        }
+        self.loading_more = false;
+    }
+
+    fn append_medias(&mut self, medias: Vec<FavMedia>) {
+        let show_invalid = self.show_invalid;
+        for media in medias
+            .into_iter()
+            .filter(FavMedia::is_video)
+            .filter(|m| show_invalid || !m.is_invalid())
+        {
+            let mut card = VideoCard::new(
+                Some(media.bvid.clone()),
+                Some(media.id),
+                media.title.clone(),
+                media.upper.name.clone(),
+                media.format_play(),
+                media.format_duration(),
+                media.cover_url(),
+            );
+            if media.is_invalid() {
+                card.add_badge(CardBadge::Invalid);
+            } else if media.page > 1 {
+                card.add_badge(CardBadge::MultiPart(media.page));
+            }
+            self.grid.add_card(card);
+        }
+        self.select.mark_cards(&mut self.grid.cards);
+    }
+
+    /// Switch folder by offset, wrapping around; returns whether it changed
+    fn cycle_folder(&mut self, forward: bool) -> bool {
+        let len = self.folders.len();
+        if len < 2 {
+            return false;
+        }
+        self.current_folder = if forward {
+            (self.current_folder + 1) % len
+        } else {
+            (self.current_folder + len - 1) % len
+        };
+        true
+    }
+
+    /// Open the target folder popup for the selected video
+    fn open_picker(&mut self, copy: bool) -> AppAction {
+        let Some((aid, title)) = self
+            .grid
+            .selected_card()
+            .and_then(|card| Some((card.aid?, card.title.clone())))
+        else {
+            return AppAction::None;
+        };
+        let targets: Vec<usize> = (0..self.folders.len())
+            .filter(|&idx| idx != self.current_folder)
+            .collect();
+        if targets.is_empty() {
+            return AppAction::ShowError(t("没有其他收藏夹").to_string());
+        }
+        self.picker = Some(FolderPicker {
+            copy,
+            aid,
+            title,
+            targets,
+            selected: 0,
+        });
+        AppAction::None
+    }
+
+    /// Keys while the target folder popup is open
+    fn handle_picker_input(&mut self, key: KeyCode, keys: &Keybindings) -> AppAction {
+        let Some(picker) = self.picker.as_mut() else {
+            return AppAction::None;
+        };
+        if keys.matches_back(key) || keys.matches_quit(key) {
+            self.picker = None;
+        } else if keys.matches_up(key) {
+            picker.selected = picker.selected.saturating_sub(1);
+        } else if keys.matches_down(key) {
+            if picker.selected + 1 < picker.targets.len() {
+                picker.selected += 1;
+            }
+        } else if keys.matches_confirm(key) {
+            let picker = self.picker.take().expect("picker is open");
+            let from = self.folders.get(self.current_folder);
+            let to = picker
+                .targets
+                .get(picker.selected)
+                .and_then(|&idx| self.folders.get(idx));
+            if let (Some(from), Some(to)) = (from, to) {
+                return AppAction::TransferFavorite {
+                    aid: picker.aid,
+                    from: from.id,
+                    to: to.id,
+                    copy: picker.copy,
+                };
+            }
+        }
+        AppAction::None
+    }
+
+    /// Open the name popup for a new folder, or for renaming the current one
+    fn open_form(&mut self, rename: bool) {
+        let folder = self.folders.get(self.current_folder);
+        self.form = match (rename, folder) {
+            (true, Some(folder)) => Some(FolderForm {
+                media_id: Some(folder.id),
+                title: folder.title.clone(),
+                private: folder.is_private(),
+            }),
+            (true, None) => None,
+            (false, _) => Some(FolderForm {
+                media_id: None,
+                title: String::new(),
+                private: false,
+            }),
+        };
+    }
+
+    /// Keys while the name popup is open
+    fn handle_form_input(&mut self, key: KeyCode) -> AppAction {
+        let Some(form) = self.form.as_mut() else {
+            return AppAction::None;
+        };
+        match key {
+            KeyCode::Esc => self.form = None,
+            KeyCode::Tab => form.private = !form.private,
+            KeyCode::Backspace => {
+                form.title.pop();
+            }
+            KeyCode::Enter => {
+                let title = form.title.trim().to_string();
+                if !title.is_empty() {
+                    let form = self.form.take().expect("form is open");
+                    return AppAction::SaveFavFolder {
+                        media_id: form.media_id,
+                        title,
+                        private: form.private,
+                    };
+                }
+            }
+            // 收藏夹名称最长 20 字
+            KeyCode::Char(c) if form.title.chars().count() < 20 => form.title.push(c),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    /// First press asks for confirmation, the second deletes the current folder
+    fn delete_folder(&mut self, confirming: bool) -> AppAction {
+        let Some(folder) = self.folders.get(self.current_folder) else {
+            return AppAction::None;
+        };
+        if folder.is_default() {
+            return AppAction::ShowError(t("默认收藏夹不能删除").to_string());
+        }
+        if confirming {
+            return AppAction::DeleteFavFolder(folder.id);
+        }
+        self.confirm_delete = true;
+        AppAction::None
+    }
+
+    /// Title of a folder by media_id, for toasts
+    pub fn folder_title(&self, media_id: i64) -> Option<&str> {
+        self.folders
+            .iter()
+            .find(|f| f.id == media_id)
+            .map(|f| f.title.as_str())
+    }
+
+    fn render_form(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let Some(form) = &self.form else {
+            return;
+        };
+        let width = 48.min(area.width);
+        let height = 6.min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        clear_popup(frame, popup);
+
+        let title = if form.media_id.is_some() {
+            t(" ✏️ 重命名收藏夹 ")
+        } else {
+            t(" 📁 新建收藏夹 ")
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                title,
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                t(" [Enter] 保存  [Esc] 取消 "),
+                Style::default().fg(theme.fg_muted),
+            )));
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let privacy = if form.private {
+            t("🔒 私密")
+        } else {
+            t("🌐 公开")
+        };
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(t("名称: "), Style::default().fg(theme.fg_secondary)),
+                Span::styled(
+                    format!("{}_", form.title),
+                    Style::default().fg(theme.fg_primary),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(privacy, Style::default().fg(theme.fg_accent)),
+                Span::styled(t("  [Tab] 切换"), Style::default().fg(theme.fg_muted)),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines), inner.inner(Margin::new(1, 0)));
+    }
+
+    /// Keys while in multi-select mode; navigation falls through to the normal handler
+    fn handle_multi_select_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        let current = self.grid.selected_card().and_then(|card| card.aid);
+        let action = match self.select.handle_input(key, keys, current) {
+            MultiSelectKey::Handled => AppAction::None,
+            MultiSelectKey::Remove(aids) => match self.folders.get(self.current_folder) {
+                Some(folder) => AppAction::RemoveFavorites {
+                    media_id: folder.id,
+                    aids,
+                },
+                None => AppAction::None,
+            },
+            MultiSelectKey::Enqueue => {
+                let bvids = self.select.marked_bvids(&self.grid.cards);
+                self.select.finish_enqueue(bvids.len());
+                AppAction::EnqueueVideos(bvids)
+            }
+            MultiSelectKey::Unhandled => return None,
+        };
+        self.select.mark_cards(&mut self.grid.cards);
+        Some(action)
+    }
+
+    /// Drop the videos from the grid and the folder count before the request finishes;
+    /// returns them with their positions for [`Self::restore_removed`]
+    pub fn remove_cards(&mut self, media_id: i64, aids: &[i64]) -> Vec<(usize, VideoCard)> {
+        let positions: Vec<usize> = self
+            .grid
+            .cards
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| card.aid.is_some_and(|aid| aids.contains(&aid)))
+            .map(|(idx, _)| idx)
+            .collect();
+        // From the back, so the positions before each one stay valid
+        let mut removed: Vec<(usize, VideoCard)> = positions
+            .into_iter()
+            .rev()
+            .filter_map(|idx| self.grid.remove(idx).map(|card| (idx, card)))
+            .collect();
+        removed.reverse();
+
+        if let Some(folder) = self.folders.iter_mut().find(|f| f.id == media_id) {
+            folder.media_count = (folder.media_count - removed.len() as i32).max(0);
+        }
+        self.select.finish_remove(aids, &[]);
+        self.select.mark_cards(&mut self.grid.cards);
+        removed
+    }
+
+    /// Put back videos whose removal failed, at their old positions if the folder is
+    /// still shown
+    pub fn restore_removed(&mut self, media_id: i64, cards: Vec<(usize, VideoCard)>) {
+        if let Some(folder) = self.folders.iter_mut().find(|f| f.id == media_id) {
+            folder.media_count += cards.len() as i32;
+        }
+        if self.folders.get(self.current_folder).map(|f| f.id) != Some(media_id) {
+            return;
+        }
+        // Ascending positions, so each lands where it was
+        for (idx, card) in cards {
+            if self.grid.cards.iter().all(|c| c.aid != card.aid) {
+                self.grid.insert(idx, card);
+            }
+        }
+    }
+
+    /// Show a finished move or copy: a moved video leaves the grid and the counts follow;
+    /// returns the title of the target folder
+    pub fn finish_transfer(&mut self, aid: i64, from: i64, to: i64, copy: bool) -> String {
+        if !copy {
+            if let Some(idx) = self
+                .grid
+                .cards
+                .iter()
+                .position(|card| card.aid == Some(aid))
+            {
+                self.grid.remove(idx);
+            }
+        }
+        let mut title = String::new();
+        for folder in &mut self.folders {
+            if folder.id == to {
+                folder.media_count += 1;
+                title = folder.title.clone();
+            } else if folder.id == from && !copy {
+                folder.media_count = (folder.media_count - 1).max(0);
+            }
+        }
+        title
+    }
+
+    fn render_picker(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let Some(picker) = &self.picker else {
+            return;
+        };
+        let width = 48.min(area.width);
+        let height = (picker.targets.len() as u16 + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        clear_popup(frame, popup);
+
+        let title = if picker.copy {
+            t(" 📋 复制到收藏夹 ")
+        } else {
+            t(" 📦 移动到收藏夹 ")
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                title,
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                format!(" {} ", picker.title),
+                Style::default().fg(theme.fg_muted),
+            )));
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let visible = inner.height.saturating_sub(2) as usize;
+        let offset = (picker.selected + 1).saturating_sub(visible);
+        let items: Vec<ListItem> = picker
+            .targets
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .filter_map(|(idx, &folder)| {
+                let folder = self.folders.get(folder)?;
+                let style = if idx == picker.selected {
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.selection_bg)
+                } else {
+                    Style::default().fg(theme.fg_secondary)
+                };
+                Some(
+                    ListItem::new(format!(" {} ({})", folder.title, folder.media_count))
+                        .style(style),
+                )
+            })
+            .collect();
+        frame.render_widget(List::new(items), inner.inner(Margin::new(0, 1)));
+    }
+
+    /// Fetch every page of the selected folder in the background and write CSV + JSON
+    pub fn start_export(&mut self, api_client: &Arc<ApiClient>) {
+        if self.export_rx.is_some() {
+            return;
+        }
+        let Some(folder) = self.folders.get(self.current_folder).cloned() else {
+            return;
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.export_rx = Some(rx);
+        self.export_status = Some(tf("正在导出「{}」...", &[&folder.title]));
+
+        let client = api_client.clone();
+        tokio::spawn(async move {
+            let mut records = Vec::new();
+            let mut page = 1;
+            loop {
+                let data = match client.get_fav_resources(folder.id, page, PAGE_SIZE).await {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let _ = tx.send(ExportEvent::Finished(Err(e.to_string())));
+                        return;
+                    }
+                };
+                let medias = data.medias.unwrap_or_default();
+                let empty = medias.is_empty();
+                records.extend(
+                    medias
+                        .iter()
+                        .filter(|m| m.is_video())
+                        .map(|m| FavoriteRecord {
+                            title: m.title.clone(),
+                            bvid: m.bvid.clone(),
+                            up: m.upper.name.clone(),
+                            link: m.url(),
+                            fav_time: export::format_timestamp(m.fav_time),
+                        }),
+                );
+                let _ = tx.send(ExportEvent::Progress(records.len()));
+
+                if !data.has_more || empty {
+                    break;
+                }
+                page += 1;
+            }
+
+            let result = tokio::task::spawn_blocking(move || {
+                export::write_favorites(&folder.title, &records)
+            })
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| r.map_err(|e| e.to_string()));
+            let _ = tx.send(ExportEvent::Finished(result));
+        });
+    }
+
+    /// Drain export progress from the background task
+    pub fn poll_export(&mut self) {
+        let Some(rx) = &mut self.export_rx else {
+            return;
+        };
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                ExportEvent::Progress(count) => {
+                    self.export_status = Some(tf("正在导出... 已获取 {} 条", &[&count]));
+                }
+                ExportEvent::Finished(result) => {
+                    self.export_status = Some(match result {
+                        Ok(paths) => {
+                            let dir = paths
+                                .first()
+                                .and_then(|p| p.parent())
+                                .map(|p| p.display().to_string())
+                                .unwrap_or_default();
+                            tf("✓ 已导出 CSV 和 JSON 到 {}", &[&dir])
+                        }
+                        Err(e) => tf("导出失败: {}", &[&e]),
+                    });
+                    self.export_rx = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn poll_cover_results(&mut self) {
+        self.grid.poll_cover_results();
+    }
+
+    pub fn start_cover_downloads(&mut self) {
+        self.grid.start_cover_downloads();
+    }
+
+    fn render_tabs(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut spans = Vec::new();
+        // Keep the selected folder visible when there are many
+        let start = self.current_folder.saturating_sub(3);
+        if start > 0 {
+            spans.push(Span::styled("◀ ", Style::default().fg(theme.fg_secondary)));
+        }
+        for (i, folder) in self.folders.iter().enumerate().skip(start) {
+            if i > start {
+                spans.push(Span::raw("  "));
+            }
+            let lock = if folder.is_private() { "🔒" } else { "" };
+            let text = format!("{}{} ({})", lock, folder.title, folder.media_count);
+            if i == self.current_folder {
+                spans.push(Span::styled(
+                    text,
+                    Style::default()
+                        .fg(theme.fg_accent)
+                        .add_modifier(Modifier::BOLD)
+                        .add_modifier(Modifier::UNDERLINED),
+                ));
+            } else {
+                spans.push(Span::styled(text, Style::default().fg(theme.fg_secondary)));
+            }
+        }
+
+        let tabs = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border_subtle))
+                .title(Span::styled(
+                    t(" ⭐ 我的收藏 "),
+                    Style::default().fg(theme.bilibili_pink),
+                )),
+        );
+        frame.render_widget(tabs, area);
+    }
+
+    fn layout(area: Rect) -> std::rc::Rc<[Rect]> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Folder tabs
+                Constraint::Min(10),   // Videos grid
+                Constraint::Length(1), // Export status
+                Constraint::Length(2), // Help
+            ])
+            .split(area)
+    }
+}
+
+impl Default for FavoritesPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for FavoritesPage {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let chunks = Self::layout(area);
+
+        self.render_tabs(frame, chunks[0], theme);
+
+        if self.loading {
+            let loading = Paragraph::new(t("⏳ 加载中..."))
+                .style(Style::default().fg(theme.warning))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading, chunks[1]);
+        } else if let Some(error) = &self.error_message {
+            let error_widget = Paragraph::new(format!("❌ {}", error))
+                .style(Style::default().fg(theme.error))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_widget, chunks[1]);
+        } else if self.grid.cards.is_empty() {
+            let empty = Paragraph::new(t("收藏夹是空的"))
+                .style(Style::default().fg(theme.fg_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            self.grid.render(frame, chunks[1], theme);
+        }
+
+        if self.select.is_active() {
+            self.select
+                .render_bar(frame, chunks[2], theme, keys, t("移出收藏夹"));
+        } else if self.confirm_delete {
+            let title = self
+                .folders
+                .get(self.current_folder)
+                .map(|f| f.title.as_str())
+                .unwrap_or_default();
+            let confirm = Paragraph::new(tf(
+                "确认删除收藏夹「{}」及其中的视频？再按 [{}] 确认，其他键取消",
+                &[&title, &keys.fav_delete],
+            ))
+            .style(
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Center);
+            frame.render_widget(confirm, chunks[2]);
+        } else if let Some(status) = &self.export_status {
+            let style = if self.export_rx.is_some() {
+                Style::default().fg(theme.warning)
+            } else {
+                Style::default().fg(theme.fg_muted)
+            };
+            let status = Paragraph::new(status.as_str())
+                .style(style)
+                .alignment(Alignment::Center);
+            frame.render_widget(status, chunks[2]);
+        }
+
+        let help_text = tf(
+            "[{}/{}] 导航  [{}/{}] 切换收藏夹  [{}] 详情  [{}] 更多  [{}/{}] 移动/复制  [{}/{}/{}] 新建/重命名/删除  [{}] 多选  [{}] 导出",
+            &[
+                &keys.get_arrow_keys_display(),
+                &keys.get_nav_keys_display(),
+                &keys.section_prev,
+                &keys.section_next,
+                &keys.confirm,
+                &keys.play,
+                &keys.fav_move,
+                &keys.fav_copy,
+                &keys.fav_new,
+                &keys.fav_rename,
+                &keys.fav_delete,
+                &keys.multi_select,
+                &keys.export,
+            ],
+        );
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[3]);
+
+        self.render_picker(frame, area, theme);
+        self.render_form(frame, area, theme);
+    }
+
+    fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if self.form.is_some() {
+            return Some(self.handle_form_input(key));
+        }
+        if self.picker.is_some() {
+            return Some(self.handle_picker_input(key, keys));
+        }
+        if self.select.is_active() {
+            if let Some(action) = self.handle_multi_select_input(key, keys) {
+                return Some(action);
+            }
+        } else if keys.matches_multi_select(key) && !self.grid.cards.is_empty() {
+            self.select.start();
+            self.select.mark_cards(&mut self.grid.cards);
+            return Some(AppAction::None);
+        }
+        let confirming = std::mem::take(&mut self.confirm_delete);
+        if keys.matches_fav_delete(key) {
+            return Some(self.delete_folder(confirming));
+        }
+        if confirming {
+            return Some(AppAction::None);
+        }
+        if keys.matches_quit(key) || keys.matches_back(key) {
+            return Some(AppAction::BackToList);
+        }
+        if keys.matches_nav_next(key) {
+            return Some(AppAction::NavNext);
+        }
+        if keys.matches_nav_prev(key) {
+            return Some(AppAction::NavPrev);
+        }
+        if keys.matches_next_theme(key) {
+            return Some(AppAction::NextTheme);
+        }
+        if keys.matches_section_next(key) {
+            if self.cycle_folder(true) {
+                return Some(AppAction::LoadFavorites);
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_section_prev(key) {
+            if self.cycle_folder(false) {
+                return Some(AppAction::LoadFavorites);
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_refresh(key) {
+            return Some(AppAction::LoadFavorites);
+        }
+        if keys.matches_export(key) {
+            return Some(AppAction::ExportFavorites);
+        }
+        if keys.matches_fav_move(key) || keys.matches_fav_copy(key) {
+            return Some(self.open_picker(keys.matches_fav_copy(key)));
+        }
+        if keys.matches_fav_new(key) || keys.matches_fav_rename(key) {
+            self.open_form(keys.matches_fav_rename(key));
+            return Some(AppAction::None);
+        }
+        if keys.matches_down(key) {
+            if self.grid.move_down() && self.grid.is_near_bottom(3) && !self.loading_more {
+                return Some(AppAction::LoadMoreFavorites);
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_up(key) {
+            self.grid.move_up();
+            return Some(AppAction::None);
+        }
+        if keys.matches_right(key) {
+            self.grid.move_right();
+            return Some(AppAction::None);
+        }
+        if keys.matches_left(key) {
+            self.grid.move_left();
+            return Some(AppAction::None);
+        }
+        if keys.matches_play(key) {
+            if let Some(card) = self.grid.selected_card() {
+                if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                    return Some(AppAction::OpenActionMenu {
+                        bvid: bvid.clone(),
+                        aid,
+                        title: card.title.clone(),
+                    });
+                }
+            }
+            return Some(AppAction::None);
+        }
+        if self.grid.select_visible(key) || keys.matches_confirm(key) {
+            if let Some(card) = self.grid.selected_card() {
+                if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                    return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
+                }
+            }
+            return Some(AppAction::None);
+        }
+        Some(AppAction::None)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        if self.picker.is_some() || self.form.is_some() {
+            return None;
+        }
+        // Dragging the scrollbar jumps straight to a row
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return (self.grid.is_near_bottom(3) && !self.loading_more)
+                .then_some(AppAction::LoadMoreFavorites);
+        }
+
+        match event.kind {
+            MouseEventKind::ScrollDown => {
+                if self.grid.move_down() && self.grid.is_near_bottom(3) && !self.loading_more {
+                    return Some(AppAction::LoadMoreFavorites);
+                }
+                None
+            }
+            MouseEventKind::ScrollUp => {
+                self.grid.move_up();
+                None
+            }
+            MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
+                let forward = event.kind == MouseEventKind::ScrollRight;
+                self.cycle_folder(forward)
+                    .then_some(AppAction::LoadFavorites)
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some(card) = self.grid.cards.get(click_idx) {
+                        if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                            return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
+                        }
+                    }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_folders(ctx.client, ctx.mid))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_export();
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.form.is_some()
+    }
+
+    fn marks_watched(&self) -> bool {
+        true
+    }
+
+    fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        self.grid.set_watched(watched, dim);
+    }
+
+    fn set_show_invalid(&mut self, show: bool) {
+        self.show_invalid = show;
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+
+    fn session(&self) -> Option<Session> {
+        Some(Session {
+            page: SessionPage::Favorites,
+            selected: self.grid.selected_index,
+            search_query: String::new(),
+        })
+    }
+
+    fn restore_selection(&mut self, selected: usize) {
+        self.grid.select(selected);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(aid: i64) -> VideoCard {
+        VideoCard::new(
+            None,
+            Some(aid),
+            format!("video {}", aid),
+            String::new(),
+            String::new(),
+            String::new(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_restore_removed_keeps_positions() {
+        let mut page = FavoritesPage::new();
+        page.folders = vec![FavFolder {
+            id: 7,
+            title: "默认收藏夹".to_string(),
+            media_count: 5,
+            attr: 0,
+            fav_state: 0,
+        }];
+        for aid in 1..=5 {
+            page.grid.add_card(card(aid));
+        }
+
+        let removed = page.remove_cards(7, &[2, 4, 5]);
+        assert_eq!(page.grid.cards.len(), 2);
+        assert_eq!(page.folders[0].media_count, 2);
+
+        page.restore_removed(7, removed);
+        let aids: Vec<Option<i64>> = page.grid.cards.iter().map(|c| c.aid).collect();
+        assert_eq!(aids, [1, 2, 3, 4, 5].map(Some));
+        assert_eq!(page.folders[0].media_count, 5);
+    }
+}
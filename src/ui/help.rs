@@ -0,0 +1,98 @@
+//! Read-only keybinding cheat sheet, generated from the current [`Keybindings`]
+
+use super::{clear_popup, Theme};
+use crate::app::AppAction;
+use crate::i18n::t;
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Full-screen overlay listing all keybindings grouped by page
+#[derive(Default)]
+pub struct HelpOverlay {
+    scroll: u16,
+}
+
+impl HelpOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if keys.matches_back(key) || keys.matches_quit(key) || keys.matches_help(key) {
+            return Some(AppAction::CloseHelp);
+        }
+        if keys.matches_down(key) {
+            self.scroll = self.scroll.saturating_add(1);
+        } else if keys.matches_up(key) {
+            self.scroll = self.scroll.saturating_sub(1);
+        }
+        Some(AppAction::None)
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let width = 64.min(area.width);
+        let height = area.height.saturating_sub(2).max(3).min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+
+        clear_popup(frame, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                t(" ⌨️ 快捷键速查 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                format!(
+                    " [{}/{}] {}  [{}] {} ",
+                    keys.nav_up,
+                    keys.nav_down,
+                    t("滚动"),
+                    keys.back,
+                    t("关闭")
+                ),
+                Style::default().fg(theme.fg_muted),
+            )));
+
+        let mut lines = Vec::new();
+        for (group, entries) in keys.cheat_sheet() {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                t(group),
+                Style::default()
+                    .fg(theme.bilibili_cyan)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for (label, key) in entries {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<18}", key),
+                        Style::default()
+                            .fg(theme.fg_accent)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(t(label), Style::default().fg(theme.fg_primary)),
+                ]));
+            }
+        }
+
+        // Keep the last line reachable but don't scroll past it
+        let visible = popup.height.saturating_sub(2);
+        let max_scroll = (lines.len() as u16).saturating_sub(visible);
+        self.scroll = self.scroll.min(max_scroll);
+
+        let paragraph = Paragraph::new(lines).block(block).scroll((self.scroll, 0));
+        frame.render_widget(paragraph, popup);
+    }
+}
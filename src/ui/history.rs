@@ -1,11 +1,17 @@
 //! History page with watch history display in a grid layout with cover images
 
-use super::{Component, Theme};
+use super::cover_pool;
+use super::hit_map::HitMap;
+use super::multi_select::{MultiSelect, MultiSelectKey};
+use super::scrollbar::DragScrollbar;
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
 use crate::api::history::{HistoryCursor, HistoryItem};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
-use image::DynamicImage;
+use crate::export::{self, ExportEvent, HistoryRecord};
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, Session, SessionPage, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
     crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
     prelude::*,
@@ -14,9 +20,12 @@ use ratatui::{
 use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
 use std::collections::HashSet;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+/// Gap between pages fetched while looking for filter matches
+const FILTER_PAGE_INTERVAL: Duration = Duration::from_millis(300);
+
 /// History card with cached cover image
 struct HistoryCard {
     item: HistoryItem,
@@ -26,11 +35,16 @@ struct HistoryCard {
 /// Message for completed cover download
 struct CoverResult {
     index: usize,
+    /// Cover URL, to drop results whose index shifted after deleting entries
+    url: String,
     protocol: StatefulProtocol,
 }
 
 pub struct HistoryPage {
     items: Vec<HistoryCard>,
+    /// Indices into `items` of the shown entries, all of them unless filtering
+    shown: Vec<usize>,
+    /// Position in `shown`
     selected: usize,
     scroll_offset: usize,
     loading: bool,
@@ -40,11 +54,34 @@ pub struct HistoryPage {
     has_more: bool,
 
     pending_downloads: HashSet<usize>,
+    scrollbar: DragScrollbar,
+    /// Card areas of the last render, by position in `shown`
+    card_areas: HitMap<usize>,
     cover_rx: mpsc::Receiver<CoverResult>,
     cover_tx: mpsc::Sender<CoverResult>,
 
     last_click_time: Option<Instant>,
     last_click_index: Option<usize>,
+
+    /// 多选模式，按删除用的 kid 勾选
+    select: MultiSelect<String>,
+
+    /// Running export, drained in [`Self::poll_export`]
+    export_rx: Option<mpsc::UnboundedReceiver<ExportEvent>>,
+    /// Export progress or result, shown at the bottom of the page
+    export_status: Option<String>,
+    exported: usize,
+
+    /// Title/UP filter, None when not filtering
+    filter: Option<String>,
+    /// The filter is being typed
+    filter_typing: bool,
+    /// When the last page was fetched to look for filter matches
+    last_filter_page: Option<Instant>,
+    /// List deleted videos greyed out instead of hiding them
+    show_invalid: bool,
+    /// Title blocklist: matching records are hidden or collapsed
+    blocklist: Arc<TitleBlocklist>,
 }
 
 impl HistoryPage {
@@ -54,6 +91,7 @@ impl HistoryPage {
 
         Self {
             items: Vec::new(),
+            shown: Vec::new(),
             selected: 0,
             scroll_offset: 0,
             loading: false,
@@ -62,10 +100,21 @@ impl HistoryPage {
             cursor: None,
             has_more: true,
             pending_downloads: HashSet::new(),
+            scrollbar: DragScrollbar::default(),
+            card_areas: HitMap::new(),
             cover_rx: rx,
             cover_tx: tx,
             last_click_time: None,
             last_click_index: None,
+            select: MultiSelect::default(),
+            export_rx: None,
+            export_status: None,
+            exported: 0,
+            filter: None,
+            filter_typing: false,
+            last_filter_page: None,
+            show_invalid: false,
+            blocklist: Arc::new(TitleBlocklist::default()),
         }
     }
 
@@ -86,14 +135,20 @@ impl HistoryPage {
                 self.cursor = Some(data.cursor);
                 self.has_more = !self.items.is_empty();
                 self.loading = false;
+                self.refilter();
             }
             Err(e) => {
-                self.error = Some(format!("加载历史记录失败: {}", e));
+                self.error = Some(tf("加载历史记录失败: {}", &[&e]));
                 self.loading = false;
             }
         }
     }
 
+    /// Bvids of loaded video entries
+    pub fn watched_bvids(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().filter_map(|card| card.item.get_bvid())
+    }
+
     pub async fn load_more(&mut self, api_client: &ApiClient) {
         if self.loading || !self.has_more {
             return;
@@ -128,22 +183,174 @@ impl HistoryPage {
                 } else {
                     self.cursor = Some(data.cursor);
                     self.items.extend(new_items);
+                    self.refilter();
                 }
                 self.loading = false;
             }
             Err(e) => {
-                self.error = Some(format!("加载更多失败: {}", e));
+                self.error = Some(tf("加载更多失败: {}", &[&e]));
                 self.loading = false;
             }
         }
     }
 
+    /// Page through the whole history in the background and write it as JSONL
+    pub fn start_export(&mut self, api_client: &Arc<ApiClient>) {
+        if self.export_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.export_rx = Some(rx);
+        self.exported = 0;
+        self.export_status = Some(t("正在导出观看历史...").to_string());
+
+        let client = api_client.clone();
+        tokio::spawn(async move {
+            let mut records: Vec<HistoryRecord> = Vec::new();
+            let mut cursor: Option<HistoryCursor> = None;
+            loop {
+                let data = match &cursor {
+                    None => client.get_history(None, None, None).await,
+                    Some(c) => {
+                        client
+                            .get_history(Some(c.max), Some(c.view_at), Some(&c.business))
+                            .await
+                    }
+                };
+                let data = match data {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let _ = tx.send(ExportEvent::Finished(Err(e.to_string())));
+                        return;
+                    }
+                };
+                if data.list.is_empty() {
+                    break;
+                }
+                records.extend(data.list.iter().map(HistoryRecord::from));
+                cursor = Some(data.cursor);
+                let _ = tx.send(ExportEvent::Progress(records.len()));
+                // 翻页不要太快，避免触发风控
+                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+            }
+
+            let result = tokio::task::spawn_blocking(move || export::write_history(&records))
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|r| r.map(|path| vec![path]).map_err(|e| e.to_string()));
+            let _ = tx.send(ExportEvent::Finished(result));
+        });
+    }
+
+    /// Drain export progress from the background task
+    pub fn poll_export(&mut self) {
+        let Some(rx) = &mut self.export_rx else {
+            return;
+        };
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                ExportEvent::Progress(count) => {
+                    self.exported = count;
+                    self.export_status = Some(tf("正在导出观看历史... 已获取 {} 条", &[&count]));
+                }
+                ExportEvent::Finished(result) => {
+                    self.export_status = Some(match result {
+                        Ok(paths) => {
+                            let path = paths
+                                .first()
+                                .map(|p| p.display().to_string())
+                                .unwrap_or_default();
+                            tf("✓ 已导出 {} 条到 {}", &[&self.exported, &path])
+                        }
+                        Err(e) => tf("导出失败: {}", &[&e]),
+                    });
+                    self.export_rx = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Select an item by index, clamped to the shown items
+    pub fn select_index(&mut self, index: usize) {
+        self.selected = index.min(self.shown.len().saturating_sub(1));
+    }
+
+    /// Card at a position of the shown list
+    fn card_at(&self, pos: usize) -> Option<&HistoryCard> {
+        self.shown.get(pos).and_then(|&idx| self.items.get(idx))
+    }
+
+    /// Rebuild the shown list after the filter or the loaded entries changed
+    fn refilter(&mut self) {
+        let query = self.filter.as_deref().unwrap_or_default().to_lowercase();
+        self.shown = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| self.show_invalid || !card.item.is_invalid())
+            .filter(|(_, card)| !self.blocklist.hides(&card.item.title))
+            .filter(|(_, card)| query.is_empty() || matches_filter(&card.item, &query))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.select_index(self.selected);
+    }
+
+    /// A filter without any match yet keeps paging back through the history until the end
+    fn wants_more_matches(&self) -> bool {
+        self.filter.as_ref().is_some_and(|f| !f.is_empty())
+            && self.shown.is_empty()
+            && self.has_more
+            && !self.loading
+            && self.error.is_none()
+    }
+
+    fn handle_filter_input(&mut self, key: KeyCode) -> Option<AppAction> {
+        let filter = self.filter.as_mut()?;
+        match key {
+            KeyCode::Enter => {
+                self.filter_typing = false;
+                if filter.is_empty() {
+                    self.filter = None;
+                }
+                return Some(AppAction::None);
+            }
+            KeyCode::Esc => {
+                self.filter = None;
+                self.filter_typing = false;
+            }
+            KeyCode::Backspace => {
+                filter.pop();
+            }
+            KeyCode::Char(c) => filter.push(c),
+            _ => return Some(AppAction::None),
+        }
+        self.selected = 0;
+        self.scroll_offset = 0;
+        self.refilter();
+        Some(AppAction::None)
+    }
+
+    /// Filter shown above the grid, with the match count or the paging state
+    fn filter_title(&self, filter: &str) -> String {
+        let cursor = if self.filter_typing { "▏" } else { "" };
+        let status = if filter.is_empty() || !self.shown.is_empty() {
+            tf("{} 条匹配", &[&self.shown.len()])
+        } else if self.has_more {
+            t("向后翻页查找中...").to_string()
+        } else {
+            t("无匹配").to_string()
+        };
+        tf(" 🔍 过滤: {}{} · {} ", &[&filter, &cursor, &status])
+    }
+
     fn is_near_bottom(&self, visible_rows: usize) -> bool {
-        if self.items.is_empty() {
+        if self.shown.is_empty() {
             return false;
         }
         let cols = 4;
-        let total_rows = self.items.len().div_ceil(cols);
+        let total_rows = self.shown.len().div_ceil(cols);
         let current_row = self.selected / cols;
         current_row + 2 >= self.scroll_offset + visible_rows.min(total_rows)
     }
@@ -157,14 +364,19 @@ impl HistoryPage {
         // Calculate visible range
         let cols = 4;
         let visible_start = self.scroll_offset * cols;
-        let visible_end = (visible_start + 5 * cols).min(self.items.len());
+        let visible_end = (visible_start + 5 * cols).min(self.shown.len());
 
-        for idx in visible_start..visible_end {
+        for pos in visible_start..visible_end {
+            let idx = self.shown[pos];
             if self.items[idx].cover_protocol.is_some() || self.pending_downloads.contains(&idx) {
                 continue;
             }
 
-            let Some(cover_url) = self.items[idx].item.get_cover() else {
+            let item = &self.items[idx].item;
+            let Some(cover_url) = item
+                .get_cover()
+                .filter(|_| self.blocklist.matched(&item.title).is_none())
+            else {
                 continue;
             };
 
@@ -174,11 +386,12 @@ impl HistoryPage {
             let picker = Arc::clone(&self.picker);
 
             tokio::spawn(async move {
-                if let Some(img) = Self::download_image(&url).await {
+                if let Some(img) = cover_pool::download_image(&url).await {
                     let protocol = picker.new_resize_protocol(img);
                     let _ = tx
                         .send(CoverResult {
                             index: idx,
+                            url,
                             protocol,
                         })
                         .await;
@@ -191,16 +404,77 @@ impl HistoryPage {
     pub fn poll_cover_results(&mut self) {
         while let Ok(result) = self.cover_rx.try_recv() {
             self.pending_downloads.remove(&result.index);
-            if result.index < self.items.len() {
-                self.items[result.index].cover_protocol = Some(result.protocol);
+            if let Some(card) = self.items.get_mut(result.index) {
+                if card.item.get_cover() == Some(result.url.as_str()) {
+                    card.cover_protocol = Some(result.protocol);
+                }
+            }
+        }
+    }
+
+    /// Bvids of the checked video entries, in list order
+    fn marked_bvids(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|card| card.item.is_video() && self.select.is_marked(&card.item.delete_kid()))
+            .filter_map(|card| card.item.get_bvid().map(str::to_string))
+            .collect()
+    }
+
+    /// Keys while in multi-select mode; navigation falls through to the normal handler
+    fn handle_multi_select_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        let current = self
+            .card_at(self.selected)
+            .map(|card| card.item.delete_kid());
+        match self.select.handle_input(key, keys, current) {
+            MultiSelectKey::Handled => Some(AppAction::None),
+            MultiSelectKey::Remove(kids) => Some(AppAction::DeleteHistory(kids)),
+            MultiSelectKey::Enqueue => {
+                let bvids = self.marked_bvids();
+                self.select.finish_enqueue(bvids.len());
+                Some(AppAction::EnqueueVideos(bvids))
             }
+            MultiSelectKey::Unhandled => None,
         }
     }
 
-    async fn download_image(url: &str) -> Option<DynamicImage> {
-        let response = reqwest::get(url).await.ok()?;
-        let bytes = response.bytes().await.ok()?;
-        image::load_from_memory(&bytes).ok()
+    /// Drop deleted entries; failed ones stay checked so they can be retried
+    pub fn finish_delete(&mut self, deleted: &[String], errors: &[String]) {
+        let removed: HashSet<&String> = deleted.iter().collect();
+        self.items
+            .retain(|card| !removed.contains(&card.item.delete_kid()));
+        self.pending_downloads.clear();
+        self.refilter();
+        self.select.finish_remove(deleted, errors);
+    }
+
+    /// Put a just-watched record at the top, moving its old entry (and cover) if loaded
+    pub fn upsert_latest(&mut self, item: HistoryItem) {
+        let kid = item.delete_kid();
+        let selected_kid = self.card_at(self.selected).map(|c| c.item.delete_kid());
+        let card = match self.items.iter().position(|c| c.item.delete_kid() == kid) {
+            Some(idx) => {
+                let mut card = self.items.remove(idx);
+                card.item = item;
+                card
+            }
+            None => HistoryCard {
+                item,
+                cover_protocol: None,
+            },
+        };
+        self.items.insert(0, card);
+        self.pending_downloads.clear();
+        self.refilter();
+
+        // Keep the cursor on the entry it was on
+        if let Some(pos) = selected_kid.and_then(|kid| {
+            self.shown
+                .iter()
+                .position(|&idx| self.items[idx].item.delete_kid() == kid)
+        }) {
+            self.select_index(pos);
+        }
     }
 
     fn visible_rows(&self, height: u16) -> usize {
@@ -229,26 +503,59 @@ impl Default for HistoryPage {
 }
 
 impl Component for HistoryPage {
-    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _keys: &Keybindings) {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
         // Main block
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 📜 观看历史 ",
+                t(" 📜 观看历史 "),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
             ))
             .title_alignment(Alignment::Left);
+        let block = match &self.export_status {
+            Some(status) => block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {} ", status),
+                    Style::default().fg(if self.export_rx.is_some() {
+                        theme.warning
+                    } else {
+                        theme.fg_muted
+                    }),
+                ))
+                .right_aligned(),
+            ),
+            None => block,
+        };
+        let block = match &self.filter {
+            Some(filter) => block.title(Span::styled(
+                self.filter_title(filter),
+                Style::default().fg(theme.fg_accent),
+            )),
+            None => block,
+        };
 
-        let inner = block.inner(area);
+        let mut inner = block.inner(area);
         frame.render_widget(block, area);
 
+        // 多选模式底部显示已选数量
+        if self.select.is_active() {
+            let bar_area = Rect {
+                y: inner.y + inner.height.saturating_sub(1),
+                height: inner.height.min(1),
+                ..inner
+            };
+            inner.height = inner.height.saturating_sub(1);
+            self.select
+                .render_bar(frame, bar_area, theme, keys, t("删除历史"));
+        }
+
         // Loading state
         if self.loading && self.items.is_empty() {
-            let loading = Paragraph::new("加载中...")
+            let loading = Paragraph::new(t("加载中..."))
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted));
             frame.render_widget(loading, inner);
@@ -265,8 +572,15 @@ impl Component for HistoryPage {
         }
 
         // Empty state
-        if self.items.is_empty() {
-            let empty = Paragraph::new("暂无历史记录")
+        if self.shown.is_empty() {
+            let text = if self.items.is_empty() {
+                t("暂无历史记录")
+            } else if self.has_more {
+                t("向后翻页查找中...")
+            } else {
+                t("没有匹配的历史记录")
+            };
+            let empty = Paragraph::new(text)
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted));
             frame.render_widget(empty, inner);
@@ -283,11 +597,34 @@ impl Component for HistoryPage {
         keys: &crate::storage::Keybindings,
     ) -> Option<AppAction> {
         let cols = 4;
-        let total = self.items.len();
+        let total = self.shown.len();
+
+        if self.filter_typing {
+            return self.handle_filter_input(key);
+        }
+        if self.select.is_active() {
+            if let Some(action) = self.handle_multi_select_input(key, keys) {
+                return Some(action);
+            }
+        } else if keys.matches_multi_select(key) && !self.shown.is_empty() {
+            self.select.start();
+            return Some(AppAction::None);
+        } else if keys.matches_search_focus(key) {
+            self.filter.get_or_insert_with(String::new);
+            self.filter_typing = true;
+            return Some(AppAction::None);
+        } else if keys.matches_back(key) && self.filter.is_some() {
+            self.filter = None;
+            self.refilter();
+            return Some(AppAction::None);
+        }
 
         if keys.matches_quit(key) || keys.matches_back(key) {
             return Some(AppAction::BackToList);
         }
+        if keys.matches_export(key) && !self.select.is_active() {
+            return Some(AppAction::ExportHistory);
+        }
         if keys.matches_left(key) {
             if self.selected > 0 {
                 self.selected -= 1;
@@ -316,8 +653,22 @@ impl Component for HistoryPage {
             }
             return None;
         }
+        if keys.matches_play(key) {
+            if let Some(card) = self.card_at(self.selected) {
+                if card.item.is_video() {
+                    if let Some(bvid) = card.item.get_bvid() {
+                        return Some(AppAction::OpenActionMenu {
+                            bvid: bvid.to_string(),
+                            aid: card.item.history.oid,
+                            title: card.item.title.clone(),
+                        });
+                    }
+                }
+            }
+            return None;
+        }
         if keys.matches_confirm(key) {
-            if let Some(card) = self.items.get(self.selected) {
+            if let Some(card) = self.card_at(self.selected) {
                 // Only open video detail for video types
                 if card.item.is_video() {
                     if let Some(bvid) = card.item.get_bvid() {
@@ -340,9 +691,15 @@ impl Component for HistoryPage {
         None
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
         let cols = 4;
-        let total = self.items.len();
+        let total = self.shown.len();
+
+        // Dragging the scrollbar jumps straight to a row
+        if let Some(row) = self.scrollbar.handle_mouse(&event) {
+            self.selected = (row * cols + self.selected % cols).min(total.saturating_sub(1));
+            return self.is_near_bottom(4).then_some(AppAction::LoadMoreHistory);
+        }
 
         match event.kind {
             MouseEventKind::ScrollDown => {
@@ -361,24 +718,9 @@ impl Component for HistoryPage {
                 None
             }
             MouseEventKind::Down(MouseButton::Left) => {
-                let inner = area.inner(Margin::new(1, 1));
-
-                if !inner.contains(ratatui::layout::Position::new(event.column, event.row)) {
-                    return None;
-                }
-
-                let card_height = 12u16;
-                let card_width = inner.width / cols as u16;
-
-                let relative_y = event.row - inner.y;
-                let click_row = (relative_y / card_height) as usize;
-                let actual_row = self.scroll_offset + click_row;
-
-                let click_col = (event.column.saturating_sub(inner.x) / card_width) as usize;
+                let click_idx = self.card_areas.at(event.column, event.row)?;
 
-                let click_idx = actual_row * cols + click_col;
-
-                if click_idx < self.items.len() {
+                if click_idx < total {
                     let now = Instant::now();
                     let is_double_click = self.last_click_index == Some(click_idx)
                         && self
@@ -388,7 +730,7 @@ impl Component for HistoryPage {
                     if is_double_click {
                         self.last_click_time = None;
                         self.last_click_index = None;
-                        if let Some(card) = self.items.get(click_idx) {
+                        if let Some(card) = self.card_at(click_idx) {
                             if card.item.is_video() {
                                 if let Some(bvid) = card.item.get_bvid() {
                                     let aid = card.item.history.oid;
@@ -397,9 +739,8 @@ impl Component for HistoryPage {
                             }
                         }
                     } else {
+                        // The clicked card is on screen, so the scroll offset stays as drawn
                         self.selected = click_idx;
-                        let visible_rows = self.visible_rows(area.height);
-                        self.update_scroll(visible_rows);
                         self.last_click_time = Some(now);
                         self.last_click_index = Some(click_idx);
                     }
@@ -409,6 +750,52 @@ impl Component for HistoryPage {
             _ => None,
         }
     }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_history(ctx.client))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_export();
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+
+        let more = self.wants_more_matches()
+            && self
+                .last_filter_page
+                .is_none_or(|at| at.elapsed() >= FILTER_PAGE_INTERVAL);
+        if more {
+            self.last_filter_page = Some(Instant::now());
+        }
+        Box::pin(async move { more.then_some(AppAction::LoadMoreHistory) })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.filter_typing
+    }
+
+    fn set_show_invalid(&mut self, show: bool) {
+        self.show_invalid = show;
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.blocklist = blocklist;
+        self.refilter();
+    }
+
+    fn session(&self) -> Option<Session> {
+        Some(Session {
+            page: SessionPage::History,
+            selected: self.selected,
+            search_query: String::new(),
+        })
+    }
+
+    fn restore_selection(&mut self, selected: usize) {
+        self.select_index(selected);
+    }
 }
 
 impl HistoryPage {
@@ -417,13 +804,25 @@ impl HistoryPage {
         let visible_rows = self.visible_rows(area.height);
         self.update_scroll(visible_rows);
 
+        let total_rows = self.shown.len().div_ceil(cols);
+        self.scrollbar.render(
+            frame,
+            area,
+            total_rows,
+            self.scroll_offset,
+            visible_rows,
+            theme,
+        );
+        let area = DragScrollbar::content_area(area, total_rows, visible_rows);
+
         let card_height = 12u16;
         let card_width = area.width / cols as u16;
 
         let start_idx = self.scroll_offset * cols;
-        let end_idx = (start_idx + visible_rows * cols).min(self.items.len());
+        let end_idx = (start_idx + visible_rows * cols).min(self.shown.len());
 
-        for (i, idx) in (start_idx..end_idx).enumerate() {
+        self.card_areas.clear();
+        for (i, pos) in (start_idx..end_idx).enumerate() {
             let row = i / cols;
             let col = i % cols;
 
@@ -435,15 +834,16 @@ impl HistoryPage {
             }
 
             let card_area = Rect::new(x, y, card_width, card_height);
-            let is_selected = idx == self.selected;
+            let is_selected = pos == self.selected;
+            self.card_areas.push(card_area, pos);
 
-            self.render_history_card(frame, card_area, idx, is_selected, theme);
+            self.render_history_card(frame, card_area, self.shown[pos], is_selected, theme);
         }
 
         // Loading indicator at bottom
         if self.loading && !self.items.is_empty() {
             let loading_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
-            let loading = Paragraph::new("加载更多...")
+            let loading = Paragraph::new(t("加载更多..."))
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted));
             frame.render_widget(loading, loading_area);
@@ -458,11 +858,18 @@ impl HistoryPage {
         is_selected: bool,
         theme: &Theme,
     ) {
+        let mark = self.select.mark_of(&self.items[idx].item.delete_kid());
+        let blocked = self
+            .blocklist
+            .matched(&self.items[idx].item.title)
+            .map(str::to_string);
         let card = &mut self.items[idx];
 
         // Card border
         let border_color = if is_selected {
             theme.bilibili_pink
+        } else if mark == Some(true) {
+            theme.success
         } else {
             theme.border_subtle
         };
@@ -483,6 +890,21 @@ impl HistoryPage {
             return;
         }
 
+        // Collapsed blocked title, same as the video cards
+        if let Some(rule) = blocked {
+            let [_, middle, _] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .areas(inner);
+            let collapsed = Paragraph::new(tf("已屏蔽：{}", &[&rule]))
+                .style(Style::default().fg(theme.fg_muted))
+                .alignment(Alignment::Center);
+            frame.render_widget(collapsed, middle);
+            return;
+        }
+
         // Split into cover area and info area
         let cover_height = 6u16.min(inner.height.saturating_sub(3));
         let chunks = Layout::default()
@@ -496,7 +918,11 @@ impl HistoryPage {
             frame.render_stateful_widget(image, chunks[0], protocol);
         } else {
             // Placeholder with badge
-            let badge = card.item.badge.as_deref().unwrap_or("");
+            let badge = if card.item.is_invalid() {
+                t("已失效")
+            } else {
+                card.item.badge.as_deref().unwrap_or("")
+            };
             let placeholder = Paragraph::new(badge)
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted).bg(theme.bg_secondary));
@@ -519,10 +945,17 @@ impl HistoryPage {
             Style::default()
                 .fg(theme.fg_primary)
                 .add_modifier(Modifier::BOLD)
+        } else if card.item.is_invalid() {
+            Style::default().fg(theme.fg_muted)
         } else {
             Style::default().fg(theme.fg_primary)
         };
-        let title_widget = Paragraph::new(title.as_str())
+        let title = match mark {
+            Some(true) => format!("☑ {}", title),
+            Some(false) => format!("☐ {}", title),
+            None => title.clone(),
+        };
+        let title_widget = Paragraph::new(title)
             .style(title_style)
             .wrap(Wrap { trim: true });
         frame.render_widget(title_widget, info_chunks[0]);
@@ -549,3 +982,8 @@ impl HistoryPage {
         }
     }
 }
+
+/// Case-insensitive match of a lowercased query against the title and UP name
+fn matches_filter(item: &HistoryItem, query: &str) -> bool {
+    item.title.to_lowercase().contains(query) || item.author_name.to_lowercase().contains(query)
+}
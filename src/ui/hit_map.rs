@@ -0,0 +1,78 @@
+//! Click hit-testing shared by all pages: clickable areas are registered while drawing
+//! and mouse events only look them up, so clicks always follow the actual layout
+
+use ratatui::layout::{Position, Rect};
+
+/// Clickable areas of the last frame and what each one stands for
+#[derive(Debug, Clone)]
+pub struct HitMap<T> {
+    hits: Vec<(Rect, T)>,
+}
+
+impl<T> Default for HitMap<T> {
+    fn default() -> Self {
+        Self { hits: Vec::new() }
+    }
+}
+
+impl<T: Copy> HitMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the areas of the previous frame, call before drawing the widgets again
+    pub fn clear(&mut self) {
+        self.hits.clear();
+    }
+
+    /// Register a clickable area; empty areas are ignored
+    pub fn push(&mut self, area: Rect, hit: T) {
+        if !area.is_empty() {
+            self.hits.push((area, hit));
+        }
+    }
+
+    /// What was drawn at a screen cell; the last registered area wins where they overlap
+    pub fn at(&self, column: u16, row: u16) -> Option<T> {
+        let position = Position::new(column, row);
+        self.hits
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, hit)| *hit)
+    }
+
+    /// The n-th registered area, in drawing order
+    pub fn get(&self, n: usize) -> Option<(Rect, T)> {
+        self.hits.get(n).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Rect, T)> + '_ {
+        self.hits.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_follows_registered_areas() {
+        let mut hits = HitMap::new();
+        hits.push(Rect::new(0, 0, 10, 2), 1);
+        hits.push(Rect::new(10, 0, 10, 2), 2);
+        hits.push(Rect::new(5, 1, 0, 0), 3);
+        assert_eq!(hits.at(0, 0), Some(1));
+        assert_eq!(hits.at(19, 1), Some(2));
+        assert_eq!(hits.at(20, 0), None);
+        assert_eq!(hits.iter().count(), 2);
+
+        // A popup drawn later covers what is below it
+        hits.push(Rect::new(8, 0, 4, 1), 4);
+        assert_eq!(hits.at(9, 0), Some(4));
+        assert_eq!(hits.at(9, 1), Some(1));
+
+        hits.clear();
+        assert_eq!(hits.at(0, 0), None);
+    }
+}
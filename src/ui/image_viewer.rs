@@ -0,0 +1,148 @@
+//! Async image loading keyed by URL, and an overlay for browsing a set of images
+
+use super::cover_pool;
+use super::{clear_popup, Theme};
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Downloads images in the background and keeps their render protocols by URL
+pub struct ImageLoader {
+    picker: Arc<Picker>,
+    images: HashMap<String, StatefulProtocol>,
+    pending: HashSet<String>,
+    tx: mpsc::Sender<(String, StatefulProtocol)>,
+    rx: mpsc::Receiver<(String, StatefulProtocol)>,
+}
+
+impl ImageLoader {
+    pub fn new() -> Self {
+        // Try to detect terminal graphics protocol (Kitty/Sixel/iTerm2)
+        // Fall back to halfblocks if detection fails
+        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
+        let (tx, rx) = mpsc::channel(8);
+        Self {
+            picker,
+            images: HashMap::new(),
+            pending: HashSet::new(),
+            tx,
+            rx,
+        }
+    }
+
+    /// Start downloading `url` unless it is loaded or already on its way (non-blocking)
+    pub fn request(&mut self, url: &str) {
+        if self.images.contains_key(url) || self.pending.contains(url) {
+            return;
+        }
+        self.pending.insert(url.to_string());
+        let tx = self.tx.clone();
+        let picker = Arc::clone(&self.picker);
+        let url = url.to_string();
+        tokio::spawn(async move {
+            if let Some(img) = cover_pool::download_image(&url).await {
+                let protocol = picker.new_resize_protocol(img);
+                let _ = tx.send((url, protocol)).await;
+            }
+        });
+    }
+
+    /// Poll for completed downloads (non-blocking)
+    pub fn poll(&mut self) {
+        while let Ok((url, protocol)) = self.rx.try_recv() {
+            self.pending.remove(&url);
+            self.images.insert(url, protocol);
+        }
+    }
+
+    /// Draw the image fitted into `area`, or a loading hint while it is downloading
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, url: &str, theme: &Theme) {
+        if let Some(protocol) = self.images.get_mut(url) {
+            frame.render_stateful_widget(StatefulImage::new(), area, protocol);
+            return;
+        }
+        let loading_text = if self.pending.contains(url) {
+            t("加载中...")
+        } else {
+            t("等待加载...")
+        };
+        let loading = Paragraph::new(loading_text)
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(loading, area);
+    }
+}
+
+/// Full-size overlay for the images of a comment, switched with left/right
+pub struct ImageViewer {
+    urls: Vec<String>,
+    index: usize,
+}
+
+/// What the page should do after a key in the viewer
+pub enum ViewerEvent {
+    None,
+    Close,
+    /// The confirm key, left to the page (e.g. like the comment the images belong to)
+    Confirm,
+}
+
+impl ImageViewer {
+    pub fn new(urls: Vec<String>) -> Self {
+        Self { urls, index: 0 }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode, keys: &Keybindings) -> ViewerEvent {
+        if keys.matches_back(key) || keys.matches_quit(key) {
+            ViewerEvent::Close
+        } else if keys.matches_confirm(key) {
+            ViewerEvent::Confirm
+        } else {
+            if keys.matches_left(key) {
+                self.index = self.index.saturating_sub(1);
+            } else if keys.matches_right(key) && self.index + 1 < self.urls.len() {
+                self.index += 1;
+            }
+            ViewerEvent::None
+        }
+    }
+
+    /// Draw over most of `area`, loading the current image and its neighbours
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        loader: &mut ImageLoader,
+        theme: &Theme,
+        keys: &Keybindings,
+    ) {
+        let popup = area.inner(Margin::new(area.width / 10, area.height / 12));
+        let Some(url) = self.urls.get(self.index) else {
+            return;
+        };
+        for neighbour in self.urls.iter().skip(self.index.saturating_sub(1)).take(3) {
+            loader.request(neighbour);
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_focused))
+            .title(tf(" 图片 {}/{} ", &[&(self.index + 1), &self.urls.len()]))
+            .title_bottom(
+                Line::from(tf(
+                    " [{}/{}] 切换  [{}] 点赞  [{}] 关闭 ",
+                    &[&keys.nav_left, &keys.nav_right, &keys.confirm, &keys.back],
+                ))
+                .centered(),
+            );
+        let inner = block.inner(popup);
+        clear_popup(frame, popup);
+        frame.render_widget(block, popup);
+        loader.render(frame, inner, url, theme);
+    }
+}
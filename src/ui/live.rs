@@ -1,50 +1,151 @@
 //! Live streaming recommendations page with grid layout
 
-use super::{Component, Theme};
+use super::card_grid::{render_cover, CardGrid, CoverPlacement, GridCard};
+use super::hit_map::HitMap;
+use super::video_card::portrait_badge;
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
-use crate::api::live::LiveRoom;
+use crate::api::live::{AreaRankRoom, LiveRoom};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
-use image::DynamicImage;
+use crate::format::format_count;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, Session, SessionPage};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
     crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
     prelude::*,
     widgets::*,
 };
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
-use std::collections::HashSet;
-use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::mpsc;
+use ratatui_image::protocol::StatefulProtocol;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Live card with cached cover image
 struct LiveCard {
-    pub room: LiveRoom,
-    pub cover_image: Option<StatefulProtocol>,
+    room: LiveRoom,
+    cover_image: Option<StatefulProtocol>,
+    cover_placement: CoverPlacement,
+    /// 竖屏直播
+    portrait: bool,
 }
 
-/// Message for completed cover download
-struct CoverResult {
-    room_id: i64,
-    protocol: Option<StatefulProtocol>,
+impl LiveCard {
+    fn new(room: LiveRoom) -> Self {
+        Self {
+            room,
+            cover_image: None,
+            cover_placement: CoverPlacement::default(),
+            portrait: false,
+        }
+    }
 }
 
-pub struct LivePage {
-    rooms: Vec<LiveCard>,
-    selected_index: usize,
-    columns: usize,
-    scroll_offset: usize,
+impl GridCard for LiveCard {
+    fn cover_url(&self) -> Option<String> {
+        [&self.room.cover, &self.room.keyframe]
+            .into_iter()
+            .find(|url| !url.is_empty())
+            .cloned()
+    }
+
+    fn has_cover(&self) -> bool {
+        self.cover_image.is_some()
+    }
+
+    fn set_cover(&mut self, cover: StatefulProtocol) {
+        self.cover_image = Some(cover);
+        self.cover_placement = CoverPlacement::default();
+    }
+
+    fn set_portrait(&mut self, portrait: bool) {
+        self.portrait = portrait;
+    }
+}
+
+/// 人气榜相对上次刷新的变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trend {
+    Up,
+    Down,
+    Same,
+    /// 上次刷新时不在榜上
+    New,
+}
+
+fn trend(previous: Option<i64>, online: i64) -> Trend {
+    match previous {
+        None => Trend::New,
+        Some(prev) if online > prev => Trend::Up,
+        Some(prev) if online < prev => Trend::Down,
+        Some(_) => Trend::Same,
+    }
+}
+
+/// Popularity ranking of one parent area, shown beside the grid
+struct RankPanel {
+    area_id: i64,
+    area_name: String,
+    rooms: Vec<AreaRankRoom>,
+    /// 每个房间相对上次刷新的变化
+    trends: Vec<Trend>,
+    selected: usize,
     loading: bool,
     error: Option<String>,
-    last_area_height: u16,
+    last_refresh: Option<Instant>,
+    hits: HitMap<usize>,
+}
 
-    // Image loading state
-    picker: Arc<Picker>,
-    cover_tx: mpsc::Sender<CoverResult>,
-    cover_rx: mpsc::Receiver<CoverResult>,
-    pending_downloads: HashSet<i64>,
+impl RankPanel {
+    fn new(area_id: i64, area_name: String) -> Self {
+        Self {
+            area_id,
+            area_name,
+            rooms: Vec::new(),
+            trends: Vec::new(),
+            selected: 0,
+            loading: false,
+            error: None,
+            last_refresh: None,
+            hits: HitMap::new(),
+        }
+    }
+
+    /// Replace the ranking, comparing each room with its previous popularity
+    fn set_rooms(&mut self, rooms: Vec<AreaRankRoom>) {
+        // The first load has nothing to compare with
+        let previous: Option<HashMap<i64, i64>> = (!self.rooms.is_empty())
+            .then(|| self.rooms.iter().map(|r| (r.roomid, r.online)).collect());
+        self.trends = rooms
+            .iter()
+            .map(|room| match &previous {
+                Some(previous) => trend(previous.get(&room.roomid).copied(), room.online),
+                None => Trend::Same,
+            })
+            .collect();
+        // Keep the same room selected when it moved
+        let selected_room = self.rooms.get(self.selected).map(|r| r.roomid);
+        self.selected = selected_room
+            .and_then(|id| rooms.iter().position(|r| r.roomid == id))
+            .unwrap_or(0);
+        self.rooms = rooms;
+    }
+
+    fn needs_refresh(&self) -> bool {
+        !self.loading
+            && self
+                .last_refresh
+                .is_none_or(|at| at.elapsed() >= LivePage::RANK_REFRESH_INTERVAL)
+    }
+}
+
+pub struct LivePage {
+    grid: CardGrid<LiveCard>,
+    loading: bool,
+    error: Option<String>,
     last_load_time: Option<Instant>,
     loading_more: bool,
+    /// 当前分区人气榜，打开时占据右侧
+    rank: Option<RankPanel>,
 }
 
 impl LivePage {
@@ -56,24 +157,21 @@ impl LivePage {
     const PREFETCH_ROWS: usize = 4;
     /// 默认可见行数
     const DEFAULT_VISIBLE_ROWS: usize = 3;
+    /// 人气榜显示的主播数
+    const RANK_SIZE: usize = 20;
+    /// 人气榜自动刷新间隔
+    const RANK_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+    /// 人气榜宽度
+    const RANK_WIDTH: u16 = 36;
 
     pub fn new() -> Self {
-        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
-        let (tx, rx) = mpsc::channel(50);
         Self {
-            rooms: Vec::new(),
-            selected_index: 0,
-            columns: Self::DEFAULT_COLUMNS,
-            scroll_offset: 0,
+            grid: CardGrid::new(Self::DEFAULT_COLUMNS, Self::CARD_HEIGHT).with_number_hints(),
             loading: false,
             error: None,
-            last_area_height: 0,
-            picker,
-            cover_tx: tx,
-            cover_rx: rx,
-            pending_downloads: HashSet::new(),
             last_load_time: None,
             loading_more: false,
+            rank: None,
         }
     }
 
@@ -86,30 +184,20 @@ impl LivePage {
 
         match api_client.get_live_recommendations().await {
             Ok(rooms) => {
-                self.rooms = rooms
-                    .into_iter()
-                    .map(|room| LiveCard {
-                        room,
-                        cover_image: None,
-                    })
-                    .collect();
+                self.grid
+                    .set_cards(rooms.into_iter().map(LiveCard::new).collect());
                 self.loading = false;
-                self.selected_index = 0;
-                self.scroll_offset = 0;
                 self.last_load_time = Some(Instant::now());
             }
             Err(e) => {
-                self.error = Some(format!("加载直播推荐失败: {}", e));
+                self.error = Some(tf("加载直播推荐失败: {}", &[&e]));
                 self.loading = false;
             }
         }
     }
 
     pub async fn refresh(&mut self, api_client: &ApiClient) {
-        self.rooms.clear();
-        self.selected_index = 0;
-        self.scroll_offset = 0;
-        self.pending_downloads.clear();
+        self.grid.clear();
         self.load_recommendations(api_client).await;
     }
 
@@ -120,108 +208,94 @@ impl LivePage {
 
         self.loading_more = true;
 
-        match api_client.get_live_recommendations().await {
-            Ok(rooms) => {
-                for room in rooms {
-                    self.rooms.push(LiveCard {
-                        room,
-                        cover_image: None,
-                    });
-                }
-                self.loading_more = false;
-            }
-            Err(_) => {
-                self.loading_more = false;
+        if let Ok(rooms) = api_client.get_live_recommendations().await {
+            for room in rooms {
+                self.grid.push(LiveCard::new(room));
             }
         }
-    }
-
-    pub fn is_near_bottom(&self, visible_rows: usize) -> bool {
-        if self.rooms.is_empty() {
-            return false;
-        }
-        let current_row = self.selected_row();
-        let total = self.total_rows();
-        current_row + 2 >= total.saturating_sub(1) && total > visible_rows
+        self.loading_more = false;
     }
 
     /// Start background downloads for visible covers
-    fn start_cover_downloads(&mut self) {
-        let visible_rows = self.visible_rows(self.last_area_height);
-        let prefetch_rows = visible_rows + Self::PREFETCH_ROWS;
-        let start_idx = self.scroll_offset * self.columns;
-        let end_idx = std::cmp::min(start_idx + prefetch_rows * self.columns, self.rooms.len());
-
-        for idx in start_idx..end_idx {
-            let room = &self.rooms[idx];
-            if room.cover_image.is_some() || self.pending_downloads.contains(&room.room.roomid) {
-                continue;
-            }
-
-            let cover_url = if !room.room.cover.is_empty() {
-                room.room.cover.clone()
-            } else if !room.room.keyframe.is_empty() {
-                room.room.keyframe.clone()
-            } else {
-                continue;
-            };
-
-            self.pending_downloads.insert(room.room.roomid);
-            let room_id = room.room.roomid;
-            let tx = self.cover_tx.clone();
-            let picker = Arc::clone(&self.picker);
-
-            tokio::spawn(async move {
-                let protocol = Self::download_image(&cover_url)
-                    .await
-                    .map(|img| picker.new_resize_protocol(img));
-                let _ = tx.send(CoverResult { room_id, protocol }).await;
-            });
-        }
+    pub fn start_cover_downloads(&mut self) {
+        self.grid
+            .start_cover_downloads_with_prefetch(Self::PREFETCH_ROWS);
     }
 
     /// Poll for completed cover downloads
-    fn poll_cover_results(&mut self) {
-        while let Ok(result) = self.cover_rx.try_recv() {
-            // Handle cover download result
-            self.pending_downloads.remove(&result.room_id);
-            if let Some(protocol) = result.protocol {
-                if let Some(card) = self
-                    .rooms
-                    .iter_mut()
-                    .find(|c| c.room.roomid == result.room_id)
-                {
-                    card.cover_image = Some(protocol);
-                }
-            }
-        }
+    pub fn poll_cover_results(&mut self) {
+        self.grid.poll_cover_results();
     }
 
-    async fn download_image(url: &str) -> Option<DynamicImage> {
-        let response = reqwest::get(url).await.ok()?;
-        let bytes = response.bytes().await.ok()?;
-        image::load_from_memory(&bytes).ok()
+    /// Move down a row, requesting more rooms near the bottom
+    fn scroll_down(&mut self) -> Option<AppAction> {
+        if self.grid.move_down()
+            && self.grid.is_near_bottom(Self::DEFAULT_VISIBLE_ROWS)
+            && !self.loading_more
+        {
+            return Some(AppAction::LoadMoreLive);
+        }
+        None
     }
 
-    fn visible_rows(&self, height: u16) -> usize {
-        (height as usize / Self::CARD_HEIGHT as usize).max(1)
+    fn open_room(&self, index: usize) -> Option<AppAction> {
+        let card = self.grid.cards.get(index)?;
+        Some(AppAction::OpenLiveDetail(card.room.roomid))
     }
 
-    fn selected_row(&self) -> usize {
-        self.selected_index / self.columns
+    /// Open the ranking of the selected room's area, or close the open one
+    fn toggle_rank(&mut self) {
+        if self.rank.take().is_some() {
+            return;
+        }
+        if let Some(card) = self.grid.cards.get(self.grid.selected_index) {
+            let room = &card.room;
+            if room.area_v2_parent_id > 0 {
+                self.rank = Some(RankPanel::new(
+                    room.area_v2_parent_id,
+                    room.area_v2_parent_name.clone(),
+                ));
+            }
+        }
     }
 
-    fn update_scroll(&mut self, visible_rows: usize) {
-        let selected_row = self.selected_row();
-        if selected_row < self.scroll_offset {
-            self.scroll_offset = selected_row;
-        } else if selected_row >= self.scroll_offset + visible_rows {
-            self.scroll_offset = selected_row.saturating_sub(visible_rows - 1);
+    async fn load_rank(&mut self, api_client: &ApiClient) {
+        let Some(rank) = &mut self.rank else {
+            return;
+        };
+        rank.loading = true;
+        let result = api_client
+            .get_area_rank(rank.area_id, Self::RANK_SIZE)
+            .await;
+        rank.loading = false;
+        match result {
+            Ok(rooms) => {
+                rank.set_rooms(rooms);
+                rank.error = None;
+            }
+            Err(e) => rank.error = Some(e.to_string()),
         }
+        rank.last_refresh = Some(Instant::now());
     }
 
-    fn total_rows(&self) -> usize {
-        self.rooms.len().div_ceil(self.columns)
+    /// Keys while the ranking is open: move within it and enter the selected room
+    fn handle_rank_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        let rank = self.rank.as_mut()?;
+        if keys.matches_up(key) {
+            rank.selected = rank.selected.saturating_sub(1);
+        } else if keys.matches_down(key) {
+            if rank.selected + 1 < rank.rooms.len() {
+                rank.selected += 1;
+            }
+        } else if keys.matches_confirm(key) || keys.matches_play(key) {
+            let room = rank.rooms.get(rank.selected)?;
+            return Some(AppAction::OpenLiveDetail(room.roomid));
+        } else if keys.matches_back(key) {
+            self.rank = None;
+        } else {
+            return None;
+        }
+        Some(AppAction::None)
     }
 }
 
@@ -233,10 +307,6 @@ impl Default for LivePage {
 
 impl Component for LivePage {
     fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
-        self.last_area_height = area.height;
-        self.poll_cover_results();
-        self.start_cover_downloads();
-
         // Split area into header/content/footer like home page
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -248,14 +318,14 @@ impl Component for LivePage {
             .split(area);
 
         // Header
-        let header = Paragraph::new("📺 直播推荐")
+        let header = Paragraph::new(t("📺 直播推荐"))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(theme.border_subtle))
                     .title(Span::styled(
-                        " 直播 ",
+                        t(" 直播 "),
                         Style::default()
                             .fg(theme.fg_accent)
                             .add_modifier(Modifier::BOLD),
@@ -270,8 +340,8 @@ impl Component for LivePage {
         frame.render_widget(header, chunks[0]);
 
         // Content area
-        if self.loading && self.rooms.is_empty() {
-            let loading = Paragraph::new("⏳ 加载中...")
+        if self.loading && self.grid.cards.is_empty() {
+            let loading = Paragraph::new(t("⏳ 加载中..."))
                 .style(
                     Style::default()
                         .fg(theme.warning)
@@ -283,23 +353,42 @@ impl Component for LivePage {
         }
 
         if let Some(ref err) = self.error {
-            let error = Paragraph::new(format!("❌ 加载失败: {}", err))
+            let error = Paragraph::new(tf("❌ 加载失败: {}", &[&err]))
                 .style(Style::default().fg(theme.error))
                 .alignment(Alignment::Center);
             frame.render_widget(error, chunks[1]);
             return;
         }
 
-        if self.rooms.is_empty() {
-            let empty = Paragraph::new("📭 暂无直播推荐")
+        if self.grid.cards.is_empty() {
+            let empty = Paragraph::new(t("📭 暂无直播推荐"))
                 .style(Style::default().fg(theme.fg_secondary))
                 .alignment(Alignment::Center);
             frame.render_widget(empty, chunks[1]);
             return;
         }
 
+        let grid_area = match &mut self.rank {
+            Some(rank) => {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(20), Constraint::Length(Self::RANK_WIDTH)])
+                    .split(chunks[1]);
+                render_rank_panel(frame, columns[1], rank, theme);
+                columns[0]
+            }
+            None => chunks[1],
+        };
+
         // Render grid
-        self.render_grid(frame, chunks[1], theme);
+        self.grid.render_with(
+            frame,
+            grid_area,
+            theme,
+            |frame, card_area, card, is_selected| {
+                render_live_card(frame, card_area, card, is_selected, theme)
+            },
+        );
 
         // Footer with hints
         let nav_keys = keys.get_nav_keys_display();
@@ -312,7 +401,7 @@ impl Component for LivePage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("导航", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("导航"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.confirm,
@@ -328,7 +417,7 @@ impl Component for LivePage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("进入", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("进入"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.refresh,
@@ -337,14 +426,23 @@ impl Component for LivePage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("刷新", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("刷新"), Style::default().fg(theme.fg_secondary)),
+            Span::styled("  [", Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                &keys.live_rank,
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("] ", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("人气榜"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.next_theme,
                 Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("切换主题", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("切换主题"), Style::default().fg(theme.fg_secondary)),
         ]))
         .block(
             Block::default()
@@ -376,228 +474,280 @@ impl Component for LivePage {
         if keys.matches_refresh(key) {
             return Some(AppAction::RefreshLive);
         }
-
-        if self.rooms.is_empty() {
+        if keys.matches_live_rank(key) {
+            self.toggle_rank();
             return Some(AppAction::None);
         }
+        if let Some(action) = self.handle_rank_input(key, keys) {
+            return Some(action);
+        }
 
         if keys.matches_up(key) {
-            if self.selected_index >= self.columns {
-                self.selected_index -= self.columns;
-                self.update_scroll(Self::DEFAULT_VISIBLE_ROWS);
-            }
+            self.grid.move_up();
             return Some(AppAction::None);
         }
         if keys.matches_down(key) {
-            let new_idx = self.selected_index + self.columns;
-            if new_idx < self.rooms.len() {
-                self.selected_index = new_idx;
-                self.update_scroll(Self::DEFAULT_VISIBLE_ROWS);
-            }
-            // Check for pagination
-            if self.is_near_bottom(Self::DEFAULT_VISIBLE_ROWS) && !self.loading_more {
-                return Some(AppAction::LoadMoreLive);
-            }
-            return Some(AppAction::None);
+            return Some(self.scroll_down().unwrap_or(AppAction::None));
         }
         if keys.matches_left(key) {
-            if self.selected_index > 0 {
-                self.selected_index -= 1;
-                self.update_scroll(Self::DEFAULT_VISIBLE_ROWS);
-            }
+            self.grid.move_left();
             return Some(AppAction::None);
         }
         if keys.matches_right(key) {
-            if self.selected_index + 1 < self.rooms.len() {
-                self.selected_index += 1;
-                self.update_scroll(Self::DEFAULT_VISIBLE_ROWS);
-            }
+            self.grid.move_right();
             return Some(AppAction::None);
         }
-        if keys.matches_confirm(key) || keys.matches_play(key) {
-            if let Some(card) = self.rooms.get(self.selected_index) {
-                return Some(AppAction::OpenLiveDetail(card.room.roomid));
+        if self.grid.select_visible(key) || keys.matches_confirm(key) || keys.matches_play(key) {
+            if let Some(action) = self.open_room(self.grid.selected_index) {
+                return Some(action);
             }
         }
         Some(AppAction::None)
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
-        match event.kind {
-            MouseEventKind::Down(MouseButton::Left) => {
-                // Calculate which card was clicked
-                let x = event.column.saturating_sub(area.x + 1);
-                let y = event.row.saturating_sub(area.y + 1);
-
-                let card_width = area.width.saturating_sub(2) / self.columns as u16;
-                let col = (x / card_width) as usize;
-                let row = (y / Self::CARD_HEIGHT) as usize + self.scroll_offset;
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        // Dragging the scrollbar jumps straight to a row
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return (self.grid.is_near_bottom(Self::DEFAULT_VISIBLE_ROWS) && !self.loading_more)
+                .then_some(AppAction::LoadMoreLive);
+        }
 
-                let idx = row * self.columns + col;
-                if idx < self.rooms.len() && col < self.columns {
-                    self.selected_index = idx;
+        if let Some(rank) = &mut self.rank {
+            if let Some(idx) = rank.hits.at(event.column, event.row) {
+                if event.kind != MouseEventKind::Down(MouseButton::Left) {
+                    return None;
                 }
-                None
-            }
-            MouseEventKind::ScrollDown => {
-                let new_idx = self.selected_index + self.columns;
-                if new_idx < self.rooms.len() {
-                    self.selected_index = new_idx;
-                    // Check for pagination
-                    if self.is_near_bottom(Self::DEFAULT_VISIBLE_ROWS) && !self.loading_more {
-                        return Some(AppAction::LoadMoreLive);
-                    }
+                // A second click on the selected anchor enters the room
+                if rank.selected == idx {
+                    return Some(AppAction::OpenLiveDetail(rank.rooms.get(idx)?.roomid));
                 }
+                rank.selected = idx;
+                return None;
+            }
+        }
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let idx = self.grid.index_at(event.column, event.row)?;
+                self.grid.select(idx);
                 None
             }
+            MouseEventKind::ScrollDown => self.scroll_down(),
             MouseEventKind::ScrollUp => {
-                if self.selected_index >= self.columns {
-                    self.selected_index -= self.columns;
-                }
+                self.grid.move_up();
                 None
             }
             _ => None,
         }
     }
-}
 
-impl LivePage {
-    fn render_grid(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let visible_rows = self.visible_rows(area.height);
-        self.update_scroll(visible_rows);
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_recommendations(ctx.client))
+    }
 
-        let row_constraints: Vec<Constraint> = (0..visible_rows)
-            .map(|_| Constraint::Min(Self::CARD_HEIGHT))
-            .collect();
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        let refresh_rank =
+            !ctx.throttled && self.rank.as_ref().is_some_and(RankPanel::needs_refresh);
+        Box::pin(async move {
+            if refresh_rank {
+                self.load_rank(ctx.client).await;
+            }
+            None
+        })
+    }
 
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(row_constraints)
-            .split(area);
+    fn session(&self) -> Option<Session> {
+        Some(Session {
+            page: SessionPage::Live,
+            selected: self.grid.selected_index,
+            search_query: String::new(),
+        })
+    }
 
-        // Collect all card areas first
-        let mut card_areas: Vec<(usize, Rect)> = Vec::new();
+    fn restore_selection(&mut self, selected: usize) {
+        self.grid.select(selected);
+    }
+}
 
-        for (row_offset, row_area) in rows.iter().enumerate() {
-            let actual_row = self.scroll_offset + row_offset;
-            let start_idx = actual_row * self.columns;
+fn render_live_card(
+    frame: &mut Frame,
+    area: Rect,
+    card: &mut LiveCard,
+    is_selected: bool,
+    theme: &Theme,
+) {
+    let room = &card.room;
+
+    // Enhanced border styling (consistent with home page)
+    let (border_style, border_type) = if is_selected {
+        (
+            Style::default()
+                .fg(theme.border_focused)
+                .add_modifier(Modifier::BOLD),
+            BorderType::Rounded,
+        )
+    } else {
+        (
+            Style::default().fg(theme.border_unfocused),
+            BorderType::Rounded,
+        )
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type)
+        .border_style(border_style);
+    if card.portrait {
+        block = block.title(portrait_badge(theme));
+    }
 
-            if start_idx >= self.rooms.len() {
-                break;
-            }
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Split into cover and info
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Cover
+            Constraint::Min(2),    // Info
+        ])
+        .split(inner);
+
+    // Render cover image or placeholder
+    if let Some(ref mut protocol) = card.cover_image {
+        render_cover(frame, chunks[0], protocol, &mut card.cover_placement);
+    } else {
+        let placeholder = Paragraph::new("🎬")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.fg_muted));
+        frame.render_widget(placeholder, chunks[0]);
+    }
 
-            let col_constraints: Vec<Constraint> = (0..self.columns)
-                .map(|_| Constraint::Ratio(1, self.columns as u32))
-                .collect();
+    // Info section
+    let title = room.title.chars().take(20).collect::<String>();
+    let title_style = if is_selected {
+        Style::default()
+            .fg(theme.bilibili_pink)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.fg_primary)
+    };
+
+    // Format online count
+    let online_text = format!("👁 {}", format_count(room.online));
+
+    let info_lines = vec![
+        Line::from(Span::styled(title, title_style)),
+        Line::from(vec![Span::styled(
+            &room.uname,
+            Style::default().fg(theme.fg_secondary),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                format!("{} | ", room.area_v2_name),
+                Style::default().fg(theme.fg_muted),
+            ),
+            Span::styled(online_text, Style::default().fg(theme.fg_accent)),
+        ]),
+    ];
 
-            let cols = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(col_constraints)
-                .split(*row_area);
+    let info = Paragraph::new(info_lines).wrap(Wrap { trim: true });
+    frame.render_widget(info, chunks[1]);
+}
 
-            for (col_idx, col_area) in cols.iter().enumerate() {
-                let room_idx = start_idx + col_idx;
-                if room_idx >= self.rooms.len() {
-                    break;
-                }
-                card_areas.push((room_idx, *col_area));
+fn render_rank_panel(frame: &mut Frame, area: Rect, rank: &mut RankPanel, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border_focused))
+        .title(Span::styled(
+            tf(" 🔥 {} 人气榜 ", &[&rank.area_name]),
+            Style::default()
+                .fg(theme.bilibili_pink)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    rank.hits.clear();
+
+    if rank.rooms.is_empty() {
+        let (text, color) = match &rank.error {
+            Some(err) => (tf("❌ 加载失败: {}", &[err]), theme.error),
+            None if rank.loading || rank.last_refresh.is_none() => {
+                (t("⏳ 加载中...").to_string(), theme.warning)
             }
-        }
-
-        // Now render each card with mutable access
-        for (room_idx, col_area) in card_areas {
-            let is_selected = room_idx == self.selected_index;
-            self.render_live_card(frame, col_area, room_idx, is_selected, theme);
-        }
+            None => (t("📭 暂无开播主播").to_string(), theme.fg_secondary),
+        };
+        let message = Paragraph::new(text)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(message, inner);
+        return;
     }
 
-    fn render_live_card(
-        &mut self,
-        frame: &mut Frame,
-        area: Rect,
-        idx: usize,
-        is_selected: bool,
-        theme: &Theme,
-    ) {
-        let card = &mut self.rooms[idx];
-        let room = &card.room;
-
-        // Enhanced border styling (consistent with home page)
-        let (border_style, border_type) = if is_selected {
-            (
-                Style::default()
-                    .fg(theme.border_focused)
-                    .add_modifier(Modifier::BOLD),
-                BorderType::Rounded,
-            )
-        } else {
-            (
-                Style::default().fg(theme.border_unfocused),
-                BorderType::Rounded,
-            )
+    // 每位主播两行：名次、趋势、主播名 / 人气、标题
+    let rows = (inner.height / 2).max(1) as usize;
+    let offset = (rank.selected + 1).saturating_sub(rows);
+    let mut lines = Vec::new();
+    for (idx, room) in rank.rooms.iter().enumerate().skip(offset).take(rows) {
+        let is_selected = idx == rank.selected;
+        let (arrow, arrow_color) = match rank.trends.get(idx).copied().unwrap_or(Trend::Same) {
+            Trend::Up => ("▲", theme.success),
+            Trend::Down => ("▼", theme.error),
+            Trend::Same => ("–", theme.fg_muted),
+            Trend::New => ("✦", theme.warning),
         };
-
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(border_type)
-            .border_style(border_style);
-
-        let inner = block.inner(area);
-        frame.render_widget(block, area);
-
-        // Split into cover and info
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(4), // Cover
-                Constraint::Min(2),    // Info
-            ])
-            .split(inner);
-
-        // Render cover image or placeholder
-        if let Some(ref mut protocol) = card.cover_image {
-            let image = StatefulImage::new();
-            frame.render_stateful_widget(image, chunks[0], protocol);
-        } else {
-            let placeholder = Paragraph::new("🎬")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(theme.fg_muted));
-            frame.render_widget(placeholder, chunks[0]);
-        }
-
-        // Info section
-        let title = room.title.chars().take(20).collect::<String>();
-        let title_style = if is_selected {
+        let name_style = if is_selected {
             Style::default()
                 .fg(theme.bilibili_pink)
-                .add_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED)
         } else {
             Style::default().fg(theme.fg_primary)
         };
-
-        // Format online count
-        let online_text = if room.online >= 10000 {
-            format!("👁 {:.1}万", room.online as f64 / 10000.0)
+        let rank_color = if idx < 3 {
+            theme.warning
         } else {
-            format!("👁 {}", room.online)
+            theme.fg_secondary
         };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>2} ", idx + 1),
+                Style::default().fg(rank_color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{} ", arrow), Style::default().fg(arrow_color)),
+            Span::styled(room.uname.clone(), name_style),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("     👁 {} ", format_count(room.online)),
+                Style::default().fg(theme.fg_accent),
+            ),
+            Span::styled(room.title.clone(), Style::default().fg(theme.fg_muted)),
+        ]));
+
+        let row_y = inner.y + ((idx - offset) * 2) as u16;
+        rank.hits.push(
+            Rect::new(inner.x, row_y, inner.width, 2).intersection(inner),
+            idx,
+        );
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let info_lines = vec![
-            Line::from(Span::styled(title, title_style)),
-            Line::from(vec![Span::styled(
-                &room.uname,
-                Style::default().fg(theme.fg_secondary),
-            )]),
-            Line::from(vec![
-                Span::styled(
-                    format!("{} | ", room.area_v2_name),
-                    Style::default().fg(theme.fg_muted),
-                ),
-                Span::styled(online_text, Style::default().fg(theme.fg_accent)),
-            ]),
-        ];
-
-        let info = Paragraph::new(info_lines).wrap(Wrap { trim: true });
-        frame.render_widget(info, chunks[1]);
+    #[test]
+    fn test_trend() {
+        assert_eq!(trend(None, 100), Trend::New);
+        assert_eq!(trend(Some(80), 100), Trend::Up);
+        assert_eq!(trend(Some(120), 100), Trend::Down);
+        assert_eq!(trend(Some(100), 100), Trend::Same);
     }
 }
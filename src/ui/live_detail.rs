@@ -1,26 +1,70 @@
 //! Live streaming detail page with room info and real-time messages
 
-use super::{Component, Theme};
+use super::cover_pool;
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
-use crate::api::live::LiveRoomInfo;
+use crate::api::heartbeat::LiveHeartbeat;
+use crate::api::live::{format_live_duration, LiveAnchorInfo, LiveRoomInfo};
 use crate::api::live_client::LiveClient;
-use crate::api::live_ws::LiveMessage;
+use crate::api::live_ws::{FanMedal, LiveMessage, RankUser};
 use crate::app::AppAction;
+use crate::format::{format_count, truncate_chars};
+use crate::i18n::{t, tf};
 use crate::storage::Keybindings;
+use futures_util::future::LocalBoxFuture;
 use ratatui::crossterm::event::KeyCode;
 use ratatui::{prelude::*, widgets::*};
+use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
 use std::collections::VecDeque;
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
 /// Maximum number of messages to keep in buffers
 const MAX_MESSAGES: usize = 100;
 
+/// 高能榜拉取人数
+const RANK_PAGE_SIZE: i32 = 20;
+
+/// 关键帧刷新间隔，作为低帧率预览
+const KEYFRAME_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 弹幕过快提示的显示时长
+const OMITTED_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// 心跳上报失败后重新进房的等待时间
+const HEARTBEAT_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// 房间信息栏可调宽度范围与步长
+const MIN_INFO_WIDTH: u16 = 20;
+const MAX_INFO_WIDTH: u16 = 60;
+const INFO_WIDTH_STEP: u16 = 2;
+
+/// Image slot a download result belongs to
+enum ImageSlot {
+    Preview,
+    Avatar,
+}
+
+/// Message for completed image download
+struct ImageResult {
+    slot: ImageSlot,
+    url: String,
+    protocol: StatefulProtocol,
+}
+
 /// Danmaku item for display
 #[derive(Debug, Clone)]
 struct DanmakuItem {
     uname: String,
     content: String,
     color: Color,
+    medal: Option<FanMedal>,
+    /// 大航海等级: 0 = 无, 1 = 总督, 2 = 提督, 3 = 舰长
+    guard_level: u8,
+    /// 命中高亮关键词
+    highlighted: bool,
     #[allow(dead_code)]
     timestamp: Instant,
 }
@@ -48,11 +92,45 @@ pub struct LiveDetailPage {
     danmakus: VecDeque<DanmakuItem>,
     entries: VecDeque<EntryItem>,
     popularity: Option<u32>,
+    /// Danmaku dropped during a burst and when the last ones were dropped
+    omitted: Option<(usize, Instant)>,
+    /// 收到 PREPARING，主播已下播
+    ended: bool,
     history_loaded: bool,
+
+    // Keyword highlight
+    highlight_keywords: Vec<String>,
+    highlight_bell: bool,
+    bell_pending: bool,
+
+    // High-energy rank (高能榜)
+    rank_list: Vec<RankUser>,
+    rank_online_num: Option<i64>,
+
+    // Cover/keyframe preview and anchor avatar
+    anchor: Option<LiveAnchorInfo>,
+    picker: Arc<Picker>,
+    image_tx: mpsc::Sender<ImageResult>,
+    image_rx: mpsc::Receiver<ImageResult>,
+    preview: Option<StatefulProtocol>,
+    preview_url: String,
+    avatar: Option<StatefulProtocol>,
+    avatar_requested: bool,
+    last_keyframe_refresh: Instant,
+
+    // Layout, adjustable and saved to the config
+    info_width: u16,
+    danmaku_wrap: bool,
+
+    /// 观看时长心跳上报任务
+    heartbeat: Option<JoinHandle<()>>,
 }
 
 impl LiveDetailPage {
     pub fn new(room_id: i64) -> Self {
+        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
+        let (image_tx, image_rx) = mpsc::channel(4);
+
         Self {
             room_id,
             room_info: None,
@@ -64,8 +142,94 @@ impl LiveDetailPage {
             danmakus: VecDeque::with_capacity(MAX_MESSAGES),
             entries: VecDeque::with_capacity(MAX_MESSAGES),
             popularity: None,
+            omitted: None,
+            ended: false,
             history_loaded: false,
+            highlight_keywords: Vec::new(),
+            highlight_bell: false,
+            bell_pending: false,
+            rank_list: Vec::new(),
+            rank_online_num: None,
+            anchor: None,
+            picker,
+            image_tx,
+            image_rx,
+            preview: None,
+            preview_url: String::new(),
+            avatar: None,
+            avatar_requested: false,
+            last_keyframe_refresh: Instant::now(),
+            info_width: 30,
+            danmaku_wrap: false,
+            heartbeat: None,
+        }
+    }
+
+    /// Report watch time in the background while the page is open, if the room is live
+    pub fn start_heartbeat(&mut self, api_client: &Arc<ApiClient>) {
+        let Some(info) = self.room_info.as_ref().filter(|info| info.live_status == 1) else {
+            return;
+        };
+        if self.heartbeat.is_some() {
+            return;
+        }
+        let mut heartbeat = LiveHeartbeat::new(info.room_id, info.parent_area_id, info.area_id);
+        let client = Arc::clone(api_client);
+        self.heartbeat = Some(tokio::spawn(async move {
+            loop {
+                // A failed report starts over with a new E after a while
+                let wait = heartbeat
+                    .beat(&client)
+                    .await
+                    .unwrap_or(HEARTBEAT_RETRY_DELAY);
+                tokio::time::sleep(wait).await;
+            }
+        }));
+    }
+
+    /// Stop reporting watch time, when the page closes or the stream ends
+    fn stop_heartbeat(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+    }
+
+    /// Width of the room info column and whether long danmaku wrap
+    pub fn set_layout(&mut self, info_width: u16, danmaku_wrap: bool) {
+        self.info_width = info_width.clamp(MIN_INFO_WIDTH, MAX_INFO_WIDTH);
+        self.danmaku_wrap = danmaku_wrap;
+    }
+
+    fn layout_changed(&self) -> Option<AppAction> {
+        Some(AppAction::SaveLiveLayout {
+            info_width: self.info_width,
+            danmaku_wrap: self.danmaku_wrap,
+        })
+    }
+
+    /// Keywords whose danmaku are highlighted, optionally ringing the terminal bell
+    pub fn set_highlight(&mut self, keywords: &[String], bell: bool) {
+        self.highlight_keywords = keywords
+            .iter()
+            .map(|k| k.trim().to_lowercase())
+            .filter(|k| !k.is_empty())
+            .collect();
+        self.highlight_bell = bell;
+    }
+
+    fn is_highlighted(&self, content: &str) -> bool {
+        if self.highlight_keywords.is_empty() {
+            return false;
         }
+        let lower = content.to_lowercase();
+        self.highlight_keywords
+            .iter()
+            .any(|k| lower.contains(k.as_str()))
+    }
+
+    /// Whether a highlighted danmaku arrived since the last call
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.bell_pending)
     }
 
     pub async fn load_room_info(&mut self, api_client: &ApiClient) {
@@ -78,13 +242,98 @@ impl LiveDetailPage {
         match api_client.get_live_room_info(self.room_id).await {
             Ok(info) => {
                 self.room_info = Some(info);
+                self.ended = false;
                 self.loading = false;
             }
             Err(e) => {
-                self.error = Some(format!("加载房间信息失败: {}", e));
+                self.error = Some(tf("加载房间信息失败: {}", &[&e]));
                 self.loading = false;
             }
         }
+
+        if self.anchor.is_none() {
+            self.anchor = api_client.get_live_anchor_info(self.room_id).await.ok();
+        }
+        if let Some(ruid) = self.room_info.as_ref().map(|info| info.uid) {
+            if let Ok(rank) = api_client
+                .get_online_gold_rank(ruid, self.room_id, RANK_PAGE_SIZE)
+                .await
+            {
+                self.rank_online_num = Some(rank.online_num);
+                self.rank_list = rank.items.into_iter().map(RankUser::from).collect();
+            }
+        }
+        self.last_keyframe_refresh = Instant::now();
+        self.start_image_downloads();
+    }
+
+    /// Re-fetch room info periodically to pick up a new keyframe
+    pub async fn refresh_keyframe(&mut self, api_client: &ApiClient) {
+        let is_live = self
+            .room_info
+            .as_ref()
+            .is_some_and(|info| info.live_status == 1);
+        if !is_live || self.last_keyframe_refresh.elapsed() < KEYFRAME_REFRESH_INTERVAL {
+            return;
+        }
+        self.last_keyframe_refresh = Instant::now();
+
+        if let Ok(info) = api_client.get_live_room_info(self.room_id).await {
+            self.room_info = Some(info);
+            self.start_image_downloads();
+        }
+    }
+
+    /// Start background downloads for the preview and avatar (non-blocking)
+    fn start_image_downloads(&mut self) {
+        if let Some(ref info) = self.room_info {
+            let url = info.preview_url();
+            if !url.is_empty() && url != self.preview_url {
+                self.preview_url = url.to_string();
+                self.spawn_download(ImageSlot::Preview, self.preview_url.clone());
+            }
+        }
+
+        if !self.avatar_requested {
+            if let Some(face) = self.anchor.as_ref().map(|a| a.face.clone()) {
+                if !face.is_empty() {
+                    self.avatar_requested = true;
+                    self.spawn_download(ImageSlot::Avatar, face);
+                }
+            }
+        }
+    }
+
+    fn spawn_download(&self, slot: ImageSlot, url: String) {
+        let tx = self.image_tx.clone();
+        let picker = Arc::clone(&self.picker);
+
+        tokio::spawn(async move {
+            if let Some(img) = cover_pool::download_image(&url).await {
+                let protocol = picker.new_resize_protocol(img);
+                let _ = tx
+                    .send(ImageResult {
+                        slot,
+                        url,
+                        protocol,
+                    })
+                    .await;
+            }
+        });
+    }
+
+    /// Poll for completed image downloads (non-blocking)
+    fn poll_image_results(&mut self) {
+        while let Ok(result) = self.image_rx.try_recv() {
+            match result.slot {
+                // Drop stale keyframes that were superseded while downloading
+                ImageSlot::Preview if result.url == self.preview_url => {
+                    self.preview = Some(result.protocol);
+                }
+                ImageSlot::Preview => {}
+                ImageSlot::Avatar => self.avatar = Some(result.protocol),
+            }
+        }
     }
 
     /// Load history danmaku before connecting to WebSocket
@@ -104,10 +353,14 @@ impl LiveDetailPage {
                     .collect();
 
                 for item in all_messages {
+                    let highlighted = self.is_highlighted(&item.text);
                     self.danmakus.push_back(DanmakuItem {
                         uname: item.nickname.clone(),
                         content: item.text.clone(),
                         color: Color::White, // History messages use default color
+                        medal: item.fan_medal(),
+                        guard_level: item.guard_level,
+                        highlighted,
                         timestamp: Instant::now(),
                     });
                 }
@@ -137,7 +390,7 @@ impl LiveDetailPage {
                         self.ws_error = None;
                     }
                     Err(e) => {
-                        self.ws_error = Some(format!("WS连接失败: {}", e));
+                        self.ws_error = Some(tf("WS连接失败: {}", &[&e]));
                     }
                 }
             }
@@ -148,18 +401,29 @@ impl LiveDetailPage {
         }
     }
 
-    /// Poll for new messages from WebSocket
+    /// Poll for new message batches from WebSocket
     pub fn poll_messages(&mut self) {
         // Collect messages first to avoid borrow issues
-        let messages: Vec<LiveMessage> = if let Some(ref mut client) = self.live_client {
-            let mut msgs = Vec::new();
-            while let Some(msg) = client.try_recv() {
-                msgs.push(msg);
+        let mut messages: Vec<LiveMessage> = Vec::new();
+        let mut omitted = 0;
+        if let Some(ref mut client) = self.live_client {
+            while let Some(batch) = client.try_recv() {
+                messages.extend(batch.messages);
+                omitted += batch.omitted;
             }
-            msgs
-        } else {
-            Vec::new()
-        };
+        }
+
+        if self
+            .omitted
+            .is_some_and(|(_, at)| at.elapsed() >= OMITTED_NOTICE_DURATION)
+        {
+            self.omitted = None;
+        }
+        if omitted > 0 {
+            // Keep counting while the notice is still shown
+            let total = self.omitted.map_or(0, |(n, _)| n) + omitted;
+            self.omitted = Some((total, Instant::now()));
+        }
 
         // Process collected messages
         for msg in messages {
@@ -174,18 +438,24 @@ impl LiveDetailPage {
                 uname,
                 content,
                 color,
+                medal,
+                guard_level,
                 ..
             } => {
-                // Convert color from u32 to ratatui Color
-                let r = ((color >> 16) & 0xFF) as u8;
-                let g = ((color >> 8) & 0xFF) as u8;
-                let b = (color & 0xFF) as u8;
-                let color = Color::Rgb(r, g, b);
+                let color = rgb(color);
+
+                let highlighted = self.is_highlighted(&content);
+                if highlighted && self.highlight_bell {
+                    self.bell_pending = true;
+                }
 
                 self.danmakus.push_back(DanmakuItem {
                     uname,
                     content,
                     color,
+                    medal,
+                    guard_level,
+                    highlighted,
                     timestamp: Instant::now(),
                 });
 
@@ -207,42 +477,41 @@ impl LiveDetailPage {
             LiveMessage::Popularity(count) => {
                 self.popularity = Some(count);
             }
-            LiveMessage::AuthReply { code } => {
-                if code != 0 {
-                    self.ws_error = Some(format!("WebSocket认证失败: {}", code));
+            LiveMessage::Preparing => {
+                self.ended = true;
+                self.stop_heartbeat();
+                if let Some(info) = self.room_info.as_mut() {
+                    info.live_status = 0;
                 }
             }
+            LiveMessage::OnlineRank { list } => {
+                self.rank_list = list;
+            }
+            LiveMessage::AuthReply { code } if code != 0 => {
+                self.ws_error = Some(tf("WebSocket认证失败: {}", &[&code]));
+            }
             _ => {}
         }
     }
+}
 
-    fn format_online(online: i64) -> String {
-        if online >= 10000 {
-            format!("{:.1}万", online as f64 / 10000.0)
-        } else {
-            format!("{}", online)
-        }
-    }
-
-    fn format_attention(attention: i64) -> String {
-        if attention >= 10000 {
-            format!("{:.1}万", attention as f64 / 10000.0)
-        } else {
-            format!("{}", attention)
-        }
+impl Drop for LiveDetailPage {
+    fn drop(&mut self) {
+        self.stop_heartbeat();
     }
 }
 
 impl Component for LiveDetailPage {
     fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
-        // Poll for new messages
+        // Poll for new messages and images
         self.poll_messages();
+        self.poll_image_results();
 
         // Main block
         let title = if let Some(ref info) = self.room_info {
             format!(" 📺 {} ", info.title)
         } else {
-            " 📺 直播详情 ".to_string()
+            t(" 📺 直播详情 ").to_string()
         };
 
         let block = Block::default()
@@ -260,7 +529,7 @@ impl Component for LiveDetailPage {
         frame.render_widget(block, area);
 
         if self.loading && self.room_info.is_none() {
-            let loading = Paragraph::new("加载中...")
+            let loading = Paragraph::new(t("加载中..."))
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted));
             frame.render_widget(loading, inner);
@@ -268,15 +537,15 @@ impl Component for LiveDetailPage {
         }
 
         if let Some(ref err) = self.error {
-            let error = Paragraph::new(format!("加载失败: {}", err))
+            let error = Paragraph::new(tf("加载失败: {}", &[&err]))
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.bilibili_pink));
             frame.render_widget(error, inner);
             return;
         }
 
-        if let Some(ref info) = self.room_info {
-            self.render_room_content(frame, inner, info, theme, keys);
+        if self.room_info.is_some() {
+            self.render_room_content(frame, inner, theme, keys);
         }
     }
 
@@ -288,6 +557,29 @@ impl Component for LiveDetailPage {
                 room_id: info.room_id,
                 title: info.title.clone(),
             })
+        } else if keys.matches_left(key) {
+            self.info_width = self
+                .info_width
+                .saturating_sub(INFO_WIDTH_STEP)
+                .max(MIN_INFO_WIDTH);
+            self.layout_changed()
+        } else if keys.matches_right(key) {
+            self.info_width = (self.info_width + INFO_WIDTH_STEP).min(MAX_INFO_WIDTH);
+            self.layout_changed()
+        } else if keys.matches_danmaku_wrap(key) {
+            self.danmaku_wrap = !self.danmaku_wrap;
+            self.layout_changed()
+        } else if keys.matches_live_alert(key) {
+            let info = self.room_info.as_ref()?;
+            let uname = self
+                .anchor
+                .as_ref()
+                .map(|anchor| anchor.uname.clone())
+                .unwrap_or_default();
+            Some(AppAction::ToggleLiveAlert {
+                uid: info.uid,
+                uname,
+            })
         } else if keys.matches_next_theme(key) {
             Some(AppAction::NextTheme)
         } else if keys.matches_open_settings(key) {
@@ -296,14 +588,32 @@ impl Component for LiveDetailPage {
             Some(AppAction::None)
         }
     }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_room_info(ctx.client))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        if self.take_bell() && !ctx.quiet {
+            use std::io::Write;
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+        Box::pin(async move {
+            if !ctx.throttled {
+                self.refresh_keyframe(ctx.client).await;
+            }
+            None
+        })
+    }
 }
 
 impl LiveDetailPage {
     fn render_room_content(
-        &self,
+        &mut self,
         frame: &mut Frame,
         area: Rect,
-        info: &LiveRoomInfo,
         theme: &Theme,
         keys: &Keybindings,
     ) {
@@ -316,12 +626,13 @@ impl LiveDetailPage {
             ])
             .split(area);
 
-        // Content layout: left info panel + right messages
+        // Content layout: left info panel + messages + high-energy rank
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Length(30), // Room info
-                Constraint::Min(20),    // Messages
+                Constraint::Length(self.info_width), // Room info
+                Constraint::Min(20),                 // Messages
+                Constraint::Length(28),              // Rank
             ])
             .split(chunks[0]);
 
@@ -334,8 +645,23 @@ impl LiveDetailPage {
             ])
             .split(content_chunks[1]);
 
+        // Left side: preview + anchor + room info
+        let left_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(10), // Cover / keyframe
+                Constraint::Length(6),  // Anchor
+                Constraint::Min(8),     // Room info
+            ])
+            .split(content_chunks[0]);
+
+        self.render_preview_panel(frame, left_chunks[0], theme);
+        self.render_anchor_panel(frame, left_chunks[1], theme);
+
         // Room info panel
-        self.render_info_panel(frame, content_chunks[0], info, theme);
+        if let Some(ref info) = self.room_info {
+            self.render_info_panel(frame, left_chunks[2], info, theme);
+        }
 
         // Danmaku panel
         self.render_danmaku_panel(frame, right_chunks[0], theme);
@@ -343,6 +669,9 @@ impl LiveDetailPage {
         // Entry panel
         self.render_entry_panel(frame, right_chunks[1], theme);
 
+        // High-energy rank panel
+        self.render_rank_panel(frame, content_chunks[2], theme);
+
         // Bottom hints
         let hints = Paragraph::new(Line::from(vec![
             Span::styled(
@@ -353,20 +682,129 @@ impl LiveDetailPage {
                 format!("{} ", &keys.play),
                 Style::default().fg(theme.fg_accent),
             ),
-            Span::styled("播放  ", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("播放  "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                format!("{}/{} ", &keys.nav_left, &keys.nav_right),
+                Style::default().fg(theme.fg_accent),
+            ),
+            Span::styled(t("信息栏宽度  "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                format!("{} ", &keys.danmaku_wrap),
+                Style::default().fg(theme.fg_accent),
+            ),
+            Span::styled(t("弹幕换行  "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                format!("{} ", &keys.live_alert),
+                Style::default().fg(theme.fg_accent),
+            ),
+            Span::styled(t("开播提醒  "), Style::default().fg(theme.fg_secondary)),
             Span::styled(&keys.back, Style::default().fg(theme.error)),
             Span::styled("/", Style::default().fg(theme.fg_secondary)),
             Span::styled(&keys.quit, Style::default().fg(theme.error)),
-            Span::styled(" 返回", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t(" 返回"), Style::default().fg(theme.fg_secondary)),
         ]))
         .alignment(Alignment::Center);
         frame.render_widget(hints, chunks[1]);
     }
 
+    fn render_preview_panel(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let is_keyframe = self
+            .room_info
+            .as_ref()
+            .is_some_and(|info| info.live_status == 1 && !info.keyframe.is_empty());
+        let title = if is_keyframe {
+            t(" 直播画面 ")
+        } else {
+            t(" 封面 ")
+        };
+
+        let block = Block::default()
+            .title(Span::styled(
+                title,
+                Style::default()
+                    .fg(theme.fg_secondary)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if let Some(ref mut preview) = self.preview {
+            frame.render_stateful_widget(StatefulImage::new(), inner, preview);
+        } else {
+            let text = if self.preview_url.is_empty() {
+                t("📺 暂无封面")
+            } else {
+                t("📺 加载中...")
+            };
+            let placeholder = Paragraph::new(text)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.fg_muted));
+            frame.render_widget(placeholder, inner);
+        }
+    }
+
+    fn render_anchor_panel(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .title(Span::styled(
+                t(" 主播 "),
+                Style::default()
+                    .fg(theme.fg_secondary)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(8), // Avatar
+                Constraint::Min(10),   // Name
+            ])
+            .split(inner);
+
+        if let Some(ref mut avatar) = self.avatar {
+            frame.render_stateful_widget(StatefulImage::new(), chunks[0], avatar);
+        } else {
+            let placeholder = Paragraph::new("👤")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.fg_muted));
+            frame.render_widget(placeholder, chunks[0]);
+        }
+
+        let lines = match self.anchor {
+            Some(ref anchor) => vec![
+                Line::from(Span::styled(
+                    anchor.uname.as_str(),
+                    Style::default()
+                        .fg(theme.bilibili_pink)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(Span::styled(
+                    format!("UID: {}", anchor.uid),
+                    Style::default().fg(theme.fg_muted),
+                )),
+            ],
+            None => vec![Line::from(Span::styled(
+                "-",
+                Style::default().fg(theme.fg_muted),
+            ))],
+        };
+        let name = Paragraph::new(lines).wrap(Wrap { trim: true });
+        frame.render_widget(name, chunks[1].inner(Margin::new(1, 0)));
+    }
+
     fn render_info_panel(&self, frame: &mut Frame, area: Rect, info: &LiveRoomInfo, theme: &Theme) {
         let block = Block::default()
             .title(Span::styled(
-                " 房间信息 ",
+                t(" 房间信息 "),
                 Style::default()
                     .fg(theme.fg_secondary)
                     .add_modifier(Modifier::BOLD),
@@ -386,12 +824,27 @@ impl LiveDetailPage {
 
         // Use popularity from WebSocket if available
         let online_str = if let Some(pop) = self.popularity {
-            Self::format_online(pop as i64)
+            format_count(pop as i64)
+        } else {
+            format_count(info.online)
+        };
+
+        let uptime = if self.ended {
+            Some(Span::styled(
+                t("已下播"),
+                Style::default().fg(theme.warning),
+            ))
         } else {
-            Self::format_online(info.online)
+            info.live_since().map(|since| {
+                let secs = (chrono::Local::now() - since).num_seconds();
+                Span::styled(
+                    format_live_duration(secs),
+                    Style::default().fg(theme.fg_secondary),
+                )
+            })
         };
 
-        let lines = vec![
+        let mut lines = vec![
             Line::from(vec![
                 Span::styled("ID: ", Style::default().fg(theme.fg_muted)),
                 Span::styled(
@@ -400,7 +853,7 @@ impl LiveDetailPage {
                 ),
             ]),
             Line::from(vec![
-                Span::styled("分区: ", Style::default().fg(theme.fg_muted)),
+                Span::styled(t("分区: "), Style::default().fg(theme.fg_muted)),
                 Span::styled(&info.area_name, Style::default().fg(theme.fg_accent)),
             ]),
             Line::from(""),
@@ -408,6 +861,11 @@ impl LiveDetailPage {
                 Span::styled("● ", Style::default().fg(status_color)),
                 Span::styled(info.status_text(), Style::default().fg(status_color)),
             ]),
+        ];
+        if let Some(uptime) = uptime {
+            lines.push(Line::from(vec![Span::raw("⏱ "), uptime]));
+        }
+        lines.extend([
             Line::from(vec![
                 Span::styled("👁 ", Style::default().fg(theme.fg_muted)),
                 Span::styled(online_str, Style::default().fg(theme.fg_accent)),
@@ -415,20 +873,20 @@ impl LiveDetailPage {
             Line::from(vec![
                 Span::styled("❤ ", Style::default().fg(theme.bilibili_pink)),
                 Span::styled(
-                    Self::format_attention(info.attention),
+                    format_count(info.attention),
                     Style::default().fg(theme.bilibili_pink),
                 ),
             ]),
-        ];
+        ]);
 
         let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
         frame.render_widget(paragraph, inner);
     }
 
     fn render_danmaku_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let block = Block::default()
+        let mut block = Block::default()
             .title(Span::styled(
-                format!(" 弹幕 ({}) ", self.danmakus.len()),
+                tf(" 弹幕 ({}) ", &[&self.danmakus.len()]),
                 Style::default()
                     .fg(theme.fg_secondary)
                     .add_modifier(Modifier::BOLD),
@@ -436,18 +894,27 @@ impl LiveDetailPage {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle));
+        if let Some((omitted, _)) = self.omitted {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    tf(" 弹幕过快，已省略 {} 条 ", &[&omitted]),
+                    Style::default().fg(theme.warning),
+                ))
+                .right_aligned(),
+            );
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
         if self.danmakus.is_empty() {
             let msg = if self.ws_connected {
-                "等待弹幕...".to_string()
+                t("等待弹幕...").to_string()
             } else if let Some(ref err) = self.ws_error {
                 // Show the actual error message
-                format!("连接失败: {}", err)
+                tf("连接失败: {}", &[&err])
             } else {
-                "弹幕功能加载中...".to_string()
+                t("弹幕功能加载中...").to_string()
             };
             let placeholder = Paragraph::new(msg)
                 .alignment(Alignment::Center)
@@ -459,20 +926,93 @@ impl LiveDetailPage {
 
         // Show recent danmakus (auto-scroll to bottom)
         let visible_lines = inner.height as usize;
-        let start = self.danmakus.len().saturating_sub(visible_lines);
+        let mut lines: Vec<Line> = Vec::with_capacity(visible_lines);
+        for d in self.danmakus.iter().rev() {
+            if lines.len() >= visible_lines {
+                break;
+            }
+            let mut spans = danmaku_badges(d, theme);
+            if d.highlighted {
+                let style = Style::default()
+                    .fg(theme.warning)
+                    .bg(theme.bg_highlight)
+                    .add_modifier(Modifier::BOLD);
+                spans.insert(0, Span::styled("★ ", style));
+                spans.push(Span::styled(format!("[{}] ", d.uname), style));
+                spans.push(Span::styled(&d.content, style));
+            } else {
+                spans.push(Span::styled(
+                    format!("[{}] ", d.uname),
+                    Style::default().fg(theme.fg_muted),
+                ));
+                spans.push(Span::styled(&d.content, Style::default().fg(d.color)));
+            }
+            let line = Line::from(spans);
+            if self.danmaku_wrap {
+                lines.extend(wrap_line(line, inner.width).into_iter().rev());
+            } else {
+                lines.push(line);
+            }
+        }
+        // Collected newest first; a wrapped danmaku may overflow the top
+        lines.truncate(visible_lines);
+        lines.reverse();
+
+        let paragraph = Paragraph::new(lines);
+        frame.render_widget(paragraph, inner);
+    }
+
+    fn render_rank_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let title = match self.rank_online_num {
+            Some(num) => tf(" 高能榜 ({}) ", &[&num]),
+            None => t(" 高能榜 ").to_string(),
+        };
+        let block = Block::default()
+            .title(Span::styled(
+                title,
+                Style::default()
+                    .fg(theme.fg_secondary)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.rank_list.is_empty() {
+            let placeholder = Paragraph::new(t("暂无高能用户"))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.fg_muted));
+            frame.render_widget(placeholder, inner);
+            return;
+        }
 
         let lines: Vec<Line> = self
-            .danmakus
+            .rank_list
             .iter()
-            .skip(start)
-            .take(visible_lines)
-            .map(|d| {
+            .take(inner.height as usize)
+            .map(|user| {
+                let rank_color = match user.rank {
+                    1 => theme.warning,
+                    2 => theme.fg_accent,
+                    3 => theme.bilibili_pink,
+                    _ => theme.fg_muted,
+                };
                 Line::from(vec![
                     Span::styled(
-                        format!("[{}] ", d.uname),
+                        format!("{:>2} ", user.rank),
+                        Style::default().fg(rank_color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        truncate_chars(&user.uname, 14),
+                        Style::default().fg(theme.fg_primary),
+                    ),
+                    Span::styled(
+                        format!(" {}", user.score),
                         Style::default().fg(theme.fg_muted),
                     ),
-                    Span::styled(&d.content, Style::default().fg(d.color)),
                 ])
             })
             .collect();
@@ -484,7 +1024,7 @@ impl LiveDetailPage {
     fn render_entry_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .title(Span::styled(
-                " 入场 ",
+                t(" 入场 "),
                 Style::default()
                     .fg(theme.fg_secondary)
                     .add_modifier(Modifier::BOLD),
@@ -497,7 +1037,7 @@ impl LiveDetailPage {
         frame.render_widget(block, area);
 
         if self.entries.is_empty() {
-            let placeholder = Paragraph::new("等待入场消息...")
+            let placeholder = Paragraph::new(t("等待入场消息..."))
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(theme.fg_muted));
             frame.render_widget(placeholder, inner);
@@ -515,7 +1055,7 @@ impl LiveDetailPage {
             .take(visible_lines)
             .map(|e| {
                 Line::from(Span::styled(
-                    format!("欢迎 {} 进入直播间", e.uname),
+                    tf("欢迎 {} 进入直播间", &[&e.uname]),
                     Style::default().fg(theme.fg_muted),
                 ))
             })
@@ -525,3 +1065,63 @@ impl LiveDetailPage {
         frame.render_widget(paragraph, inner);
     }
 }
+
+/// Convert a 0xRRGGBB color from the API into a terminal color
+fn rgb(color: u32) -> Color {
+    Color::Rgb(
+        ((color >> 16) & 0xFF) as u8,
+        ((color >> 8) & 0xFF) as u8,
+        (color & 0xFF) as u8,
+    )
+}
+
+/// 舰长/提督/总督 and fan medal prefixes of a danmaku
+fn danmaku_badges<'a>(d: &DanmakuItem, theme: &Theme) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let guard = match d.guard_level {
+        1 => Some((t("总督"), Color::Rgb(0xFF, 0x7C, 0x28))),
+        2 => Some((t("提督"), Color::Rgb(0xE1, 0x7A, 0xFF))),
+        3 => Some((t("舰长"), theme.bilibili_blue)),
+        _ => None,
+    };
+    if let Some((name, color)) = guard {
+        spans.push(Span::styled(
+            format!("⚓{} ", name),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(ref medal) = d.medal {
+        let color = if medal.color == 0 {
+            theme.bilibili_pink
+        } else {
+            rgb(medal.color)
+        };
+        spans.push(Span::styled(
+            format!(" {} {} ", medal.name, medal.level),
+            Style::default().fg(theme.bg_primary).bg(color),
+        ));
+        spans.push(Span::raw(" "));
+    }
+    spans
+}
+
+/// Break a line into rows of at most `width` columns, splitting anywhere (CJK text has no spaces)
+fn wrap_line(line: Line<'_>, width: u16) -> Vec<Line<'_>> {
+    let width = width.max(1) as usize;
+    let mut rows = vec![Line::default()];
+    let mut row_width = 0;
+    for span in line.spans {
+        for grapheme in span.styled_graphemes(Style::default()) {
+            let grapheme_width = Span::raw(grapheme.symbol).width();
+            if row_width + grapheme_width > width && row_width > 0 {
+                rows.push(Line::default());
+                row_width = 0;
+            }
+            row_width += grapheme_width;
+            if let Some(row) = rows.last_mut() {
+                row.push_span(Span::styled(grapheme.symbol.to_string(), grapheme.style));
+            }
+        }
+    }
+    rows
+}
@@ -1,25 +1,164 @@
 //! Login page with QR code display
 
-use super::{Component, Theme};
+use super::{Component, PageContext, Theme};
 use crate::api::auth::{QrcodeData, QrcodePollStatus};
 use crate::api::client::ApiClient;
 use crate::app::AppAction;
+use crate::i18n::{t, tf};
 use crate::storage::{Credentials, Keybindings};
+use futures_util::future::LocalBoxFuture;
 use image::DynamicImage;
 use qrcode::QrCode;
 use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
+use ratatui_image::{
+    picker::{Picker, ProtocolType},
+    protocol::StatefulProtocol,
+    StatefulImage,
+};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tui_qrcode::{Colors, QrCodeWidget, QuietZone};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tui_qrcode::{Colors, QrCodeWidget, QuietZone, Scaling};
+
+/// 轮询间隔，与网页端一致
+const POLL_INTERVAL: Duration = Duration::from_millis(2500);
+/// 二维码有效期，到期自动换新码
+const QR_LIFETIME: Duration = Duration::from_secs(180);
+/// 获取二维码失败后的重试间隔
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Progress reported by the background poller
+enum LoginEvent {
+    /// A new code to show, and when it was issued
+    Code(QrcodeData, Instant),
+    Status(QrcodePollStatus),
+    Success(Credentials),
+    /// Getting a code failed, retried after [`RETRY_DELAY`]
+    CodeError(String),
+    /// One poll failed, the next one is tried as usual
+    PollError(String),
+}
+
+/// What the poller does after a poll
+#[derive(Debug, PartialEq, Eq)]
+enum PollStep {
+    /// Poll the same code again
+    Wait,
+    /// Get a new code
+    Renew,
+    /// Logged in, stop
+    Finish,
+}
+
+fn next_step(status: QrcodePollStatus, age: Duration) -> PollStep {
+    match status {
+        QrcodePollStatus::Success => PollStep::Finish,
+        QrcodePollStatus::Expired => PollStep::Renew,
+        _ if age >= QR_LIFETIME => PollStep::Renew,
+        _ => PollStep::Wait,
+    }
+}
+
+/// Get a code, poll it until it is scanned or expires, and start over with a new one,
+/// until logged in or the page is gone
+async fn run_poller(client: Arc<ApiClient>, tx: mpsc::UnboundedSender<LoginEvent>) {
+    loop {
+        let data = match client.get_qrcode_data().await {
+            Ok(data) => data,
+            Err(e) => {
+                if tx.send(LoginEvent::CodeError(e.to_string())).is_err() {
+                    return;
+                }
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+        let qrcode_key = data.qrcode_key.clone();
+        let issued_at = Instant::now();
+        if tx.send(LoginEvent::Code(data, issued_at)).is_err() {
+            return;
+        }
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let event = match client.poll_qrcode(&qrcode_key).await {
+                Ok(result) => {
+                    let Some(data) = result.data else {
+                        continue;
+                    };
+                    let status = QrcodePollStatus::from(data.code);
+                    if status == QrcodePollStatus::Success {
+                        // Extract credentials from cookies
+                        let event =
+                            Credentials::from_cookies(&result.cookies, Some(data.refresh_token))
+                                .map_or_else(
+                                    || LoginEvent::PollError(t("登录响应中没有 Cookie").into()),
+                                    LoginEvent::Success,
+                                );
+                        let _ = tx.send(event);
+                        return;
+                    }
+                    LoginEvent::Status(status)
+                }
+                Err(e) => LoginEvent::PollError(e.to_string()),
+            };
+            let step = match &event {
+                LoginEvent::Status(status) => next_step(*status, issued_at.elapsed()),
+                _ => next_step(QrcodePollStatus::Waiting, issued_at.elapsed()),
+            };
+            if tx.send(event).is_err() {
+                return;
+            }
+            match step {
+                PollStep::Wait => {}
+                PollStep::Renew => break,
+                PollStep::Finish => return,
+            }
+        }
+    }
+}
 
 pub struct LoginPage {
     qrcode_data: Option<QrcodeData>,
+    /// When the shown code was issued, for the remaining time
+    issued_at: Option<Instant>,
     error_message: Option<String>,
     poll_status: QrcodePollStatus,
-    last_poll: Option<Instant>,
     picker: Arc<Picker>,
     qr_image_protocol: Option<StatefulProtocol>,
+    /// Result of saving the QR code as an image, or the last poll error
+    notice: Option<String>,
+    events: Option<mpsc::UnboundedReceiver<LoginEvent>>,
+    poller: Option<JoinHandle<()>>,
+}
+
+/// Full quiet zone around the code, in modules
+const QUIET_ZONE: u16 = 4;
+/// Largest module scale for the character QR code
+const MAX_QR_SCALE: u16 = 3;
+
+/// How the character QR code fits an area
+#[derive(Debug, PartialEq, Eq)]
+enum QrFit {
+    /// Each module `scale` columns wide and `scale` half-rows tall, with the full quiet zone
+    Scaled(u16),
+    /// 1x, with a one cell white margin instead of the 4 module quiet zone
+    Compact,
+}
+
+impl QrFit {
+    /// Pick the largest rendering of a `modules` wide code that fits into `width` x `height` cells
+    fn choose(modules: u16, width: u16, height: u16) -> Option<Self> {
+        let zoned = modules + QUIET_ZONE * 2;
+        let scaled = (1..=MAX_QR_SCALE)
+            .rev()
+            .find(|&s| zoned * s <= width && (zoned * s).div_ceil(2) <= height)
+            .map(QrFit::Scaled);
+        scaled.or_else(|| {
+            (modules + 2 <= width && modules.div_ceil(2) + 2 <= height).then_some(QrFit::Compact)
+        })
+    }
 }
 
 impl LoginPage {
@@ -27,32 +166,72 @@ impl LoginPage {
         let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
         Self {
             qrcode_data: None,
+            issued_at: None,
             error_message: None,
             poll_status: QrcodePollStatus::Waiting,
-            last_poll: None,
             picker,
             qr_image_protocol: None,
+            notice: None,
+            events: None,
+            poller: None,
         }
     }
 
-    pub async fn load_qrcode(&mut self, api_client: &ApiClient) {
-        match api_client.get_qrcode_data().await {
-            Ok(data) => {
-                // Generate QR code image if the terminal supports it
-                self.qr_image_protocol = Self::generate_qr_image(&data.url, &self.picker);
-                self.qrcode_data = Some(data);
-                self.error_message = None;
-                self.poll_status = QrcodePollStatus::Waiting;
-                self.last_poll = None;
-            }
-            Err(e) => {
-                self.error_message = Some(format!("获取二维码失败: {}", e));
+    /// Start polling in the background, replacing a running poller
+    fn start_poller(&mut self, api_client: &Arc<ApiClient>) {
+        if let Some(poller) = self.poller.take() {
+            poller.abort();
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.events = Some(rx);
+        self.poller = Some(tokio::spawn(run_poller(Arc::clone(api_client), tx)));
+    }
+
+    /// Apply what the poller reported since the last tick
+    fn poll_events(&mut self) -> Option<AppAction> {
+        let events = self.events.as_mut()?;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                LoginEvent::Code(data, issued_at) => {
+                    // Generate QR code image if the terminal supports it
+                    self.qr_image_protocol = Self::generate_qr_image(&data.url, &self.picker);
+                    self.notice = None;
+                    self.qrcode_data = Some(data);
+                    self.issued_at = Some(issued_at);
+                    self.error_message = None;
+                    self.poll_status = QrcodePollStatus::Waiting;
+                }
+                LoginEvent::Status(status) => {
+                    self.poll_status = status;
+                    self.notice = None;
+                }
+                LoginEvent::Success(creds) => {
+                    self.poll_status = QrcodePollStatus::Success;
+                    return Some(AppAction::LoginSuccess(creds));
+                }
+                LoginEvent::CodeError(e) => {
+                    self.error_message = Some(tf("获取二维码失败: {}", &[&e]));
+                }
+                LoginEvent::PollError(e) => {
+                    self.notice = Some(tf("轮询失败: {}", &[&e]));
+                }
             }
         }
+        None
+    }
+
+    /// Time left before the shown code is replaced
+    fn remaining(&self) -> Option<Duration> {
+        self.issued_at
+            .map(|at| QR_LIFETIME.saturating_sub(at.elapsed()))
     }
 
     /// Generate QR code image for terminal display
     fn generate_qr_image(url: &str, picker: &Picker) -> Option<StatefulProtocol> {
+        // Halfblocks would blur the modules, the character renderer is sharper there
+        if picker.protocol_type() == ProtocolType::Halfblocks {
+            return None;
+        }
         let qr_code = QrCode::new(url.as_bytes()).ok()?;
 
         // Render QR code to an image with proper scaling
@@ -67,64 +246,111 @@ impl LoginPage {
         Some(picker.new_resize_protocol(dynamic_image))
     }
 
-    pub async fn tick(&mut self, api_client: &ApiClient) -> Option<AppAction> {
-        // Only poll if we have a QR code and haven't succeeded/expired
-        let qrcode_key = match &self.qrcode_data {
-            Some(data) => data.qrcode_key.clone(),
-            None => return None,
-        };
-
-        // Don't poll if already successful or expired
-        if matches!(
-            self.poll_status,
-            QrcodePollStatus::Success | QrcodePollStatus::Expired
-        ) {
-            return None;
-        }
-
-        // Poll every 2 seconds
-        let should_poll = self
-            .last_poll
-            .map(|t| t.elapsed() > Duration::from_secs(2))
-            .unwrap_or(true);
-
-        if !should_poll {
-            return None;
+    fn status_text(&self, theme: &Theme) -> (&str, Color) {
+        match self.poll_status {
+            QrcodePollStatus::Waiting => (t("⏳ 等待扫描二维码..."), theme.warning),
+            QrcodePollStatus::Scanned => (t("📱 已扫描，请在手机上确认登录"), theme.info),
+            QrcodePollStatus::Success => (t("✅ 登录成功！"), theme.success),
+            QrcodePollStatus::Expired => {
+                (t("⏳ 二维码已过期，正在获取新的二维码..."), theme.warning)
+            }
+            QrcodePollStatus::Unknown(_) => (t("❓ 未知状态"), theme.fg_secondary),
         }
+    }
+}
 
-        self.last_poll = Some(Instant::now());
-
-        match api_client.poll_qrcode(&qrcode_key).await {
-            Ok(result) => {
-                if let Some(data) = result.data {
-                    self.poll_status = QrcodePollStatus::from(data.code);
+impl LoginPage {
+    /// Draw the QR code centered in `area`, as an image when the terminal supports graphics
+    fn render_qr(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        keys: &Keybindings,
+        url: &str,
+    ) {
+        let Ok(qr_code) = QrCode::new(url) else {
+            return;
+        };
+        let modules = qr_code.width() as u16;
 
-                    if self.poll_status == QrcodePollStatus::Success {
-                        // Extract credentials from cookies
-                        if let Some(creds) =
-                            Credentials::from_cookies(&result.cookies, Some(data.refresh_token))
-                        {
-                            return Some(AppAction::LoginSuccess(creds));
-                        }
-                    }
-                }
+        if let Some(protocol) = &mut self.qr_image_protocol {
+            // Square region in pixels, at least 2 px per module to stay scannable
+            let font = self.picker.font_size();
+            let (fw, fh) = (font.0.max(1), font.1.max(1));
+            let side = (area.width * fw).min(area.height * fh);
+            if side >= (modules + QUIET_ZONE * 2) * 2 {
+                let (cols, rows) = (side / fw, side / fh);
+                let image_area = Self::centered(area, cols, rows);
+                frame.render_stateful_widget(StatefulImage::new(), image_area, protocol);
+                return;
             }
-            Err(e) => {
-                self.error_message = Some(format!("轮询失败: {}", e));
+        } else if let Some(fit) = QrFit::choose(modules, area.width, area.height) {
+            // Inverted colors: black modules on white background (standard QR format)
+            let widget = QrCodeWidget::new(qr_code)
+                .colors(Colors::Inverted)
+                .style(Style::default().fg(Color::Black).bg(Color::White));
+            let widget = match fit {
+                QrFit::Scaled(scale) => widget
+                    .quiet_zone(QuietZone::Enabled)
+                    .scaling(Scaling::Exact(scale, scale)),
+                QrFit::Compact => widget.quiet_zone(QuietZone::Disabled),
+            };
+            let size = widget.size(area);
+            let qr_area = Self::centered(area, size.width, size.height);
+            if fit == QrFit::Compact {
+                let margin = Self::centered(area, size.width + 2, size.height + 2);
+                frame.render_widget(
+                    Block::default().style(Style::default().bg(Color::White)),
+                    margin,
+                );
             }
+            frame.render_widget(widget, qr_area);
+            return;
         }
 
-        None
+        let hint = Paragraph::new(tf(
+            "终端太小，请调大窗口或按 [{}] 保存二维码图片",
+            &[&keys.export],
+        ))
+        .style(Style::default().fg(theme.warning))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+        frame.render_widget(hint, area);
     }
 
-    fn status_text(&self, theme: &Theme) -> (&str, Color) {
-        match self.poll_status {
-            QrcodePollStatus::Waiting => ("⏳ 等待扫描二维码...", theme.warning),
-            QrcodePollStatus::Scanned => ("📱 已扫描，请在手机上确认登录", theme.info),
-            QrcodePollStatus::Success => ("✅ 登录成功！", theme.success),
-            QrcodePollStatus::Expired => ("❌ 二维码已过期，请按 r 刷新", theme.error),
-            QrcodePollStatus::Unknown(_) => ("❓ 未知状态", theme.fg_secondary),
-        }
+    /// A `width` x `height` rect centered in `area`, clamped to it
+    fn centered(area: Rect, width: u16, height: u16) -> Rect {
+        let width = width.min(area.width);
+        let height = height.min(area.height);
+        Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        )
+    }
+
+    /// Save the current QR code as a PNG in the export directory, to scan from another screen
+    fn save_qr_image(&mut self) {
+        let Some(data) = &self.qrcode_data else {
+            return;
+        };
+        let result = QrCode::new(data.url.as_bytes())
+            .map_err(anyhow::Error::from)
+            .and_then(|qr| {
+                let image = qr
+                    .render::<image::Luma<u8>>()
+                    .min_dimensions(400, 400)
+                    .build();
+                let path = crate::export::export_dir()?.join("login-qrcode.png");
+                image.save(&path)?;
+                Ok(path)
+            });
+        self.notice = Some(match result {
+            Ok(path) => tf("二维码已保存到 {}", &[&path.display()]),
+            Err(e) => tf("保存二维码失败: {}", &[&e]),
+        });
     }
 }
 
@@ -134,6 +360,14 @@ impl Default for LoginPage {
     }
 }
 
+impl Drop for LoginPage {
+    fn drop(&mut self) {
+        if let Some(poller) = self.poller.take() {
+            poller.abort();
+        }
+    }
+}
+
 impl Component for LoginPage {
     fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
         // Layout: title, QR code, status, help
@@ -168,7 +402,7 @@ impl Component for LoginPage {
                     .fg(theme.fg_primary)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("登录", Style::default().fg(theme.bilibili_cyan)),
+            Span::styled(t("登录"), Style::default().fg(theme.bilibili_cyan)),
         ]);
 
         let title = Paragraph::new(title_line)
@@ -193,7 +427,7 @@ impl Component for LoginPage {
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_unfocused))
             .title(Span::styled(
-                " 扫码登录 ",
+                t(" 扫码登录 "),
                 Style::default().fg(theme.fg_secondary),
             ));
 
@@ -207,38 +441,10 @@ impl Component for LoginPage {
             frame.render_widget(qr_block.clone(), chunks[1]);
             let inner_area = qr_block.inner(chunks[1]);
 
-            // Try to render as image first (for better visual quality in supported terminals)
-            if let Some(ref mut protocol) = self.qr_image_protocol {
-                let image = StatefulImage::new();
-                frame.render_stateful_widget(image, inner_area, protocol);
-            } else if let Ok(qr_code) = QrCode::new(&qrcode_data.url) {
-                // Fallback to character-based QR code
-                // Create QR code widget with optimized settings for scanning:
-                // - Inverted colors: black modules on white background (standard QR format)
-                // - QuietZone::Enabled: white border around QR for better scanning
-                let qr_widget = QrCodeWidget::new(qr_code)
-                    .colors(Colors::Inverted)
-                    .quiet_zone(QuietZone::Enabled)
-                    .style(Style::default().fg(Color::Black).bg(Color::White));
-
-                // Get the actual size the QR code will render at
-                let qr_size = qr_widget.size(inner_area);
-
-                // Center the QR code based on its actual size
-                let x_offset = (inner_area.width.saturating_sub(qr_size.width)) / 2;
-                let y_offset = (inner_area.height.saturating_sub(qr_size.height)) / 2;
-
-                let qr_area = Rect::new(
-                    inner_area.x + x_offset,
-                    inner_area.y + y_offset,
-                    qr_size.width.min(inner_area.width),
-                    qr_size.height.min(inner_area.height),
-                );
-
-                frame.render_widget(qr_widget, qr_area);
-            }
+            let url = qrcode_data.url.clone();
+            self.render_qr(frame, inner_area, theme, keys, &url);
         } else {
-            let loading = Paragraph::new("⏳ 加载中...")
+            let loading = Paragraph::new(t("⏳ 加载中..."))
                 .style(
                     Style::default()
                         .fg(theme.warning)
@@ -250,7 +456,36 @@ impl Component for LoginPage {
         }
 
         // Status with enhanced styling
-        let (status_text, status_color) = self.status_text(theme);
+        let (status_text, status_color) = match &self.notice {
+            Some(notice) => (notice.as_str(), theme.info),
+            None => self.status_text(theme),
+        };
+        let mut status_block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_unfocused))
+            .title(Span::styled(
+                t(" 状态 "),
+                Style::default().fg(theme.fg_secondary),
+            ));
+        if let Some(remaining) = self
+            .remaining()
+            .filter(|_| self.poll_status != QrcodePollStatus::Success)
+        {
+            let secs = remaining.as_secs();
+            // 最后 30 秒提示即将换码
+            let color = if secs <= 30 {
+                theme.warning
+            } else {
+                theme.fg_secondary
+            };
+            let text = tf(
+                " 二维码剩余 {}:{} ",
+                &[&(secs / 60), &format!("{:02}", secs % 60)],
+            );
+            status_block = status_block
+                .title(Line::from(Span::styled(text, Style::default().fg(color))).right_aligned());
+        }
         let status = Paragraph::new(status_text)
             .style(
                 Style::default()
@@ -258,16 +493,7 @@ impl Component for LoginPage {
                     .add_modifier(Modifier::BOLD),
             )
             .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.border_unfocused))
-                    .title(Span::styled(
-                        " 状态 ",
-                        Style::default().fg(theme.fg_secondary),
-                    )),
-            );
+            .block(status_block);
         frame.render_widget(status, chunks[2]);
 
         // Help with styled shortcuts
@@ -280,7 +506,14 @@ impl Component for LoginPage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("刷新二维码", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("刷新二维码"), Style::default().fg(theme.fg_secondary)),
+            Span::styled("  [", Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                &keys.export,
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("] ", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("保存二维码图片"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.quit,
@@ -289,7 +522,7 @@ impl Component for LoginPage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("退出", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("退出"), Style::default().fg(theme.fg_secondary)),
         ]);
         let help = Paragraph::new(help_line).alignment(Alignment::Center);
         frame.render_widget(help, chunks[3]);
@@ -303,8 +536,15 @@ impl Component for LoginPage {
         if keys.matches_quit(key) {
             return Some(AppAction::Quit);
         }
+        if keys.matches_export(key) {
+            self.save_qr_image();
+            return Some(AppAction::None);
+        }
         if keys.matches_refresh(key) {
             // Request refresh - will be handled by App
+            if let Some(poller) = self.poller.take() {
+                poller.abort();
+            }
             self.qrcode_data = None;
             self.qr_image_protocol = None;
             self.poll_status = QrcodePollStatus::Waiting;
@@ -312,4 +552,53 @@ impl Component for LoginPage {
         }
         Some(AppAction::None)
     }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        self.start_poller(ctx.client);
+        Box::pin(async {})
+    }
+
+    fn tick<'a>(&'a mut self, _ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        let action = self.poll_events();
+        Box::pin(async move { action })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        true
+    }
+
+    fn is_full_screen(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_fit_prefers_largest_scale() {
+        // 25 modules + quiet zone = 33
+        assert_eq!(QrFit::choose(25, 120, 60), Some(QrFit::Scaled(3)));
+        assert_eq!(QrFit::choose(25, 70, 40), Some(QrFit::Scaled(2)));
+        assert_eq!(QrFit::choose(25, 33, 17), Some(QrFit::Scaled(1)));
+        assert_eq!(QrFit::choose(25, 30, 15), Some(QrFit::Compact));
+        assert_eq!(QrFit::choose(25, 20, 10), None);
+    }
+
+    #[test]
+    fn test_next_step_renews_expired_codes() {
+        let fresh = Duration::from_secs(10);
+        assert_eq!(next_step(QrcodePollStatus::Waiting, fresh), PollStep::Wait);
+        assert_eq!(next_step(QrcodePollStatus::Scanned, fresh), PollStep::Wait);
+        assert_eq!(next_step(QrcodePollStatus::Expired, fresh), PollStep::Renew);
+        assert_eq!(
+            next_step(QrcodePollStatus::Waiting, QR_LIFETIME),
+            PollStep::Renew
+        );
+        assert_eq!(
+            next_step(QrcodePollStatus::Success, fresh),
+            PollStep::Finish
+        );
+    }
 }
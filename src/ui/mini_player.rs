@@ -0,0 +1,52 @@
+//! One-line player bar shown above the status bar while the private FM is on
+
+use super::Theme;
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
+use ratatui::{prelude::*, widgets::*};
+
+/// Draw the bar; `track` is (title, author) of the current song, None while loading
+pub fn render_mini_player(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    keys: &Keybindings,
+    track: Option<(&str, &str)>,
+) {
+    let muted = Style::default().fg(theme.fg_muted);
+    let mut spans = vec![
+        Span::styled(
+            t(" 📻 私人 FM "),
+            Style::default()
+                .fg(theme.bilibili_pink)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("│ ", Style::default().fg(theme.border_subtle)),
+    ];
+    match track {
+        Some((title, author)) => {
+            spans.push(Span::styled(
+                format!("♪ {}", title),
+                Style::default().fg(theme.fg_primary),
+            ));
+            if !author.is_empty() {
+                spans.push(Span::styled(format!(" - {}", author), muted));
+            }
+        }
+        None => spans.push(Span::styled(t("正在挑选曲目..."), muted)),
+    }
+
+    let hint = tf("[{}] 下一首  [{}] 关闭 ", &[&keys.fm_skip, &keys.fm_toggle]);
+    let hint_width = Span::raw(&hint).width() as u16;
+    let [left, right] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(hint_width)]).areas(area);
+
+    let style = Style::default().bg(theme.bg_secondary);
+    frame.render_widget(Paragraph::new(Line::from(spans)).style(style), left);
+    frame.render_widget(
+        Paragraph::new(Span::styled(hint, muted))
+            .alignment(Alignment::Right)
+            .style(style),
+        right,
+    );
+}
@@ -1,41 +1,112 @@
+mod action_menu;
+mod branch_menu;
+mod card_grid;
+mod comment_editor;
+mod cover_pool;
 mod dynamic;
+mod dynamic_composer;
 mod dynamic_detail;
+mod error_hint;
+mod favorites;
+mod help;
 mod history;
+mod hit_map;
 mod home;
+mod image_viewer;
 mod live;
 mod live_detail;
 mod login;
+mod mini_player;
+mod multi_select;
+mod open_dialog;
+mod quality_menu;
+mod scrollbar;
 mod search;
 mod settings;
 mod sidebar;
+mod stats_panel;
+mod status_bar;
+mod subscriptions;
 pub mod theme;
+mod toast;
+mod user_space;
 mod video_card;
 mod video_detail;
+mod watch_later;
+mod watch_stats;
 
+pub use action_menu::{ActionMenu, VideoAction};
+pub use branch_menu::BranchMenu;
+pub use card_grid::{clear_popup, invalidate_covers};
 pub use dynamic::{DynamicPage, DynamicTab};
 pub use dynamic_detail::DynamicDetailPage;
+pub use favorites::FavoritesPage;
+pub use help::HelpOverlay;
 pub use history::HistoryPage;
 pub use home::HomePage;
 pub use live::LivePage;
 pub use live_detail::LiveDetailPage;
 pub use login::LoginPage;
+pub use mini_player::render_mini_player;
+pub use multi_select::failure_notice;
+pub use open_dialog::OpenDialog;
+pub use quality_menu::QualityMenu;
 pub use search::SearchPage;
 pub use settings::SettingsPage;
 pub use sidebar::{NavItem, Sidebar};
+pub use stats_panel::render_stats_panel;
+pub use status_bar::StatusBar;
+pub use subscriptions::SubscriptionsPage;
 pub use theme::{Theme, ThemeVariant};
+pub use toast::Toast;
+pub use user_space::UserSpacePage;
 pub use video_card::{VideoCard, VideoCardGrid};
 pub use video_detail::VideoDetailPage;
+pub use watch_later::WatchLaterPage;
+pub use watch_stats::WatchStatsPage;
 
+use crate::api::client::ApiClient;
 use crate::app::AppAction;
-use crate::storage::Keybindings;
+use crate::storage::{Keybindings, Session, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
     crossterm::event::{KeyCode, KeyModifiers, MouseEvent},
     prelude::Rect,
     Frame,
 };
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
 
-/// UI Component trait
-pub trait Component {
+/// App state handed to a page when it loads or polls its background work
+#[derive(Clone, Copy)]
+pub struct PageContext<'a> {
+    pub client: &'a Arc<ApiClient>,
+    /// UID of the logged-in user
+    pub mid: Option<i64>,
+    /// mpv is playing or the terminal lost focus, so no new cover downloads
+    pub throttled: bool,
+    /// Inside the do-not-disturb hours, so no terminal bell
+    pub quiet: bool,
+}
+
+/// Lets the app get a concrete page back from a `Box<dyn Component>`
+pub trait AsAny: Any {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// UI Component trait, implemented by every page
+pub trait Component: AsAny {
     fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings);
     fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
         let _ = (key, keys);
@@ -50,22 +121,61 @@ pub trait Component {
         let _ = modifiers;
         self.handle_input(key, keys)
     }
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
-        let _ = (event, area);
+    /// Mouse events; pages hit-test against the areas they recorded in their last `draw`
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        let _ = event;
+        None
+    }
+    /// Load the page's data when it is shown
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        let _ = ctx;
+        Box::pin(async {})
+    }
+    /// Poll background work on every loop of the app
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        let _ = ctx;
+        Box::pin(async { None })
+    }
+    /// Keys go to a text input (search box, comment, key editing), global shortcuts are off
+    fn is_text_input_active(&self) -> bool {
+        false
+    }
+    /// Pages drawn over the whole screen without the sidebar
+    fn is_full_screen(&self) -> bool {
+        false
+    }
+    /// Pages that mark watched videos, the watch history is loaded before they are shown
+    fn marks_watched(&self) -> bool {
+        false
+    }
+    fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        let _ = (watched, dim);
+    }
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        let _ = blocklist;
+    }
+    /// Whether deleted or taken down videos are listed (greyed out) instead of filtered
+    fn set_show_invalid(&mut self, show: bool) {
+        let _ = show;
+    }
+    /// List pages report their selection for session restore
+    fn session(&self) -> Option<Session> {
         None
     }
+    /// Select the item saved by [`Component::session`]
+    fn restore_selection(&mut self, selected: usize) {
+        let _ = selected;
+    }
 }
 
-/// Application pages
-pub enum Page {
-    Login(LoginPage),
-    Home(HomePage),
-    Search(SearchPage),
-    Dynamic(DynamicPage),
-    DynamicDetail(Box<DynamicDetailPage>),
-    VideoDetail(Box<VideoDetailPage>),
-    History(HistoryPage),
-    Live(LivePage),
-    LiveDetail(Box<LiveDetailPage>),
-    Settings(Box<SettingsPage>),
+impl dyn Component {
+    pub fn is<T: Component>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+    pub fn downcast_ref<T: Component>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+    pub fn downcast_mut<T: Component>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
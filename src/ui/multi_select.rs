@@ -0,0 +1,243 @@
+//! Multi-select mode of the list pages (历史、稍后再看、收藏夹): check several cards,
+//! then remove them or add them to the play queue in one go
+
+use super::video_card::{CardBadge, VideoCard};
+use super::Theme;
+use crate::i18n::tf;
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// What a key pressed in multi-select mode asks the page to do
+#[derive(Debug, PartialEq, Eq)]
+pub enum MultiSelectKey<K> {
+    /// Consumed by the mode itself
+    Handled,
+    /// The removal was confirmed for these entries
+    Remove(Vec<K>),
+    /// Add the checked entries to the play queue
+    Enqueue,
+    /// Not a multi-select key, the page handles it (navigation)
+    Unhandled,
+}
+
+/// Checked entries, keyed by what the page removes them by (history kid, aid)
+pub struct MultiSelect<K> {
+    active: bool,
+    marked: HashSet<K>,
+    /// Waiting for a second remove key press
+    confirm_remove: bool,
+    /// Result of the last batch operation, shown in the bar
+    notice: Option<String>,
+}
+
+impl<K> Default for MultiSelect<K> {
+    fn default() -> Self {
+        Self {
+            active: false,
+            marked: HashSet::new(),
+            confirm_remove: false,
+            notice: None,
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> MultiSelect<K> {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn start(&mut self) {
+        self.active = true;
+        self.notice = None;
+    }
+
+    pub fn exit(&mut self) {
+        self.active = false;
+        self.marked.clear();
+        self.confirm_remove = false;
+    }
+
+    /// Checkbox state of an entry, None outside multi-select mode
+    pub fn mark_of(&self, key: &K) -> Option<bool> {
+        self.active.then(|| self.marked.contains(key))
+    }
+
+    pub fn is_marked(&self, key: &K) -> bool {
+        self.marked.contains(key)
+    }
+
+    /// Keys in multi-select mode; `current` is the entry under the cursor
+    pub fn handle_input(
+        &mut self,
+        key: KeyCode,
+        keys: &Keybindings,
+        current: Option<K>,
+    ) -> MultiSelectKey<K> {
+        let confirming = std::mem::take(&mut self.confirm_remove);
+
+        if keys.matches_multi_select(key) || keys.matches_back(key) {
+            self.exit();
+            return MultiSelectKey::Handled;
+        }
+        if keys.matches_mark(key) {
+            if let Some(current) = current {
+                if !self.marked.remove(&current) {
+                    self.marked.insert(current);
+                }
+            }
+            self.notice = None;
+            return MultiSelectKey::Handled;
+        }
+        if keys.matches_batch_delete(key) && !self.marked.is_empty() {
+            if confirming {
+                return MultiSelectKey::Remove(self.marked.iter().cloned().collect());
+            }
+            self.confirm_remove = true;
+            return MultiSelectKey::Handled;
+        }
+        if keys.matches_play(key) && !self.marked.is_empty() {
+            return MultiSelectKey::Enqueue;
+        }
+        MultiSelectKey::Unhandled
+    }
+
+    /// Uncheck everything after it was added to the play queue
+    pub fn finish_enqueue(&mut self, count: usize) {
+        self.marked.clear();
+        self.notice = Some(tf("已加入播放队列 {} 个视频", &[&count]));
+    }
+
+    /// Uncheck the removed entries and leave the mode, or keep the failed ones checked
+    /// for a retry and show why they failed
+    pub fn finish_remove(&mut self, removed: &[K], errors: &[String]) {
+        for key in removed {
+            self.marked.remove(key);
+        }
+        if errors.is_empty() {
+            self.exit();
+            self.notice = None;
+        } else {
+            self.notice = Some(failure_notice(errors));
+        }
+    }
+
+    /// Bottom bar with the checked count and the keys; `remove` names the removal
+    pub fn render_bar(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        keys: &Keybindings,
+        remove: &str,
+    ) {
+        let text = if self.confirm_remove {
+            tf(
+                "{}：已选 {} 项，再按 [{}] 确认，其他键取消",
+                &[&remove, &self.marked.len(), &keys.batch_delete],
+            )
+        } else {
+            let text = tf(
+                "☑ 已选 {} 项  [{}] 勾选  [{}] {}  [{}] 加入播放队列  [{}] 退出多选",
+                &[
+                    &self.marked.len(),
+                    &keys.mark,
+                    &keys.batch_delete,
+                    &remove,
+                    &keys.play,
+                    &keys.multi_select,
+                ],
+            );
+            match &self.notice {
+                Some(notice) => format!("{}  · {}", notice, text),
+                None => text,
+            }
+        };
+        let style = if self.confirm_remove {
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.fg_accent)
+        };
+        let bar = Paragraph::new(text)
+            .style(style.bg(theme.bg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(bar, area);
+    }
+}
+
+impl MultiSelect<i64> {
+    /// Show the checked state on video cards keyed by aid
+    pub fn mark_cards(&self, cards: &mut [VideoCard]) {
+        for card in cards {
+            let marked = card.aid.is_some_and(|aid| self.mark_of(&aid) == Some(true));
+            if marked {
+                card.add_badge(CardBadge::Marked);
+            } else {
+                card.remove_badge(CardBadge::Marked);
+            }
+        }
+    }
+
+    /// Bvids of the checked video cards, in grid order
+    pub fn marked_bvids(&self, cards: &[VideoCard]) -> Vec<String> {
+        cards
+            .iter()
+            .filter(|card| card.aid.is_some_and(|aid| self.is_marked(&aid)))
+            .filter_map(|card| card.bvid.clone())
+            .collect()
+    }
+}
+
+/// "N 项失败: reason" with each distinct reason once, for batches sent entry by entry
+pub fn failure_notice(errors: &[String]) -> String {
+    let mut reasons: Vec<&str> = Vec::new();
+    for error in errors {
+        if !reasons.contains(&error.as_str()) {
+            reasons.push(error);
+        }
+    }
+    tf("{} 项失败: {}", &[&errors.len(), &reasons.join("；")])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_needs_confirmation() {
+        let keys = Keybindings::default();
+        let mut select = MultiSelect::default();
+        select.start();
+        let mark = Keybindings::parse_keycode(&keys.mark).unwrap();
+        let delete = Keybindings::parse_keycode(&keys.batch_delete).unwrap();
+
+        // Nothing checked: the remove key does nothing
+        assert_eq!(
+            select.handle_input(delete, &keys, Some(1)),
+            MultiSelectKey::Unhandled
+        );
+        select.handle_input(mark, &keys, Some(1));
+        select.handle_input(mark, &keys, Some(2));
+        select.handle_input(mark, &keys, Some(2));
+        assert_eq!(select.mark_of(&1), Some(true));
+        assert_eq!(select.mark_of(&2), Some(false));
+
+        assert_eq!(
+            select.handle_input(delete, &keys, Some(1)),
+            MultiSelectKey::Handled
+        );
+        assert_eq!(
+            select.handle_input(delete, &keys, Some(1)),
+            MultiSelectKey::Remove(vec![1])
+        );
+
+        select.finish_remove(&[], &["-101".to_string(), "-101".to_string()]);
+        assert!(select.is_active());
+        assert_eq!(select.mark_of(&1), Some(true));
+        select.finish_remove(&[1], &[]);
+        assert!(!select.is_active());
+    }
+}
@@ -0,0 +1,107 @@
+//! "Open link" popup: paste a BV号, av号, video/live URL or b23.tv short link
+
+use super::{clear_popup, Theme};
+use crate::app::AppAction;
+use crate::i18n::t;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Input popup for opening a video directly
+#[derive(Default)]
+pub struct OpenDialog {
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl OpenDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_error(&mut self, msg: impl Into<String>) {
+        self.error = Some(msg.into());
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) -> Option<AppAction> {
+        match key {
+            KeyCode::Esc => Some(AppAction::CloseOpenDialog),
+            KeyCode::Enter => {
+                let input = self.input.trim().to_string();
+                if input.is_empty() {
+                    return Some(AppAction::None);
+                }
+                Some(AppAction::OpenLink(input))
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.error = None;
+                Some(AppAction::None)
+            }
+            KeyCode::Char(c) => {
+                self.input.push(c);
+                self.error = None;
+                Some(AppAction::None)
+            }
+            _ => Some(AppAction::None),
+        }
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = 60.min(area.width);
+        let height = 5.min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+
+        clear_popup(frame, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                t(" 🔗 打开链接 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                t(" Enter 打开  Esc 取消 "),
+                Style::default().fg(theme.fg_muted),
+            )));
+
+        // Keep the tail of long pasted links visible
+        let max_input = (width as usize).saturating_sub(4);
+        let char_count = self.input.chars().count();
+        let visible: String = self
+            .input
+            .chars()
+            .skip(char_count.saturating_sub(max_input))
+            .collect();
+
+        let status = if let Some(err) = &self.error {
+            Line::from(Span::styled(
+                format!("❌ {}", err),
+                Style::default().fg(theme.error),
+            ))
+        } else {
+            Line::from(Span::styled(
+                t("支持 BV号 / av号 / 视频或直播间链接 / b23.tv 短链"),
+                Style::default().fg(theme.fg_muted),
+            ))
+        };
+
+        let text = vec![
+            Line::from(Span::styled(
+                format!("{}▌", visible),
+                Style::default().fg(theme.fg_primary),
+            )),
+            status,
+        ];
+
+        let paragraph = Paragraph::new(text).block(block);
+        frame.render_widget(paragraph, popup);
+    }
+}
@@ -0,0 +1,112 @@
+//! Quality popup of the video detail page: pick the 画质 mpv plays from now on
+
+use super::{clear_popup, Theme};
+use crate::app::AppAction;
+use crate::i18n::t;
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Popup listing the qualities offered for a video, plus handing playback to yt-dlp
+pub struct QualityMenu {
+    /// (qn, name); None is the yt-dlp entry
+    entries: Vec<(Option<i32>, String)>,
+    /// Quality in the config, marked ✓
+    current: Option<i32>,
+    selected: usize,
+}
+
+impl QualityMenu {
+    pub fn new(qualities: Vec<(i32, String)>, current: Option<i32>) -> Self {
+        let mut entries = vec![(None, t("自动（yt-dlp 网页地址）").to_string())];
+        entries.extend(qualities.into_iter().map(|(qn, name)| (Some(qn), name)));
+        let selected = entries
+            .iter()
+            .position(|(qn, _)| *qn == current)
+            .unwrap_or(0);
+        Self {
+            entries,
+            current,
+            selected,
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if keys.matches_back(key) || keys.matches_quit(key) {
+            return Some(AppAction::CloseQualityMenu);
+        }
+        if keys.matches_up(key) {
+            self.selected = self.selected.saturating_sub(1);
+            return Some(AppAction::None);
+        }
+        if keys.matches_down(key) {
+            if self.selected + 1 < self.entries.len() {
+                self.selected += 1;
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_confirm(key) {
+            let (qn, _) = &self.entries[self.selected];
+            return Some(AppAction::ChooseQuality(*qn));
+        }
+        Some(AppAction::None)
+    }
+
+    /// Name of a quality in the list, for the toast after choosing it
+    pub fn name(&self, qn: Option<i32>) -> &str {
+        self.entries
+            .iter()
+            .find(|(q, _)| *q == qn)
+            .map_or("", |(_, name)| name.as_str())
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = 36.min(area.width);
+        let height = (self.entries.len() as u16 + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+
+        clear_popup(frame, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.bilibili_pink))
+            .title(Span::styled(
+                t(" 🎞 画质 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(Span::styled(
+                t(" 高于账号权限时自动降档 "),
+                Style::default().fg(theme.fg_muted),
+            )));
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (qn, name))| {
+                let style = if idx == self.selected {
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.selection_bg)
+                } else {
+                    Style::default().fg(theme.fg_secondary)
+                };
+                let mark = if *qn == self.current { "✓" } else { " " };
+                ListItem::new(format!(" {} {}", mark, name)).style(style)
+            })
+            .collect();
+
+        frame.render_widget(List::new(items), inner.inner(Margin::new(0, 1)));
+    }
+}
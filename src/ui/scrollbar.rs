@@ -0,0 +1,121 @@
+//! Vertical scrollbar for long lists that can be dragged with the mouse
+
+use super::Theme;
+use ratatui::{
+    crossterm::event::{MouseButton, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+/// Remembers where the bar was drawn so clicks and drags on it can be mapped back to a position
+#[derive(Default)]
+pub struct DragScrollbar {
+    /// Track of the last render, empty when the list fits on screen
+    track: Rect,
+    total: usize,
+    dragging: bool,
+}
+
+impl DragScrollbar {
+    /// Area left for the content when a bar is shown on the right edge of `area`
+    pub fn content_area(area: Rect, total: usize, viewport: usize) -> Rect {
+        if total > viewport {
+            Rect {
+                width: area.width.saturating_sub(1),
+                ..area
+            }
+        } else {
+            area
+        }
+    }
+
+    /// Draw the bar on the right edge of `area`, nothing when `total` fits in `viewport`
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        total: usize,
+        position: usize,
+        viewport: usize,
+        theme: &Theme,
+    ) {
+        self.total = total;
+        if total <= viewport || area.width == 0 {
+            self.track = Rect::default();
+            self.dragging = false;
+            return;
+        }
+
+        self.track = Rect {
+            x: area.right() - 1,
+            width: 1,
+            ..area
+        };
+        let mut state = ScrollbarState::new(total.saturating_sub(viewport) + 1)
+            .position(position)
+            .viewport_content_length(viewport);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_style(Style::default().fg(theme.border_subtle))
+            .thumb_style(Style::default().fg(if self.dragging {
+                theme.bilibili_pink
+            } else {
+                theme.fg_muted
+            }));
+        frame.render_stateful_widget(scrollbar, area, &mut state);
+    }
+
+    /// Handle a press / drag / release on the bar, returning the position to jump to
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> Option<usize> {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let on_track = self.track.contains(Position::new(event.column, event.row));
+                if !on_track {
+                    return None;
+                }
+                self.dragging = true;
+                Some(self.position_at(event.row))
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                Some(self.position_at(event.row))
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Map a screen row to a position, clamping rows dragged past either end of the track
+    fn position_at(&self, row: u16) -> usize {
+        let last = self.total.saturating_sub(1);
+        let span = self.track.height.saturating_sub(1) as usize;
+        if span == 0 {
+            return 0;
+        }
+        let offset = row.saturating_sub(self.track.y) as usize;
+        (offset.min(span) * last + span / 2) / span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_at_maps_track_to_range() {
+        let bar = DragScrollbar {
+            track: Rect::new(10, 5, 1, 11),
+            total: 101,
+            dragging: true,
+        };
+        assert_eq!(bar.position_at(5), 0);
+        assert_eq!(bar.position_at(10), 50);
+        assert_eq!(bar.position_at(15), 100);
+        // Dragging above / below the track clamps to the ends
+        assert_eq!(bar.position_at(0), 0);
+        assert_eq!(bar.position_at(40), 100);
+    }
+}
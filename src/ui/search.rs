@@ -1,121 +1,319 @@
 //! Search page with video card grid display
 
-use super::video_card::{VideoCard, VideoCardGrid};
-use super::{Component, Theme};
+use super::error_hint;
+use super::hit_map::HitMap;
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
+use crate::api::error::ApiErrorKind;
 use crate::api::search::{HotwordItem, SearchVideoItem};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, Session, SessionPage, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
     crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
     prelude::*,
     widgets::*,
 };
-use std::time::Instant;
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// 热搜榜后台刷新间隔
+const HOTWORD_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 缓存最近几个关键词的结果
+const RESULT_CACHE_SIZE: usize = 5;
+
+/// What a click on the hot list lands on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HotHit {
+    /// Border or empty space of the list, only scrolls it
+    List,
+    Word(usize),
+}
+
+/// Results and scroll position of an earlier keyword
+struct CachedSearch {
+    query: String,
+    results: Vec<SearchVideoItem>,
+    total: i32,
+    page: i32,
+    selected: usize,
+    scroll_row: usize,
+}
+
+/// Least recently used keywords are dropped first
+#[derive(Default)]
+struct SearchCache {
+    /// Most recent first
+    entries: VecDeque<CachedSearch>,
+}
+
+impl SearchCache {
+    fn put(&mut self, entry: CachedSearch) {
+        self.remove(&entry.query);
+        self.entries.push_front(entry);
+        self.entries.truncate(RESULT_CACHE_SIZE);
+    }
+
+    fn remove(&mut self, query: &str) -> Option<CachedSearch> {
+        let idx = self.entries.iter().position(|e| e.query == query)?;
+        self.entries.remove(idx)
+    }
+}
 
 pub struct SearchPage {
     pub query: String,
     pub grid: VideoCardGrid,
     pub loading: bool,
     pub error_message: Option<String>,
+    /// What kind of error is shown, picks the guidance and keys
+    pub error_kind: ApiErrorKind,
     pub input_mode: bool,
     pub hotwords: Vec<HotwordItem>,
     pub hotword_error: Option<String>,
     pub hotword_loading: bool,
     pub show_hot_list: bool,
     hot_selected: Option<usize>,
+    /// Hot list area and its visible rows of the last render
+    hot_hits: HitMap<HotHit>,
+    /// Last time the hot list was fetched (successfully or not)
+    hotwords_updated: Option<Instant>,
+    hotword_tx: mpsc::Sender<Result<Vec<HotwordItem>, String>>,
+    hotword_rx: mpsc::Receiver<Result<Vec<HotwordItem>, String>>,
     pub page: i32,
     pub total_results: i32,
     pub loading_more: bool,
+    /// Raw items behind the cards, kept for the keyword cache
+    results: Vec<SearchVideoItem>,
+    /// Keyword the current results belong to
+    results_query: Option<String>,
+    cache: SearchCache,
     last_click_time: Option<Instant>,
     last_click_index: Option<usize>,
 }
 
 impl SearchPage {
     pub fn new() -> Self {
+        let (hotword_tx, hotword_rx) = mpsc::channel(1);
         Self {
             query: String::new(),
-            grid: VideoCardGrid::new(),
+            grid: VideoCardGrid::new().with_number_hints(),
             loading: false,
             error_message: None,
+            error_kind: ApiErrorKind::Other,
             input_mode: true,
             hotwords: Vec::new(),
             hotword_error: None,
             hotword_loading: false,
             show_hot_list: true,
             hot_selected: None,
+            hot_hits: HitMap::new(),
+            hotwords_updated: None,
+            hotword_tx,
+            hotword_rx,
             page: 1,
             total_results: 0,
             loading_more: false,
+            results: Vec::new(),
+            results_query: None,
+            cache: SearchCache::default(),
             last_click_time: None,
             last_click_index: None,
         }
     }
 
-    pub fn set_results(&mut self, results: Vec<SearchVideoItem>, total: i32) {
+    pub fn set_results(&mut self, query: &str, results: Vec<SearchVideoItem>, total: i32) {
+        self.stash_results();
         self.grid.clear();
-        for item in results {
-            let card = VideoCard::new(
-                item.bvid.clone(),
-                item.mid,
-                item.display_title(),
-                item.author_name().to_string(),
-                item.format_play(),
-                item.duration.clone().unwrap_or_default(),
-                item.cover_url(),
-            );
-            self.grid.add_card(card);
+        for item in &results {
+            self.grid.add_card(make_card(item));
         }
+        self.results = results;
+        self.results_query = Some(query.to_string());
         self.total_results = total;
-        self.loading = false;
-        self.input_mode = false;
-        self.show_hot_list = false;
-        self.error_message = None;
+        self.show_results();
     }
 
     pub fn append_results(&mut self, results: Vec<SearchVideoItem>) {
-        for item in results {
-            let card = VideoCard::new(
-                item.bvid.clone(),
-                item.mid,
-                item.display_title(),
-                item.author_name().to_string(),
-                item.format_play(),
-                item.duration.clone().unwrap_or_default(),
-                item.cover_url(),
-            );
-            self.grid.add_card(card);
+        for item in &results {
+            self.grid.add_card(make_card(item));
         }
+        self.results.extend(results);
         self.loading_more = false;
     }
 
+    /// Show the cached results of `query` with their scroll position, instead of searching again.
+    /// Returns false when the keyword is not cached
+    pub fn restore_cached(&mut self, query: &str) -> bool {
+        if self.results_query.as_deref() == Some(query) {
+            self.show_results();
+            return true;
+        }
+        let Some(entry) = self.cache.remove(query) else {
+            return false;
+        };
+        self.stash_results();
+
+        self.grid.clear();
+        for item in &entry.results {
+            self.grid.add_card(make_card(item));
+        }
+        self.grid.selected_index = entry.selected.min(self.grid.cards.len().saturating_sub(1));
+        self.grid.scroll_row = entry.scroll_row;
+        self.results = entry.results;
+        self.results_query = Some(entry.query);
+        self.total_results = entry.total;
+        self.page = entry.page;
+        self.show_results();
+        true
+    }
+
+    /// Search the current keyword again among the uploads of the selected card's UP
+    fn search_in_uploader(&self) -> Option<AppAction> {
+        let bvid = self.grid.selected_card()?.bvid.as_deref()?;
+        let mid = self
+            .results
+            .iter()
+            .find(|r| r.bvid.as_deref() == Some(bvid))?
+            .mid?;
+        Some(AppAction::SearchUserSpace {
+            mid,
+            keyword: self.results_query.clone().unwrap_or_default(),
+        })
+    }
+
+    /// Drop cached results of `query` so the next search fetches them again
+    fn forget_cached(&mut self, query: &str) {
+        self.cache.remove(query);
+        if self.results_query.as_deref() == Some(query) {
+            self.results_query = None;
+        }
+    }
+
+    /// Move the current results into the keyword cache
+    fn stash_results(&mut self) {
+        let Some(query) = self.results_query.take() else {
+            return;
+        };
+        if self.results.is_empty() {
+            return;
+        }
+        self.cache.put(CachedSearch {
+            query,
+            results: std::mem::take(&mut self.results),
+            total: self.total_results,
+            page: self.page,
+            selected: self.grid.selected_index,
+            scroll_row: self.grid.scroll_row,
+        });
+    }
+
+    fn show_results(&mut self) {
+        self.loading = false;
+        self.input_mode = false;
+        self.show_hot_list = false;
+        self.error_message = None;
+        self.error_kind = ApiErrorKind::Other;
+    }
+
     pub fn set_error(&mut self, msg: String) {
         self.error_message = Some(msg);
+        self.error_kind = ApiErrorKind::Other;
         self.loading = false;
         self.loading_more = false;
         self.show_hot_list = false;
     }
 
-    pub fn start_hotword_loading(&mut self) {
+    /// Show a failed search, with guidance for the kind of error
+    pub fn set_search_error(&mut self, err: &anyhow::Error) {
+        self.set_error(tf("搜索失败: {}", &[err]));
+        self.error_kind = ApiErrorKind::of(err);
+    }
+
+    /// Fetch the hot list in the background; the current list stays visible until it arrives
+    pub fn refresh_hotwords(&mut self, api_client: &Arc<ApiClient>) {
+        if self.hotword_loading {
+            return;
+        }
         self.hotword_loading = true;
-        self.hotword_error = None;
-        self.hot_selected = None;
+
+        let client = api_client.clone();
+        let tx = self.hotword_tx.clone();
+        tokio::spawn(async move {
+            let result = client.get_hot_search().await.map_err(|e| e.to_string());
+            let _ = tx.send(result).await;
+        });
+    }
+
+    /// Whether the hot list is due for a background refresh
+    pub fn hotwords_stale(&self) -> bool {
+        !self.hotword_loading
+            && self
+                .hotwords_updated
+                .is_none_or(|t| t.elapsed() >= HOTWORD_REFRESH_INTERVAL)
+    }
+
+    /// Apply a finished hot list request (non-blocking)
+    pub fn poll_hotword_results(&mut self) {
+        if let Ok(result) = self.hotword_rx.try_recv() {
+            match result {
+                Ok(list) => self.set_hotwords(list),
+                Err(e) => self.set_hotword_error(tf("加载热搜失败: {}", &[&e])),
+            }
+        }
     }
 
     pub fn set_hotwords(&mut self, hotwords: Vec<HotwordItem>) {
         self.hotwords = hotwords;
         self.hotword_loading = false;
         self.hotword_error = None;
+        self.hotwords_updated = Some(Instant::now());
+        // Keep the selection across background refreshes
         self.hot_selected = if self.hotwords.is_empty() {
             None
         } else {
-            Some(0)
+            Some(self.hot_selected.unwrap_or(0).min(self.hotwords.len() - 1))
         };
     }
 
     pub fn set_hotword_error(&mut self, msg: String) {
         self.hotword_error = Some(msg);
         self.hotword_loading = false;
+        self.hotwords_updated = Some(Instant::now());
+    }
+
+    /// Toggle the hot list while results are shown
+    fn toggle_hot_list(&mut self) {
+        if self.show_hot_list {
+            if !self.grid.cards.is_empty() {
+                self.show_hot_list = false;
+            }
+        } else {
+            self.show_hot_list = true;
+            if self.hot_selected.is_none() && !self.hotwords.is_empty() {
+                self.hot_selected = Some(0);
+            }
+        }
+    }
+
+    /// Split the content area into the results grid and the hot list;
+    /// the hot list becomes a right-hand sidebar when there are results
+    fn content_layout(&self, area: Rect) -> (Option<Rect>, Option<Rect>) {
+        if !self.show_hot_list {
+            return (Some(area), None);
+        }
+        if self.grid.cards.is_empty() {
+            return (None, Some(area));
+        }
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(area);
+        (Some(chunks[0]), Some(chunks[1]))
     }
 
     pub async fn load_more(&mut self, api_client: &ApiClient) {
@@ -175,18 +373,94 @@ impl SearchPage {
         None
     }
 
-    fn draw_hot_list(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+    fn draw_results(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        if self.loading {
+            let loading = Paragraph::new(t("⏳ 搜索中..."))
+                .style(Style::default().fg(theme.warning))
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(theme.border_unfocused))
+                        .title(Span::styled(
+                            tf(" 结果 ({}) ", &[&self.total_results]),
+                            Style::default().fg(theme.fg_secondary),
+                        )),
+                );
+            frame.render_widget(loading, area);
+        } else if let Some(error) = &self.error_message {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border_unfocused));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            error_hint::render(frame, inner, theme, keys, self.error_kind, error, None);
+        } else if self.grid.cards.is_empty() {
+            let empty = Paragraph::new(if self.query.is_empty() {
+                t("输入关键词开始搜索")
+            } else {
+                t("没有找到相关视频")
+            })
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border_unfocused)),
+            );
+            frame.render_widget(empty, area);
+        } else {
+            // Render with header
+            let header = Paragraph::new(Line::from(vec![
+                Span::styled(t(" 搜索结果 "), Style::default().fg(theme.bilibili_pink)),
+                Span::styled(
+                    format!("({}/{})", self.grid.cards.len(), self.total_results),
+                    Style::default().fg(theme.fg_muted),
+                ),
+                if self.loading_more {
+                    Span::styled(t(" 加载中..."), Style::default().fg(theme.warning))
+                } else {
+                    Span::raw("")
+                },
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border_subtle)),
+            );
+
+            let header_area = Rect { height: 2, ..area };
+            let grid_area = Rect {
+                y: area.y + 2,
+                height: area.height.saturating_sub(2),
+                ..area
+            };
+
+            frame.render_widget(header, header_area);
+            self.grid.render(frame, grid_area, theme);
+        }
+    }
+
+    fn draw_hot_list(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        self.hot_hits.clear();
+        self.hot_hits.push(area, HotHit::List);
+
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 热搜榜 ",
+                t(" 热搜榜 "),
                 Style::default().fg(theme.bilibili_pink),
             ));
 
-        if self.hotword_loading {
-            let loading = Paragraph::new("⏳ 正在获取热搜...")
+        // A background refresh keeps showing the previous list
+        if self.hotword_loading && self.hotwords.is_empty() {
+            let loading = Paragraph::new(t("⏳ 正在获取热搜..."))
                 .style(Style::default().fg(theme.fg_secondary))
                 .alignment(Alignment::Center)
                 .block(block);
@@ -194,7 +468,11 @@ impl SearchPage {
             return;
         }
 
-        if let Some(err) = &self.hotword_error {
+        if let Some(err) = self
+            .hotword_error
+            .as_ref()
+            .filter(|_| self.hotwords.is_empty())
+        {
             let error_widget = Paragraph::new(format!("❌ {}", err))
                 .style(Style::default().fg(theme.error))
                 .alignment(Alignment::Center)
@@ -204,7 +482,7 @@ impl SearchPage {
         }
 
         if self.hotwords.is_empty() {
-            let empty = Paragraph::new("暂无热搜数据")
+            let empty = Paragraph::new(t("暂无热搜数据"))
                 .style(Style::default().fg(theme.fg_secondary))
                 .alignment(Alignment::Center)
                 .block(block);
@@ -243,6 +521,15 @@ impl SearchPage {
 
         let mut state = ListState::default().with_selected(self.hot_selected);
         frame.render_stateful_widget(list, area, &mut state);
+
+        // The list may have scrolled to keep the selection visible
+        let inner = area.inner(Margin::new(1, 1));
+        let visible = (self.hotwords.len() - state.offset()).min(inner.height as usize);
+        for row in 0..visible {
+            let row_area = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            self.hot_hits
+                .push(row_area, HotHit::Word(state.offset() + row));
+        }
     }
 }
 
@@ -279,7 +566,7 @@ impl Component for SearchPage {
                 Style::default().fg(theme.border_subtle)
             })
             .title(Span::styled(
-                " 🔍 搜索视频 ",
+                t(" 🔍 搜索视频 "),
                 Style::default().fg(theme.bilibili_pink),
             ));
 
@@ -290,98 +577,32 @@ impl Component for SearchPage {
         frame.render_widget(input, chunks[0]);
 
         // Results
-        if self.show_hot_list {
-            self.draw_hot_list(frame, chunks[1], theme);
-        } else if self.loading {
-            let loading = Paragraph::new("⏳ 搜索中...")
-                .style(Style::default().fg(theme.warning))
-                .alignment(Alignment::Center)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_type(BorderType::Rounded)
-                        .border_style(Style::default().fg(theme.border_unfocused))
-                        .title(Span::styled(
-                            format!(" 结果 ({}) ", self.total_results),
-                            Style::default().fg(theme.fg_secondary),
-                        )),
-                );
-            frame.render_widget(loading, chunks[1]);
-        } else if let Some(error) = &self.error_message {
-            let error_widget = Paragraph::new(format!("❌ {}", error))
-                .style(Style::default().fg(theme.error))
-                .alignment(Alignment::Center)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_type(BorderType::Rounded)
-                        .border_style(Style::default().fg(theme.border_unfocused)),
-                );
-            frame.render_widget(error_widget, chunks[1]);
-        } else if self.grid.cards.is_empty() {
-            let empty = Paragraph::new(if self.query.is_empty() {
-                "输入关键词开始搜索"
-            } else {
-                "没有找到相关视频"
-            })
-            .style(Style::default().fg(theme.fg_secondary))
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.border_unfocused)),
-            );
-            frame.render_widget(empty, chunks[1]);
-        } else {
-            // Render with header
-            let header = Paragraph::new(Line::from(vec![
-                Span::styled(" 搜索结果 ", Style::default().fg(theme.bilibili_pink)),
-                Span::styled(
-                    format!("({}/{})", self.grid.cards.len(), self.total_results),
-                    Style::default().fg(theme.fg_muted),
-                ),
-                if self.loading_more {
-                    Span::styled(" 加载中...", Style::default().fg(theme.warning))
-                } else {
-                    Span::raw("")
-                },
-            ]))
-            .block(
-                Block::default()
-                    .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
-                    .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.border_subtle)),
-            );
-
-            let header_area = Rect {
-                height: 2,
-                ..chunks[1]
-            };
-            let grid_area = Rect {
-                y: chunks[1].y + 2,
-                height: chunks[1].height.saturating_sub(2),
-                ..chunks[1]
-            };
-
-            frame.render_widget(header, header_area);
-            self.grid.render(frame, grid_area, theme);
+        let (results_area, hot_area) = self.content_layout(chunks[1]);
+        if let Some(hot_area) = hot_area {
+            self.draw_hot_list(frame, hot_area, theme);
+        }
+        if let Some(results_area) = results_area {
+            self.draw_results(frame, results_area, theme, keys);
         }
 
         // Help
         let help_text = if self.input_mode {
-            format!(
+            tf(
                 "[{}] 搜索  [{}] 取消  [{}] 导航",
-                keys.confirm, keys.back, keys.nav_next_page
+                &[&keys.confirm, &keys.back, &keys.nav_next_page],
             )
         } else {
-            format!(
-                "[{}/{}] 导航  [{}] 详情  [{}] 搜索  [{}] 切换",
-                keys.get_arrow_keys_display(),
-                keys.get_nav_keys_display(),
-                keys.confirm,
-                keys.search_focus,
-                keys.nav_next_page
+            tf(
+                "[{}/{}] 导航  [{}] 详情  [{}] 搜索  [{}] 在UP投稿中搜  [{}] 热搜  [{}] 切换",
+                &[
+                    &keys.get_arrow_keys_display(),
+                    &keys.get_nav_keys_display(),
+                    &keys.confirm,
+                    &keys.search_focus,
+                    &keys.open_space,
+                    &keys.hot_search,
+                    &keys.nav_next_page,
+                ],
             )
         };
         let help = Paragraph::new(help_text)
@@ -395,6 +616,17 @@ impl Component for SearchPage {
         key: KeyCode,
         keys: &crate::storage::Keybindings,
     ) -> Option<AppAction> {
+        if self.error_message.is_some() && !self.input_mode {
+            if let Some(action) = error_hint::handle_key(self.error_kind, key, keys) {
+                return Some(action);
+            }
+            if keys.matches_refresh(key) && !self.query.trim().is_empty() {
+                self.forget_cached(&self.query.clone());
+                self.loading = true;
+                self.page = 1;
+                return Some(AppAction::Search(self.query.clone()));
+            }
+        }
         if self.input_mode {
             match key {
                 KeyCode::Char(c) => {
@@ -473,6 +705,10 @@ impl Component for SearchPage {
             if keys.matches_confirm(key) {
                 return self.search_selected_hotword();
             }
+            if keys.matches_hot_search(key) || keys.matches_back(key) {
+                self.toggle_hot_list();
+                return Some(AppAction::None);
+            }
             if keys.matches_search_focus(key) {
                 self.input_mode = true;
                 self.show_hot_list = true;
@@ -509,7 +745,19 @@ impl Component for SearchPage {
                 self.grid.move_left();
                 return Some(AppAction::None);
             }
-            if keys.matches_confirm(key) {
+            if keys.matches_play(key) {
+                if let Some(card) = self.grid.selected_card() {
+                    if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                        return Some(AppAction::OpenActionMenu {
+                            bvid: bvid.clone(),
+                            aid,
+                            title: card.title.clone(),
+                        });
+                    }
+                }
+                return Some(AppAction::None);
+            }
+            if self.grid.select_visible(key) || keys.matches_confirm(key) {
                 if let Some(card) = self.grid.selected_card() {
                     if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
                         return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
@@ -517,6 +765,13 @@ impl Component for SearchPage {
                 }
                 return Some(AppAction::None);
             }
+            if keys.matches_open_space(key) {
+                return Some(self.search_in_uploader().unwrap_or(AppAction::None));
+            }
+            if keys.matches_hot_search(key) {
+                self.toggle_hot_list();
+                return Some(AppAction::None);
+            }
             if keys.matches_search_focus(key) {
                 self.input_mode = true;
                 self.show_hot_list = true;
@@ -538,7 +793,7 @@ impl Component for SearchPage {
         }
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
         // Don't handle mouse in input mode
         if self.input_mode {
             return None;
@@ -546,32 +801,16 @@ impl Component for SearchPage {
 
         // Handle hot list mouse interactions
         if self.show_hot_list {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Min(10),
-                    Constraint::Length(2),
-                ])
-                .split(area);
-
-            let list_area = chunks[1];
-
-            if !list_area.contains(ratatui::layout::Position::new(event.column, event.row)) {
-                return None;
-            }
+            let hit = self.hot_hits.at(event.column, event.row)?;
 
             return match event.kind {
-                MouseEventKind::Down(MouseButton::Left) => {
-                    // Convert click position to list index (account for top border)
-                    let row_offset = event.row.saturating_sub(list_area.y + 1);
-                    let idx = row_offset as usize;
-                    if idx < self.hotwords.len() {
+                MouseEventKind::Down(MouseButton::Left) => match hit {
+                    HotHit::Word(idx) if idx < self.hotwords.len() => {
                         self.select_hotword(idx);
-                        return self.search_selected_hotword();
+                        self.search_selected_hotword()
                     }
-                    None
-                }
+                    _ => None,
+                },
                 MouseEventKind::ScrollDown => {
                     if !self.hotwords.is_empty() {
                         let len = self.hotwords.len();
@@ -598,6 +837,12 @@ impl Component for SearchPage {
             return None;
         }
 
+        // Dragging the scrollbar jumps straight to a row
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return (self.grid.is_near_bottom(3) && !self.loading_more)
+                .then_some(AppAction::LoadMoreSearch);
+        }
+
         match event.kind {
             MouseEventKind::ScrollDown => {
                 if self.grid.move_down() {
@@ -613,61 +858,128 @@ impl Component for SearchPage {
                 None
             }
             MouseEventKind::Down(MouseButton::Left) => {
-                let chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([
-                        Constraint::Length(3),
-                        Constraint::Min(10),
-                        Constraint::Length(2),
-                    ])
-                    .split(area);
-
-                let header_height = 2u16;
-                let grid_area = Rect {
-                    y: chunks[1].y + header_height,
-                    height: chunks[1].height.saturating_sub(header_height),
-                    x: chunks[1].x,
-                    width: chunks[1].width,
-                };
-
-                if !grid_area.contains(ratatui::layout::Position::new(event.column, event.row)) {
-                    return None;
-                }
-
-                let relative_y = event.row - grid_area.y;
-                let click_row = (relative_y / self.grid.card_height) as usize;
-                let actual_row = self.grid.scroll_row + click_row;
-
-                let card_width = grid_area.width / self.grid.columns as u16;
-                let click_col = (event.column.saturating_sub(grid_area.x) / card_width) as usize;
-
-                let click_idx = actual_row * self.grid.columns + click_col;
-
-                if click_idx < self.grid.cards.len() {
-                    let now = Instant::now();
-                    let is_double_click = self.last_click_index == Some(click_idx)
-                        && self
-                            .last_click_time
-                            .is_some_and(|t| now.duration_since(t).as_millis() < 500);
-
-                    if is_double_click {
-                        self.last_click_time = None;
-                        self.last_click_index = None;
-                        if let Some(card) = self.grid.cards.get(click_idx) {
-                            if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
-                                return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
-                            }
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some(card) = self.grid.cards.get(click_idx) {
+                        if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                            return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
                         }
-                    } else {
-                        self.grid.selected_index = click_idx;
-                        self.grid.update_scroll(self.grid.cached_visible_rows);
-                        self.last_click_time = Some(now);
-                        self.last_click_index = Some(click_idx);
                     }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
                 }
                 None
             }
             _ => None,
         }
     }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        self.refresh_hotwords(ctx.client);
+        Box::pin(async {})
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_hotword_results();
+        if self.hotwords_stale() {
+            self.refresh_hotwords(ctx.client);
+        }
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.input_mode
+    }
+
+    fn marks_watched(&self) -> bool {
+        true
+    }
+
+    fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        self.grid.set_watched(watched, dim);
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+
+    fn session(&self) -> Option<Session> {
+        Some(Session {
+            page: SessionPage::Search,
+            selected: self.grid.selected_index,
+            search_query: self.query.clone(),
+        })
+    }
+
+    fn restore_selection(&mut self, selected: usize) {
+        self.grid.select(selected);
+    }
+}
+
+/// Card for a search result, with the matched keywords highlighted in the title
+fn make_card(item: &SearchVideoItem) -> VideoCard {
+    let (title, highlights) = item.title_with_highlights();
+    let mut card = VideoCard::new(
+        item.bvid.clone(),
+        item.mid,
+        title,
+        item.author_name().to_string(),
+        item.format_play(),
+        item.duration.clone().unwrap_or_default(),
+        item.cover_url(),
+    );
+    card.highlights = highlights;
+    if item.is_union_video == 1 {
+        card.add_badge(CardBadge::Union);
+    }
+    if item.is_charge_video == 1 {
+        card.add_badge(CardBadge::ChargeOnly);
+    }
+    card
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(query: &str) -> CachedSearch {
+        CachedSearch {
+            query: query.to_string(),
+            results: Vec::new(),
+            total: 0,
+            page: 1,
+            selected: 0,
+            scroll_row: 0,
+        }
+    }
+
+    #[test]
+    fn test_search_cache_drops_least_recent() {
+        let mut cache = SearchCache::default();
+        for i in 0..RESULT_CACHE_SIZE {
+            cache.put(entry(&i.to_string()));
+        }
+        // Re-putting "0" makes it the most recent, so "1" is evicted next
+        cache.put(entry("0"));
+        cache.put(entry("new"));
+        assert!(cache.remove("1").is_none());
+        assert!(cache.remove("0").is_some());
+        assert!(cache.remove("new").is_some());
+        assert!(cache.remove("0").is_none());
+    }
 }
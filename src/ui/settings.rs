@@ -1,15 +1,23 @@
-//! Settings page with theme selection, keybinding display, and account management
+//! Settings page with theme selection, keybinding display, language and account management
 
 use super::{Component, Theme, ThemeVariant};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
-use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+use crate::i18n::{self, t, tf, Language};
+use crate::storage::{Credentials, Keybindings};
+use ratatui::{
+    crossterm::event::{KeyCode, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::*,
+};
 
 /// Settings sections
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SettingsSection {
     Theme,
     Keybindings,
+    Language,
+    Content,
+    Player,
     Account,
 }
 
@@ -18,15 +26,53 @@ impl SettingsSection {
         &[
             SettingsSection::Theme,
             SettingsSection::Keybindings,
+            SettingsSection::Language,
+            SettingsSection::Content,
+            SettingsSection::Player,
             SettingsSection::Account,
         ]
     }
 
     pub fn label(&self) -> &'static str {
         match self {
-            SettingsSection::Theme => "🎨 主题",
-            SettingsSection::Keybindings => "⌨️ 快捷键",
-            SettingsSection::Account => "👤 账户",
+            SettingsSection::Theme => t("🎨 主题"),
+            SettingsSection::Keybindings => t("⌨️ 快捷键"),
+            SettingsSection::Language => t("🌐 语言"),
+            SettingsSection::Content => t("🗂️ 内容"),
+            SettingsSection::Player => t("▶️ 播放器"),
+            SettingsSection::Account => t("👤 账户"),
+        }
+    }
+}
+
+/// Entries of the account section, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AccountItem {
+    CopyCookie,
+    ExportCookies,
+    Logout,
+}
+
+impl AccountItem {
+    const ALL: [AccountItem; 3] = [
+        AccountItem::CopyCookie,
+        AccountItem::ExportCookies,
+        AccountItem::Logout,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            AccountItem::CopyCookie => t("📋 复制 Cookie"),
+            AccountItem::ExportCookies => t("💾 导出 cookies.txt"),
+            AccountItem::Logout => t("🚪 退出登录"),
+        }
+    }
+
+    fn action(self) -> AppAction {
+        match self {
+            AccountItem::CopyCookie => AppAction::CopyCookie,
+            AccountItem::ExportCookies => AppAction::ExportCookies,
+            AccountItem::Logout => AppAction::Logout,
         }
     }
 }
@@ -35,10 +81,18 @@ pub struct SettingsPage {
     pub current_section: SettingsSection,
     pub selected_theme_index: usize,
     pub selected_keybind_index: usize,
+    pub selected_language_index: usize,
     pub keybindings: Keybindings,
     pub current_theme_variant: ThemeVariant,
     section_index: usize,
     pub editing_keybind: bool,
+    /// 收藏夹和历史中列出已失效的视频
+    pub show_invalid_videos: bool,
+    selected_account_index: usize,
+    /// 登录用户 UID
+    uid: Option<String>,
+    /// SESSDATA 过期时间（Unix 秒）
+    sessdata_expires: Option<i64>,
 }
 
 impl SettingsPage {
@@ -52,44 +106,109 @@ impl SettingsPage {
             current_section: SettingsSection::Theme,
             selected_theme_index: theme_index,
             selected_keybind_index: 0,
+            selected_language_index: Language::all()
+                .iter()
+                .position(|l| *l == i18n::language())
+                .unwrap_or(0),
             keybindings,
             current_theme_variant: theme_variant,
             section_index: 0,
             editing_keybind: false,
+            show_invalid_videos: false,
+            selected_account_index: 0,
+            uid: None,
+            sessdata_expires: None,
         }
     }
 
+    pub fn with_show_invalid_videos(mut self, show: bool) -> Self {
+        self.show_invalid_videos = show;
+        self
+    }
+
+    /// Show the login UID and when the cookie expires in the account section
+    pub fn with_credentials(mut self, credentials: Option<&Credentials>) -> Self {
+        self.uid = credentials.map(|c| c.dede_user_id.clone());
+        self.sessdata_expires = credentials.and_then(Credentials::sessdata_expires);
+        self
+    }
+
     fn keybind_labels(&self) -> Vec<(&'static str, &str)> {
         vec![
             // Global actions
-            ("退出", &self.keybindings.quit),
-            ("确认", &self.keybindings.confirm),
-            ("返回", &self.keybindings.back),
-            ("刷新", &self.keybindings.refresh),
+            (t("退出"), &self.keybindings.quit),
+            (t("确认"), &self.keybindings.confirm),
+            (t("返回"), &self.keybindings.back),
+            (t("刷新"), &self.keybindings.refresh),
             // Navigation
-            ("向上", &self.keybindings.nav_up),
-            ("向下", &self.keybindings.nav_down),
-            ("向左", &self.keybindings.nav_left),
-            ("向右", &self.keybindings.nav_right),
-            ("下一页面", &self.keybindings.nav_next_page),
-            ("上一页面", &self.keybindings.nav_prev_page),
+            (t("向上"), &self.keybindings.nav_up),
+            (t("向下"), &self.keybindings.nav_down),
+            (t("向左"), &self.keybindings.nav_left),
+            (t("向右"), &self.keybindings.nav_right),
+            (t("下一页面"), &self.keybindings.nav_next_page),
+            (t("上一页面"), &self.keybindings.nav_prev_page),
             // Section/Tab
-            ("上一分区", &self.keybindings.section_prev),
-            ("下一分区", &self.keybindings.section_next),
-            ("标签1", &self.keybindings.tab_1),
-            ("标签2", &self.keybindings.tab_2),
-            ("标签3", &self.keybindings.tab_3),
+            (t("上一分区"), &self.keybindings.section_prev),
+            (t("下一分区"), &self.keybindings.section_next),
+            (t("标签1"), &self.keybindings.tab_1),
+            (t("标签2"), &self.keybindings.tab_2),
+            (t("标签3"), &self.keybindings.tab_3),
             // Actions
-            ("切换主题", &self.keybindings.next_theme),
-            ("播放", &self.keybindings.play),
-            ("设置", &self.keybindings.open_settings),
-            ("搜索", &self.keybindings.search_focus),
+            (t("切换主题"), &self.keybindings.next_theme),
+            (t("播放"), &self.keybindings.play),
+            (t("设置"), &self.keybindings.open_settings),
+            (t("搜索"), &self.keybindings.search_focus),
+            (t("打开链接"), &self.keybindings.open_link),
+            (t("网络统计"), &self.keybindings.toggle_stats),
+            (t("快捷键速查"), &self.keybindings.help),
+            (t("热搜榜"), &self.keybindings.hot_search),
+            (t("多选模式"), &self.keybindings.multi_select),
+            (t("新版本发布页"), &self.keybindings.open_release),
+            (t("重新登录"), &self.keybindings.relogin),
+            (t("导出"), &self.keybindings.export),
             // Comments
-            ("评论", &self.keybindings.comment),
-            ("展开回复", &self.keybindings.toggle_replies),
+            (t("评论"), &self.keybindings.comment),
+            (t("展开回复"), &self.keybindings.toggle_replies),
+            (t("UP空间"), &self.keybindings.open_space),
+            (t("跳转链接"), &self.keybindings.follow_link),
+            (t("从时间点播放"), &self.keybindings.play_at),
+            (t("跳转楼层"), &self.keybindings.goto_floor),
             // Dynamic page
-            ("上一UP", &self.keybindings.up_prev),
-            ("下一UP", &self.keybindings.up_next),
+            (t("上一UP"), &self.keybindings.up_prev),
+            (t("下一UP"), &self.keybindings.up_next),
+            (t("切换UP列表"), &self.keybindings.up_source),
+            // Live detail page
+            (t("弹幕换行"), &self.keybindings.danmaku_wrap),
+            // 私人 FM
+            (t("开关私人FM"), &self.keybindings.fm_toggle),
+            (t("FM下一首"), &self.keybindings.fm_skip),
+            // Clipboard
+            (t("复制链接"), &self.keybindings.copy_link),
+            // 开播 / 更新提醒
+            (t("开关主播开播提醒"), &self.keybindings.live_alert),
+            (t("打开最新提醒"), &self.keybindings.open_alert),
+            // Video detail page
+            (t("转发到动态"), &self.keybindings.share_dynamic),
+            (t("只看UP评论"), &self.keybindings.up_only_comments),
+            (t("浏览器打开评论"), &self.keybindings.open_comment_web),
+            (t("点赞视频"), &self.keybindings.like_video),
+            (t("投币"), &self.keybindings.coin_video),
+            (t("收藏视频"), &self.keybindings.fav_video),
+            (t("一键三连"), &self.keybindings.triple),
+            (t("点踩评论"), &self.keybindings.dislike_comment),
+            (t("关注/取关UP主"), &self.keybindings.follow_up),
+            (t("选择画质"), &self.keybindings.quality),
+            // Live page
+            (t("分区人气榜"), &self.keybindings.live_rank),
+            // Favorites page
+            (t("移动到收藏夹"), &self.keybindings.fav_move),
+            (t("复制到收藏夹"), &self.keybindings.fav_copy),
+            (t("新建收藏夹"), &self.keybindings.fav_new),
+            (t("重命名收藏夹"), &self.keybindings.fav_rename),
+            (t("删除收藏夹"), &self.keybindings.fav_delete),
+            // List pages
+            (t("多选勾选"), &self.keybindings.mark),
+            (t("批量删除"), &self.keybindings.batch_delete),
         ]
     }
 }
@@ -116,7 +235,7 @@ impl Component for SettingsPage {
         let header_line = Line::from(vec![
             Span::styled("⚙️ ", Style::default().fg(theme.bilibili_pink)),
             Span::styled(
-                "设置",
+                t("设置"),
                 Style::default()
                     .fg(theme.fg_primary)
                     .add_modifier(Modifier::BOLD),
@@ -150,6 +269,11 @@ impl Component for SettingsPage {
             SettingsSection::Keybindings => {
                 self.draw_keybindings_section(frame, content_chunks[1], theme)
             }
+            SettingsSection::Language => {
+                self.draw_language_section(frame, content_chunks[1], theme)
+            }
+            SettingsSection::Content => self.draw_content_section(frame, content_chunks[1], theme),
+            SettingsSection::Player => self.draw_player_section(frame, content_chunks[1], theme),
             SettingsSection::Account => self.draw_account_section(frame, content_chunks[1], theme),
         }
 
@@ -163,7 +287,7 @@ impl Component for SettingsPage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("切换分类", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("切换分类"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 format!("{}{}", keys.nav_up, keys.nav_down),
@@ -172,7 +296,7 @@ impl Component for SettingsPage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("选择", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("选择"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.confirm,
@@ -181,14 +305,14 @@ impl Component for SettingsPage {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("确认", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("确认"), Style::default().fg(theme.fg_secondary)),
             Span::styled("  [", Style::default().fg(theme.fg_secondary)),
             Span::styled(
                 &keys.nav_next_page,
                 Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
             ),
             Span::styled("] ", Style::default().fg(theme.fg_secondary)),
-            Span::styled("切页面", Style::default().fg(theme.fg_secondary)),
+            Span::styled(t("切页面"), Style::default().fg(theme.fg_secondary)),
         ]);
         let help = Paragraph::new(help_line).alignment(Alignment::Center);
         frame.render_widget(help, main_chunks[2]);
@@ -221,21 +345,11 @@ impl Component for SettingsPage {
             return Some(AppAction::NavPrev);
         }
         if keys.matches_section_prev(key) {
-            // Cycle through sections backwards
-            let sections = SettingsSection::all();
-            self.section_index = if self.section_index == 0 {
-                sections.len() - 1
-            } else {
-                self.section_index - 1
-            };
-            self.current_section = sections[self.section_index];
+            self.cycle_section(false);
             return Some(AppAction::None);
         }
         if keys.matches_section_next(key) {
-            // Cycle through sections forwards
-            let sections = SettingsSection::all();
-            self.section_index = (self.section_index + 1) % sections.len();
-            self.current_section = sections[self.section_index];
+            self.cycle_section(true);
             return Some(AppAction::None);
         }
         if keys.matches_up(key) {
@@ -250,7 +364,13 @@ impl Component for SettingsPage {
                         self.selected_keybind_index -= 1;
                     }
                 }
-                SettingsSection::Account => {}
+                SettingsSection::Language => {
+                    self.selected_language_index = self.selected_language_index.saturating_sub(1);
+                }
+                SettingsSection::Account => {
+                    self.selected_account_index = self.selected_account_index.saturating_sub(1);
+                }
+                SettingsSection::Content | SettingsSection::Player => {}
             }
             return Some(AppAction::None);
         }
@@ -268,7 +388,17 @@ impl Component for SettingsPage {
                         self.selected_keybind_index += 1;
                     }
                 }
-                SettingsSection::Account => {}
+                SettingsSection::Language => {
+                    let max = Language::all().len().saturating_sub(1);
+                    if self.selected_language_index < max {
+                        self.selected_language_index += 1;
+                    }
+                }
+                SettingsSection::Account => {
+                    let max = AccountItem::ALL.len() - 1;
+                    self.selected_account_index = (self.selected_account_index + 1).min(max);
+                }
+                SettingsSection::Content | SettingsSection::Player => {}
             }
             return Some(AppAction::None);
         }
@@ -282,9 +412,20 @@ impl Component for SettingsPage {
                         return Some(AppAction::SetTheme(selected));
                     }
                 }
+                SettingsSection::Language => {
+                    if let Some(lang) = Language::all().get(self.selected_language_index) {
+                        return Some(AppAction::SetLanguage(*lang));
+                    }
+                }
+                SettingsSection::Content => {
+                    self.show_invalid_videos = !self.show_invalid_videos;
+                    return Some(AppAction::SetShowInvalidVideos(self.show_invalid_videos));
+                }
+                SettingsSection::Player => {}
                 SettingsSection::Account => {
-                    // Logout
-                    return Some(AppAction::Logout);
+                    if let Some(item) = AccountItem::ALL.get(self.selected_account_index) {
+                        return Some(item.action());
+                    }
                 }
                 SettingsSection::Keybindings => {
                     // Enter keybind editing mode
@@ -298,16 +439,40 @@ impl Component for SettingsPage {
         }
         Some(AppAction::None)
     }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        match event.kind {
+            MouseEventKind::ScrollLeft => self.cycle_section(false),
+            MouseEventKind::ScrollRight => self.cycle_section(true),
+            _ => {}
+        }
+        None
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.editing_keybind
+    }
 }
 
 impl SettingsPage {
+    /// Move to the next / previous section, wrapping around
+    fn cycle_section(&mut self, forward: bool) {
+        let sections = SettingsSection::all();
+        self.section_index = if forward {
+            (self.section_index + 1) % sections.len()
+        } else {
+            (self.section_index + sections.len() - 1) % sections.len()
+        };
+        self.current_section = sections[self.section_index];
+    }
+
     fn draw_section_list(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::RIGHT)
             .border_type(BorderType::Plain)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 分类 ",
+                t(" 分类 "),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
@@ -344,7 +509,7 @@ impl SettingsPage {
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 🎨 选择主题 ",
+                t(" 🎨 选择主题 "),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
@@ -390,7 +555,7 @@ impl SettingsPage {
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " ⌨️ 快捷键 ",
+                t(" ⌨️ 快捷键 "),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
@@ -432,13 +597,13 @@ impl SettingsPage {
         frame.render_widget(list, inner);
     }
 
-    fn draw_account_section(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+    fn draw_language_section(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 👤 账户 ",
+                t(" 🌐 界面语言 "),
                 Style::default()
                     .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
@@ -447,35 +612,202 @@ impl SettingsPage {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        // Layout for account info + logout button
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(1)
-            .constraints([
-                Constraint::Length(3), // Info
-                Constraint::Length(3), // Logout button
-                Constraint::Min(0),    // Spacer
-            ])
-            .split(inner);
+        let current = i18n::language();
+        let items: Vec<ListItem> = Language::all()
+            .iter()
+            .enumerate()
+            .map(|(idx, lang)| {
+                let is_selected = idx == self.selected_language_index;
+                let is_current = *lang == current;
+
+                let mut style = if is_selected {
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.selection_bg)
+                } else {
+                    Style::default().fg(theme.fg_secondary)
+                };
+                if is_current && !is_selected {
+                    style = style.fg(theme.success);
+                }
+
+                let prefix = if is_selected { "▶ " } else { "  " };
+                let suffix = if is_current { " ✓" } else { "" };
+                ListItem::new(format!("{}{}{}", prefix, lang.label(), suffix)).style(style)
+            })
+            .collect();
+
+        let list = List::new(items);
+        frame.render_widget(list, inner);
+    }
+
+    fn draw_content_section(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle))
+            .title(Span::styled(
+                t(" 🗂️ 内容 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ));
 
-        let info = Paragraph::new("已登录")
-            .style(Style::default().fg(theme.success))
-            .alignment(Alignment::Left);
-        frame.render_widget(info, chunks[0]);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
 
-        let logout_btn = Paragraph::new("▶ 退出登录")
-            .style(
+        let (state, state_color) = if self.show_invalid_videos {
+            (t("[开]"), theme.success)
+        } else {
+            (t("[关]"), theme.fg_muted)
+        };
+        let style = Style::default()
+            .fg(theme.fg_primary)
+            .add_modifier(Modifier::BOLD)
+            .bg(theme.selection_bg);
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("▶ ", style),
+                Span::styled(format!("{:<12}", t("显示已失效视频")), style),
+                Span::styled(
+                    state,
+                    Style::default()
+                        .fg(state_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(Span::styled(
+                t("  收藏夹和历史中被删除或下架的视频：开启时置灰显示，关闭时过滤"),
+                Style::default().fg(theme.fg_muted),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Where mpv and yt-dlp were found, read-only
+    fn draw_player_section(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle))
+            .title(Span::styled(
+                t(" ▶️ 播放器 "),
                 Style::default()
-                    .fg(theme.error)
+                    .fg(theme.bilibili_pink)
                     .add_modifier(Modifier::BOLD),
-            )
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.error)),
-            )
-            .alignment(Alignment::Center);
-        frame.render_widget(logout_btn, chunks[1]);
+            ));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let tools = crate::player::tools();
+        let mut lines = Vec::new();
+        for (name, path) in [("mpv", &tools.mpv), ("yt-dlp", &tools.ytdlp)] {
+            let (state, color) = match path {
+                Some(path) => (path.display().to_string(), theme.success),
+                None => (t("未找到").to_string(), theme.error),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<8}", name),
+                    Style::default()
+                        .fg(theme.fg_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(state, Style::default().fg(color)),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            t("  在 PATH 和常见安装位置（Windows 的 Program Files、scoop、winget、chocolatey）中查找"),
+            Style::default().fg(theme.fg_muted),
+        )));
+        if tools.mpv.is_none() || tools.ytdlp.is_none() {
+            lines.push(Line::from(Span::styled(
+                t("  播放需要 mpv 和 yt-dlp，安装后重启生效"),
+                Style::default().fg(theme.warning),
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+    }
+
+    fn draw_account_section(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle))
+            .title(Span::styled(
+                t(" 👤 账户 "),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut lines = vec![Line::from("")];
+        let login = match &self.uid {
+            Some(uid) => tf("  已登录 · UID {}", &[uid]),
+            None => t("  未登录").to_string(),
+        };
+        lines.push(Line::from(Span::styled(
+            login,
+            Style::default().fg(theme.success),
+        )));
+
+        // 剩余时间不足一周时提醒重新登录
+        let now = chrono::Local::now().timestamp();
+        let (expiry, color) = match self.sessdata_expires {
+            Some(ts) if ts <= now => (
+                tf(
+                    "  Cookie 已于 {} 过期",
+                    &[&crate::export::format_timestamp(ts)],
+                ),
+                theme.error,
+            ),
+            Some(ts) => {
+                let days = (ts - now) / 86400;
+                let color = if days < 7 {
+                    theme.warning
+                } else {
+                    theme.fg_secondary
+                };
+                let when = crate::export::format_timestamp(ts);
+                (
+                    tf("  Cookie 过期时间: {}（剩余 {} 天）", &[&when, &days]),
+                    color,
+                )
+            }
+            None => (t("  Cookie 过期时间未知").to_string(), theme.fg_muted),
+        };
+        lines.push(Line::from(Span::styled(expiry, Style::default().fg(color))));
+        lines.push(Line::from(""));
+
+        for (idx, item) in AccountItem::ALL.iter().enumerate() {
+            let selected = idx == self.selected_account_index;
+            let color = if *item == AccountItem::Logout {
+                theme.error
+            } else {
+                theme.fg_primary
+            };
+            let mut style = Style::default().fg(color);
+            if selected {
+                style = style.add_modifier(Modifier::BOLD | Modifier::REVERSED);
+            }
+            let marker = if selected { "  ▶ " } else { "    " };
+            lines.push(Line::from(vec![
+                Span::styled(marker, Style::default().fg(theme.bilibili_pink)),
+                Span::styled(item.label(), style),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            t("  Cookie 可以直接登录你的账号，只在自己信任的工具里使用"),
+            Style::default().fg(theme.warning),
+        )));
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
     }
 }
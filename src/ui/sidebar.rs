@@ -1,6 +1,8 @@
 //! Left sidebar navigation component
 
 use super::Theme;
+use crate::i18n::{t, tf};
+use crate::storage::Keybindings;
 use ratatui::{prelude::*, widgets::*};
 
 /// Navigation menu items
@@ -9,20 +11,28 @@ pub enum NavItem {
     Home,
     Search,
     Dynamic,
+    Subscriptions,
     History,
+    Favorites,
+    WatchLater,
     Live,
+    WatchStats,
     Settings,
 }
 
 impl NavItem {
     pub fn label(&self) -> &'static str {
         match self {
-            NavItem::Home => "🏠 首页",
-            NavItem::Search => "🔍 搜索",
-            NavItem::Dynamic => "📺 动态",
-            NavItem::History => "📜 历史",
-            NavItem::Live => "📡 直播",
-            NavItem::Settings => "⚙️ 设置",
+            NavItem::Home => t("🏠 首页"),
+            NavItem::Search => t("🔍 搜索"),
+            NavItem::Dynamic => t("📺 动态"),
+            NavItem::Subscriptions => t("📬 订阅"),
+            NavItem::History => t("📜 历史"),
+            NavItem::Favorites => t("⭐ 收藏"),
+            NavItem::WatchLater => t("🕒 稍后再看"),
+            NavItem::Live => t("📡 直播"),
+            NavItem::WatchStats => t("📊 统计"),
+            NavItem::Settings => t("⚙️ 设置"),
         }
     }
 
@@ -31,8 +41,12 @@ impl NavItem {
             NavItem::Home,
             NavItem::Search,
             NavItem::Dynamic,
+            NavItem::Subscriptions,
             NavItem::History,
+            NavItem::Favorites,
+            NavItem::WatchLater,
             NavItem::Live,
+            NavItem::WatchStats,
             NavItem::Settings,
         ]
     }
@@ -40,16 +54,22 @@ impl NavItem {
 
 pub struct Sidebar {
     pub selected: NavItem,
+    /// Newer release found by the update check
+    pub update_available: Option<String>,
+    /// credentials.json can be read by other users
+    pub credentials_too_open: bool,
 }
 
 impl Sidebar {
     pub fn new() -> Self {
         Self {
             selected: NavItem::Home,
+            update_available: None,
+            credentials_too_open: false,
         }
     }
 
-    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
         // Main block with subtle right border
         let block = Block::default()
             .borders(Borders::RIGHT)
@@ -59,14 +79,41 @@ impl Sidebar {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
+        // Security warning first, then the update notice; two lines each
+        let mut footer = Vec::new();
+        if self.credentials_too_open {
+            footer.push(Line::from(Span::styled(
+                t(" ⚠ 凭据文件权限过宽"),
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            footer.push(Line::from(Span::styled(
+                t(" 重新登录或 chmod 600"),
+                Style::default().fg(theme.fg_muted),
+            )));
+        }
+        if let Some(version) = &self.update_available {
+            footer.push(Line::from(Span::styled(
+                format!(" 🆕 {}", version),
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            footer.push(Line::from(Span::styled(
+                tf(" [{}] 查看更新", &[&keys.open_release]),
+                Style::default().fg(theme.fg_muted),
+            )));
+        }
+
         // Split into header and nav items
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(4), // Header with branding
-                Constraint::Length(1), // Separator
-                Constraint::Min(5),    // Nav items
-                Constraint::Length(1), // Footer separator
+                Constraint::Length(4),                   // Header with branding
+                Constraint::Length(1),                   // Separator
+                Constraint::Min(5),                      // Nav items
+                Constraint::Length(footer.len() as u16), // Footer: permission / update notices
             ])
             .split(inner);
 
@@ -132,6 +179,8 @@ impl Sidebar {
         let list = List::new(items).highlight_style(Style::default().add_modifier(Modifier::BOLD));
 
         frame.render_widget(list, chunks[2]);
+
+        frame.render_widget(Paragraph::new(footer), chunks[3]);
     }
 
     pub fn next(&mut self) {
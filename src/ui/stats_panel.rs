@@ -0,0 +1,85 @@
+//! Network statistics overlay
+
+use super::{clear_popup, Theme};
+use crate::api::stats::HttpStatsSnapshot;
+use crate::i18n::t;
+use ratatui::{prelude::*, widgets::*};
+
+/// Draw the stats panel in the top-right corner of `area`
+pub fn render_stats_panel(frame: &mut Frame, area: Rect, theme: &Theme, stats: &HttpStatsSnapshot) {
+    let width = 46.min(area.width);
+    let error_rows = stats.recent_errors.len().max(1) as u16;
+    let height = (error_rows + 8).min(area.height);
+    let panel = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        y: area.y,
+        width,
+        height,
+    };
+
+    clear_popup(frame, panel);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.info))
+        .title(Span::styled(
+            t(" 📊 网络统计 "),
+            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+        ));
+
+    let label = Style::default().fg(theme.fg_secondary);
+    let value = Style::default().fg(theme.fg_primary);
+
+    let failed_style = if stats.failed > 0 {
+        Style::default().fg(theme.error)
+    } else {
+        value
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(t("请求总数  "), label),
+            Span::styled(stats.total.to_string(), value),
+        ]),
+        Line::from(vec![
+            Span::styled(t("失败      "), label),
+            Span::styled(stats.failed.to_string(), failed_style),
+        ]),
+        Line::from(vec![
+            Span::styled(t("平均耗时  "), label),
+            Span::styled(format!("{} ms", stats.avg_latency.as_millis()), value),
+        ]),
+        Line::from(vec![
+            Span::styled(t("进行中    "), label),
+            Span::styled(stats.active.to_string(), value),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(t("最近限流/风控"), label)),
+    ];
+
+    if stats.recent_errors.is_empty() {
+        lines.push(Line::from(Span::styled(
+            t("  无"),
+            Style::default().fg(theme.fg_muted),
+        )));
+    } else {
+        for err in &stats.recent_errors {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", err.time.format("%H:%M:%S")),
+                    Style::default().fg(theme.fg_muted),
+                ),
+                Span::styled(format!("{} ", err.code), Style::default().fg(theme.error)),
+                Span::styled(err.path.clone(), value),
+                Span::styled(
+                    format!(" {}", err.message),
+                    Style::default().fg(theme.fg_muted),
+                ),
+            ]));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, panel);
+}
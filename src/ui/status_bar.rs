@@ -0,0 +1,91 @@
+//! One-line status bar at the bottom of the screen, shared by all pages
+
+use super::Theme;
+use crate::api::stats::HttpStatsSnapshot;
+use crate::i18n::{t, tf};
+use ratatui::{prelude::*, widgets::*};
+
+/// Login and message state shown in the status bar, refreshed in the background
+#[derive(Default)]
+pub struct StatusBar {
+    /// 登录用户名，未获取到时为 None
+    pub uname: Option<String>,
+    /// 消息中心未读数
+    pub unread: Option<i64>,
+    /// 免打扰时段内：未读数不高亮，显示 🌙
+    pub quiet: bool,
+}
+
+impl StatusBar {
+    /// Forget the user, e.g. after logging out
+    pub fn clear(&mut self) {
+        self.uname = None;
+        self.unread = None;
+    }
+
+    /// Draw the bar; `tasks` is the number of requests and players running in the background
+    pub fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        logged_in: bool,
+        stats: &HttpStatsSnapshot,
+        tasks: usize,
+    ) {
+        let muted = Style::default().fg(theme.fg_muted);
+        let separator = Span::styled(" │ ", Style::default().fg(theme.border_subtle));
+
+        let user = match (&self.uname, logged_in) {
+            (Some(uname), true) => Span::styled(
+                format!("👤 {}", uname),
+                Style::default().fg(theme.fg_secondary),
+            ),
+            (None, true) => Span::styled(t("👤 已登录"), Style::default().fg(theme.fg_secondary)),
+            (_, false) => Span::styled(t("👤 未登录"), muted),
+        };
+        let network = match stats.last_ok {
+            Some(true) => Span::styled(t("● 网络正常"), Style::default().fg(theme.success)),
+            Some(false) => Span::styled(t("● 网络异常"), Style::default().fg(theme.error)),
+            None => Span::styled(t("○ 暂无请求"), muted),
+        };
+
+        let mut spans = vec![Span::raw(" "), user, separator.clone(), network];
+        if let Some(unread) = self.unread.filter(|_| logged_in) {
+            let style = if unread > 0 && !self.quiet {
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                muted
+            };
+            spans.push(separator.clone());
+            spans.push(Span::styled(tf("✉ 未读 {}", &[&unread]), style));
+        }
+        if self.quiet {
+            spans.push(separator.clone());
+            spans.push(Span::styled(t("🌙 免打扰"), muted));
+        }
+        if tasks > 0 {
+            spans.push(separator);
+            spans.push(Span::styled(
+                tf("⟳ 后台任务 {}", &[&tasks]),
+                Style::default().fg(theme.fg_accent),
+            ));
+        }
+
+        let clock = chrono::Local::now().format("%H:%M ").to_string();
+        let clock_width = clock.len() as u16;
+        let [left, right] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(clock_width)]).areas(area);
+
+        let style = Style::default().bg(theme.bg_secondary);
+        frame.render_widget(Paragraph::new(Line::from(spans)).style(style), left);
+        frame.render_widget(
+            Paragraph::new(Span::styled(clock, muted))
+                .alignment(Alignment::Right)
+                .style(style),
+            right,
+        );
+    }
+}
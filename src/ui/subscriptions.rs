@@ -0,0 +1,614 @@
+//! Subscription page: recent uploads of followed UPs grouped by UP, with unread marks
+//! like an RSS reader
+
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{Component, PageContext, Theme};
+use crate::api::client::ApiClient;
+use crate::api::space::SpaceVideo;
+use crate::app::AppAction;
+use crate::export::{self, ExportEvent, FollowedUp};
+use crate::i18n::{t, tf};
+use crate::storage::subscriptions::{self, SubscriptionState};
+use crate::storage::{Keybindings, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
+use ratatui::{
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::*,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// Followed UPs whose uploads are fetched, most recently followed first
+const MAX_UPS: i32 = 50;
+/// Latest uploads fetched per UP
+const VIDEOS_PER_UP: i32 = 6;
+const UP_LIST_WIDTH: u16 = 26;
+/// Page size when fetching every followed UP for the OPML export
+const EXPORT_PAGE_SIZE: i32 = 50;
+
+/// Latest uploads of one UP
+struct UpFeed {
+    mid: i64,
+    name: String,
+    videos: Vec<SpaceVideo>,
+}
+
+impl UpFeed {
+    fn newest(&self) -> i64 {
+        self.videos
+            .iter()
+            .filter_map(|v| v.created)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub struct SubscriptionsPage {
+    /// Newest upload first
+    ups: Vec<UpFeed>,
+    /// Shown UP, None for all of them in one timeline
+    current: Option<i64>,
+    pub grid: VideoCardGrid,
+    read: SubscriptionState,
+    watched: Arc<HashSet<String>>,
+    loading: bool,
+    error_message: Option<String>,
+    /// (fetched, total) while uploads are being fetched in the background
+    progress: Option<(usize, usize)>,
+    feed_rx: Option<mpsc::UnboundedReceiver<UpFeed>>,
+    /// UP mid of each card, for copying its RSS link
+    card_mids: Vec<i64>,
+    export_rx: Option<mpsc::UnboundedReceiver<ExportEvent>>,
+    /// Export progress or result line
+    export_status: Option<String>,
+
+    last_click_time: Option<Instant>,
+    last_click_index: Option<usize>,
+}
+
+impl SubscriptionsPage {
+    pub fn new() -> Self {
+        Self {
+            ups: Vec::new(),
+            current: None,
+            grid: VideoCardGrid::new().with_number_hints(),
+            read: subscriptions::load_subscription_state().unwrap_or_default(),
+            watched: Arc::default(),
+            loading: true,
+            error_message: None,
+            progress: None,
+            feed_rx: None,
+            card_mids: Vec::new(),
+            export_rx: None,
+            export_status: None,
+            last_click_time: None,
+            last_click_index: None,
+        }
+    }
+
+    /// Load the followed UPs, then fetch their uploads one by one in the background
+    pub async fn load(&mut self, api_client: &Arc<ApiClient>, mid: Option<i64>) {
+        let Some(mid) = mid else {
+            self.loading = false;
+            self.error_message = Some(t("请先登录").to_string());
+            return;
+        };
+
+        self.loading = true;
+        self.error_message = None;
+        let followings = match api_client.get_followings(mid, MAX_UPS, 1).await {
+            Ok(data) => data.list.unwrap_or_default(),
+            Err(e) => {
+                self.error_message = Some(tf("加载关注列表失败: {}", &[&e]));
+                self.loading = false;
+                return;
+            }
+        };
+        let ups: Vec<(i64, String)> = followings
+            .into_iter()
+            .filter_map(|user| Some((user.mid?, user.uname.unwrap_or_default())))
+            .collect();
+
+        self.ups.clear();
+        self.grid.clear();
+        self.progress = Some((0, ups.len()));
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.feed_rx = Some(rx);
+
+        let client = api_client.clone();
+        tokio::spawn(async move {
+            // Sequential, the global rate limiter spaces the requests
+            for (mid, name) in ups {
+                let videos = client
+                    .get_user_videos(mid, 1, VIDEOS_PER_UP, "")
+                    .await
+                    .map(|data| data.list.vlist)
+                    .unwrap_or_default();
+                // The page was closed
+                if tx.send(UpFeed { mid, name, videos }).is_err() {
+                    return;
+                }
+            }
+        });
+        self.loading = false;
+    }
+
+    /// Fetch every followed UP in the background and write an OPML file of RSSHub feeds
+    pub fn start_export(
+        &mut self,
+        api_client: &Arc<ApiClient>,
+        mid: Option<i64>,
+        rsshub_base: &str,
+    ) {
+        if self.export_rx.is_some() {
+            return;
+        }
+        let Some(mid) = mid else {
+            return;
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.export_rx = Some(rx);
+        self.export_status = Some(t("正在导出关注列表...").to_string());
+
+        let client = api_client.clone();
+        let base = rsshub_base.to_string();
+        tokio::spawn(async move {
+            let mut ups = Vec::new();
+            let mut page = 1;
+            loop {
+                let data = match client.get_followings(mid, EXPORT_PAGE_SIZE, page).await {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let _ = tx.send(ExportEvent::Finished(Err(e.to_string())));
+                        return;
+                    }
+                };
+                let list = data.list.unwrap_or_default();
+                let empty = list.is_empty();
+                ups.extend(list.into_iter().filter_map(|user| {
+                    Some(FollowedUp {
+                        mid: user.mid?,
+                        name: user.uname.unwrap_or_default(),
+                    })
+                }));
+                let _ = tx.send(ExportEvent::Progress(ups.len()));
+
+                let total = data.total.unwrap_or(0).max(0) as usize;
+                if empty || ups.len() >= total {
+                    break;
+                }
+                page += 1;
+            }
+
+            let result = tokio::task::spawn_blocking(move || export::write_opml(&base, &ups))
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|r| r.map(|path| vec![path]).map_err(|e| e.to_string()));
+            let _ = tx.send(ExportEvent::Finished(result));
+        });
+    }
+
+    /// Drain export progress from the background task
+    pub fn poll_export(&mut self) {
+        let Some(rx) = &mut self.export_rx else {
+            return;
+        };
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                ExportEvent::Progress(count) => {
+                    self.export_status = Some(tf("正在导出... 已获取 {} 位 UP", &[&count]));
+                }
+                ExportEvent::Finished(result) => {
+                    self.export_status = Some(match result {
+                        Ok(paths) => {
+                            let path = paths
+                                .first()
+                                .map(|p| p.display().to_string())
+                                .unwrap_or_default();
+                            tf("✓ 已导出 OPML 到 {}", &[&path])
+                        }
+                        Err(e) => tf("导出失败: {}", &[&e]),
+                    });
+                    self.export_rx = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Take the uploads fetched so far (non-blocking)
+    pub fn poll_feeds(&mut self) {
+        let Some(rx) = &mut self.feed_rx else {
+            return;
+        };
+        let mut changed = false;
+        while let Ok(feed) = rx.try_recv() {
+            if let Some((fetched, _)) = &mut self.progress {
+                *fetched += 1;
+            }
+            if !feed.videos.is_empty() {
+                self.ups.push(feed);
+                changed = true;
+            }
+        }
+        if self
+            .progress
+            .is_some_and(|(fetched, total)| fetched >= total)
+        {
+            self.progress = None;
+            self.feed_rx = None;
+        }
+        if changed {
+            self.ups.sort_by_key(|up| std::cmp::Reverse(up.newest()));
+            self.rebuild_grid();
+        }
+    }
+
+    fn is_unread(&self, mid: i64, video: &SpaceVideo, now: i64) -> bool {
+        !self.watched.contains(&video.bvid)
+            && video
+                .created
+                .is_some_and(|created| self.read.is_unread(mid, created, now))
+    }
+
+    fn unread_count(&self, up: &UpFeed, now: i64) -> usize {
+        up.videos
+            .iter()
+            .filter(|v| self.is_unread(up.mid, v, now))
+            .count()
+    }
+
+    /// Show the uploads of the current UP, or of all UPs newest first
+    fn rebuild_grid(&mut self) {
+        let now = chrono::Local::now().timestamp();
+        let selected_aid = self.grid.selected_card().and_then(|card| card.aid);
+
+        let mut videos: Vec<(i64, &SpaceVideo)> = self
+            .ups
+            .iter()
+            .filter(|up| self.current.is_none_or(|mid| mid == up.mid))
+            .flat_map(|up| up.videos.iter().map(move |v| (up.mid, v)))
+            .collect();
+        videos.sort_by_key(|(_, v)| std::cmp::Reverse(v.created.unwrap_or(0)));
+
+        self.card_mids = videos.iter().map(|(mid, _)| *mid).collect();
+        let cards: Vec<VideoCard> = videos
+            .into_iter()
+            .map(|(mid, video)| {
+                let mut card = VideoCard::new(
+                    Some(video.bvid.clone()),
+                    Some(video.aid),
+                    video.title.clone(),
+                    video.author.clone().unwrap_or_default(),
+                    video.format_play(),
+                    video.length.clone().unwrap_or_default(),
+                    video.cover_url(),
+                );
+                if self.is_unread(mid, video, now) {
+                    card.add_badge(CardBadge::New);
+                }
+                if video.is_charging_arc {
+                    card.add_badge(CardBadge::ChargeOnly);
+                }
+                card
+            })
+            .collect();
+
+        self.grid.clear();
+        for card in cards {
+            self.grid.add_card(card);
+        }
+        if let Some(index) =
+            selected_aid.and_then(|aid| self.grid.cards.iter().position(|c| c.aid == Some(aid)))
+        {
+            self.grid.select(index);
+        }
+    }
+
+    /// Move to the next / previous UP, marking the uploads of the one left as read
+    fn cycle_up(&mut self, forward: bool) {
+        if let Some(up) = self
+            .current
+            .and_then(|mid| self.ups.iter().find(|up| up.mid == mid))
+        {
+            self.read.mark_read(up.mid, up.newest());
+            let _ = subscriptions::save_subscription_state(&self.read);
+        }
+
+        // 0 is the all-UPs timeline
+        let len = self.ups.len() + 1;
+        let index = self
+            .current
+            .and_then(|mid| self.ups.iter().position(|up| up.mid == mid))
+            .map_or(0, |i| i + 1);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        self.current = next.checked_sub(1).map(|i| self.ups[i].mid);
+        self.grid.select(0);
+        self.rebuild_grid();
+    }
+
+    fn render_up_list(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let now = chrono::Local::now().timestamp();
+        let total_unread: usize = self.ups.iter().map(|up| self.unread_count(up, now)).sum();
+
+        let mut rows = vec![(None, t("全部").to_string(), total_unread)];
+        rows.extend(
+            self.ups
+                .iter()
+                .map(|up| (Some(up.mid), up.name.clone(), self.unread_count(up, now))),
+        );
+
+        let items: Vec<ListItem> = rows
+            .into_iter()
+            .map(|(mid, name, unread)| {
+                let selected = mid == self.current;
+                let style = if selected {
+                    Style::default()
+                        .fg(theme.bilibili_pink)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(theme.bg_highlight)
+                } else if unread > 0 {
+                    Style::default().fg(theme.fg_primary)
+                } else {
+                    Style::default().fg(theme.fg_muted)
+                };
+                let mut spans = vec![Span::styled(
+                    format!("{}{}", if selected { "▌" } else { " " }, name),
+                    style,
+                )];
+                if unread > 0 {
+                    spans.push(Span::styled(
+                        format!(" ●{}", unread),
+                        Style::default().fg(theme.bilibili_pink),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let title = match self.progress {
+            Some((fetched, total)) => tf(" 📬 订阅 {}/{} ", &[&fetched, &total]),
+            None => t(" 📬 订阅 ").to_string(),
+        };
+        let selected = self
+            .current
+            .and_then(|mid| self.ups.iter().position(|up| up.mid == mid))
+            .map_or(0, |i| i + 1);
+        let mut state = ListState::default().with_selected(Some(selected));
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border_subtle))
+                .title(Span::styled(
+                    title,
+                    Style::default().fg(theme.bilibili_pink),
+                )),
+        );
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    /// The UP shown, or the UP of the selected card in the all-UPs timeline
+    fn selected_up(&self) -> Option<i64> {
+        self.current
+            .or_else(|| self.card_mids.get(self.grid.selected_index).copied())
+    }
+
+    fn selected_video(&self, index: usize) -> Option<(String, i64, String)> {
+        let card = self.grid.cards.get(index)?;
+        Some((card.bvid.clone()?, card.aid?, card.title.clone()))
+    }
+}
+
+impl Default for SubscriptionsPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for SubscriptionsPage {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let [body, status_area, help_area] = Layout::vertical([
+            Constraint::Min(10),
+            Constraint::Length(1),
+            Constraint::Length(2),
+        ])
+        .areas(area);
+        let [list_area, grid_area] =
+            Layout::horizontal([Constraint::Length(UP_LIST_WIDTH), Constraint::Min(20)])
+                .areas(body);
+
+        self.render_up_list(frame, list_area, theme);
+
+        if self.loading {
+            let loading = Paragraph::new(t("⏳ 加载中..."))
+                .style(Style::default().fg(theme.warning))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading, grid_area);
+        } else if let Some(error) = &self.error_message {
+            let error_widget = Paragraph::new(format!("❌ {}", error))
+                .style(Style::default().fg(theme.error))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_widget, grid_area);
+        } else if self.grid.cards.is_empty() {
+            let text = if self.progress.is_some() {
+                t("正在获取关注 UP 的投稿...")
+            } else {
+                t("关注的 UP 最近没有投稿")
+            };
+            let empty = Paragraph::new(text)
+                .style(Style::default().fg(theme.fg_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, grid_area);
+        } else {
+            self.grid.render(frame, grid_area, theme);
+        }
+
+        if let Some(status) = &self.export_status {
+            let style = if self.export_rx.is_some() {
+                Style::default().fg(theme.warning)
+            } else {
+                Style::default().fg(theme.fg_muted)
+            };
+            let status = Paragraph::new(status.as_str())
+                .style(style)
+                .alignment(Alignment::Center);
+            frame.render_widget(status, status_area);
+        }
+
+        let help_text = tf(
+            "[{}/{}] 导航  [{}/{}] 切换 UP（离开时标为已读）  [{}] 详情  [{}] 更多  [{}] 刷新  [{}] 导出 OPML  [{}] 复制 RSS",
+            &[
+                &keys.get_arrow_keys_display(),
+                &keys.get_nav_keys_display(),
+                &keys.section_prev,
+                &keys.section_next,
+                &keys.confirm,
+                &keys.play,
+                &keys.refresh,
+                &keys.export,
+                &keys.copy_link,
+            ],
+        );
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, help_area);
+    }
+
+    fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if keys.matches_quit(key) || keys.matches_back(key) {
+            return Some(AppAction::BackToList);
+        }
+        if keys.matches_nav_next(key) {
+            return Some(AppAction::NavNext);
+        }
+        if keys.matches_nav_prev(key) {
+            return Some(AppAction::NavPrev);
+        }
+        if keys.matches_next_theme(key) {
+            return Some(AppAction::NextTheme);
+        }
+        if keys.matches_section_next(key) || keys.matches_section_prev(key) {
+            self.cycle_up(keys.matches_section_next(key));
+            return Some(AppAction::None);
+        }
+        if keys.matches_refresh(key) {
+            return Some(AppAction::RefreshSubscriptions);
+        }
+        if keys.matches_export(key) {
+            return Some(AppAction::ExportFollowingsOpml);
+        }
+        if keys.matches_copy_link(key) {
+            return Some(
+                self.selected_up()
+                    .map_or(AppAction::None, AppAction::CopyRssLink),
+            );
+        }
+        if keys.matches_down(key) {
+            self.grid.move_down();
+            return Some(AppAction::None);
+        }
+        if keys.matches_up(key) {
+            self.grid.move_up();
+            return Some(AppAction::None);
+        }
+        if keys.matches_right(key) {
+            self.grid.move_right();
+            return Some(AppAction::None);
+        }
+        if keys.matches_left(key) {
+            self.grid.move_left();
+            return Some(AppAction::None);
+        }
+        if keys.matches_play(key) {
+            if let Some((bvid, aid, title)) = self.selected_video(self.grid.selected_index) {
+                return Some(AppAction::OpenActionMenu { bvid, aid, title });
+            }
+            return Some(AppAction::None);
+        }
+        if self.grid.select_visible(key) || keys.matches_confirm(key) {
+            if let Some((bvid, aid, _)) = self.selected_video(self.grid.selected_index) {
+                return Some(AppAction::OpenVideoDetail(bvid, aid));
+            }
+            return Some(AppAction::None);
+        }
+        Some(AppAction::None)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return None;
+        }
+
+        match event.kind {
+            MouseEventKind::ScrollDown => {
+                self.grid.move_down();
+                None
+            }
+            MouseEventKind::ScrollUp => {
+                self.grid.move_up();
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some((bvid, aid, _)) = self.selected_video(click_idx) {
+                        return Some(AppAction::OpenVideoDetail(bvid, aid));
+                    }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load(ctx.client, ctx.mid))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_feeds();
+        self.poll_export();
+        self.grid.poll_cover_results();
+        if !ctx.throttled {
+            self.grid.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn marks_watched(&self) -> bool {
+        true
+    }
+
+    fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        self.watched = watched.clone();
+        self.grid.set_watched(watched, dim);
+        self.rebuild_grid();
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+}
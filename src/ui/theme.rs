@@ -1,3 +1,4 @@
+use crate::i18n::t;
 use ratatui::style::Color;
 use std::fmt;
 use std::str::FromStr;
@@ -37,7 +38,7 @@ impl ThemeVariant {
 
     pub fn label(&self) -> &'static str {
         match self {
-            ThemeVariant::Default => "默认暗色",
+            ThemeVariant::Default => t("默认暗色"),
             ThemeVariant::CatppuccinLatte => "Catppuccin Latte",
             ThemeVariant::CatppuccinFrappe => "Catppuccin Frappé",
             ThemeVariant::CatppuccinMacchiato => "Catppuccin Macchiato",
@@ -0,0 +1,66 @@
+//! Short message shown at the bottom of the screen for a few seconds
+
+use super::{clear_popup, Theme};
+use ratatui::{prelude::*, widgets::*};
+use std::time::{Duration, Instant};
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+pub struct Toast {
+    message: String,
+    is_error: bool,
+    shown_at: Instant,
+}
+
+impl Toast {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            is_error: false,
+            shown_at: Instant::now(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            is_error: true,
+            ..Self::info(message)
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= TOAST_DURATION
+    }
+
+    /// Draw centered above the bottom edge of `area`
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let color = if self.is_error {
+            theme.error
+        } else {
+            theme.info
+        };
+        let width = (Span::raw(&self.message).width() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let toast_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.bottom().saturating_sub(height + 1).max(area.y),
+            width,
+            height,
+        };
+
+        clear_popup(frame, toast_area);
+        let paragraph = Paragraph::new(Span::styled(
+            self.message.as_str(),
+            Style::default().fg(theme.fg_primary),
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(color)),
+        );
+        frame.render_widget(paragraph, toast_area);
+    }
+}
@@ -0,0 +1,465 @@
+//! User space page: UP主 profile header and uploaded videos
+
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{Component, PageContext, Theme};
+use crate::api::client::ApiClient;
+use crate::api::space::{SpaceVideo, UserCardData};
+use crate::app::AppAction;
+use crate::format::format_count;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
+use ratatui::{
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::*,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+const PAGE_SIZE: i32 = 30;
+
+pub struct UserSpacePage {
+    pub mid: i64,
+    pub user: Option<UserCardData>,
+    pub grid: VideoCardGrid,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub page: i32,
+    pub total_videos: i32,
+    pub loading_more: bool,
+    /// 只看标题匹配该关键词的投稿，空为全部
+    pub keyword: String,
+    /// Keyword being typed, None when not typing
+    pub keyword_input: Option<String>,
+    last_click_time: Option<Instant>,
+    last_click_index: Option<usize>,
+}
+
+impl UserSpacePage {
+    pub fn new(mid: i64) -> Self {
+        Self {
+            mid,
+            user: None,
+            grid: VideoCardGrid::new().with_number_hints(),
+            loading: true,
+            error_message: None,
+            page: 1,
+            total_videos: 0,
+            loading_more: false,
+            keyword: String::new(),
+            keyword_input: None,
+            last_click_time: None,
+            last_click_index: None,
+        }
+    }
+
+    /// Load the profile card and the first page of uploads
+    pub async fn load_data(&mut self, api_client: &ApiClient) {
+        self.user = api_client.get_user_card(self.mid).await.ok();
+        self.load_videos(api_client).await;
+    }
+
+    /// Load the first page of uploads matching the current keyword
+    pub async fn load_videos(&mut self, api_client: &ApiClient) {
+        self.loading = true;
+        match api_client
+            .get_user_videos(self.mid, 1, PAGE_SIZE, &self.keyword)
+            .await
+        {
+            Ok(data) => {
+                self.grid.clear();
+                self.page = 1;
+                self.total_videos = data.page.count;
+                self.append_videos(data.list.vlist);
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.error_message = Some(tf("加载投稿失败: {}", &[&e]));
+            }
+        }
+        self.loading = false;
+    }
+
+    pub async fn load_more(&mut self, api_client: &ApiClient) {
+        if self.loading_more || self.grid.cards.len() >= self.total_videos as usize {
+            return;
+        }
+
+        self.loading_more = true;
+        self.page += 1;
+
+        match api_client
+            .get_user_videos(self.mid, self.page, PAGE_SIZE, &self.keyword)
+            .await
+        {
+            Ok(data) => {
+                if data.list.vlist.is_empty() {
+                    self.page -= 1;
+                }
+                self.append_videos(data.list.vlist);
+            }
+            Err(_) => {
+                self.page -= 1;
+            }
+        }
+        self.loading_more = false;
+    }
+
+    fn append_videos(&mut self, videos: Vec<SpaceVideo>) {
+        for video in videos {
+            let mut card = VideoCard::new(
+                Some(video.bvid.clone()),
+                Some(video.aid),
+                video.title.clone(),
+                video.author.clone().unwrap_or_default(),
+                video.format_play(),
+                video.length.clone().unwrap_or_default(),
+                video.cover_url(),
+            );
+            if video.is_union_video == 1 {
+                card.add_badge(CardBadge::Union);
+            }
+            if video.is_charging_arc {
+                card.add_badge(CardBadge::ChargeOnly);
+            }
+            self.grid.add_card(card);
+        }
+    }
+
+    pub fn poll_cover_results(&mut self) {
+        self.grid.poll_cover_results();
+    }
+
+    pub fn start_cover_downloads(&mut self) {
+        self.grid.start_cover_downloads();
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_subtle))
+            .title(Span::styled(
+                t(" 👤 UP主空间 "),
+                Style::default().fg(theme.bilibili_pink),
+            ));
+
+        let Some(user) = &self.user else {
+            let placeholder = Paragraph::new(format!("UID: {}", self.mid))
+                .style(Style::default().fg(theme.fg_secondary))
+                .block(block);
+            frame.render_widget(placeholder, area);
+            return;
+        };
+
+        let mut name_line = vec![Span::styled(
+            user.card.name.clone(),
+            Style::default()
+                .fg(theme.fg_primary)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if let Some(level) = user.card.level_info.as_ref().and_then(|l| l.current_level) {
+            name_line.push(Span::styled(
+                format!(" Lv{}", level),
+                Style::default().fg(theme.warning),
+            ));
+        }
+        name_line.push(Span::styled(
+            format!("  UID: {}", user.card.mid),
+            Style::default().fg(theme.fg_muted),
+        ));
+        if user.following {
+            name_line.push(Span::styled(
+                t("  ✓已关注"),
+                Style::default().fg(theme.success),
+            ));
+        }
+
+        let stats_line = Line::from(vec![
+            Span::styled(t("粉丝 "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                format_count(user.follower.unwrap_or(0)),
+                Style::default().fg(theme.fg_primary),
+            ),
+            Span::styled(t("  投稿 "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                format_count(user.archive_count.unwrap_or(self.total_videos as i64)),
+                Style::default().fg(theme.fg_primary),
+            ),
+        ]);
+
+        let sign = user
+            .card
+            .sign
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or(t("这个人很神秘，什么都没有写"));
+
+        let text = vec![
+            Line::from(name_line),
+            stats_line,
+            Line::from(Span::styled(
+                sign.replace('\n', " "),
+                Style::default().fg(theme.fg_muted),
+            )),
+        ];
+
+        let header = Paragraph::new(text).block(block);
+        frame.render_widget(header, area);
+    }
+
+    fn grid_area(area: Rect) -> Rect {
+        let chunks = Self::layout(area);
+        Rect {
+            y: chunks[1].y + 2,
+            height: chunks[1].height.saturating_sub(2),
+            ..chunks[1]
+        }
+    }
+
+    fn layout(area: Rect) -> std::rc::Rc<[Rect]> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5), // Profile header
+                Constraint::Min(10),   // Videos grid
+                Constraint::Length(2), // Help
+            ])
+            .split(area)
+    }
+}
+
+impl Component for UserSpacePage {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let chunks = Self::layout(area);
+
+        self.render_header(frame, chunks[0], theme);
+
+        if self.loading {
+            let loading = Paragraph::new(t("⏳ 加载中..."))
+                .style(Style::default().fg(theme.warning))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading, chunks[1]);
+        } else if let Some(error) = &self.error_message {
+            let error_widget = Paragraph::new(format!("❌ {}", error))
+                .style(Style::default().fg(theme.error))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_widget, chunks[1]);
+        } else if self.grid.cards.is_empty() {
+            let message = if self.keyword.is_empty() {
+                t("该用户还没有投稿").to_string()
+            } else {
+                tf("没有标题包含「{}」的投稿", &[&self.keyword])
+            };
+            let empty = Paragraph::new(message)
+                .style(Style::default().fg(theme.fg_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            let title = if self.keyword.is_empty() {
+                t(" 投稿视频 ").to_string()
+            } else {
+                tf(" 投稿中搜索「{}」 ", &[&self.keyword])
+            };
+            let header = Paragraph::new(Line::from(vec![
+                Span::styled(title, Style::default().fg(theme.bilibili_pink)),
+                Span::styled(
+                    format!("({}/{})", self.grid.cards.len(), self.total_videos),
+                    Style::default().fg(theme.fg_muted),
+                ),
+                if self.loading_more {
+                    Span::styled(t(" 加载中..."), Style::default().fg(theme.warning))
+                } else {
+                    Span::raw("")
+                },
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border_subtle)),
+            );
+
+            let header_area = Rect {
+                height: 2,
+                ..chunks[1]
+            };
+            frame.render_widget(header, header_area);
+            self.grid.render(frame, Self::grid_area(area), theme);
+        }
+
+        let help_text = match &self.keyword_input {
+            Some(input) => tf(
+                "在投稿中搜索: {}_  [Enter] 搜索（留空显示全部）  [Esc] 取消",
+                &[input],
+            ),
+            None => tf(
+                "[{}/{}] 导航  [{}] 详情  [{}] 更多  [{}] 搜索投稿  [{}] 复制 RSS  [{}] 返回",
+                &[
+                    &keys.get_arrow_keys_display(),
+                    &keys.get_nav_keys_display(),
+                    &keys.confirm,
+                    &keys.play,
+                    &keys.search_focus,
+                    &keys.copy_link,
+                    &keys.back,
+                ],
+            ),
+        };
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[2]);
+    }
+
+    fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if let Some(input) = &mut self.keyword_input {
+            match key {
+                KeyCode::Enter => {
+                    let keyword = input.trim().to_string();
+                    self.keyword_input = None;
+                    return Some(AppAction::SearchUserSpace {
+                        mid: self.mid,
+                        keyword,
+                    });
+                }
+                KeyCode::Esc => self.keyword_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_search_focus(key) {
+            self.keyword_input = Some(self.keyword.clone());
+            return Some(AppAction::None);
+        }
+        if keys.matches_quit(key) || keys.matches_back(key) {
+            return Some(AppAction::CloseUserSpace);
+        }
+        if keys.matches_copy_link(key) {
+            return Some(AppAction::CopyRssLink(self.mid));
+        }
+        if keys.matches_down(key) {
+            if self.grid.move_down() && self.grid.is_near_bottom(3) && !self.loading_more {
+                return Some(AppAction::LoadMoreUserSpace);
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_up(key) {
+            self.grid.move_up();
+            return Some(AppAction::None);
+        }
+        if keys.matches_right(key) {
+            self.grid.move_right();
+            return Some(AppAction::None);
+        }
+        if keys.matches_left(key) {
+            self.grid.move_left();
+            return Some(AppAction::None);
+        }
+        if keys.matches_play(key) {
+            if let Some(card) = self.grid.selected_card() {
+                if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                    return Some(AppAction::OpenActionMenu {
+                        bvid: bvid.clone(),
+                        aid,
+                        title: card.title.clone(),
+                    });
+                }
+            }
+            return Some(AppAction::None);
+        }
+        if self.grid.select_visible(key) || keys.matches_confirm(key) {
+            if let Some(card) = self.grid.selected_card() {
+                if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                    return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
+                }
+            }
+            return Some(AppAction::None);
+        }
+        Some(AppAction::None)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        // Dragging the scrollbar jumps straight to a row
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return (self.grid.is_near_bottom(3) && !self.loading_more)
+                .then_some(AppAction::LoadMoreUserSpace);
+        }
+
+        match event.kind {
+            MouseEventKind::ScrollDown => {
+                if self.grid.move_down() && self.grid.is_near_bottom(3) && !self.loading_more {
+                    return Some(AppAction::LoadMoreUserSpace);
+                }
+                None
+            }
+            MouseEventKind::ScrollUp => {
+                self.grid.move_up();
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some(card) = self.grid.cards.get(click_idx) {
+                        if let (Some(bvid), Some(aid)) = (&card.bvid, card.aid) {
+                            return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
+                        }
+                    }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load_data(ctx.client))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.keyword_input.is_some()
+    }
+
+    fn is_full_screen(&self) -> bool {
+        true
+    }
+
+    fn marks_watched(&self) -> bool {
+        true
+    }
+
+    fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        self.grid.set_watched(watched, dim);
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+}
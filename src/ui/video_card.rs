@@ -1,18 +1,72 @@
 //! Shared video card components for grid display across pages
 
+use super::card_grid::{render_cover, CardGrid, CoverPlacement, GridCard};
 use super::Theme;
-use image::DynamicImage;
+use crate::i18n::{t, tf};
+use crate::storage::TitleBlocklist;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol, StatefulImage};
+use ratatui_image::protocol::StatefulProtocol;
 use std::collections::HashSet;
+use std::ops::{Deref, DerefMut, Range};
 use std::sync::Arc;
-use tokio::sync::mpsc;
 
-/// Message for completed cover download
-pub struct CoverResult {
-    pub index: usize,
-    pub protocol: StatefulProtocol,
+/// Small colored tag on the top border of a card, filled by each page from its data source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardBadge {
+    /// UP 主正在直播
+    Live,
+    /// 充电专属
+    ChargeOnly,
+    /// 多 P，带分P数
+    MultiPart(i32),
+    /// 联合投稿
+    Union,
+    /// 订阅页中未看过的新投稿
+    New,
+    /// 收藏夹中已失效的视频
+    Invalid,
+    /// 动态页中已取关的 UP 主
+    Unfollowed,
+    /// 多选模式中已勾选
+    Marked,
+}
+
+impl CardBadge {
+    fn label(self) -> String {
+        match self {
+            CardBadge::Live => t(" 直播中 ").to_string(),
+            CardBadge::ChargeOnly => t(" 充电专属 ").to_string(),
+            CardBadge::MultiPart(pages) => tf(" {}P ", &[&pages]),
+            CardBadge::Union => t(" 合作 ").to_string(),
+            CardBadge::New => t(" 新 ").to_string(),
+            CardBadge::Invalid => t(" 已失效 ").to_string(),
+            CardBadge::Unfollowed => t(" 未关注 ").to_string(),
+            CardBadge::Marked => t(" ☑ 已选 ").to_string(),
+        }
+    }
+
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            CardBadge::Live => theme.bilibili_pink,
+            CardBadge::ChargeOnly => theme.warning,
+            CardBadge::MultiPart(_) => theme.bilibili_cyan,
+            CardBadge::Union => theme.success,
+            CardBadge::New => theme.bilibili_pink,
+            CardBadge::Invalid => theme.fg_muted,
+            CardBadge::Unfollowed => theme.fg_muted,
+            CardBadge::Marked => theme.success,
+        }
+    }
+
+    /// Right-aligned tag for a block title
+    fn to_line<'a>(self, theme: &Theme) -> Line<'a> {
+        Line::from(Span::styled(
+            self.label(),
+            Style::default().fg(theme.bg_primary).bg(self.color(theme)),
+        ))
+        .right_aligned()
+    }
 }
 
 /// A video card with cover image
@@ -25,6 +79,15 @@ pub struct VideoCard {
     pub duration: String,
     pub pic_url: Option<String>,
     pub cover: Option<StatefulProtocol>,
+    cover_placement: CoverPlacement,
+    /// 竖屏封面，卡片上显示竖屏标记
+    pub portrait: bool,
+    /// 命中的屏蔽规则，折叠显示
+    pub blocked: Option<String>,
+    /// 标题中命中搜索关键词的字符区间
+    pub highlights: Vec<Range<usize>>,
+    /// 直播中 / 充电专属 / 多P / 合作 等徽标
+    pub badges: Vec<CardBadge>,
 }
 
 impl VideoCard {
@@ -46,11 +109,34 @@ impl VideoCard {
             duration,
             pic_url,
             cover: None,
+            cover_placement: CoverPlacement::default(),
+            portrait: false,
+            blocked: None,
+            highlights: Vec::new(),
+            badges: Vec::new(),
         }
     }
 
+    /// Add a badge unless the card already has it
+    pub fn add_badge(&mut self, badge: CardBadge) {
+        if !self.badges.contains(&badge) {
+            self.badges.push(badge);
+        }
+    }
+
+    pub fn remove_badge(&mut self, badge: CardBadge) {
+        self.badges.retain(|b| *b != badge);
+    }
+
     /// Render a single video card
-    pub fn render(&mut self, frame: &mut Frame, area: Rect, is_selected: bool, theme: &Theme) {
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        is_selected: bool,
+        watched: WatchedMark,
+        theme: &Theme,
+    ) {
         // Enhanced border styling - use Bilibili pink for selection
         let (border_style, border_type) = if is_selected {
             (
@@ -78,15 +164,35 @@ impl VideoCard {
             Span::raw("")
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_type(border_type)
             .border_style(border_style)
             .title(title_span);
+        for badge in &self.badges {
+            block = block.title(badge.to_line(theme));
+        }
+        if self.portrait {
+            block = block.title(portrait_badge(theme));
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
+        if let Some(rule) = &self.blocked {
+            let [_, middle, _] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .areas(inner);
+            let collapsed = Paragraph::new(tf("已屏蔽：{}", &[&rule]))
+                .style(Style::default().fg(theme.fg_muted))
+                .alignment(Alignment::Center);
+            frame.render_widget(collapsed, middle);
+            return;
+        }
+
         let card_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -108,8 +214,7 @@ impl VideoCard {
         };
 
         if let Some(ref mut cover) = self.cover {
-            let image_widget = StatefulImage::new();
-            frame.render_stateful_widget(image_widget, centered_cover, cover);
+            render_cover(frame, centered_cover, cover, &mut self.cover_placement);
         } else {
             // Modern placeholder with subtle styling
             let placeholder = Paragraph::new("📺")
@@ -136,21 +241,37 @@ impl VideoCard {
             Style::default()
                 .fg(theme.fg_primary)
                 .add_modifier(Modifier::BOLD)
+        } else if watched == WatchedMark::WatchedDimmed || self.badges.contains(&CardBadge::Invalid)
+        {
+            Style::default().fg(theme.fg_muted)
         } else {
             Style::default().fg(theme.fg_secondary)
         };
 
+        let mut meta_spans = vec![
+            Span::styled(&self.views, Style::default().fg(theme.fg_muted)),
+            Span::styled(" · ", Style::default().fg(theme.fg_muted)),
+            Span::styled(&self.duration, Style::default().fg(theme.success)),
+        ];
+        if watched != WatchedMark::Unwatched {
+            meta_spans.push(Span::styled(
+                t(" ✓已看"),
+                Style::default().fg(theme.fg_muted),
+            ));
+        }
+
         let info_text = Text::from(vec![
-            Line::from(Span::styled(&display_title, title_style)),
+            Line::from(highlight_spans(
+                &display_title,
+                &self.highlights,
+                title_style,
+                title_style.fg(theme.fg_accent).add_modifier(Modifier::BOLD),
+            )),
             Line::from(Span::styled(
                 &self.author,
                 Style::default().fg(theme.bilibili_cyan),
             )),
-            Line::from(vec![
-                Span::styled(&self.views, Style::default().fg(theme.fg_muted)),
-                Span::styled(" · ", Style::default().fg(theme.fg_muted)),
-                Span::styled(&self.duration, Style::default().fg(theme.success)),
-            ]),
+            Line::from(meta_spans),
         ]);
 
         let info = Paragraph::new(info_text)
@@ -160,217 +281,177 @@ impl VideoCard {
     }
 }
 
-/// Video card grid manager for async cover loading
-pub struct VideoCardGrid {
-    pub cards: Vec<VideoCard>,
-    pub selected_index: usize,
-    pub scroll_row: usize,
-    pub columns: usize,
-    pub card_height: u16,
-    pub picker: Arc<Picker>,
-    pub cover_tx: mpsc::Sender<CoverResult>,
-    pub cover_rx: mpsc::Receiver<CoverResult>,
-    pub pending_downloads: HashSet<usize>,
-    pub cached_visible_rows: usize,
-}
-
-impl VideoCardGrid {
-    pub fn new() -> Self {
-        let picker = Arc::new(Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks()));
-        let (cover_tx, cover_rx) = mpsc::channel(32);
+/// Split `text` into spans, styling the char ranges in `highlights` with `accent`
+fn highlight_spans<'a>(
+    text: &'a str,
+    highlights: &[Range<usize>],
+    base: Style,
+    accent: Style,
+) -> Vec<Span<'a>> {
+    if highlights.is_empty() {
+        return vec![Span::styled(text, base)];
+    }
 
-        Self {
-            cards: Vec::new(),
-            selected_index: 0,
-            scroll_row: 0,
-            columns: 3,
-            card_height: 12,
-            picker,
-            cover_tx,
-            cover_rx,
-            pending_downloads: HashSet::new(),
-            cached_visible_rows: 3,
+    let mut spans = Vec::new();
+    let mut run_start = 0;
+    let mut run_marked = false;
+    for (pos, (byte, _)) in text.char_indices().enumerate() {
+        let marked = highlights.iter().any(|range| range.contains(&pos));
+        if marked != run_marked && byte > run_start {
+            let style = if run_marked { accent } else { base };
+            spans.push(Span::styled(&text[run_start..byte], style));
+            run_start = byte;
         }
+        run_marked = marked;
     }
+    let style = if run_marked { accent } else { base };
+    spans.push(Span::styled(&text[run_start..], style));
+    spans
+}
 
-    pub fn clear(&mut self) {
-        self.cards.clear();
-        self.selected_index = 0;
-        self.scroll_row = 0;
-        self.pending_downloads.clear();
+impl GridCard for VideoCard {
+    fn cover_url(&self) -> Option<String> {
+        // Collapsed cards show no cover
+        self.pic_url.clone().filter(|_| self.blocked.is_none())
     }
 
-    pub fn add_card(&mut self, card: VideoCard) {
-        self.cards.push(card);
+    fn has_cover(&self) -> bool {
+        self.cover.is_some()
     }
 
-    pub fn visible_rows(&self, height: u16) -> usize {
-        let available_height = height.saturating_sub(1);
-        (available_height / self.card_height).max(1) as usize
+    fn set_cover(&mut self, cover: StatefulProtocol) {
+        self.cover = Some(cover);
+        self.cover_placement = CoverPlacement::default();
     }
 
-    pub fn selected_row(&self) -> usize {
-        self.selected_index / self.columns
+    fn set_portrait(&mut self, portrait: bool) {
+        self.portrait = portrait;
     }
+}
 
-    pub fn total_rows(&self) -> usize {
-        self.cards.len().div_ceil(self.columns)
-    }
+/// Right-aligned "竖屏" mark for the top border of a card with a portrait cover
+pub fn portrait_badge<'a>(theme: &Theme) -> Line<'a> {
+    Line::from(Span::styled(
+        t(" 竖屏 "),
+        Style::default()
+            .fg(theme.bg_primary)
+            .bg(theme.bilibili_blue),
+    ))
+    .right_aligned()
+}
 
-    pub fn update_scroll(&mut self, visible_rows: usize) {
-        let current_row = self.selected_row();
-        if current_row < self.scroll_row {
-            self.scroll_row = current_row;
-        } else if current_row >= self.scroll_row + visible_rows {
-            self.scroll_row = current_row - visible_rows + 1;
-        }
-    }
+/// Whether a card is marked as in the recent history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchedMark {
+    Unwatched,
+    /// 显示「✓已看」
+    Watched,
+    /// 显示「✓已看」并淡化标题（dim_watched）
+    WatchedDimmed,
+}
 
-    pub fn move_down(&mut self) -> bool {
-        if !self.cards.is_empty() {
-            let new_idx = self.selected_index + self.columns;
-            if new_idx < self.cards.len() {
-                self.selected_index = new_idx;
-                self.update_scroll(self.cached_visible_rows);
-                return true;
-            }
+impl WatchedMark {
+    /// Mark of a watched video, dimmed when `dim` is set
+    pub fn watched(dim: bool) -> Self {
+        if dim {
+            Self::WatchedDimmed
+        } else {
+            Self::Watched
         }
-        false
     }
+}
 
-    pub fn move_up(&mut self) -> bool {
-        if !self.cards.is_empty() && self.selected_index >= self.columns {
-            self.selected_index -= self.columns;
-            self.update_scroll(self.cached_visible_rows);
-            return true;
-        }
-        false
-    }
+/// Grid of video cards with watched marks and the title blocklist applied
+pub struct VideoCardGrid {
+    grid: CardGrid<VideoCard>,
+    /// 最近看过的视频 bvid，用于显示已看标记，和 App 共用
+    pub watched: Arc<HashSet<String>>,
+    pub dim_watched: bool,
+    pub blocklist: Arc<TitleBlocklist>,
+}
 
-    pub fn move_right(&mut self) -> bool {
-        if !self.cards.is_empty() && self.selected_index + 1 < self.cards.len() {
-            self.selected_index += 1;
-            self.update_scroll(self.cached_visible_rows);
-            return true;
+impl VideoCardGrid {
+    pub fn new() -> Self {
+        Self {
+            grid: CardGrid::new(3, 12),
+            watched: Arc::default(),
+            dim_watched: false,
+            blocklist: Arc::new(TitleBlocklist::default()),
         }
-        false
     }
 
-    pub fn move_left(&mut self) -> bool {
-        if !self.cards.is_empty() && self.selected_index > 0 {
-            self.selected_index -= 1;
-            self.update_scroll(self.cached_visible_rows);
-            return true;
-        }
-        false
+    /// Show 1-9 on the visible cards and open them with the number keys
+    pub fn with_number_hints(mut self) -> Self {
+        self.grid.number_hints = true;
+        self
     }
 
-    /// Check if near bottom for pagination
-    pub fn is_near_bottom(&self, visible_rows: usize) -> bool {
-        if self.cards.is_empty() {
-            return false;
-        }
-        let current_row = self.selected_row();
-        let total = self.total_rows();
-        current_row + 2 >= total.saturating_sub(1) && total > visible_rows
+    /// Update the watched set used to mark cards
+    pub fn set_watched(&mut self, watched: Arc<HashSet<String>>, dim: bool) {
+        self.watched = watched;
+        self.dim_watched = dim;
     }
 
-    /// Start background downloads for visible covers
-    pub fn start_cover_downloads(&mut self) {
-        if self.cards.is_empty() {
-            return;
+    /// Add a card, dropping or collapsing it if its title is blocked
+    pub fn add_card(&mut self, card: VideoCard) {
+        if let Some(card) = self.screen_card(card) {
+            self.grid.push(card);
         }
+    }
 
-        let start = self.scroll_row * self.columns;
-        // Prefetch all visible rows plus 2 extra rows for smooth scrolling
-        let prefetch_rows = self.cached_visible_rows + 2;
-        let end = (start + self.columns * prefetch_rows).min(self.cards.len());
-
-        for idx in start..end {
-            if self.cards[idx].cover.is_some() || self.pending_downloads.contains(&idx) {
-                continue;
-            }
+    /// Put cards above the loaded ones, dropping or collapsing blocked titles like
+    /// [`Self::add_card`]
+    pub fn insert_front(&mut self, cards: Vec<VideoCard>) {
+        let cards = cards
+            .into_iter()
+            .filter_map(|card| self.screen_card(card))
+            .collect();
+        self.grid.insert_front(cards);
+    }
 
-            if let Some(pic_url) = self.cards[idx].pic_url.clone() {
-                self.pending_downloads.insert(idx);
-                let tx = self.cover_tx.clone();
-                let picker = Arc::clone(&self.picker);
-
-                tokio::spawn(async move {
-                    if let Some(img) = download_image(&pic_url).await {
-                        let protocol = picker.new_resize_protocol(img);
-                        let _ = tx
-                            .send(CoverResult {
-                                index: idx,
-                                protocol,
-                            })
-                            .await;
-                    }
-                });
+    /// None for a blocked title, or the card collapsed when the blocklist says so
+    fn screen_card(&self, mut card: VideoCard) -> Option<VideoCard> {
+        if let Some(rule) = self.blocklist.matched(&card.title) {
+            if !self.blocklist.collapse {
+                return None;
             }
+            card.blocked = Some(rule.to_string());
         }
+        Some(card)
     }
 
-    /// Poll for completed cover downloads
-    pub fn poll_cover_results(&mut self) {
-        while let Ok(result) = self.cover_rx.try_recv() {
-            if result.index < self.cards.len() {
-                self.cards[result.index].cover = Some(result.protocol);
-                self.pending_downloads.remove(&result.index);
-            }
-        }
+    /// Start background downloads for visible covers
+    pub fn start_cover_downloads(&mut self) {
+        // Prefetch 2 extra rows for smooth scrolling
+        self.grid.start_cover_downloads_with_prefetch(2);
     }
 
     /// Render the grid
     pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let visible_rows = self.visible_rows(area.height);
-        self.cached_visible_rows = visible_rows;
-
-        let row_constraints: Vec<Constraint> = (0..visible_rows)
-            .map(|_| Constraint::Min(self.card_height))
-            .collect();
-
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(row_constraints)
-            .split(area);
-
-        let mut card_areas: Vec<(usize, Rect)> = Vec::new();
-
-        for (row_offset, row_area) in rows.iter().enumerate() {
-            let actual_row = self.scroll_row + row_offset;
-            let start_idx = actual_row * self.columns;
-
-            if start_idx >= self.cards.len() {
-                break;
-            }
+        let watched = &self.watched;
+        let dim_watched = self.dim_watched;
+        self.grid
+            .render_with(frame, area, theme, |frame, card_area, card, is_selected| {
+                let mark = match &card.bvid {
+                    Some(bvid) if watched.contains(bvid) => WatchedMark::watched(dim_watched),
+                    _ => WatchedMark::Unwatched,
+                };
+                card.render(frame, card_area, is_selected, mark, theme);
+            });
+    }
+}
 
-            let col_constraints: Vec<Constraint> = (0..self.columns)
-                .map(|_| Constraint::Ratio(1, self.columns as u32))
-                .collect();
-
-            let cols = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(col_constraints)
-                .split(*row_area);
-
-            for (col_idx, col_area) in cols.iter().enumerate() {
-                let video_idx = start_idx + col_idx;
-                if video_idx >= self.cards.len() {
-                    break;
-                }
-                card_areas.push((video_idx, *col_area));
-            }
-        }
+impl Deref for VideoCardGrid {
+    type Target = CardGrid<VideoCard>;
 
-        for (video_idx, col_area) in card_areas {
-            let is_selected = video_idx == self.selected_index;
-            self.cards[video_idx].render(frame, col_area, is_selected, theme);
-        }
+    fn deref(&self) -> &Self::Target {
+        &self.grid
     }
+}
 
-    pub fn selected_card(&self) -> Option<&VideoCard> {
-        self.cards.get(self.selected_index)
+impl DerefMut for VideoCardGrid {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.grid
     }
 }
 
@@ -379,9 +460,3 @@ impl Default for VideoCardGrid {
         Self::new()
     }
 }
-
-async fn download_image(url: &str) -> Option<DynamicImage> {
-    let response = reqwest::get(url).await.ok()?;
-    let bytes = response.bytes().await.ok()?;
-    image::load_from_memory(&bytes).ok()
-}
@@ -1,19 +1,39 @@
 //! Video detail page showing video info, comments, and related videos
 
+use super::comment_editor::{CommentEditor, EditorEvent};
+use super::image_viewer::{ImageLoader, ImageViewer, ViewerEvent};
+use super::scrollbar::DragScrollbar;
 use super::video_card::{VideoCard, VideoCardGrid};
-use super::{Component, Theme};
+use super::{Component, PageContext, Theme};
 use crate::api::client::ApiClient;
-use crate::api::comment::CommentItem;
-use crate::api::video::{RelatedVideoItem, VideoInfo};
+use crate::api::comment::{CommentData, CommentItem, CommentVote};
+use crate::api::link;
+use crate::api::video::{RelatedVideoItem, VideoInfo, VideoRelation};
 use crate::app::AppAction;
-use crate::storage::Keybindings;
+use crate::format::truncate_chars;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
 use ratatui::{
-    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     prelude::*,
     widgets::*,
 };
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// Most comment pages loaded for a single floor jump (20 comments each)
+const MAX_FLOOR_LOAD_PAGES: usize = 25;
+/// "只看 UP" 过滤后不足这么多条时继续往后加载
+const UP_ONLY_MIN_COMMENTS: usize = 5;
+/// Most comment pages loaded at once looking for UP comments
+const MAX_UP_ONLY_LOAD_PAGES: usize = 5;
+/// 图片评论下方最多显示的缩略图数量
+const MAX_THUMBNAILS: usize = 3;
+const THUMBNAIL_WIDTH: u16 = 16;
+const THUMBNAIL_HEIGHT: u16 = 5;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum DetailFocus {
@@ -22,6 +42,24 @@ pub enum DetailFocus {
     Related,
 }
 
+/// A single row in the flattened comment list
+enum CommentRow<'a> {
+    Comment(usize),
+    LoadingReplies,
+    Reply(&'a CommentItem),
+}
+
+/// Result of one of the requests started by [`VideoDetailPage::load_data`]
+enum LoadResult {
+    Info(Result<Box<VideoInfo>, String>),
+    Comments(Result<CommentData, String>),
+    Related(Result<Vec<RelatedVideoItem>, String>),
+    /// Only sent when logged in and the request worked
+    Relation(VideoRelation),
+    /// Whether the user follows the UP, sent like [`LoadResult::Relation`]
+    Following(bool),
+}
+
 pub struct VideoDetailPage {
     pub bvid: String,
     pub aid: i64,
@@ -29,10 +67,19 @@ pub struct VideoDetailPage {
     pub comments: Vec<CommentItem>,
     pub related_videos: Vec<RelatedVideoItem>,
     pub related_card_grid: VideoCardGrid,
+    /// 视频信息加载中
     pub loading: bool,
+    /// 视频信息加载失败
     pub error_message: Option<String>,
+    pub loading_comments: bool,
+    pub comments_error: Option<String>,
+    pub loading_related: bool,
+    pub related_error: Option<String>,
+    load_tx: mpsc::Sender<LoadResult>,
+    load_rx: mpsc::Receiver<LoadResult>,
     pub comment_page: i32,
     pub comment_scroll: usize,
+    comment_scrollbar: DragScrollbar,
     pub related_scroll: usize,
     pub focus: DetailFocus,
     pub has_more_comments: bool,
@@ -40,15 +87,46 @@ pub struct VideoDetailPage {
     pub expanded_comment: Option<i64>,
     pub comment_replies: Vec<CommentItem>,
     pub loading_replies: bool,
-    pub liked_comments: HashSet<i64>,
+    /// Votes changed on this page, over what the comment list says
+    pub comment_votes: HashMap<i64, CommentVote>,
     pub input_mode: bool,
-    pub input_buffer: String,
+    /// The editor holds the comment of a share to the dynamic feed instead of a video comment
+    sharing: bool,
+    pub comment_editor: CommentEditor,
     last_click_time: Option<Instant>,
     last_click_index: Option<usize>,
     /// Current episode index for multi-part videos (0-based)
     pub current_page_index: usize,
     /// Scroll position in episode list
     pub episode_scroll: usize,
+    /// P number or title filter being typed in the episode list after the goto_floor key
+    pub episode_input: Option<String>,
+    /// Title filter applied to the episode list, empty for all episodes
+    episode_filter: String,
+    /// Comment time point last played with the play_at key: (rpid, index)
+    play_at_cursor: Option<(i64, usize)>,
+    /// 评论总数（接口返回的约数）
+    comment_total: Option<i32>,
+    /// Floor number being typed after the goto_floor key
+    pub floor_input: Option<String>,
+    /// Floor (0-based) to select once enough comments are loaded, with the pages left to load for it
+    pending_floor: Option<(usize, usize)>,
+    /// Comment a link pointed at, kept on top of the list and marked 📍
+    located: Option<CommentItem>,
+    /// Only comments by the UP and floors the UP replied in are listed
+    pub up_only: bool,
+    /// Every loaded comment while `up_only` is on; `comments` then holds the filtered ones
+    all_comments: Vec<CommentItem>,
+    /// Pages left to load looking for UP comments
+    up_only_pages: usize,
+    /// Thumbnails and full-size pictures of image comments
+    comment_images: ImageLoader,
+    /// Overlay browsing the pictures of the selected comment
+    pub image_viewer: Option<ImageViewer>,
+    /// Whether the user liked, coined and favorited the video
+    pub relation: VideoRelation,
+    /// Whether the user follows the UP, None until known
+    pub following: Option<bool>,
 }
 
 impl VideoDetailPage {
@@ -56,6 +134,7 @@ impl VideoDetailPage {
         let mut related_card_grid = VideoCardGrid::new();
         related_card_grid.columns = 2;
         related_card_grid.card_height = 8;
+        let (load_tx, load_rx) = mpsc::channel(4);
 
         Self {
             bvid,
@@ -66,8 +145,15 @@ impl VideoDetailPage {
             related_card_grid,
             loading: true,
             error_message: None,
+            loading_comments: false,
+            comments_error: None,
+            loading_related: false,
+            related_error: None,
+            load_tx,
+            load_rx,
             comment_page: 1,
             comment_scroll: 0,
+            comment_scrollbar: DragScrollbar::default(),
             related_scroll: 0,
             focus: DetailFocus::Comments,
             has_more_comments: true,
@@ -75,77 +161,179 @@ impl VideoDetailPage {
             expanded_comment: None,
             comment_replies: Vec::new(),
             loading_replies: false,
-            liked_comments: HashSet::new(),
+            comment_votes: HashMap::new(),
             input_mode: false,
-            input_buffer: String::new(),
+            sharing: false,
+            comment_editor: CommentEditor::default(),
             last_click_time: None,
             last_click_index: None,
             current_page_index: 0,
             episode_scroll: 0,
+            episode_input: None,
+            episode_filter: String::new(),
+            play_at_cursor: None,
+            comment_total: None,
+            floor_input: None,
+            pending_floor: None,
+            located: None,
+            up_only: false,
+            all_comments: Vec::new(),
+            up_only_pages: 0,
+            comment_images: ImageLoader::new(),
+            image_viewer: None,
+            relation: VideoRelation::default(),
+            following: None,
         }
     }
 
-    pub async fn load_data(&mut self, api_client: &ApiClient) {
+    /// Start loading video info, comments and related videos concurrently;
+    /// each section is filled in by [`Self::poll_load_results`] as soon as it arrives
+    pub fn load_data(&mut self, api_client: &Arc<ApiClient>) {
         self.loading = true;
         self.error_message = None;
-
-        // Load video info
-        match api_client.get_video_info(&self.bvid).await {
-            Ok(info) => {
-                self.video_info = Some(info);
-            }
-            Err(e) => {
-                self.error_message = Some(format!("加载视频信息失败: {}", e));
+        self.loading_comments = true;
+        self.comments_error = None;
+        self.loading_related = true;
+        self.related_error = None;
+
+        let client = api_client.clone();
+        let tx = self.load_tx.clone();
+        let bvid = self.bvid.clone();
+        let aid = self.aid;
+        tokio::spawn(async move {
+            let info = client.get_video_info(&bvid).await;
+            // Some entry points only know the bvid, so comments have to wait for the aid
+            let comments_aid = match &info {
+                Ok(info) if aid == 0 => Some(info.aid),
+                _ => None,
+            };
+            let relation_ids = info.as_ref().map(|info| (info.aid, info.owner.mid)).ok();
+            let _ = tx
+                .send(LoadResult::Info(
+                    info.map(Box::new).map_err(|e| e.to_string()),
+                ))
+                .await;
+            if let Some(aid) = comments_aid {
+                let comments = client.get_comments(aid, 1).await;
+                let _ = tx
+                    .send(LoadResult::Comments(comments.map_err(|e| e.to_string())))
+                    .await;
             }
-        }
-
-        // Load comments
-        match api_client.get_comments(self.aid, 1).await {
-            Ok(data) => {
-                self.comments = data.replies.unwrap_or_default();
-                self.comment_page = 1;
-                if let Some(page) = data.page {
-                    self.has_more_comments = page.count.unwrap_or(0) > self.comments.len() as i32;
+            // Fails when logged out, the indicators then stay off
+            if let Some((aid, up_mid)) = relation_ids {
+                if let Ok(relation) = client.get_video_relation(aid).await {
+                    let _ = tx.send(LoadResult::Relation(relation)).await;
                 }
-            }
-            Err(e) => {
-                if self.error_message.is_none() {
-                    self.error_message = Some(format!("加载评论失败: {}", e));
+                if let Ok(relation) = client.get_user_relation(up_mid).await {
+                    let _ = tx
+                        .send(LoadResult::Following(relation.is_following()))
+                        .await;
                 }
             }
+        });
+
+        if self.aid != 0 {
+            let client = api_client.clone();
+            let tx = self.load_tx.clone();
+            let aid = self.aid;
+            tokio::spawn(async move {
+                let comments = client.get_comments(aid, 1).await;
+                let _ = tx
+                    .send(LoadResult::Comments(comments.map_err(|e| e.to_string())))
+                    .await;
+            });
         }
 
-        // Load related videos
-        match api_client.get_related_videos(&self.bvid).await {
-            Ok(videos) => {
-                self.related_videos = videos.clone();
-                // Populate video card grid
-                self.related_card_grid.clear();
-                for video in &videos {
-                    let card = VideoCard::new(
-                        video.bvid.clone(),
-                        video.aid,
-                        video.title.clone().unwrap_or_else(|| "无标题".to_string()),
-                        video.author_name().to_string(),
-                        video.format_views(),
-                        video.format_duration(),
-                        video.cover_url(),
-                    );
-                    self.related_card_grid.add_card(card);
+        let client = api_client.clone();
+        let tx = self.load_tx.clone();
+        let bvid = self.bvid.clone();
+        tokio::spawn(async move {
+            let related = client.get_related_videos(&bvid).await;
+            let _ = tx
+                .send(LoadResult::Related(related.map_err(|e| e.to_string())))
+                .await;
+        });
+    }
+
+    /// Apply finished requests from [`Self::load_data`] (non-blocking)
+    pub fn poll_load_results(&mut self) {
+        while let Ok(result) = self.load_rx.try_recv() {
+            match result {
+                LoadResult::Info(Ok(info)) => {
+                    if self.aid == 0 {
+                        self.aid = info.aid;
+                    }
+                    self.video_info = Some(*info);
+                    self.loading = false;
                 }
-            }
-            Err(e) => {
-                if self.error_message.is_none() {
-                    self.error_message = Some(format!("加载相关视频失败: {}", e));
+                LoadResult::Info(Err(e)) => {
+                    self.error_message = Some(tf("加载视频信息失败: {}", &[&e]));
+                    self.loading = false;
+                    // Comments can't be requested without the aid
+                    if self.aid == 0 {
+                        self.loading_comments = false;
+                    }
+                }
+                LoadResult::Comments(Ok(data)) => {
+                    self.comments = data.replies.unwrap_or_default();
+                    self.up_only = false;
+                    self.all_comments.clear();
+                    self.comment_page = 1;
+                    if let Some(page) = data.page {
+                        self.has_more_comments =
+                            page.count.unwrap_or(0) > self.comments.len() as i32;
+                        self.comment_total = page.count;
+                    }
+                    self.loading_comments = false;
+                    self.place_located();
+                }
+                LoadResult::Comments(Err(e)) => {
+                    self.comments_error = Some(tf("加载评论失败: {}", &[&e]));
+                    self.loading_comments = false;
+                }
+                LoadResult::Related(Ok(videos)) => {
+                    self.set_related_videos(videos);
+                    self.loading_related = false;
                 }
+                LoadResult::Related(Err(e)) => {
+                    self.related_error = Some(tf("加载相关视频失败: {}", &[&e]));
+                    self.loading_related = false;
+                }
+                LoadResult::Relation(relation) => self.relation = relation,
+                LoadResult::Following(following) => self.following = Some(following),
             }
         }
+    }
 
-        self.loading = false;
+    fn set_related_videos(&mut self, videos: Vec<RelatedVideoItem>) {
+        self.related_card_grid.clear();
+        for video in &videos {
+            let card = VideoCard::new(
+                video.bvid.clone(),
+                video.aid,
+                video
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| t("无标题").to_string()),
+                video.author_name().to_string(),
+                video.format_views(),
+                video.format_duration(),
+                video.cover_url(),
+            );
+            self.related_card_grid.add_card(card);
+        }
+        self.related_videos = videos;
+    }
+
+    /// Reopen the comment editor with a comment that failed to send
+    pub fn restore_comment(&mut self, message: String) {
+        self.input_mode = true;
+        self.sharing = false;
+        self.comment_editor.restore(message);
     }
 
     pub async fn load_more_comments(&mut self, api_client: &ApiClient) {
-        if !self.has_more_comments || self.loading_more_comments {
+        if !self.has_more_comments || self.loading_more_comments || self.loading_comments {
             return;
         }
 
@@ -153,9 +341,17 @@ impl VideoDetailPage {
         self.comment_page += 1;
         match api_client.get_comments(self.aid, self.comment_page).await {
             Ok(data) => {
-                if let Some(replies) = data.replies {
+                if let Some(mut replies) = data.replies {
+                    // The located comment is already on top
+                    if let Some(located) = &self.located {
+                        replies.retain(|c| c.rpid != located.rpid);
+                    }
                     if replies.is_empty() {
                         self.has_more_comments = false;
+                    } else if let Some(up_mid) = self.up_mid().filter(|_| self.up_only) {
+                        self.comments
+                            .extend(replies.iter().filter(|c| c.involves_up(up_mid)).cloned());
+                        self.all_comments.extend(replies);
                     } else {
                         self.comments.extend(replies);
                     }
@@ -165,9 +361,236 @@ impl VideoDetailPage {
             }
             Err(_) => {
                 self.comment_page -= 1;
+                self.pending_floor = None;
             }
         }
         self.loading_more_comments = false;
+
+        if let Some((floor, _)) = self.pending_floor {
+            if floor < self.comments.len() || !self.has_more_comments {
+                self.select_floor(floor);
+            }
+        }
+    }
+
+    /// Jump to the n-th comment (1-based), loading more pages when it isn't loaded yet
+    fn goto_floor(&mut self, floor: usize) -> AppAction {
+        // Floors count all comments
+        if self.up_only {
+            self.toggle_up_only();
+        }
+        let index = floor.max(1) - 1;
+        self.focus = DetailFocus::Comments;
+        if index < self.comments.len() || !self.has_more_comments {
+            self.select_floor(index);
+            return AppAction::None;
+        }
+        self.comment_scroll = self.comments.len().saturating_sub(1);
+        self.pending_floor = Some((index, MAX_FLOOR_LOAD_PAGES));
+        AppAction::LoadMoreComments
+    }
+
+    /// Select the comment a link pointed at, also once the first page arrives later
+    pub fn locate_comment(&mut self, comment: CommentItem) {
+        self.located = Some(comment);
+        self.place_located();
+    }
+
+    /// Select the located comment, putting it on top when it isn't on the first page
+    fn place_located(&mut self) {
+        let Some(located) = &self.located else {
+            return;
+        };
+        self.focus = DetailFocus::Comments;
+        self.comment_scroll = match self.comments.iter().position(|c| c.rpid == located.rpid) {
+            Some(index) => index,
+            None => {
+                self.comments.insert(0, located.clone());
+                0
+            }
+        };
+    }
+
+    /// Select a comment by index, clamped to the loaded comments
+    fn select_floor(&mut self, index: usize) {
+        self.pending_floor = None;
+        self.comment_scroll = index.min(self.comments.len().saturating_sub(1));
+    }
+
+    /// Whether a floor jump or the UP filter still needs another page
+    pub fn needs_more_comments(&mut self) -> bool {
+        if self.floor_needs_more() {
+            return true;
+        }
+        if self.up_only
+            && self.up_only_pages > 0
+            && self.has_more_comments
+            && self.comments.len() < UP_ONLY_MIN_COMMENTS
+        {
+            self.up_only_pages -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// Whether a floor jump still needs another page; counts down its page budget
+    fn floor_needs_more(&mut self) -> bool {
+        match &mut self.pending_floor {
+            Some((_, 0)) => {
+                let loaded = self.comments.len();
+                self.select_floor(loaded);
+                false
+            }
+            Some((_, pages_left)) if self.has_more_comments => {
+                *pages_left -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn up_mid(&self) -> Option<i64> {
+        self.video_info.as_ref().map(|info| info.owner.mid)
+    }
+
+    /// Switch between all comments and only those the UP wrote or replied under,
+    /// keeping the selected comment when it is in both
+    fn toggle_up_only(&mut self) -> AppAction {
+        let Some(up_mid) = self.up_mid() else {
+            return AppAction::None;
+        };
+        let selected = self.comments.get(self.comment_scroll).map(|c| c.rpid);
+        self.up_only = !self.up_only;
+        if self.up_only {
+            self.all_comments = std::mem::take(&mut self.comments);
+            self.comments = self
+                .all_comments
+                .iter()
+                .filter(|c| c.involves_up(up_mid))
+                .cloned()
+                .collect();
+        } else {
+            self.comments = std::mem::take(&mut self.all_comments);
+        }
+        self.pending_floor = None;
+        self.comment_scroll = selected
+            .and_then(|rpid| self.comments.iter().position(|c| c.rpid == rpid))
+            .unwrap_or(0);
+
+        // Few UP comments among the loaded pages, look further
+        if self.up_only && self.comments.len() < UP_ONLY_MIN_COMMENTS && self.has_more_comments {
+            self.up_only_pages = MAX_UP_ONLY_LOAD_PAGES;
+            return AppAction::LoadMoreComments;
+        }
+        AppAction::None
+    }
+
+    /// Keys while typing a floor number
+    fn handle_floor_input(&mut self, key: KeyCode) -> AppAction {
+        let Some(input) = self.floor_input.as_mut() else {
+            return AppAction::None;
+        };
+        match key {
+            KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 6 => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Enter => {
+                let floor = input.parse::<usize>().ok();
+                self.floor_input = None;
+                if let Some(floor) = floor {
+                    return self.goto_floor(floor);
+                }
+            }
+            KeyCode::Esc => self.floor_input = None,
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    /// Filter the episode list is shown with: the one being typed, or the applied one
+    fn episode_query(&self) -> &str {
+        self.episode_input
+            .as_deref()
+            .unwrap_or(&self.episode_filter)
+    }
+
+    /// Indices of the episodes shown in the list; a P number query filters nothing
+    fn episode_matches(&self) -> Vec<usize> {
+        let Some(pages) = self.get_pages() else {
+            return Vec::new();
+        };
+        let query = self.episode_query();
+        let filter = !query.trim().is_empty() && parse_episode_number(query).is_none();
+        pages
+            .iter()
+            .enumerate()
+            .filter(|(_, page)| !filter || fuzzy_match(&page.part, query))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Move the episode selection to the next or previous shown episode
+    fn move_episode(&mut self, down: bool) {
+        let matches = self.episode_matches();
+        let next = if down {
+            matches.iter().find(|&&idx| idx > self.episode_scroll)
+        } else {
+            matches.iter().rev().find(|&&idx| idx < self.episode_scroll)
+        };
+        if let Some(&idx) = next {
+            self.episode_scroll = idx;
+        }
+    }
+
+    /// Select the episode the query points at: its P number, or the first title match
+    fn select_episode_query(&mut self) {
+        let query = self.episode_query();
+        if let Some(number) = parse_episode_number(query) {
+            let index = self
+                .get_pages()
+                .and_then(|pages| pages.iter().position(|page| page.page as usize == number));
+            if let Some(index) = index {
+                self.episode_scroll = index;
+            }
+            return;
+        }
+        let matches = self.episode_matches();
+        if !matches.contains(&self.episode_scroll) {
+            if let Some(&first) = matches.first() {
+                self.episode_scroll = first;
+            }
+        }
+    }
+
+    /// Keys while typing a P number or title filter in the episode list
+    fn handle_episode_input(&mut self, key: KeyCode) -> AppAction {
+        let Some(input) = self.episode_input.as_mut() else {
+            return AppAction::None;
+        };
+        match key {
+            KeyCode::Char(c) if input.chars().count() < 30 => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Enter => {
+                let input = self.episode_input.take().unwrap_or_default();
+                // A P number only jumps, text stays as the list filter until Esc
+                self.episode_filter = if parse_episode_number(&input).is_some() {
+                    String::new()
+                } else {
+                    input.trim().to_string()
+                };
+                return AppAction::None;
+            }
+            KeyCode::Esc => {
+                self.episode_input = None;
+                return AppAction::None;
+            }
+            _ => return AppAction::None,
+        }
+        self.select_episode_query();
+        AppAction::None
     }
 
     pub async fn toggle_comment_replies(&mut self, api_client: &ApiClient) {
@@ -214,9 +637,16 @@ impl VideoDetailPage {
         self.related_card_grid.poll_cover_results();
     }
 
-    /// Start background downloads for visible related video covers
+    /// Start background downloads for related video covers.
+    ///
+    /// 评论加载完成前不抢带宽，除非焦点已切到相关推荐；未聚焦时只下载可见的封面，不预取
     pub fn start_cover_downloads(&mut self) {
-        self.related_card_grid.start_cover_downloads();
+        if self.focus == DetailFocus::Related {
+            self.related_card_grid.start_cover_downloads();
+        } else if !self.loading_comments {
+            self.related_card_grid
+                .start_cover_downloads_with_prefetch(0);
+        }
     }
 
     /// Check if scrolling near bottom of comments
@@ -233,7 +663,7 @@ impl VideoDetailPage {
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(theme.border_subtle))
             .title(Span::styled(
-                " 📹 视频信息 ",
+                t(" 📹 视频信息 "),
                 Style::default().fg(theme.bilibili_pink),
             ));
 
@@ -259,13 +689,59 @@ impl VideoDetailPage {
             );
             frame.render_widget(title, chunks[0]);
 
-            // Author
-            let author = Paragraph::new(format!("UP: {}", info.owner.name))
-                .style(Style::default().fg(theme.bilibili_pink));
+            // Author, publish time, category and badges
+            let muted = Style::default().fg(theme.fg_muted);
+            let mut spans = vec![Span::styled(
+                format!("UP: {}", info.owner.name),
+                Style::default().fg(theme.bilibili_pink),
+            )];
+            match self.following {
+                Some(true) => spans.push(Span::styled(t(" ✓已关注"), muted)),
+                Some(false) => spans.push(Span::styled(
+                    t(" +关注"),
+                    Style::default().fg(theme.bilibili_cyan),
+                )),
+                None => {}
+            }
+            if let Some(pubdate) = info.format_pubdate() {
+                spans.push(Span::styled(tf(" · 发布于 {}", &[&pubdate]), muted));
+            }
+            if !info.tname.is_empty() {
+                spans.push(Span::styled(format!(" · {}", info.tname), muted));
+            }
+            if let Some(label) = info.copyright_label() {
+                spans.push(Span::styled(
+                    format!(" [{}]", label),
+                    Style::default().fg(theme.bilibili_cyan),
+                ));
+            }
+            if info.is_upower_exclusive {
+                spans.push(Span::styled(
+                    t(" ⚡充电专属"),
+                    Style::default().fg(theme.warning),
+                ));
+            }
+            if info.is_interactive() {
+                spans.push(Span::styled(
+                    t(" 🎮互动视频"),
+                    Style::default().fg(theme.info),
+                ));
+            }
+            let author = Paragraph::new(Line::from(spans));
             frame.render_widget(author, chunks[1]);
 
-            // Stats
-            let stats = Paragraph::new(Line::from(vec![
+            // Stats, then honors such as 全站排行榜最高第x名; what the user already did is highlighted
+            let stat = Style::default().fg(theme.fg_secondary);
+            let done = Style::default()
+                .fg(theme.bilibili_pink)
+                .add_modifier(Modifier::BOLD);
+            let pick = |on: bool| if on { done } else { stat };
+            let coins = if self.relation.coin > 0 {
+                format!("{} (+{})", info.stat.format_coin(), self.relation.coin)
+            } else {
+                info.stat.format_coin()
+            };
+            let mut spans = vec![
                 Span::styled("▶ ", Style::default().fg(theme.fg_secondary)),
                 Span::styled(
                     info.stat.format_views(),
@@ -276,26 +752,31 @@ impl VideoDetailPage {
                     info.stat.format_danmaku(),
                     Style::default().fg(theme.fg_secondary),
                 ),
-                Span::styled(" · 👍 ", Style::default().fg(theme.fg_secondary)),
-                Span::styled(
-                    info.stat.format_like(),
-                    Style::default().fg(theme.fg_secondary),
-                ),
-                Span::styled(" · 💰 ", Style::default().fg(theme.fg_secondary)),
-                Span::styled(
-                    info.stat.format_coin(),
-                    Style::default().fg(theme.fg_secondary),
-                ),
-                Span::styled(" · ⭐ ", Style::default().fg(theme.fg_secondary)),
-                Span::styled(
-                    info.stat.format_favorite(),
-                    Style::default().fg(theme.fg_secondary),
-                ),
-            ]));
+                Span::styled(" · 👍 ", stat),
+                Span::styled(info.stat.format_like(), pick(self.relation.like)),
+                Span::styled(" · 💰 ", stat),
+                Span::styled(coins, pick(self.relation.coin > 0)),
+                Span::styled(" · ⭐ ", stat),
+                Span::styled(info.stat.format_favorite(), pick(self.relation.favorite)),
+            ];
+            for honor in info.honors().iter().filter(|h| !h.desc.is_empty()) {
+                spans.push(Span::styled(
+                    format!("  {}{}", honor.icon(), honor.desc),
+                    Style::default().fg(theme.warning),
+                ));
+            }
+            let stats = Paragraph::new(Line::from(spans));
             frame.render_widget(stats, chunks[2]);
 
-            // Description
-            if let Some(desc) = &info.desc {
+            // A video the account cannot play says so in place of the description
+            if let Some(restriction) = info.play_restriction() {
+                let notice = Paragraph::new(format!("⛔ {}", restriction)).style(
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
+                );
+                frame.render_widget(notice, chunks[3]);
+            } else if let Some(desc) = &info.desc {
                 let char_count = desc.chars().count();
                 let desc_text: String = if char_count > 100 {
                     desc.chars().take(100).collect::<String>() + "..."
@@ -308,14 +789,14 @@ impl VideoDetailPage {
                 frame.render_widget(description, chunks[3]);
             }
         } else {
-            let loading = Paragraph::new("加载中...")
+            let loading = Paragraph::new(t("加载中..."))
                 .style(Style::default().fg(theme.warning))
                 .alignment(Alignment::Center);
             frame.render_widget(loading, inner);
         }
     }
 
-    fn render_comments(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+    fn render_comments(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let is_focused = self.focus == DetailFocus::Comments;
         let border_style = if is_focused {
             Style::default().fg(theme.border_focused)
@@ -328,7 +809,14 @@ impl VideoDetailPage {
             .border_type(BorderType::Rounded)
             .border_style(border_style)
             .title(Span::styled(
-                " 💬 评论 ",
+                match self.comment_total.filter(|_| !self.comments.is_empty()) {
+                    _ if self.up_only => tf(" 💬 只看 UP · {} 条 ", &[&self.comments.len()]),
+                    Some(total) => tf(
+                        " 💬 评论 · 第 {} / 约 {} 条 ",
+                        &[&(self.comment_scroll + 1), &total],
+                    ),
+                    None => t(" 💬 评论 ").to_string(),
+                },
                 Style::default().fg(if is_focused {
                     theme.bilibili_pink
                 } else {
@@ -340,120 +828,229 @@ impl VideoDetailPage {
         frame.render_widget(block, area);
 
         if self.comments.is_empty() {
-            let empty = Paragraph::new("暂无评论")
-                .style(Style::default().fg(theme.fg_secondary))
-                .alignment(Alignment::Center);
-            frame.render_widget(empty, inner);
+            let empty = if self.loading_comments {
+                Paragraph::new(t("⏳ 加载评论中...")).style(Style::default().fg(theme.warning))
+            } else if let Some(error) = &self.comments_error {
+                Paragraph::new(format!("❌ {}", error)).style(Style::default().fg(theme.error))
+            } else if self.up_only && self.loading_more_comments {
+                Paragraph::new(t("⏳ 正在查找 UP 主的评论..."))
+                    .style(Style::default().fg(theme.warning))
+            } else if self.up_only {
+                Paragraph::new(t("已加载的评论中没有 UP 主的发言"))
+                    .style(Style::default().fg(theme.fg_secondary))
+            } else {
+                Paragraph::new(t("暂无评论")).style(Style::default().fg(theme.fg_secondary))
+            };
+            frame.render_widget(empty.alignment(Alignment::Center), inner);
             return;
         }
 
-        // Build all items with replies
-        let mut all_items = Vec::new();
+        // Slice to the visible range first, then build ListItems only for those rows
         let item_height = 3;
-
-        for (idx, comment) in self.comments.iter().enumerate() {
-            let is_selected = idx == self.comment_scroll;
-            let is_expanded = self.expanded_comment == Some(comment.rpid);
-
-            // Main comment
-            let reply_indicator = if comment.reply_count() > 0 {
-                if is_expanded {
-                    "▼"
-                } else {
-                    "▶"
-                }
-            } else {
-                " "
+        let visible_count = (inner.height as usize / item_height).max(1);
+        // Drawn over the right border
+        self.comment_scrollbar.render(
+            frame,
+            area.inner(Margin::new(0, 1)),
+            self.comments.len(),
+            self.comment_scroll,
+            visible_count,
+            theme,
+        );
+        let mut y = inner.y;
+        let mut thumbnails: Vec<(Rect, String)> = Vec::new();
+        for row in self
+            .comment_rows()
+            .skip(self.comment_scroll)
+            .take(visible_count)
+        {
+            if y >= inner.bottom() {
+                break;
+            }
+            let (text, pictures) = match row {
+                CommentRow::Comment(idx) => (
+                    self.build_comment_item(idx, theme),
+                    self.comments[idx].pictures(),
+                ),
+                CommentRow::LoadingReplies => (
+                    Text::from(Line::from(Span::styled(
+                        t("  ⏳ 加载回复中..."),
+                        Style::default().fg(theme.warning),
+                    ))),
+                    Vec::new(),
+                ),
+                CommentRow::Reply(reply) => (Self::build_reply_item(reply, theme), Vec::new()),
             };
+            let height = (text.height() as u16).min(inner.bottom() - y);
+            frame.render_widget(
+                Paragraph::new(text),
+                Rect::new(inner.x, y, inner.width, height),
+            );
+            y += height;
 
-            let lines = vec![
-                Line::from(vec![
-                    Span::styled(
-                        format!("{} ", reply_indicator),
-                        Style::default().fg(theme.fg_accent),
-                    ),
-                    Span::styled(
-                        comment.author_name(),
-                        Style::default()
-                            .fg(theme.bilibili_pink)
-                            .add_modifier(if is_selected {
-                                Modifier::BOLD
-                            } else {
-                                Modifier::empty()
-                            }),
-                    ),
+            // Thumbnail strip under image comments, only when it fits entirely
+            if pictures.is_empty() || y + THUMBNAIL_HEIGHT > inner.bottom() {
+                continue;
+            }
+            let mut x = inner.x + 2;
+            for url in pictures.iter().take(MAX_THUMBNAILS) {
+                let width = THUMBNAIL_WIDTH.min(inner.right().saturating_sub(x));
+                if width < 4 {
+                    break;
+                }
+                thumbnails.push((Rect::new(x, y, width, THUMBNAIL_HEIGHT), url.to_string()));
+                x += width + 1;
+            }
+            if pictures.len() > MAX_THUMBNAILS && x + 4 <= inner.right() {
+                frame.render_widget(
                     Span::styled(
-                        format!("  {}", comment.format_time()),
+                        format!("+{}", pictures.len() - MAX_THUMBNAILS),
                         Style::default().fg(theme.fg_secondary),
                     ),
-                ]),
-                Line::from(vec![Span::styled(
-                    truncate_str(comment.message(), 60),
-                    Style::default().fg(theme.fg_primary),
-                )]),
-                Line::from(vec![Span::styled(
-                    format!(
-                        "👍 {}  💬 {} 回复",
-                        comment.format_like(),
-                        comment.reply_count()
-                    ),
-                    Style::default().fg(theme.fg_secondary),
-                )]),
-            ];
-            all_items.push(ListItem::new(lines));
+                    Rect::new(x, y + THUMBNAIL_HEIGHT / 2, 4, 1),
+                );
+            }
+            y += THUMBNAIL_HEIGHT;
+        }
 
-            // Show replies if expanded
-            if is_expanded {
-                if self.loading_replies {
-                    all_items.push(ListItem::new(vec![Line::from(vec![Span::styled(
-                        "  ⏳ 加载回复中...",
-                        Style::default().fg(theme.warning),
-                    )])]));
+        // The viewer draws the same pictures full size on top, don't fight over them
+        if self.image_viewer.is_some() {
+            return;
+        }
+        for (rect, url) in thumbnails {
+            self.comment_images.request(&url);
+            self.comment_images.render(frame, rect, &url, theme);
+        }
+    }
+
+    /// Lazily flatten comments and the expanded replies into display rows
+    fn comment_rows(&self) -> impl Iterator<Item = CommentRow<'_>> {
+        self.comments
+            .iter()
+            .enumerate()
+            .flat_map(move |(idx, comment)| {
+                let expanded = self.expanded_comment == Some(comment.rpid);
+                let loading = expanded && self.loading_replies;
+                let replies: &[CommentItem] = if expanded && !loading {
+                    &self.comment_replies
                 } else {
-                    for reply in &self.comment_replies {
-                        let reply_lines = vec![
-                            Line::from(vec![
-                                Span::styled("    ↳ ", Style::default().fg(theme.fg_secondary)),
-                                Span::styled(
-                                    reply.author_name(),
-                                    Style::default().fg(Color::Rgb(150, 150, 200)),
-                                ),
-                                Span::styled(
-                                    format!("  {}", reply.format_time()),
-                                    Style::default().fg(theme.fg_secondary),
-                                ),
-                            ]),
-                            Line::from(vec![
-                                Span::styled("      ", Style::default()),
-                                Span::styled(
-                                    truncate_str(reply.message(), 55),
-                                    Style::default().fg(theme.fg_primary),
-                                ),
-                            ]),
-                            Line::from(vec![
-                                Span::styled("      ", Style::default()),
-                                Span::styled(
-                                    format!("👍 {}", reply.format_like()),
-                                    Style::default().fg(theme.fg_secondary),
-                                ),
-                            ]),
-                        ];
-                        all_items.push(ListItem::new(reply_lines));
-                    }
-                }
+                    &[]
+                };
+                std::iter::once(CommentRow::Comment(idx))
+                    .chain(loading.then_some(CommentRow::LoadingReplies))
+                    .chain(replies.iter().map(CommentRow::Reply))
+            })
+    }
+
+    fn build_comment_item(&self, idx: usize, theme: &Theme) -> Text<'_> {
+        let comment = &self.comments[idx];
+        let is_selected = idx == self.comment_scroll;
+        let is_expanded = self.expanded_comment == Some(comment.rpid);
+
+        let reply_indicator = if comment.reply_count() > 0 {
+            if is_expanded {
+                "▼"
+            } else {
+                "▶"
             }
+        } else {
+            " "
+        };
+
+        let mut header = vec![
+            Span::styled(
+                format!("{} ", reply_indicator),
+                Style::default().fg(theme.fg_accent),
+            ),
+            Span::styled(
+                comment.author_name(),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(if is_selected {
+                        Modifier::BOLD
+                    } else {
+                        Modifier::empty()
+                    }),
+            ),
+        ];
+        if self.up_mid() == Some(comment.mid) {
+            header.push(Span::styled(" UP ", Style::default().fg(theme.warning)));
+        }
+        if self
+            .located
+            .as_ref()
+            .is_some_and(|c| c.rpid == comment.rpid)
+        {
+            header.push(Span::styled(
+                " 📍",
+                Style::default().fg(theme.bilibili_cyan),
+            ));
+        }
+        header.push(Span::styled(
+            format!("  {}", comment.format_time()),
+            Style::default().fg(theme.fg_secondary),
+        ));
+
+        let vote = comment.vote(&self.comment_votes);
+        let mut vote_line = vec![Span::styled(
+            tf(
+                "👍 {}  💬 {} 回复",
+                &[&comment.format_like(), &comment.reply_count()],
+            ),
+            Style::default().fg(theme.fg_secondary),
+        )];
+        if let Some(label) = vote.label() {
+            let color = if vote == CommentVote::Like {
+                theme.bilibili_pink
+            } else {
+                theme.fg_secondary
+            };
+            vote_line.push(Span::styled(
+                format!("  {}", label),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
         }
 
-        // Calculate scroll and visible items
-        let visible_count = (inner.height as usize / item_height).max(1);
-        let display_items: Vec<ListItem> = all_items
-            .into_iter()
-            .skip(self.comment_scroll)
-            .take(visible_count)
-            .collect();
+        let lines = vec![
+            Line::from(header),
+            Line::from(vec![Span::styled(
+                truncate_chars(comment.message(), 60),
+                Style::default().fg(theme.fg_primary),
+            )]),
+            Line::from(vote_line),
+        ];
+        Text::from(lines)
+    }
 
-        let list = List::new(display_items);
-        frame.render_widget(list, inner);
+    fn build_reply_item<'a>(reply: &'a CommentItem, theme: &Theme) -> Text<'a> {
+        let reply_lines = vec![
+            Line::from(vec![
+                Span::styled("    ↳ ", Style::default().fg(theme.fg_secondary)),
+                Span::styled(
+                    reply.author_name(),
+                    Style::default().fg(Color::Rgb(150, 150, 200)),
+                ),
+                Span::styled(
+                    format!("  {}", reply.format_time()),
+                    Style::default().fg(theme.fg_secondary),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("      ", Style::default()),
+                Span::styled(
+                    truncate_chars(reply.message(), 55),
+                    Style::default().fg(theme.fg_primary),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("      ", Style::default()),
+                Span::styled(
+                    format!("👍 {}", reply.format_like()),
+                    Style::default().fg(theme.fg_secondary),
+                ),
+            ]),
+        ];
+        Text::from(reply_lines)
     }
 
     fn render_related(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
@@ -469,7 +1066,7 @@ impl VideoDetailPage {
             .border_type(BorderType::Rounded)
             .border_style(border_style)
             .title(Span::styled(
-                " 📺 相关推荐 ",
+                t(" 📺 相关推荐 "),
                 Style::default().fg(if is_focused {
                     theme.bilibili_pink
                 } else {
@@ -481,10 +1078,17 @@ impl VideoDetailPage {
         frame.render_widget(block, area);
 
         if self.related_card_grid.cards.is_empty() {
-            let empty = Paragraph::new("暂无相关视频")
-                .style(Style::default().fg(theme.fg_secondary))
-                .alignment(Alignment::Center);
-            frame.render_widget(empty, inner);
+            let empty = if self.loading_related {
+                Paragraph::new(t("⏳ 加载相关视频中...")).style(Style::default().fg(theme.warning))
+            } else if let Some(error) = &self.related_error {
+                Paragraph::new(format!("❌ {}", error)).style(Style::default().fg(theme.error))
+            } else {
+                Paragraph::new(t("暂无相关视频")).style(Style::default().fg(theme.fg_secondary))
+            };
+            frame.render_widget(
+                empty.alignment(Alignment::Center).wrap(Wrap { trim: true }),
+                inner,
+            );
             return;
         }
 
@@ -504,6 +1108,43 @@ impl VideoDetailPage {
             .unwrap_or(false)
     }
 
+    /// Play the current episode from a time point in the selected comment;
+    /// pressing again on the same comment moves on to its next time point
+    fn play_comment_timestamp(&mut self) -> Option<AppAction> {
+        let comment = self.comments.get(self.comment_scroll)?;
+        let page = self
+            .get_pages()
+            .and_then(|pages| pages.get(self.current_page_index));
+        let (cid, duration, page_num) = match (page, &self.video_info) {
+            (Some(page), _) => (page.cid, page.duration, Some(page.page)),
+            (None, Some(info)) => (info.cid, info.duration.unwrap_or(0), None),
+            (None, None) => return None,
+        };
+
+        let timestamps: Vec<i64> = comment
+            .timestamps()
+            .into_iter()
+            .filter(|&ts| duration <= 0 || ts < duration)
+            .collect();
+        if timestamps.is_empty() {
+            return None;
+        }
+        let index = match self.play_at_cursor {
+            Some((rpid, last)) if rpid == comment.rpid => (last + 1) % timestamps.len(),
+            _ => 0,
+        };
+        self.play_at_cursor = Some((comment.rpid, index));
+
+        Some(AppAction::PlayVideoAt {
+            bvid: self.bvid.clone(),
+            aid: self.aid,
+            cid,
+            duration,
+            page_num,
+            start: timestamps[index],
+        })
+    }
+
     /// Get the video pages
     fn get_pages(&self) -> Option<&Vec<crate::api::video::VideoPage>> {
         self.video_info
@@ -523,13 +1164,22 @@ impl VideoDetailPage {
             Some(p) => p,
             None => return,
         };
+        let matches = self.episode_matches();
 
+        let title = if matches.len() == pages.len() {
+            tf(" 📑 选集 ({}) ", &[&pages.len()])
+        } else {
+            tf(
+                " 📑 选集 ({}/{}) 过滤: {} ",
+                &[&matches.len(), &pages.len(), &self.episode_query().trim()],
+            )
+        };
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style)
             .title(Span::styled(
-                format!(" 📑 选集 ({}) ", pages.len()),
+                title,
                 Style::default().fg(if is_focused {
                     theme.bilibili_pink
                 } else {
@@ -540,16 +1190,28 @@ impl VideoDetailPage {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
+        if matches.is_empty() {
+            let empty = Paragraph::new(t("没有匹配的选集"))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.fg_muted));
+            frame.render_widget(empty, inner);
+            return;
+        }
+
         let visible_count = inner.height as usize;
-        let scroll_offset = if self.episode_scroll >= visible_count {
-            self.episode_scroll - visible_count + 1
+        let selected_row = matches
+            .iter()
+            .position(|&idx| idx == self.episode_scroll)
+            .unwrap_or(0);
+        let scroll_offset = if selected_row >= visible_count {
+            selected_row - visible_count + 1
         } else {
             0
         };
 
-        let items: Vec<ListItem> = pages
+        let items: Vec<ListItem> = matches
             .iter()
-            .enumerate()
+            .map(|&idx| (idx, &pages[idx]))
             .skip(scroll_offset)
             .take(visible_count)
             .map(|(idx, page)| {
@@ -564,7 +1226,7 @@ impl VideoDetailPage {
                 };
 
                 let prefix = if is_current { "▶ " } else { "  " };
-                let title = truncate_str(&page.part, 30);
+                let title = truncate_chars(&page.part, 30);
 
                 let style = if is_selected && is_focused {
                     Style::default()
@@ -594,6 +1256,65 @@ impl VideoDetailPage {
         let list = List::new(items);
         frame.render_widget(list, inner);
     }
+
+    /// Like or dislike the selected comment, or take the vote back
+    fn vote_selected_comment(&self, pressed: CommentVote) -> AppAction {
+        match self.comments.get(self.comment_scroll) {
+            Some(comment) => {
+                let from = comment.vote(&self.comment_votes);
+                AppAction::VoteComment {
+                    oid: self.aid,
+                    rpid: comment.rpid,
+                    comment_type: 1,
+                    from,
+                    to: from.toggled(pressed),
+                }
+            }
+            None => AppAction::None,
+        }
+    }
+
+    /// Keys while the picture viewer is open
+    fn handle_viewer_input(&mut self, key: KeyCode, keys: &Keybindings) -> AppAction {
+        let Some(viewer) = self.image_viewer.as_mut() else {
+            return AppAction::None;
+        };
+        match viewer.handle_key(key, keys) {
+            ViewerEvent::None => AppAction::None,
+            ViewerEvent::Close => {
+                self.image_viewer = None;
+                AppAction::None
+            }
+            ViewerEvent::Confirm => self.vote_selected_comment(CommentVote::Like),
+        }
+    }
+
+    /// Keys while the comment editor is open
+    fn handle_comment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> AppAction {
+        match self.comment_editor.handle_key(key, modifiers) {
+            EditorEvent::None => AppAction::None,
+            EditorEvent::Cancel => {
+                self.input_mode = false;
+                AppAction::None
+            }
+            EditorEvent::Submit(text) if self.sharing => {
+                self.input_mode = false;
+                AppAction::ShareToDynamic {
+                    aid: self.aid,
+                    text,
+                }
+            }
+            EditorEvent::Submit(message) => {
+                self.input_mode = false;
+                AppAction::AddComment {
+                    oid: self.aid,
+                    comment_type: 1, // Video comment type
+                    message,
+                    root: None,
+                }
+            }
+        }
+    }
 }
 
 impl Component for VideoDetailPage {
@@ -603,9 +1324,9 @@ impl Component for VideoDetailPage {
             Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(6), // Video info
-                    Constraint::Min(8),    // Comments + Related
-                    Constraint::Length(3), // Input box
+                    Constraint::Length(6),                                      // Video info
+                    Constraint::Min(8), // Comments + Related
+                    Constraint::Length(self.comment_editor.height(area.width)), // Input box
                     Constraint::Length(2), // Help
                 ])
                 .split(area)
@@ -623,17 +1344,12 @@ impl Component for VideoDetailPage {
         // Video info
         self.render_video_info(frame, chunks[0], theme);
 
-        if self.loading {
-            let loading = Paragraph::new("⏳ 加载中...")
-                .style(Style::default().fg(theme.warning))
-                .alignment(Alignment::Center)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_type(BorderType::Rounded),
-                );
-            frame.render_widget(loading, chunks[1]);
-        } else if let Some(error) = &self.error_message {
+        // Comments and related videos render on their own; only a failed info request blocks the page
+        if let Some(error) = self
+            .error_message
+            .as_ref()
+            .filter(|_| self.video_info.is_none())
+        {
             let error_widget = Paragraph::new(format!("❌ {}", error))
                 .style(Style::default().fg(theme.error))
                 .alignment(Alignment::Center)
@@ -653,6 +1369,7 @@ impl Component for VideoDetailPage {
                 ])
                 .split(chunks[1]);
 
+            self.comment_images.poll();
             self.render_comments(frame, content_chunks[0], theme);
 
             // Right panel: Episodes (if multi-part) + Related videos
@@ -672,24 +1389,21 @@ impl Component for VideoDetailPage {
             }
         }
 
-        // Input box (only in input mode)
-        if self.input_mode {
-            let input_block = Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(theme.bilibili_pink))
-                .title(Span::styled(
-                    " ✏️ 发表评论 ",
-                    Style::default()
-                        .fg(theme.bilibili_pink)
-                        .add_modifier(Modifier::BOLD),
-                ));
+        if let Some(viewer) = &self.image_viewer {
+            viewer.render(frame, chunks[1], &mut self.comment_images, theme, keys);
+        }
 
-            let input_text = format!("{}_", self.input_buffer);
-            let input = Paragraph::new(input_text)
-                .style(Style::default().fg(theme.fg_primary))
-                .block(input_block);
-            frame.render_widget(input, chunks[2]);
+        // Input box (only in input mode)
+        if self.input_mode && self.sharing {
+            self.comment_editor.draw_titled(
+                frame,
+                chunks[2],
+                theme,
+                t(" 🔁 转发到动态 "),
+                t(" 👀 预览转发 "),
+            );
+        } else if self.input_mode {
+            self.comment_editor.draw(frame, chunks[2], theme);
         }
 
         // Help
@@ -699,18 +1413,16 @@ impl Component for VideoDetailPage {
             chunks[2]
         };
         let help_text = if self.input_mode {
-            format!("[{}] 发送评论  [{}] 取消", keys.confirm, keys.back)
-        } else {
-            format!("[{}/{}] 滚动  [{}] 切换  [{}] 点赞/选择  [{}] 评论  [{}] 回复  [{}] 播放  [{}] 返回",
-                keys.nav_up,
-                keys.nav_down,
-                keys.nav_next_page,
-                keys.confirm,
-                keys.comment,
-                keys.toggle_replies,
-                keys.play,
-                keys.back
+            self.comment_editor.help_text(keys)
+        } else if let Some(input) = &self.floor_input {
+            tf("跳转到楼层: {}_  [Enter] 确认  [Esc] 取消", &[&input])
+        } else if let Some(input) = &self.episode_input {
+            tf(
+                "选集: {}_  输入 P 号跳转，其他文字按标题过滤  [Enter] 确认  [Esc] 取消",
+                &[&input],
             )
+        } else {
+            tf("[{}/{}] 滚动  [{}] 切换  [{}] 点赞/选择  [{}] 点踩  [{}] 评论  [{}] 回复  [{}] UP空间  [{}] 播放  [{}/{}/{}/{}] 视频点赞/投币/收藏/三连  [{}] 返回", &[&keys.nav_up, &keys.nav_down, &keys.nav_next_page, &keys.confirm, &keys.dislike_comment, &keys.comment, &keys.toggle_replies, &keys.open_space, &keys.play, &keys.like_video, &keys.coin_video, &keys.fav_video, &keys.triple, &keys.back])
         };
         let help = Paragraph::new(help_text)
             .style(Style::default().fg(theme.fg_secondary))
@@ -723,44 +1435,33 @@ impl Component for VideoDetailPage {
         key: KeyCode,
         keys: &crate::storage::Keybindings,
     ) -> Option<AppAction> {
-        // Handle input mode for adding comments
         if self.input_mode {
-            match key {
-                KeyCode::Esc => {
-                    self.input_mode = false;
-                    self.input_buffer.clear();
-                    return Some(AppAction::None);
-                }
-                KeyCode::Enter => {
-                    if !self.input_buffer.is_empty() {
-                        let message = self.input_buffer.clone();
-                        self.input_buffer.clear();
-                        self.input_mode = false;
-                        return Some(AppAction::AddComment {
-                            oid: self.aid,
-                            comment_type: 1, // Video comment type
-                            message,
-                            root: None,
-                        });
-                    }
-                    return Some(AppAction::None);
-                }
-                KeyCode::Backspace => {
-                    self.input_buffer.pop();
-                    return Some(AppAction::None);
-                }
-                KeyCode::Char(c) => {
-                    self.input_buffer.push(c);
-                    return Some(AppAction::None);
-                }
-                _ => return Some(AppAction::None),
-            }
+            return Some(self.handle_comment_input(key, KeyModifiers::NONE));
+        }
+        if self.floor_input.is_some() {
+            return Some(self.handle_floor_input(key));
+        }
+        if self.episode_input.is_some() {
+            return Some(self.handle_episode_input(key));
+        }
+        if self.image_viewer.is_some() {
+            return Some(self.handle_viewer_input(key, keys));
         }
 
+        if key == KeyCode::Esc
+            && self.focus == DetailFocus::Episodes
+            && !self.episode_filter.is_empty()
+        {
+            self.episode_filter.clear();
+            return Some(AppAction::None);
+        }
         if keys.matches_quit(key) || keys.matches_back(key) {
             return Some(AppAction::BackToList);
         }
         if keys.matches_play(key) {
+            if let Some(restriction) = self.video_info.as_ref().and_then(|i| i.play_restriction()) {
+                return Some(AppAction::ShowError(restriction.to_string()));
+            }
             // For multi-part videos, use PlayVideoWithPages for auto-play next
             if let Some(pages) = self.get_pages() {
                 if pages.len() > 1 {
@@ -794,10 +1495,11 @@ impl Component for VideoDetailPage {
                 duration,
             });
         }
-        if keys.matches_comment(key) {
-            // Enter comment input mode
+        if keys.matches_comment(key) || keys.matches_share_dynamic(key) {
+            // Enter comment input mode, or write the comment of a share
             self.input_mode = true;
-            self.input_buffer.clear();
+            self.sharing = keys.matches_share_dynamic(key);
+            self.comment_editor.clear();
             return Some(AppAction::None);
         }
         if keys.matches_toggle_replies(key) {
@@ -806,6 +1508,107 @@ impl Component for VideoDetailPage {
             }
             return Some(AppAction::None);
         }
+        if keys.matches_follow_link(key) {
+            // Jump to the first link in the selected comment, or in the description
+            let text = match self.focus {
+                DetailFocus::Comments => self
+                    .comments
+                    .get(self.comment_scroll)
+                    .and_then(|c| c.content.as_ref())
+                    .and_then(|c| c.message.as_deref()),
+                _ => None,
+            }
+            .filter(|text| link::contains_link(text))
+            .or_else(|| {
+                self.video_info
+                    .as_ref()
+                    .and_then(|info| info.desc.as_deref())
+                    .filter(|desc| link::contains_link(desc))
+            });
+            if let Some(text) = text {
+                return Some(AppAction::OpenLink(text.to_string()));
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_play_at(key) {
+            if self.focus == DetailFocus::Comments {
+                return Some(self.play_comment_timestamp().unwrap_or(AppAction::None));
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_up_only_comments(key) {
+            self.focus = DetailFocus::Comments;
+            return Some(self.toggle_up_only());
+        }
+        if keys.matches_like_video(key) {
+            return Some(AppAction::LikeVideo);
+        }
+        if keys.matches_coin_video(key) {
+            return Some(AppAction::CoinVideo);
+        }
+        if keys.matches_fav_video(key) {
+            return Some(AppAction::FavoriteVideo);
+        }
+        if keys.matches_triple(key) {
+            return Some(AppAction::TripleVideo);
+        }
+        if keys.matches_quality(key) {
+            // Qualities of the episode that plays next
+            let cid = self
+                .get_pages()
+                .and_then(|pages| pages.get(self.current_page_index))
+                .map(|page| page.cid)
+                .or_else(|| self.video_info.as_ref().map(|info| info.cid));
+            return Some(
+                cid.map_or(AppAction::None, |cid| AppAction::OpenQualityMenu {
+                    bvid: self.bvid.clone(),
+                    cid,
+                }),
+            );
+        }
+        if keys.matches_follow_up(key) {
+            return Some(
+                self.up_mid()
+                    .map_or(AppAction::None, AppAction::ToggleFollow),
+            );
+        }
+        if keys.matches_dislike_comment(key) {
+            if self.focus == DetailFocus::Comments {
+                return Some(self.vote_selected_comment(CommentVote::Dislike));
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_open_comment_web(key) {
+            // Votes, notes etc. can't be shown here, so let the web page jump to the floor
+            let anchor = match self.focus {
+                DetailFocus::Comments => self
+                    .comments
+                    .get(self.comment_scroll)
+                    .map(|c| format!("#reply{}", c.rpid)),
+                _ => None,
+            }
+            .unwrap_or_else(|| "#comment".to_string());
+            return Some(AppAction::OpenInBrowser(format!(
+                "https://www.bilibili.com/video/{}{}",
+                self.bvid, anchor
+            )));
+        }
+        if keys.matches_goto_floor(key) {
+            if self.focus == DetailFocus::Episodes {
+                self.episode_input = Some(String::new());
+            } else if !self.comments.is_empty() {
+                self.floor_input = Some(String::new());
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_open_space(key) {
+            if self.focus == DetailFocus::Comments {
+                if let Some(comment) = self.comments.get(self.comment_scroll) {
+                    return Some(AppAction::OpenUserSpace(comment.mid));
+                }
+            }
+            return Some(AppAction::None);
+        }
         // Tab switches focus between Comments, Episodes, and Related (page-specific, not nav)
         if key == KeyCode::Tab {
             self.focus = if self.has_multiple_pages() {
@@ -837,13 +1640,7 @@ impl Component for VideoDetailPage {
                         return Some(AppAction::LoadMoreComments);
                     }
                 }
-                DetailFocus::Episodes => {
-                    if let Some(pages) = self.get_pages() {
-                        if self.episode_scroll + 1 < pages.len() {
-                            self.episode_scroll += 1;
-                        }
-                    }
-                }
+                DetailFocus::Episodes => self.move_episode(true),
                 DetailFocus::Related => {
                     if self.related_card_grid.move_down() {
                         self.related_scroll = self.related_card_grid.selected_index;
@@ -859,11 +1656,7 @@ impl Component for VideoDetailPage {
                         self.comment_scroll -= 1;
                     }
                 }
-                DetailFocus::Episodes => {
-                    if self.episode_scroll > 0 {
-                        self.episode_scroll -= 1;
-                    }
-                }
+                DetailFocus::Episodes => self.move_episode(false),
                 DetailFocus::Related => {
                     if self.related_card_grid.move_up() {
                         self.related_scroll = self.related_card_grid.selected_index;
@@ -887,15 +1680,18 @@ impl Component for VideoDetailPage {
         if keys.matches_confirm(key) {
             match self.focus {
                 DetailFocus::Comments => {
-                    // Like the currently selected comment
-                    if self.comment_scroll < self.comments.len() {
-                        let comment = &self.comments[self.comment_scroll];
-                        return Some(AppAction::LikeComment {
-                            oid: self.aid,
-                            rpid: comment.rpid,
-                            comment_type: 1,
-                        });
+                    // Image comments open their pictures, liking moves into the viewer
+                    let pictures = self
+                        .comments
+                        .get(self.comment_scroll)
+                        .map(|c| c.pictures())
+                        .unwrap_or_default();
+                    if !pictures.is_empty() {
+                        let urls = pictures.into_iter().map(str::to_string).collect();
+                        self.image_viewer = Some(ImageViewer::new(urls));
+                        return Some(AppAction::None);
                     }
+                    return Some(self.vote_selected_comment(CommentVote::Like));
                 }
                 DetailFocus::Episodes => {
                     // Select and play the episode with auto-advance
@@ -925,8 +1721,38 @@ impl Component for VideoDetailPage {
         Some(AppAction::None)
     }
 
-    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) -> Option<AppAction> {
+    fn handle_input_with_modifiers(
+        &mut self,
+        key: KeyCode,
+        modifiers: KeyModifiers,
+        keys: &Keybindings,
+    ) -> Option<AppAction> {
         if self.input_mode {
+            return Some(self.handle_comment_input(key, modifiers));
+        }
+        self.handle_input(key, keys)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        if self.input_mode || self.image_viewer.is_some() {
+            return None;
+        }
+
+        // Dragging a scrollbar jumps straight to a position
+        if let Some(position) = self.comment_scrollbar.handle_mouse(&event) {
+            self.focus = DetailFocus::Comments;
+            self.comment_scroll = position;
+            if self.is_near_comments_bottom(10)
+                && self.has_more_comments
+                && !self.loading_more_comments
+            {
+                return Some(AppAction::LoadMoreComments);
+            }
+            return None;
+        }
+        if self.related_card_grid.handle_scrollbar_mouse(&event) {
+            self.focus = DetailFocus::Related;
+            self.related_scroll = self.related_card_grid.selected_index;
             return None;
         }
 
@@ -949,13 +1775,7 @@ impl Component for VideoDetailPage {
                             self.related_scroll = self.related_card_grid.selected_index;
                         }
                     }
-                    DetailFocus::Episodes => {
-                        if let Some(pages) = self.get_pages() {
-                            if self.episode_scroll + 1 < pages.len() {
-                                self.episode_scroll += 1;
-                            }
-                        }
-                    }
+                    DetailFocus::Episodes => self.move_episode(true),
                 }
                 None
             }
@@ -971,11 +1791,7 @@ impl Component for VideoDetailPage {
                             self.related_scroll = self.related_card_grid.selected_index;
                         }
                     }
-                    DetailFocus::Episodes => {
-                        if self.episode_scroll > 0 {
-                            self.episode_scroll -= 1;
-                        }
-                    }
+                    DetailFocus::Episodes => self.move_episode(false),
                 }
                 None
             }
@@ -984,84 +1800,92 @@ impl Component for VideoDetailPage {
                     return None;
                 }
 
-                let chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([
-                        Constraint::Length(6),
-                        Constraint::Min(10),
-                        Constraint::Length(2),
-                    ])
-                    .split(area);
-
-                if self.loading {
+                if self.error_message.is_some() && self.video_info.is_none() {
                     return None;
                 }
 
-                if let Some(error) = &self.error_message {
-                    if error.contains("视频信息") || error.contains("加载视频") {
-                        return None;
-                    }
-                }
-
-                let content_chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-                    .split(chunks[1]);
-
-                let related_area = content_chunks[1];
+                let click_idx = self.related_card_grid.index_at(event.column, event.row)?;
 
-                if !related_area.contains(ratatui::layout::Position::new(event.column, event.row)) {
-                    return None;
-                }
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
 
-                let relative_y = event.row - related_area.y;
-                let click_row = (relative_y / self.related_card_grid.card_height) as usize;
-                let actual_row = self.related_card_grid.scroll_row + click_row;
-
-                let card_width = related_area.width / self.related_card_grid.columns as u16;
-                let click_col = (event.column.saturating_sub(related_area.x) / card_width) as usize;
-
-                let click_idx = actual_row * self.related_card_grid.columns + click_col;
-
-                if click_idx < self.related_card_grid.cards.len() {
-                    let now = Instant::now();
-                    let is_double_click = self.last_click_index == Some(click_idx)
-                        && self
-                            .last_click_time
-                            .is_some_and(|t| now.duration_since(t).as_millis() < 500);
-
-                    if is_double_click {
-                        self.last_click_time = None;
-                        self.last_click_index = None;
-                        if let Some(card) = self.related_card_grid.cards.get(click_idx) {
-                            if let Some(ref bvid) = card.bvid {
-                                let aid = card.aid.unwrap_or(0);
-                                return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
-                            }
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some(card) = self.related_card_grid.cards.get(click_idx) {
+                        if let Some(ref bvid) = card.bvid {
+                            let aid = card.aid.unwrap_or(0);
+                            return Some(AppAction::OpenVideoDetail(bvid.clone(), aid));
                         }
-                    } else {
-                        self.related_card_grid.selected_index = click_idx;
-                        self.related_card_grid
-                            .update_scroll(self.related_card_grid.cached_visible_rows);
-                        self.related_scroll = self.related_card_grid.selected_index;
-                        self.last_click_time = Some(now);
-                        self.last_click_index = Some(click_idx);
                     }
+                } else {
+                    self.related_card_grid.select(click_idx);
+                    self.related_scroll = self.related_card_grid.selected_index;
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
                 }
                 None
             }
             _ => None,
         }
     }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.poll_load_results();
+        self.poll_cover_results();
+        if !ctx.throttled {
+            self.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn is_text_input_active(&self) -> bool {
+        self.input_mode || self.floor_input.is_some() || self.episode_input.is_some()
+    }
+
+    fn is_full_screen(&self) -> bool {
+        true
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.related_card_grid.blocklist = blocklist;
+    }
+}
+
+/// P number typed in the episode list: "37", "p37" or "p 37"
+fn parse_episode_number(query: &str) -> Option<usize> {
+    let query = query.trim();
+    let number = query.strip_prefix(['p', 'P']).unwrap_or(query).trim_start();
+    number.parse().ok().filter(|&n| n > 0)
+}
+
+/// Whether every character of the query appears in the title in order, ignoring case
+fn fuzzy_match(title: &str, query: &str) -> bool {
+    let mut title = title.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|q| title.any(|c| c == q))
 }
 
-fn truncate_str(s: &str, max_len: usize) -> String {
-    if s.chars().count() > max_len {
-        s.chars()
-            .take(max_len.saturating_sub(3))
-            .collect::<String>()
-            + "..."
-    } else {
-        s.to_string()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_episode_query() {
+        assert_eq!(parse_episode_number("37"), Some(37));
+        assert_eq!(parse_episode_number("p 37"), Some(37));
+        assert_eq!(parse_episode_number("P37"), Some(37));
+        assert_eq!(parse_episode_number("p0"), None);
+        assert_eq!(parse_episode_number("pv"), None);
+
+        assert!(fuzzy_match("第37集 Rust 入门", "rust"));
+        assert!(fuzzy_match("Chapter 12 Ownership", "ch12 own"));
+        assert!(!fuzzy_match("Chapter 12", "21"));
     }
 }
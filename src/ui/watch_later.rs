@@ -0,0 +1,511 @@
+//! Watch later page: the 稍后再看 list in a grid, sortable by progress,
+//! with a batch removal of the videos already watched or checked in multi-select mode
+
+use super::multi_select::{MultiSelect, MultiSelectKey};
+use super::video_card::{CardBadge, VideoCard, VideoCardGrid};
+use super::{Component, PageContext, Theme};
+use crate::api::client::ApiClient;
+use crate::api::watch_later::WatchLaterItem;
+use crate::app::AppAction;
+use crate::i18n::{t, tf};
+use crate::storage::{Keybindings, TitleBlocklist};
+use futures_util::future::LocalBoxFuture;
+use ratatui::{
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    prelude::*,
+    widgets::*,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortOrder {
+    /// Newest added first, as returned by the API
+    Added,
+    /// Least watched first, finished ones at the end
+    Progress,
+}
+
+impl SortOrder {
+    const ALL: [SortOrder; 2] = [SortOrder::Added, SortOrder::Progress];
+
+    fn label(self) -> &'static str {
+        match self {
+            SortOrder::Added => t("添加时间"),
+            SortOrder::Progress => t("观看进度"),
+        }
+    }
+}
+
+pub struct WatchLaterPage {
+    /// In API order, `grid` shows them sorted
+    items: Vec<WatchLaterItem>,
+    sort: SortOrder,
+    pub grid: VideoCardGrid,
+    loading: bool,
+    error_message: Option<String>,
+    /// Waiting for a second clear key press
+    confirm_clear: bool,
+    /// Result of the last clear
+    notice: Option<String>,
+    /// 多选模式，按 aid 勾选
+    select: MultiSelect<i64>,
+
+    last_click_time: Option<Instant>,
+    last_click_index: Option<usize>,
+}
+
+impl WatchLaterPage {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            sort: SortOrder::Added,
+            grid: VideoCardGrid::new().with_number_hints(),
+            loading: true,
+            error_message: None,
+            confirm_clear: false,
+            notice: None,
+            select: MultiSelect::default(),
+            last_click_time: None,
+            last_click_index: None,
+        }
+    }
+
+    pub async fn load(&mut self, api_client: &ApiClient) {
+        self.loading = true;
+        match api_client.get_watch_later().await {
+            Ok(items) => {
+                self.items = items;
+                self.error_message = None;
+                self.rebuild_grid();
+            }
+            Err(e) => {
+                self.error_message = Some(tf("加载稍后再看失败: {}", &[&e]));
+            }
+        }
+        self.loading = false;
+    }
+
+    /// Aids of the videos counted as watched (progress -1 or at least 95%)
+    pub fn finished_aids(&self) -> Vec<i64> {
+        self.items
+            .iter()
+            .filter(|item| item.is_finished())
+            .map(|item| item.aid)
+            .collect()
+    }
+
+    /// Take the videos out of the list before they are removed on the server, returning
+    /// them with their positions so a failed removal can put them back. `finished` tells
+    /// clearing watched videos from removing the checked ones
+    pub fn remove_items(&mut self, aids: &[i64], finished: bool) -> Vec<(usize, WatchLaterItem)> {
+        if !finished {
+            self.select.finish_remove(aids, &[]);
+        }
+        let aids: HashSet<i64> = aids.iter().copied().collect();
+        let mut removed = Vec::new();
+        for (idx, item) in std::mem::take(&mut self.items).into_iter().enumerate() {
+            if aids.contains(&item.aid) {
+                removed.push((idx, item));
+            } else {
+                self.items.push(item);
+            }
+        }
+        self.rebuild_grid();
+        self.notice = Some(if finished {
+            tf("✓ 已清除 {} 个已看完的视频", &[&removed.len()])
+        } else {
+            tf("✓ 已移出 {} 个视频", &[&removed.len()])
+        });
+        removed
+    }
+
+    /// Put back videos whose removal failed, at their old positions
+    pub fn restore_items(&mut self, items: Vec<(usize, WatchLaterItem)>) {
+        let failed = items.len();
+        // Ascending positions, so each lands where it was
+        for (idx, item) in items {
+            if self.items.iter().all(|i| i.aid != item.aid) {
+                self.items.insert(idx.min(self.items.len()), item);
+            }
+        }
+        self.rebuild_grid();
+        self.notice = Some(tf("{} 个视频清除失败，已恢复", &[&failed]));
+    }
+
+    /// Fill the grid in the current order, keeping the selected video selected
+    fn rebuild_grid(&mut self) {
+        let selected_aid = self.grid.selected_card().and_then(|card| card.aid);
+
+        let mut items: Vec<&WatchLaterItem> = self.items.iter().collect();
+        if self.sort == SortOrder::Progress {
+            // Stable sort, so equal progress keeps the added order
+            items.sort_by_key(|item| item.progress_percent());
+        }
+
+        self.grid.clear();
+        for item in items {
+            let progress = item.format_progress();
+            let duration = if progress.is_empty() {
+                item.format_duration()
+            } else {
+                format!("{} · {}", item.format_duration(), progress)
+            };
+            let mut card = VideoCard::new(
+                Some(item.bvid.clone()),
+                Some(item.aid),
+                item.title.clone(),
+                item.owner
+                    .as_ref()
+                    .map(|o| o.name.clone())
+                    .unwrap_or_default(),
+                item.format_play(),
+                duration,
+                item.cover_url(),
+            );
+            if item.videos > 1 {
+                card.add_badge(CardBadge::MultiPart(item.videos));
+            }
+            self.grid.add_card(card);
+        }
+        self.select.mark_cards(&mut self.grid.cards);
+
+        if let Some(index) =
+            selected_aid.and_then(|aid| self.grid.cards.iter().position(|c| c.aid == Some(aid)))
+        {
+            self.grid.select(index);
+        }
+    }
+
+    fn cycle_sort(&mut self, forward: bool) {
+        let len = SortOrder::ALL.len();
+        let current = SortOrder::ALL
+            .iter()
+            .position(|s| *s == self.sort)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+        self.sort = SortOrder::ALL[next];
+        self.rebuild_grid();
+    }
+
+    fn render_tabs(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let finished = self.items.iter().filter(|item| item.is_finished()).count();
+        let mut spans = vec![Span::styled(
+            t("排序: "),
+            Style::default().fg(theme.fg_muted),
+        )];
+        for (i, sort) in SortOrder::ALL.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            let style = if *sort == self.sort {
+                Style::default()
+                    .fg(theme.fg_accent)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::UNDERLINED)
+            } else {
+                Style::default().fg(theme.fg_secondary)
+            };
+            spans.push(Span::styled(sort.label(), style));
+        }
+        spans.push(Span::styled(
+            tf(
+                "    共 {} 个，已看完 {} 个",
+                &[&self.items.len(), &finished],
+            ),
+            Style::default().fg(theme.fg_muted),
+        ));
+
+        let tabs = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border_subtle))
+                .title(Span::styled(
+                    t(" 🕒 稍后再看 "),
+                    Style::default().fg(theme.bilibili_pink),
+                )),
+        );
+        frame.render_widget(tabs, area);
+    }
+
+    fn layout(area: Rect) -> std::rc::Rc<[Rect]> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Sort tabs
+                Constraint::Min(10),   // Videos grid
+                Constraint::Length(1), // Clear notice
+                Constraint::Length(2), // Help
+            ])
+            .split(area)
+    }
+
+    /// Keys while in multi-select mode; navigation falls through to the normal handler
+    fn handle_multi_select_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        let current = self.grid.selected_card().and_then(|card| card.aid);
+        let action = match self.select.handle_input(key, keys, current) {
+            MultiSelectKey::Handled => AppAction::None,
+            MultiSelectKey::Remove(aids) => AppAction::RemoveWatchLater(aids),
+            MultiSelectKey::Enqueue => {
+                let bvids = self.select.marked_bvids(&self.grid.cards);
+                self.select.finish_enqueue(bvids.len());
+                AppAction::EnqueueVideos(bvids)
+            }
+            MultiSelectKey::Unhandled => return None,
+        };
+        self.select.mark_cards(&mut self.grid.cards);
+        Some(action)
+    }
+
+    fn selected_video(&self, index: usize) -> Option<(String, i64, String)> {
+        let card = self.grid.cards.get(index)?;
+        Some((card.bvid.clone()?, card.aid?, card.title.clone()))
+    }
+}
+
+impl Default for WatchLaterPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for WatchLaterPage {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let chunks = Self::layout(area);
+
+        self.render_tabs(frame, chunks[0], theme);
+
+        if self.loading {
+            let loading = Paragraph::new(t("⏳ 加载中..."))
+                .style(Style::default().fg(theme.warning))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading, chunks[1]);
+        } else if let Some(error) = &self.error_message {
+            let error_widget = Paragraph::new(format!("❌ {}", error))
+                .style(Style::default().fg(theme.error))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_widget, chunks[1]);
+        } else if self.grid.cards.is_empty() {
+            let empty = Paragraph::new(t("稍后再看是空的"))
+                .style(Style::default().fg(theme.fg_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            self.grid.render(frame, chunks[1], theme);
+        }
+
+        let status = if self.select.is_active() {
+            self.select
+                .render_bar(frame, chunks[2], theme, keys, t("移出稍后再看"));
+            None
+        } else if self.confirm_clear {
+            Some((
+                tf(
+                    "确认清除 {} 个已看完的视频？再按 [{}] 确认，其他键取消",
+                    &[&self.finished_aids().len(), &keys.batch_delete],
+                ),
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            self.notice
+                .clone()
+                .map(|notice| (notice, Style::default().fg(theme.fg_muted)))
+        };
+        if let Some((text, style)) = status {
+            let status = Paragraph::new(text)
+                .style(style)
+                .alignment(Alignment::Center);
+            frame.render_widget(status, chunks[2]);
+        }
+
+        let help_text = tf(
+            "[{}/{}] 导航  [{}/{}] 切换排序  [{}] 详情  [{}] 更多  [{}] 多选  [{}] 清除已看完  [{}] 刷新",
+            &[
+                &keys.get_arrow_keys_display(),
+                &keys.get_nav_keys_display(),
+                &keys.section_prev,
+                &keys.section_next,
+                &keys.confirm,
+                &keys.play,
+                &keys.multi_select,
+                &keys.batch_delete,
+                &keys.refresh,
+            ],
+        );
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.fg_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[3]);
+    }
+
+    fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if self.select.is_active() {
+            if let Some(action) = self.handle_multi_select_input(key, keys) {
+                return Some(action);
+            }
+        } else if keys.matches_multi_select(key) && !self.grid.cards.is_empty() {
+            self.select.start();
+            self.select.mark_cards(&mut self.grid.cards);
+            return Some(AppAction::None);
+        }
+        if std::mem::take(&mut self.confirm_clear) {
+            if keys.matches_batch_delete(key) {
+                return Some(AppAction::ClearWatchedLater(self.finished_aids()));
+            }
+            return Some(AppAction::None);
+        }
+
+        if keys.matches_quit(key) || keys.matches_back(key) {
+            return Some(AppAction::BackToList);
+        }
+        if keys.matches_nav_next(key) {
+            return Some(AppAction::NavNext);
+        }
+        if keys.matches_nav_prev(key) {
+            return Some(AppAction::NavPrev);
+        }
+        if keys.matches_next_theme(key) {
+            return Some(AppAction::NextTheme);
+        }
+        if keys.matches_section_next(key) || keys.matches_section_prev(key) {
+            self.cycle_sort(keys.matches_section_next(key));
+            return Some(AppAction::None);
+        }
+        if keys.matches_refresh(key) {
+            self.notice = None;
+            return Some(AppAction::LoadWatchLater);
+        }
+        if keys.matches_batch_delete(key) && !self.select.is_active() {
+            if self.finished_aids().is_empty() {
+                self.notice = Some(t("没有已看完的视频").to_string());
+            } else {
+                self.confirm_clear = true;
+            }
+            return Some(AppAction::None);
+        }
+        if keys.matches_down(key) {
+            self.grid.move_down();
+            return Some(AppAction::None);
+        }
+        if keys.matches_up(key) {
+            self.grid.move_up();
+            return Some(AppAction::None);
+        }
+        if keys.matches_right(key) {
+            self.grid.move_right();
+            return Some(AppAction::None);
+        }
+        if keys.matches_left(key) {
+            self.grid.move_left();
+            return Some(AppAction::None);
+        }
+        if keys.matches_play(key) {
+            if let Some((bvid, aid, title)) = self.selected_video(self.grid.selected_index) {
+                return Some(AppAction::OpenActionMenu { bvid, aid, title });
+            }
+            return Some(AppAction::None);
+        }
+        if self.grid.select_visible(key) || keys.matches_confirm(key) {
+            if let Some((bvid, aid, _)) = self.selected_video(self.grid.selected_index) {
+                return Some(AppAction::OpenVideoDetail(bvid, aid));
+            }
+            return Some(AppAction::None);
+        }
+        Some(AppAction::None)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> Option<AppAction> {
+        if self.grid.handle_scrollbar_mouse(&event) {
+            return None;
+        }
+
+        match event.kind {
+            MouseEventKind::ScrollDown => {
+                self.grid.move_down();
+                None
+            }
+            MouseEventKind::ScrollUp => {
+                self.grid.move_up();
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let click_idx = self.grid.index_at(event.column, event.row)?;
+
+                let now = Instant::now();
+                let is_double_click = self.last_click_index == Some(click_idx)
+                    && self
+                        .last_click_time
+                        .is_some_and(|t| now.duration_since(t).as_millis() < 500);
+
+                if is_double_click {
+                    self.last_click_time = None;
+                    self.last_click_index = None;
+                    if let Some((bvid, aid, _)) = self.selected_video(click_idx) {
+                        return Some(AppAction::OpenVideoDetail(bvid, aid));
+                    }
+                } else {
+                    self.grid.select(click_idx);
+                    self.last_click_time = Some(now);
+                    self.last_click_index = Some(click_idx);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn init<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, ()> {
+        Box::pin(self.load(ctx.client))
+    }
+
+    fn tick<'a>(&'a mut self, ctx: PageContext<'a>) -> LocalBoxFuture<'a, Option<AppAction>> {
+        self.grid.poll_cover_results();
+        if !ctx.throttled {
+            self.grid.start_cover_downloads();
+        }
+        Box::pin(async { None })
+    }
+
+    fn set_blocklist(&mut self, blocklist: Arc<TitleBlocklist>) {
+        self.grid.blocklist = blocklist;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(aid: i64) -> WatchLaterItem {
+        WatchLaterItem {
+            aid,
+            bvid: format!("BV{}", aid),
+            title: String::new(),
+            pic: None,
+            duration: 0,
+            progress: 0,
+            owner: None,
+            stat: None,
+            videos: 1,
+        }
+    }
+
+    #[test]
+    fn test_restore_keeps_positions() {
+        let mut page = WatchLaterPage::new();
+        page.items = (1..=5).map(item).collect();
+        let removed = page.remove_items(&[2, 4, 5], true);
+        assert_eq!(page.items.len(), 2);
+
+        page.restore_items(removed);
+        let aids: Vec<i64> = page.items.iter().map(|i| i.aid).collect();
+        assert_eq!(aids, [1, 2, 3, 4, 5]);
+    }
+}
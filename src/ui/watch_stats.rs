@@ -0,0 +1,235 @@
+//! Watch stats page: totals, category distribution and most watched UPs from the local watch log
+
+use super::{Component, Theme};
+use crate::app::AppAction;
+use crate::i18n::{t, tf};
+use crate::storage::watch_log::{self, WatchSummary};
+use crate::storage::Keybindings;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets::*};
+
+/// Rows shown in each ranking
+const TOP_N: usize = 12;
+/// Display width reserved for names in front of the bars
+const NAME_WIDTH: usize = 14;
+
+#[derive(Default)]
+pub struct WatchStatsPage {
+    summary: WatchSummary,
+    /// Earliest recorded playback (Unix timestamp)
+    since: Option<i64>,
+    error_message: Option<String>,
+}
+
+impl WatchStatsPage {
+    pub fn new() -> Self {
+        let mut page = Self::default();
+        page.reload();
+        page
+    }
+
+    /// Re-read the watch log from disk
+    pub fn reload(&mut self) {
+        match watch_log::load_watch_log() {
+            Ok(entries) => {
+                self.summary = WatchSummary::from_entries(&entries);
+                self.since = entries.iter().map(|e| e.played_at).min();
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.error_message = Some(tf("读取观看记录失败: {}", &[&e]));
+            }
+        }
+    }
+
+    fn render_summary(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut spans = vec![
+            Span::styled(t("共播放 "), Style::default().fg(theme.fg_secondary)),
+            Span::styled(
+                self.summary.plays.to_string(),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                t(" 次 · 累计时长 "),
+                Style::default().fg(theme.fg_secondary),
+            ),
+            Span::styled(
+                format_duration(self.summary.total_duration),
+                Style::default()
+                    .fg(theme.bilibili_pink)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if let Some(since) = self.since {
+            spans.push(Span::styled(
+                tf(" · 自 {} 起", &[&crate::export::format_timestamp(since)]),
+                Style::default().fg(theme.fg_muted),
+            ));
+        }
+
+        let summary = Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border_subtle))
+                    .title(Span::styled(
+                        t(" 📊 观看统计 "),
+                        Style::default()
+                            .fg(theme.bilibili_pink)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            );
+        frame.render_widget(summary, area);
+    }
+
+    fn render_ranking(
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        title: &str,
+        items: &[(String, usize)],
+        color: Color,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border_unfocused))
+            .title(Span::styled(
+                format!(" {} ", title),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let max = items.first().map(|(_, count)| *count).unwrap_or(0).max(1);
+        let count_width = max.to_string().len() + 1;
+        let bar_width = (inner.width as usize).saturating_sub(NAME_WIDTH + count_width + 2);
+
+        let lines: Vec<Line> = items
+            .iter()
+            .take(TOP_N)
+            .map(|(name, count)| {
+                let name = if name.is_empty() { t("其他") } else { name };
+                let filled = (bar_width * count).div_ceil(max);
+                Line::from(vec![
+                    Span::styled(
+                        pad_to_width(name, NAME_WIDTH),
+                        Style::default().fg(theme.fg_primary),
+                    ),
+                    Span::raw(" "),
+                    Span::styled("█".repeat(filled), Style::default().fg(color)),
+                    Span::styled(
+                        format!(" {}", count),
+                        Style::default().fg(theme.fg_secondary),
+                    ),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+impl Component for WatchStatsPage {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, keys: &Keybindings) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Summary
+                Constraint::Min(5),    // Rankings
+                Constraint::Length(2), // Help
+            ])
+            .split(area);
+
+        self.render_summary(frame, chunks[0], theme);
+
+        if let Some(error) = &self.error_message {
+            let error_widget = Paragraph::new(format!("❌ {}", error))
+                .style(Style::default().fg(theme.error))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_widget, chunks[1]);
+        } else if self.summary.plays == 0 {
+            let empty = Paragraph::new(t("还没有播放记录，播放视频后会在这里生成统计"))
+                .style(Style::default().fg(theme.fg_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[1]);
+            Self::render_ranking(
+                frame,
+                columns[0],
+                theme,
+                t("分区分布"),
+                &self.summary.categories,
+                theme.bilibili_cyan,
+            );
+            Self::render_ranking(
+                frame,
+                columns[1],
+                theme,
+                t("常看 UP"),
+                &self.summary.top_ups,
+                theme.bilibili_pink,
+            );
+        }
+
+        let help = Paragraph::new(tf(
+            "[{}] 刷新  [{}] 切换页面",
+            &[&keys.refresh, &keys.nav_next_page],
+        ))
+        .style(Style::default().fg(theme.fg_secondary))
+        .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[2]);
+    }
+
+    fn handle_input(&mut self, key: KeyCode, keys: &Keybindings) -> Option<AppAction> {
+        if keys.matches_quit(key) || keys.matches_back(key) {
+            return Some(AppAction::BackToList);
+        }
+        if keys.matches_refresh(key) {
+            self.reload();
+            return Some(AppAction::None);
+        }
+        if keys.matches_nav_next(key) {
+            return Some(AppAction::NavNext);
+        }
+        if keys.matches_nav_prev(key) {
+            return Some(AppAction::NavPrev);
+        }
+        if keys.matches_next_theme(key) {
+            return Some(AppAction::NextTheme);
+        }
+        None
+    }
+}
+
+/// Truncate or pad a name to a fixed display width (CJK counts as 2)
+fn pad_to_width(name: &str, width: usize) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for c in name.chars() {
+        let w = Span::raw(c.to_string()).width();
+        if used + w > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push_str(&" ".repeat(width - used));
+    out
+}
+
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    if hours > 0 {
+        tf("{} 小时 {} 分", &[&hours, &minutes])
+    } else {
+        tf("{} 分钟", &[&minutes])
+    }
+}
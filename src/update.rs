@@ -0,0 +1,59 @@
+//! 启动时检查 GitHub Releases 上的新版本
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+/// Release page opened from the update notice
+pub const RELEASES_URL: &str = "https://github.com/MareDevi/bilibili-tui/releases/latest";
+
+const LATEST_RELEASE_API: &str =
+    "https://api.github.com/repos/MareDevi/bilibili-tui/releases/latest";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+}
+
+/// Latest release tag if it is newer than the running version
+pub async fn check_latest() -> Result<Option<String>> {
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("bilibili-tui/", env!("CARGO_PKG_VERSION")))
+        .timeout(std::time::Duration::from_secs(10))
+        .build()?;
+    let resp = client.get(LATEST_RELEASE_API).send().await?;
+    if !resp.status().is_success() {
+        return Err(anyhow!("GitHub API returned {}", resp.status()));
+    }
+    let release: Release = resp.json().await?;
+
+    Ok(is_newer(&release.tag_name, env!("CARGO_PKG_VERSION")).then_some(release.tag_name))
+}
+
+/// Compare dotted numeric versions, ignoring a leading `v` and any pre-release suffix
+fn is_newer(latest: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Vec<u64> {
+        version
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+    parse(latest) > parse(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("v1.0.10", "1.0.9"));
+        assert!(is_newer("v1.1.0", "1.0.9"));
+        assert!(!is_newer("v1.0.9", "1.0.9"));
+        assert!(!is_newer("v1.0.8", "1.0.9"));
+        assert!(!is_newer("v1.0.9-rc.1", "1.0.9"));
+    }
+}